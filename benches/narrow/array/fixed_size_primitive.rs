@@ -0,0 +1,79 @@
+use criterion::{BenchmarkId, Criterion, Throughput};
+use narrow::array::Uint32Array;
+#[cfg(feature = "std")]
+use narrow::compute::ValuesRef;
+use rand::{prelude::SmallRng, Rng, SeedableRng};
+
+pub(super) fn bench(c: &mut Criterion) {
+    from_iter(c);
+    extend(c);
+    #[cfg(feature = "std")]
+    valid_values(c);
+}
+
+fn from_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FixedSizePrimitiveArray::from_iter");
+    let mut rng = SmallRng::seed_from_u64(1234);
+
+    for size in [12345] {
+        for null_fraction in [0., 0.5, 1.] {
+            let input = (0..size)
+                .map(|_| rng.gen_bool(1. - null_fraction).then(|| rng.gen::<u32>()))
+                .collect::<Vec<_>>();
+            group.throughput(Throughput::Elements(size as u64));
+            group.bench_with_input(
+                BenchmarkId::new("narrow", format!("{size}/{null_fraction}")),
+                &input,
+                |b, input| b.iter(|| Uint32Array::<true>::from_iter(input.iter().copied())),
+            );
+        }
+    }
+}
+
+fn extend(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FixedSizePrimitiveArray::extend");
+    let mut rng = SmallRng::seed_from_u64(1234);
+
+    for size in [12345] {
+        for null_fraction in [0., 0.5, 1.] {
+            let input = (0..size)
+                .map(|_| rng.gen_bool(1. - null_fraction).then(|| rng.gen::<u32>()))
+                .collect::<Vec<_>>();
+            group.throughput(Throughput::Elements(size as u64));
+            group.bench_with_input(
+                BenchmarkId::new("narrow", format!("{size}/{null_fraction}")),
+                &input,
+                |b, input| {
+                    b.iter(|| {
+                        let mut array = Uint32Array::<true>::default();
+                        array.extend(input.iter().copied());
+                        array
+                    });
+                },
+            );
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn valid_values(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FixedSizePrimitiveArray::valid_values");
+    let mut rng = SmallRng::seed_from_u64(1234);
+
+    for size in [1_000_000] {
+        for null_fraction in [0.01, 0.5] {
+            let array = (0..size)
+                .map(|_| {
+                    rng.gen_bool(1. - null_fraction)
+                        .then(|| rng.gen_range(0..16))
+                })
+                .collect::<Uint32Array<true>>();
+            group.throughput(Throughput::Elements(size as u64));
+            group.bench_with_input(
+                BenchmarkId::new("sum", format!("{size}/{null_fraction}")),
+                &array,
+                |b, input| b.iter(|| input.valid_values().sum::<u32>()),
+            );
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use criterion::Criterion;
+
+mod fixed_size_primitive;
+
+pub(super) fn bench(c: &mut Criterion) {
+    fixed_size_primitive::bench(c);
+}
@@ -1,7 +1,9 @@
 use criterion::Criterion;
 
 mod iter;
+mod validity;
 
 pub(super) fn bench(c: &mut Criterion) {
     iter::bench(c);
+    validity::bench(c);
 }
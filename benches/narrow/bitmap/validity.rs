@@ -0,0 +1,24 @@
+use criterion::{BenchmarkId, Criterion, Throughput};
+use narrow::{
+    bitmap::{Bitmap, ValidityBitmap},
+    buffer::VecBuffer,
+};
+use rand::{prelude::SmallRng, Rng, SeedableRng};
+
+pub(super) fn bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ValidityBitmap");
+    let mut rng = SmallRng::seed_from_u64(1234);
+
+    let size = 1_000_000;
+    let all_valid = Bitmap::<VecBuffer>::new_valid(size);
+    let mixed = (0..size).map(|_| rng.gen_bool(0.5)).collect::<Bitmap>();
+    group.throughput(Throughput::Bytes((size / 8) as u64));
+    group.bench_with_input(
+        BenchmarkId::new("all_valid", size),
+        &all_valid,
+        |b, input| b.iter(|| input.all_valid()),
+    );
+    group.bench_with_input(BenchmarkId::new("null_count", size), &mixed, |b, input| {
+        b.iter(|| input.null_count())
+    });
+}
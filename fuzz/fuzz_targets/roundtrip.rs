@@ -0,0 +1,28 @@
+//! Round-trips arrays through `into_iter` and `collect`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use narrow::array::{BooleanArray, Int64Array, NullArray, Uint8Array};
+
+fuzz_target!(|arrays: (
+    Uint8Array<true>,
+    Int64Array,
+    BooleanArray<true>,
+    NullArray<(), true>
+)| {
+    let (uint8, int64, boolean, null) = arrays;
+    assert_eq!(
+        uint8.clone().into_iter().collect::<Uint8Array<true>>(),
+        uint8
+    );
+    assert_eq!(int64.clone().into_iter().collect::<Int64Array>(), int64);
+    assert_eq!(
+        boolean.clone().into_iter().collect::<BooleanArray<true>>(),
+        boolean
+    );
+    assert_eq!(
+        null.clone().into_iter().collect::<NullArray<(), true>>(),
+        null
+    );
+});
@@ -2,9 +2,9 @@ use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
 use std::iter;
 use syn::{
-    parse2, parse_quote, punctuated::Punctuated, token, visit_mut::VisitMut, DeriveInput, Field,
-    Fields, Generics, Ident, Index, ItemImpl, ItemStruct, Token, Type, TypeParamBound, Variant,
-    Visibility, WhereClause, WherePredicate,
+    parse2, parse_quote, punctuated::Punctuated, token, visit_mut::VisitMut, DeriveInput, Error,
+    Field, Fields, Generics, Ident, Index, ItemImpl, ItemStruct, LitInt, Token, Type,
+    TypeParamBound, Variant, Visibility, WhereClause, WherePredicate,
 };
 
 use crate::util::{self, AddTypeParam, AddTypeParamBound, SelfReplace};
@@ -13,7 +13,11 @@ pub(super) fn derive(
     input: &DeriveInput,
     variants: &Punctuated<Variant, token::Comma>,
 ) -> TokenStream {
-    let input = Enum::new(input, variants);
+    let type_ids = match type_ids(variants) {
+        Ok(type_ids) => type_ids,
+        Err(error) => return error.to_compile_error(),
+    };
+    let input = Enum::new(input, variants, type_ids);
 
     // Generate the conversion to i8
     let i8_conversion = input.i8_conversion();
@@ -33,6 +37,12 @@ pub(super) fn derive(
     // Generate a default impl for the wrapper struct def.
     let array_struct_default_impl = input.array_struct_default_impl();
 
+    // Generate a memory usage impl for the wrapper struct def.
+    let array_struct_memory_usage_impl = input.array_struct_memory_usage_impl();
+
+    // Generate a variant lengths impl for the wrapper struct def.
+    let array_struct_variant_lengths_impl = input.array_struct_variant_lengths_impl();
+
     // Generate an extend impl for the wrapper struct def.
     let array_struct_extend_dense_impl = input.array_struct_extend_dense_impl();
 
@@ -70,6 +80,10 @@ pub(super) fn derive(
 
         #array_struct_default_impl
 
+        #array_struct_memory_usage_impl
+
+        #array_struct_variant_lengths_impl
+
         #array_struct_extend_dense_impl
 
         #array_struct_extend_sparse_impl
@@ -112,20 +126,72 @@ pub(super) fn derive(
     tokens
 }
 
+/// Returns the type ids of the variants.
+///
+/// The type id of a variant is set with `#[narrow(type_id = 7)]`. Like enum
+/// discriminants, the type id of a variant without this attribute is the type
+/// id of the previous variant plus one, or zero for the first variant.
+fn type_ids(variants: &Punctuated<Variant, token::Comma>) -> syn::Result<Vec<i8>> {
+    let mut type_ids = Vec::with_capacity(variants.len());
+    let mut next = Some(0);
+    for variant in variants {
+        let mut explicit = None;
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("narrow"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("type_id") {
+                    let lit = meta.value()?.parse::<LitInt>()?;
+                    let type_id = lit
+                        .base10_parse::<i8>()
+                        .ok()
+                        .filter(|type_id| *type_id >= 0)
+                        .ok_or_else(|| Error::new(lit.span(), "type id must be in 0..=127"))?;
+                    explicit = Some((type_id, lit.span()));
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported narrow attribute"))
+                }
+            })?;
+        }
+        let (type_id, span) = match explicit {
+            Some(explicit) => explicit,
+            None => (
+                next.ok_or_else(|| Error::new(variant.ident.span(), "type id must be in 0..=127"))?,
+                variant.ident.span(),
+            ),
+        };
+        if type_ids.contains(&type_id) {
+            return Err(Error::new(span, format!("duplicate type id {type_id}")));
+        }
+        type_ids.push(type_id);
+        next = type_id.checked_add(1);
+    }
+    Ok(type_ids)
+}
+
 struct Enum<'a> {
     vis: &'a Visibility,
     ident: &'a Ident,
     generics: &'a Generics,
     variants: &'a Punctuated<Variant, token::Comma>,
+    type_ids: Vec<i8>,
 }
 
 impl<'a> Enum<'a> {
-    pub fn new(input: &'a DeriveInput, variants: &'a Punctuated<Variant, token::Comma>) -> Self {
+    pub fn new(
+        input: &'a DeriveInput,
+        variants: &'a Punctuated<Variant, token::Comma>,
+        type_ids: Vec<i8>,
+    ) -> Self {
         Self {
             vis: &input.vis,
             ident: &input.ident,
             generics: &input.generics,
             variants,
+            type_ids,
         }
     }
     fn variant_fields(&self) -> impl Iterator<Item = &Fields> + '_ {
@@ -137,6 +203,9 @@ impl<'a> Enum<'a> {
     fn variant_indices(&self) -> impl Iterator<Item = Literal> {
         (0..self.variants.len()).map(Literal::usize_unsuffixed)
     }
+    fn variant_type_ids(&self) -> impl Iterator<Item = Literal> + '_ {
+        self.type_ids.iter().copied().map(Literal::i8_unsuffixed)
+    }
     fn variant_helper_idents_idents(&self) -> impl Iterator<Item = Ident> + '_ {
         self.variant_idents()
             .map(|ident| format_ident!("{}Variant{ident}", self.ident))
@@ -250,14 +319,11 @@ impl<'a> Enum<'a> {
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let variants = self
             .variant_pattern_ignore()
-            .enumerate()
-            .map(|(idx, pattern)| {
-                let idx = Literal::usize_unsuffixed(idx);
-                quote!(#pattern => #idx)
-            });
+            .zip(self.variant_type_ids())
+            .map(|(pattern, type_id)| quote!(#pattern => #type_id));
         let tokens = quote! {
-            impl #impl_generics ::std::convert::From<&#ident #ty_generics> for ::std::primitive::i8 #where_clause {
-                fn from(value: &#ident #ty_generics) -> ::std::primitive::i8 {
+            impl #impl_generics ::core::convert::From<&#ident #ty_generics> for ::core::primitive::i8 #where_clause {
+                fn from(value: &#ident #ty_generics) -> ::core::primitive::i8 {
                     match *value {
                         #(
                             #ident::#variants,
@@ -476,13 +542,11 @@ impl<'a> Enum<'a> {
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let fields = self
-            .variants
-            .iter()
-            .enumerate()
-            .map(|(idx, _)| {
-                let idx = Literal::usize_unsuffixed(idx);
+            .variant_indices()
+            .zip(self.variant_type_ids())
+            .map(|(idx, type_id)| {
                 quote! {
-                    #idx => {
+                    #type_id => {
                         self.#idx.next().map(<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::from_data)
                     }
 
@@ -490,7 +554,7 @@ impl<'a> Enum<'a> {
             }).chain(iter::once(
                 quote! {
                     _ => {
-                        panic!("type id greater than number of variants");
+                        panic!("type id not mapped to a variant");
                     }
                 }
             ));
@@ -500,7 +564,7 @@ impl<'a> Enum<'a> {
             impl #impl_generics #narrow::array::union::TypeIdIterator for #into_iter_ident #ty_generics #where_clause {
                 type Enum = #self_ident #self_ty_generics;
 
-                fn next(&mut self, type_id: ::std::primitive::i8) -> ::core::option::Option<Self::Enum> {
+                fn next(&mut self, type_id: ::core::primitive::i8) -> ::core::option::Option<Self::Enum> {
                     match type_id {
                         #(
                             #fields,
@@ -554,14 +618,13 @@ impl<'a> Enum<'a> {
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let fields = self
-            .variants
-            .iter()
+            .variant_type_ids()
             .enumerate()
-            .map(|(current_index, _)| {
+            .map(|(current_index, type_id)| {
                 let current_idx = Literal::usize_unsuffixed(current_index);
                 let other_idx = (0..self.variants.len()).filter(|&var_idx| current_index != var_idx).map(Literal::usize_unsuffixed);
                 quote! {
-                    #current_idx => {
+                    #type_id => {
                         #(
                             self.#other_idx.next();
                         )*
@@ -572,7 +635,7 @@ impl<'a> Enum<'a> {
             }).chain(iter::once(
                 quote! {
                     _ => {
-                        panic!("type id greater than number of variants");
+                        panic!("type id not mapped to a variant");
                     }
                 }
             ));
@@ -582,7 +645,7 @@ impl<'a> Enum<'a> {
             impl #impl_generics #narrow::array::union::TypeIdIterator for #into_iter_ident #ty_generics #where_clause {
                 type Enum = #self_ident #self_ty_generics;
 
-                fn next(&mut self, type_id: ::std::primitive::i8) -> ::core::option::Option<Self::Enum> {
+                fn next(&mut self, type_id: ::core::primitive::i8) -> ::core::option::Option<Self::Enum> {
                     match type_id {
                         #(
                             #fields,
@@ -675,6 +738,54 @@ impl<'a> Enum<'a> {
         parse2(tokens).expect("array_union_array_iterators_impl")
     }
 
+    // Adds a variant lengths impl for the array wrapper struct.
+    fn array_struct_variant_lengths_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        let variants = Literal::usize_unsuffixed(self.variants.len());
+
+        // Generics
+        let self_generics = self.generics.clone();
+        let (_, self_ty_generics, _) = self_generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(OffsetItem: #narrow::offset::OffsetElement))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(UnionLayout: #narrow::array::UnionType))
+            .visit_generics_mut(&mut generics);
+        let self_ident = self.ident;
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(
+                self.variant_indices()
+                    .map::<WherePredicate, _>(|idx|
+                        parse_quote!(
+                            <<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, UnionLayout>
+                        : #narrow::Length)
+                    )
+            );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let ident = self.array_struct_ident();
+        let field = self
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(idx, _)| Index::from(idx))
+            .collect::<Vec<_>>();
+        let tokens = quote! {
+            impl #impl_generics #narrow::array::union::VariantLengths<#variants> for #ident #ty_generics #where_clause {
+                fn variant_lengths(&self) -> [usize; #variants] {
+                    [#(#narrow::Length::len(&self.#field)),*]
+                }
+            }
+        };
+        parse2(tokens).expect("array_struct_variant_lengths_impl")
+    }
+
     // Adds a default impl for the array wrapper struct.
     fn array_struct_clone_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
@@ -700,7 +811,7 @@ impl<'a> Enum<'a> {
                     .map::<WherePredicate, _>(|idx|
                         parse_quote!(
                             <<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, UnionLayout>
-                        : ::std::clone::Clone)
+                        : ::core::clone::Clone)
                     )
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -712,7 +823,7 @@ impl<'a> Enum<'a> {
             .map(|(idx, _)| Index::from(idx))
             .map(|idx| quote!(self.#idx.clone()));
         let tokens = quote! {
-            impl #impl_generics ::std::clone::Clone for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::clone::Clone for #ident #ty_generics #where_clause {
                 fn clone(&self) -> Self {
                     Self(
                         #(
@@ -725,6 +836,56 @@ impl<'a> Enum<'a> {
         parse2(tokens).expect("array_struct_clone_impl")
     }
 
+    // Adds a memory usage impl for the array wrapper struct.
+    fn array_struct_memory_usage_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let self_generics = self.generics.clone();
+        let (_, self_ty_generics, _) = self_generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(OffsetItem: #narrow::offset::OffsetElement))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(UnionLayout: #narrow::array::UnionType))
+            .visit_generics_mut(&mut generics);
+        let self_ident = self.ident;
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(
+                self.variant_indices()
+                    .map::<WherePredicate, _>(|idx|
+                        parse_quote!(
+                            <<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, UnionLayout>
+                        : #narrow::MemoryUsage)
+                    )
+            );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let ident = self.array_struct_ident();
+        let field = self
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(idx, _)| Index::from(idx))
+            .collect::<Vec<_>>();
+        let tokens = quote! {
+            impl #impl_generics #narrow::MemoryUsage for #ident #ty_generics #where_clause {
+                fn buffer_bytes(&self) -> usize {
+                    #(#narrow::MemoryUsage::buffer_bytes(&self.#field))+*
+                }
+
+                fn allocated_bytes(&self) -> usize {
+                    #(#narrow::MemoryUsage::allocated_bytes(&self.#field))+*
+                }
+            }
+        };
+        parse2(tokens).expect("array_struct_memory_usage_impl")
+    }
+
     // Adds a default impl for the array wrapper struct.
     fn array_struct_default_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
@@ -750,7 +911,7 @@ impl<'a> Enum<'a> {
                     .map::<WherePredicate, _>(|idx|
                         parse_quote!(
                             <<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, UnionLayout>
-                        : ::std::default::Default)
+                        : ::core::default::Default)
                     )
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -758,9 +919,9 @@ impl<'a> Enum<'a> {
         let default_fields = self
             .variants
             .iter()
-            .map(|_| quote!(::std::default::Default::default()));
+            .map(|_| quote!(::core::default::Default::default()));
         let tokens = quote! {
-            impl #impl_generics ::std::default::Default for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
                 fn default() -> Self {
                     Self(
                         #(
@@ -801,7 +962,7 @@ impl<'a> Enum<'a> {
             .predicates
             .extend(
                 self.variant_indices().zip(struct_defs).map::<WherePredicate, _>(|(idx, struct_def)|{
-                    parse_quote!(<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, #narrow::array::DenseLayout>: ::std::iter::Extend<#struct_def>)
+                    parse_quote!(<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, #narrow::array::DenseLayout>: ::core::iter::Extend<#struct_def>)
                 })
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -822,7 +983,7 @@ impl<'a> Enum<'a> {
                             .collect::<Vec<_>>();
                         quote! {
                             #self_ident::#ident { #( #field_idents, )* } => {
-                                self.#idx.extend(::std::iter::once(#variant_ident { #( #field_idents, )* }));
+                                self.#idx.extend(::core::iter::once(#variant_ident { #( #field_idents, )* }));
                             }
                         }
                     }
@@ -830,13 +991,13 @@ impl<'a> Enum<'a> {
                         let field_idx = unnamed.unnamed.iter().enumerate().map(|(idx, _)| format_ident!("_{idx}")).collect::<Vec<_>>();
                         quote! {
                             #self_ident::#ident (#( #field_idx, )*) => {
-                                self.#idx.extend(::std::iter::once(#variant_ident( #( #field_idx, )* )));
+                                self.#idx.extend(::core::iter::once(#variant_ident( #( #field_idx, )* )));
                             }
                         }
                     },
                     Fields::Unit => quote! {
                         #self_ident::#ident => {
-                            self.#idx.extend(::std::iter::once(()));
+                            self.#idx.extend(::core::iter::once(()));
                         }
                     },
                 }
@@ -844,7 +1005,7 @@ impl<'a> Enum<'a> {
 
         let ident = self.array_struct_ident();
         let mut item_impl: ItemImpl = parse_quote! {
-            impl #impl_generics ::std::iter::Extend<#self_ident #self_ty_generics> for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::iter::Extend<#self_ident #self_ty_generics> for #ident #ty_generics #where_clause {
                 fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item = #self_ident #self_ty_generics> {
                     iter.into_iter().for_each(|variant| {
                         match variant {
@@ -900,7 +1061,7 @@ impl<'a> Enum<'a> {
             .predicates
             .extend(
                 self.variant_indices().zip(struct_defs).map::<WherePredicate, _>(|(idx, struct_def)|{
-                    parse_quote!(<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, #narrow::array::SparseLayout>: ::std::iter::Extend<#struct_def>)
+                    parse_quote!(<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, #narrow::array::SparseLayout>: ::core::iter::Extend<#struct_def>)
                 })
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -923,9 +1084,9 @@ impl<'a> Enum<'a> {
                             .collect::<Vec<_>>();
                         quote! {
                             #self_ident::#ident { #( #field_idents, )* } => {
-                                self.#idx.extend(::std::iter::once(#variant_ident { #( #field_idents, )* }));
+                                self.#idx.extend(::core::iter::once(#variant_ident { #( #field_idents, )* }));
                                 #(
-                                    self.#other_idx.extend(::std::iter::once(::std::default::Default::default()));
+                                    self.#other_idx.extend(::core::iter::once(::core::default::Default::default()));
                                 )*
                             }
                         }
@@ -934,9 +1095,9 @@ impl<'a> Enum<'a> {
                         let field_idx = unnamed.unnamed.iter().enumerate().map(|(idx, _)| format_ident!("_{idx}")).collect::<Vec<_>>();
                         quote! {
                             #self_ident::#ident (#( #field_idx, )*) => {
-                                self.#idx.extend(::std::iter::once(#variant_ident( #( #field_idx, )* )));
+                                self.#idx.extend(::core::iter::once(#variant_ident( #( #field_idx, )* )));
                                 #(
-                                    self.#other_idx.extend(::std::iter::once(::std::default::Default::default()));
+                                    self.#other_idx.extend(::core::iter::once(::core::default::Default::default()));
                                 )*
                             }
                         }
@@ -944,16 +1105,16 @@ impl<'a> Enum<'a> {
                     Fields::Unit => {
                         quote! {
                         #self_ident::#ident => {
-                            self.#idx.extend(::std::iter::once(()));
+                            self.#idx.extend(::core::iter::once(()));
                             #(
-                                self.#other_idx.extend(::std::iter::once(::std::default::Default::default()));
+                                self.#other_idx.extend(::core::iter::once(::core::default::Default::default()));
                             )*
                         }
                     }},
                 }
             });
         let mut item_impl: ItemImpl = parse_quote! {
-            impl #impl_generics ::std::iter::Extend<#self_ident #self_ty_generics> for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::iter::Extend<#self_ident #self_ty_generics> for #ident #ty_generics #where_clause {
                 fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item = #self_ident #self_ty_generics> {
                     iter.into_iter().for_each(|variant| {
                         match variant {
@@ -999,8 +1160,11 @@ impl<'a> Enum<'a> {
 
         let self_ident = self.ident;
         let ident = self.array_struct_ident();
+        let type_ids = self.variant_type_ids();
         let tokens = quote! {
             impl #impl_generics #narrow::array::UnionArrayType<#variants> for #self_ident #ty_generics #where_clause {
+                const TYPE_IDS: [::core::primitive::i8; #variants] = [#(#type_ids,)*];
+
                 type Array<Buffer: #narrow::buffer::BufferType, OffsetItem: #narrow::offset::OffsetElement, UnionLayout: #narrow::array::UnionType> = #ident #array_ty_generics;
             }
         };
@@ -1045,6 +1209,7 @@ impl<'a> Enum<'a> {
         let idx = self.variant_indices().collect::<Vec<_>>();
         let variants = Literal::usize_unsuffixed(self.variants.len());
         let variant_idx = (0..self.variants.len()).map(|idx| idx.to_string());
+        let type_ids = self.variant_type_ids();
         let tokens = quote! {
             impl #impl_generics #narrow::arrow::UnionArrayTypeFields<#variants> for #ident #ty_generics #where_clause {
                 fn fields() -> ::arrow_schema::Fields {
@@ -1058,10 +1223,10 @@ impl<'a> Enum<'a> {
                         )*
                     ])
                 }
-                fn type_ids() -> [::std::primitive::i8; #variants] {
+                fn type_ids() -> [::core::primitive::i8; #variants] {
                     [
                         #(
-                            #idx,
+                            #type_ids,
                         )*
                     ]
                 }
@@ -1101,7 +1266,7 @@ impl<'a> Enum<'a> {
         let (_, ty_generics, _) = generics.split_for_impl();
         let idx = self.variant_indices();
         let tokens = quote! {
-            impl #impl_generics ::std::convert::From<#ident #ty_generics> for ::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>> #where_clause {
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for ::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>> #where_clause {
                 fn from(value: #ident #ty_generics) -> Self {
                     vec![
                         #(
@@ -1149,8 +1314,8 @@ impl<'a> Enum<'a> {
                 .into())
         });
         let tokens = quote! {
-            impl #impl_generics ::std::iter::FromIterator<::std::sync::Arc<dyn ::arrow_array::Array>> for #ident #ty_generics #where_clause {
-                fn from_iter<_I: ::std::iter::IntoIterator<Item = ::std::sync::Arc<dyn ::arrow_array::Array>>>(iter: _I) -> Self {
+            impl #impl_generics ::core::iter::FromIterator<::std::sync::Arc<dyn ::arrow_array::Array>> for #ident #ty_generics #where_clause {
+                fn from_iter<_I: ::core::iter::IntoIterator<Item = ::std::sync::Arc<dyn ::arrow_array::Array>>>(iter: _I) -> Self {
                     let mut iter = iter.into_iter();
                     const VARIANTS: usize = #len;
                     Self(
@@ -1164,3 +1329,53 @@ impl<'a> Enum<'a> {
         parse2(tokens).expect("union_array_from_iter_array_impl")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_ids() {
+        let input = quote!(
+            enum Foo {
+                A,
+                #[narrow(type_id = 5)]
+                B,
+                C,
+                #[narrow(type_id = 2)]
+                D,
+            }
+        );
+        let item_enum: syn::ItemEnum = parse2(input).unwrap();
+        assert_eq!(super::type_ids(&item_enum.variants).unwrap(), [0, 5, 6, 2]);
+
+        let duplicate = quote!(
+            enum Foo {
+                A,
+                #[narrow(type_id = 0)]
+                B,
+            }
+        );
+        let item_enum: syn::ItemEnum = parse2(duplicate).unwrap();
+        let error = super::type_ids(&item_enum.variants).unwrap_err();
+        assert_eq!(error.to_string(), "duplicate type id 0");
+        assert!(error
+            .to_compile_error()
+            .to_string()
+            .starts_with(":: core :: compile_error !"));
+
+        let out_of_range = quote!(
+            enum Foo {
+                #[narrow(type_id = 128)]
+                A,
+            }
+        );
+        let item_enum: syn::ItemEnum = parse2(out_of_range).unwrap();
+        assert_eq!(
+            super::type_ids(&item_enum.variants)
+                .unwrap_err()
+                .to_string(),
+            "type id must be in 0..=127"
+        );
+    }
+}
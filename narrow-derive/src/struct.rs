@@ -1,10 +1,11 @@
 use crate::util::{self, AddTypeParam, AddTypeParamBoundWithSelf, DropOuterParam, SelfReplace};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::iter::{Enumerate, Map};
 use syn::{
     parse2, parse_quote, punctuated, token::Paren, visit_mut::VisitMut, DeriveInput, Field, Fields,
-    Generics, Ident, Index, ItemImpl, ItemStruct, Type, TypeParamBound, Visibility, WherePredicate,
+    Generics, Ident, Index, ItemImpl, ItemStruct, LitStr, Type, TypeParamBound, Visibility,
+    WherePredicate,
 };
 
 pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
@@ -34,6 +35,18 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
     // Generate the Length implementation.
     let array_len_impl = input.array_len_impl();
 
+    // Generate the MemoryUsage implementation.
+    let array_memory_usage_impl = input.array_memory_usage_impl();
+
+    // Generate the field accessor methods for structs with named fields.
+    let array_field_accessors_impl = input.array_field_accessors_impl();
+
+    // Generate the StructArrayChildren implementation.
+    let array_children_impl = input.array_children_impl();
+
+    // Generate the StructArrayFields implementation.
+    let array_fields_impl = input.array_fields_impl();
+
     // Generate the Extend implementation.
     let array_extend_impl = input.array_extend_impl();
 
@@ -64,6 +77,14 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
 
         #array_len_impl
 
+        #array_memory_usage_impl
+
+        #array_field_accessors_impl
+
+        #array_children_impl
+
+        #array_fields_impl
+
         #array_extend_impl
 
         #array_from_iter_impl
@@ -173,13 +194,68 @@ impl Struct<'_> {
             })
     }
 
-    fn field_idents(&self) -> FieldIdents {
+    fn field_idents(&self) -> FieldIdents<'_> {
         self.fields
             .iter()
             .enumerate()
             .map(|(idx, Field { ident, .. })| ident.clone().unwrap_or(format_ident!("_{idx}")))
     }
 
+    /// Returns the names of the fields, using the name set with the
+    /// `#[narrow(rename = "...")]` attribute if present.
+    ///
+    /// Panics when a `narrow` attribute is malformed.
+    fn field_names(&self) -> Vec<String> {
+        self.field_attributes()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Returns the extension type names of the fields, set with the
+    /// `#[narrow(extension = "...")]` attribute.
+    ///
+    /// Panics when a `narrow` attribute is malformed.
+    fn field_extensions(&self) -> Vec<Option<String>> {
+        self.field_attributes()
+            .into_iter()
+            .map(|(_, extension)| extension)
+            .collect()
+    }
+
+    /// Returns the names and extension type names of the fields, parsed from
+    /// the `narrow` attributes of the fields.
+    ///
+    /// Panics when a `narrow` attribute is malformed.
+    fn field_attributes(&self) -> Vec<(String, Option<String>)> {
+        self.fields
+            .iter()
+            .zip(self.field_idents())
+            .map(|(Field { attrs, .. }, ident)| {
+                let mut name = ident.to_string();
+                let mut extension = None;
+                attrs
+                    .iter()
+                    .filter(|attr| attr.path().is_ident("narrow"))
+                    .for_each(|attr| {
+                        attr.parse_nested_meta(|meta| {
+                            if meta.path.is_ident("rename") {
+                                name = meta.value()?.parse::<LitStr>()?.value();
+                                Ok(())
+                            } else if meta.path.is_ident("extension") {
+                                extension = Some(meta.value()?.parse::<LitStr>()?.value());
+                                Ok(())
+                            } else {
+                                Err(meta.error("unsupported narrow attribute"))
+                            }
+                        })
+                        .expect("narrow attribute");
+                    });
+                (name, extension)
+            })
+            .collect()
+    }
+
     /// Add a `Unit` impl for the derive input.
     fn unit_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
@@ -215,7 +291,7 @@ impl Struct<'_> {
         let non_nullable: ItemImpl = parse2(non_nullable).expect("array_type_impl");
 
         let nullable = quote! {
-            impl #impl_generics #narrow::array::ArrayType<#ident #ty_generics> for ::std::option::Option<#ident #ty_generics> #where_clause {
+            impl #impl_generics #narrow::array::ArrayType<#ident #ty_generics> for ::core::option::Option<#ident #ty_generics> #where_clause {
                 type Array<Buffer: #narrow::buffer::BufferType, OffsetItem: #narrow::offset::OffsetElement, UnionLayout: #narrow::array::UnionType> = #narrow::array::StructArray<#ident #ty_generics, true, Buffer>;
             }
         };
@@ -280,12 +356,25 @@ impl Struct<'_> {
             })
         } else {
             // Fields
-            let field_ident = self.field_idents().map(|ident| ident.to_string());
+            let field_ident = self.field_names();
             let field_ty = self.field_types();
             let field_ty_drop = self.field_types_drop_option();
+            let field = field_ident.iter().zip(field_ty).zip(field_ty_drop).map(|((field_ident, field_ty), field_ty_drop)| {
+                quote!(<<#field_ty as ::narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as #narrow::arrow::Array>::as_field(#field_ident))
+            });
+            let field_annotated =
+                self.field_extensions()
+                    .into_iter()
+                    .zip(field)
+                    .map(|(extension, field)| match extension {
+                        Some(name) => {
+                            quote!(#narrow::array::ExtensionType::new(#name).annotate_field(#field))
+                        }
+                        None => field,
+                    });
             let fields = quote!(
                 #(
-                    ::std::sync::Arc::new(<<#field_ty as ::narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as #narrow::arrow::Array>::as_field(#field_ident)),
+                    ::std::sync::Arc::new(#field_annotated),
                 )*
             );
             quote! {
@@ -348,7 +437,7 @@ impl Struct<'_> {
 
         let ident = self.array_struct_ident();
         let tokens = quote! {
-            impl #impl_generics ::std::convert::From<#ident #ty_generics> for ::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>> #where_clause  {
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for ::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>> #where_clause  {
                 fn from(value: #ident #ty_generics) -> Self {
                     vec![
                         #field_arrays
@@ -374,7 +463,7 @@ impl Struct<'_> {
             .make_where_clause()
             .predicates
             .extend(self.where_predicate_fields(parse_quote!(
-                ::std::convert::From<::std::sync::Arc<dyn ::arrow_array::Array>>
+                ::core::convert::From<::std::sync::Arc<dyn ::arrow_array::Array>>
             )));
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -403,7 +492,7 @@ impl Struct<'_> {
         });
         let ident = self.array_struct_ident();
         let tokens = quote! {
-            impl #impl_generics ::std::convert::From<::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>> for #ident #ty_generics #where_clause  {
+            impl #impl_generics ::core::convert::From<::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>> for #ident #ty_generics #where_clause  {
                 fn from(value: ::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>) -> Self {
                     let mut arrays = value.into_iter();
                     let result = Self #field_arrays;
@@ -486,7 +575,7 @@ impl Struct<'_> {
         generics
             .make_where_clause()
             .predicates
-            .extend(self.where_predicate_fields(parse_quote!(::std::clone::Clone)));
+            .extend(self.where_predicate_fields(parse_quote!(::core::clone::Clone)));
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let clone_fields = self.surround_with_delimiters(match self.fields {
@@ -517,7 +606,7 @@ impl Struct<'_> {
 
         let ident = self.array_struct_ident();
         let tokens = quote!(
-            impl #impl_generics ::std::clone::Clone for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::clone::Clone for #ident #ty_generics #where_clause {
                 fn clone(&self) -> Self {
                     Self #clone_fields
                 }
@@ -538,7 +627,7 @@ impl Struct<'_> {
         generics
             .make_where_clause()
             .predicates
-            .extend(self.where_predicate_fields(parse_quote!(::std::default::Default)));
+            .extend(self.where_predicate_fields(parse_quote!(::core::default::Default)));
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let default_fields = self.surround_with_delimiters(match self.fields {
@@ -546,12 +635,12 @@ impl Struct<'_> {
                 let field_ident = self.field_idents();
                 quote!(
                     #(
-                        #field_ident: ::std::default::Default::default(),
+                        #field_ident: ::core::default::Default::default(),
                     )*
                 )
             }
             Fields::Unnamed(_) => {
-                let default_field = std::iter::repeat(quote!(::std::default::Default::default()))
+                let default_field = std::iter::repeat(quote!(::core::default::Default::default()))
                     .take(self.fields.len());
                 quote!(
                     #(
@@ -560,13 +649,13 @@ impl Struct<'_> {
                 )
             }
             Fields::Unit => {
-                quote!(::std::default::Default::default())
+                quote!(::core::default::Default::default())
             }
         });
 
         let ident = self.array_struct_ident();
         let tokens = quote!(
-            impl #impl_generics ::std::default::Default for #ident #ty_generics #where_clause {
+            impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
                 fn default() -> Self {
                     Self #default_fields
                 }
@@ -611,6 +700,194 @@ impl Struct<'_> {
         parse2(tokens).expect("array_len_impl")
     }
 
+    fn array_memory_usage_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::MemoryUsage)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let field = match self.fields {
+            Fields::Named(_) => self.field_idents().map(|ident| quote!(#ident)).collect(),
+            Fields::Unnamed(_) => self
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(idx, _)| {
+                    let idx = Index::from(idx);
+                    quote!(#idx)
+                })
+                .collect(),
+            Fields::Unit => vec![quote!(0)],
+        };
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::MemoryUsage for #ident #ty_generics #where_clause {
+                fn buffer_bytes(&self) -> usize {
+                    #(#narrow::MemoryUsage::buffer_bytes(&self.#field))+*
+                }
+
+                fn allocated_bytes(&self) -> usize {
+                    #(#narrow::MemoryUsage::allocated_bytes(&self.#field))+*
+                }
+            }
+        );
+        parse2(tokens).expect("array_memory_usage_impl")
+    }
+
+    /// Returns an impl with accessor methods for the field arrays of structs
+    /// with named fields.
+    fn array_field_accessors_impl(&self) -> Option<ItemImpl> {
+        let narrow = util::narrow();
+
+        if !matches!(self.fields, Fields::Named(_)) {
+            return None;
+        }
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let vis = self.vis;
+        let field_ident = self.field_idents();
+        let field_ty = self.field_types();
+        let field_ty_drop = self.field_types_drop_option();
+        let field_doc = self
+            .field_idents()
+            .map(|ident| format!("Returns a reference to the array with the `{ident}` values."));
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #(
+                    #[doc = #field_doc]
+                    #vis fn #field_ident(&self) -> &<#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> {
+                        &self.#field_ident
+                    }
+                )*
+            }
+        );
+        Some(parse2(tokens).expect("array_field_accessors_impl"))
+    }
+
+    /// Returns a `StructArrayChildren` impl for structs with fields. The
+    /// children are returned as `Any` trait objects, so this is skipped for
+    /// structs with lifetime parameters.
+    fn array_children_impl(&self) -> Option<ItemImpl> {
+        let narrow = util::narrow();
+
+        if matches!(self.fields, Fields::Unit) || self.generics.lifetimes().next().is_some() {
+            return None;
+        }
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        let where_clause = generics.make_where_clause();
+        where_clause.predicates.push(parse_quote!(Buffer: 'static));
+        where_clause
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!('static)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let field = match self.fields {
+            Fields::Named(_) => self.field_idents().map(|ident| quote!(#ident)).collect(),
+            _ => self
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(idx, _)| {
+                    let idx = Index::from(idx);
+                    quote!(#idx)
+                })
+                .collect::<Vec<_>>(),
+        };
+        let field_idx = (0..field.len()).map(Literal::usize_unsuffixed);
+        let num_children = Literal::usize_unsuffixed(field.len());
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::array::StructArrayChildren for #ident #ty_generics #where_clause {
+                fn num_children(&self) -> usize {
+                    #num_children
+                }
+
+                fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+                    match index {
+                        #(
+                            #field_idx => ::core::option::Option::Some(&self.#field),
+                        )*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        );
+        Some(parse2(tokens).expect("array_children_impl"))
+    }
+
+    /// Returns a `StructArrayFields` impl for structs with fields.
+    fn array_fields_impl(&self) -> Option<ItemImpl> {
+        let narrow = util::narrow();
+
+        if matches!(self.fields, Fields::Unit) {
+            return None;
+        }
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let field_name = self.field_names();
+        let field_nullable = self
+            .field_types()
+            .zip(self.field_types_drop_option())
+            .map(|(ty, ty_drop)| quote!(#ty).to_string() != quote!(#ty_drop).to_string());
+        let field_type_name = self.field_types().map(util::type_name);
+        let field_extension = self.field_extensions().into_iter().map(|extension| {
+            extension.map_or_else(
+                || quote!(::core::option::Option::None),
+                |name| quote!(::core::option::Option::Some(#narrow::array::ExtensionType::new(#name))),
+            )
+        });
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::array::StructArrayFields for #ident #ty_generics #where_clause {
+                const FIELDS: &'static [#narrow::array::FieldInfo] = &[
+                    #(
+                        #narrow::array::FieldInfo {
+                            name: #field_name,
+                            nullable: #field_nullable,
+                            type_name: #field_type_name,
+                            extension: #field_extension,
+                        },
+                    )*
+                ];
+            }
+        );
+        Some(parse2(tokens).expect("array_fields_impl"))
+    }
+
     fn array_extend_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
         let ident = self.ident;
@@ -623,14 +900,14 @@ impl Struct<'_> {
         // Array generics
         let mut generics = self.generics.clone();
         AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
-        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::GrowableBuffer))
             .visit_generics_mut(&mut generics);
         generics
             .make_where_clause()
             .predicates
             .extend(
                 self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::Extend<#ty>))
+                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::core::iter::Extend<#ty>))
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -647,7 +924,7 @@ impl Struct<'_> {
                 quote!(
                     iter.into_iter().for_each(|#ident #fields | {
                         #(
-                            self.#field_idx.extend(::std::iter::once(#field_ident));
+                            self.#field_idx.extend(::core::iter::once(#field_ident));
                         )*
                     });
                 )
@@ -658,7 +935,7 @@ impl Struct<'_> {
                 quote!(
                     iter.into_iter().for_each(|#ident #fields | {
                         #(
-                            self.#field_ident.extend(::std::iter::once(#field_ident));
+                            self.#field_ident.extend(::core::iter::once(#field_ident));
                         )*
                     });
                 )
@@ -666,8 +943,8 @@ impl Struct<'_> {
             Fields::Unit => quote!(self.0.extend(iter)),
         };
         let tokens = quote!(
-            impl #impl_generics ::std::iter::Extend<#ident #ident_ty_generics> for #array_struct_ident #ty_generics #where_clause {
-                fn extend<_I: ::std::iter::IntoIterator<Item = #ident #ident_ty_generics>>(&mut self, iter: _I) {
+            impl #impl_generics ::core::iter::Extend<#ident #ident_ty_generics> for #array_struct_ident #ty_generics #where_clause {
+                fn extend<_I: ::core::iter::IntoIterator<Item = #ident #ident_ty_generics>>(&mut self, iter: _I) {
                     #extend
                 }
             }
@@ -694,7 +971,7 @@ impl Struct<'_> {
             .predicates
             .extend(
                 self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::default::Default + ::std::iter::Extend<#ty>))
+                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::core::default::Default + ::core::iter::Extend<#ty>))
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -729,8 +1006,8 @@ impl Struct<'_> {
             Fields::Unit => quote!(Self(iter.into_iter().collect())),
         };
         let tokens = quote!(
-            impl #impl_generics ::std::iter::FromIterator<#ident #ident_ty_generics> for #array_struct_ident #ty_generics #where_clause {
-                fn from_iter<_I: ::std::iter::IntoIterator<Item = #ident #ident_ty_generics>>(iter: _I) -> Self {
+            impl #impl_generics ::core::iter::FromIterator<#ident #ident_ty_generics> for #array_struct_ident #ty_generics #where_clause {
+                fn from_iter<_I: ::core::iter::IntoIterator<Item = #ident #ident_ty_generics>>(iter: _I) -> Self {
                     #from_iter
                 }
             }
@@ -751,7 +1028,7 @@ impl Struct<'_> {
             .predicates
             .extend(
                 self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::IntoIterator<Item = #ty>))
+                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::core::iter::IntoIterator<Item = #ty>))
             );
         let (impl_generics, _, where_clause) = generics.split_for_impl();
 
@@ -765,7 +1042,7 @@ impl Struct<'_> {
                 let narrow = util::narrow();
                 quote!(
                     #(
-                        #field_vis <<#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as ::std::iter::IntoIterator>::IntoIter,
+                        #field_vis <<#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as ::core::iter::IntoIterator>::IntoIter,
                     )*
                 )
             }
@@ -777,7 +1054,7 @@ impl Struct<'_> {
                 let narrow = util::narrow();
                 quote!(
                     #(
-                        #field_vis #field_ident: <<#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as ::std::iter::IntoIterator>::IntoIter,
+                        #field_vis #field_ident: <<#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as ::core::iter::IntoIterator>::IntoIter,
                     )*
                 )
             }
@@ -790,7 +1067,7 @@ impl Struct<'_> {
                     .make_where_clause()
                     .predicates
                     .extend(
-                       std::iter::once::<WherePredicate>(parse_quote!(#narrow::array::NullArray<#ident #ty_generics, false, Buffer>: ::std::iter::IntoIterator<Item = #ident #ty_generics>))
+                       std::iter::once::<WherePredicate>(parse_quote!(#narrow::array::NullArray<#ident #ty_generics, false, Buffer>: ::core::iter::IntoIterator<Item = #ident #ty_generics>))
                     );
                 let (impl_generics, _, where_clause) = generics.split_for_impl();
                 let narrow = util::narrow();
@@ -830,7 +1107,7 @@ impl Struct<'_> {
             .predicates
             .extend(
                 self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::IntoIterator<Item = #ty>))
+                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::core::iter::IntoIterator<Item = #ty>))
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -876,7 +1153,7 @@ impl Struct<'_> {
         let array_iter_struct_ident = self.array_iter_struct_ident();
         let (_, ty_generics_item, _) = self.generics.split_for_impl();
         let tokens = quote! {
-            impl #impl_generics ::std::iter::Iterator for #array_iter_struct_ident #ty_generics #where_clause {
+            impl #impl_generics ::core::iter::Iterator for #array_iter_struct_ident #ty_generics #where_clause {
                 type Item = #ident #ty_generics_item;
 
                 fn next(&mut self) -> Option<Self::Item> {
@@ -905,7 +1182,7 @@ impl Struct<'_> {
             .predicates
             .extend(
                 self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::IntoIterator<Item = #ty>))
+                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::core::iter::IntoIterator<Item = #ty>))
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -938,7 +1215,7 @@ impl Struct<'_> {
         });
         let (_, ty_generics_item, _) = self.generics.split_for_impl();
         let tokens = quote!(
-            impl #impl_generics ::std::iter::IntoIterator for #array_struct_ident #ty_generics #where_clause {
+            impl #impl_generics ::core::iter::IntoIterator for #array_struct_ident #ty_generics #where_clause {
                 type Item = #ident #ty_generics_item;
                 type IntoIter = #array_iter_struct_ident #ty_generics;
                 fn into_iter(self) -> Self::IntoIter {
@@ -971,7 +1248,7 @@ impl Struct<'_> {
                 let narrow = util::narrow();
                 parse_quote!(
                 <#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>:
-                    ::std::convert::Into<
+                    ::core::convert::Into<
                         ::std::sync::Arc<dyn ::arrow_array::Array>
                     >
             )})
@@ -1,6 +1,7 @@
 use crate::NARROW;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use syn::Type;
 
 mod add_type_param;
 pub(super) use add_type_param::*;
@@ -20,3 +21,44 @@ pub(super) fn narrow() -> TokenStream {
     let ident = format_ident!("{}", &*NARROW);
     quote!(#ident)
 }
+
+/// Returns the name of a type as written in the source, without the spaces
+/// between tokens that are added by the token stream formatting.
+pub(super) fn type_name(ty: &Type) -> String {
+    quote!(#ty)
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+        .replace("[ ", "[")
+        .replace(" ]", "]")
+        .replace(" ;", ";")
+        .replace("( ", "(")
+        .replace(" )", ")")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn type_name() {
+        let cases: [(Type, &str); 4] = [
+            (parse_quote!(u32), "u32"),
+            (parse_quote!(Option<Vec<u8>>), "Option<Vec<u8>>"),
+            (
+                parse_quote!(::std::option::Option<&'a [u8; 2]>),
+                "::std::option::Option<&'a [u8; 2]>",
+            ),
+            (parse_quote!((u8, bool)), "(u8, bool)"),
+        ];
+        for (ty, name) in cases {
+            assert_eq!(super::type_name(&ty), name);
+        }
+    }
+}
@@ -3,8 +3,8 @@ enum Foo<T> {
     Bar(T),
     None,
 }
-impl<T> ::std::convert::From<&Foo<T>> for ::std::primitive::i8 {
-    fn from(value: &Foo<T>) -> ::std::primitive::i8 {
+impl<T> ::core::convert::From<&Foo<T>> for ::core::primitive::i8 {
+    fn from(value: &Foo<T>) -> ::core::primitive::i8 {
         match *value {
             Foo::Foo { .. } => 0,
             Foo::Bar(..) => 1,
@@ -83,7 +83,7 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::clone::Clone for FooArray<T, Buffer, OffsetItem, UnionLayout>
+> ::core::clone::Clone for FooArray<T, Buffer, OffsetItem, UnionLayout>
 where
     <<Foo<
         T,
@@ -91,21 +91,21 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <Foo<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<Foo<
         T,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <Foo<T> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<Foo<
         T,
     > as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone(), self.2.clone())
@@ -116,7 +116,7 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::default::Default for FooArray<T, Buffer, OffsetItem, UnionLayout>
+> ::core::default::Default for FooArray<T, Buffer, OffsetItem, UnionLayout>
 where
     <<Foo<
         T,
@@ -124,27 +124,27 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <Foo<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<Foo<
         T,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <Foo<T> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<Foo<
         T,
     > as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
 {
     fn default() -> Self {
         Self(
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
         )
     }
 }
@@ -152,7 +152,85 @@ impl<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<Foo<T>>
+    UnionLayout: narrow::array::UnionType,
+> narrow::MemoryUsage for FooArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+            + narrow::MemoryUsage::buffer_bytes(&self.2)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+            + narrow::MemoryUsage::allocated_bytes(&self.2)
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::VariantLengths<3>
+for FooArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+{
+    fn variant_lengths(&self) -> [usize; 3] {
+        [
+            narrow::Length::len(&self.0),
+            narrow::Length::len(&self.1),
+            narrow::Length::len(&self.2),
+        ]
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<Foo<T>>
 for FooArray<T, Buffer, OffsetItem, narrow::array::DenseLayout>
 where
     <<Foo<
@@ -165,7 +243,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooVariantFoo<T>>,
+    >: ::core::iter::Extend<FooVariantFoo<T>>,
     <<Foo<
         T,
     > as narrow::array::union::EnumVariant<
@@ -176,14 +254,14 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooVariantBar<T>>,
+    >: ::core::iter::Extend<FooVariantBar<T>>,
     <<Foo<
         T,
     > as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -193,13 +271,13 @@ where
             .for_each(|variant| {
                 match variant {
                     Foo::Foo { bar } => {
-                        self.0.extend(::std::iter::once(FooVariantFoo { bar }));
+                        self.0.extend(::core::iter::once(FooVariantFoo { bar }));
                     }
                     Foo::Bar(_0) => {
-                        self.1.extend(::std::iter::once(FooVariantBar(_0)));
+                        self.1.extend(::core::iter::once(FooVariantBar(_0)));
                     }
                     Foo::None => {
-                        self.2.extend(::std::iter::once(()));
+                        self.2.extend(::core::iter::once(()));
                     }
                 }
             });
@@ -209,7 +287,7 @@ impl<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<Foo<T>>
+> ::core::iter::Extend<Foo<T>>
 for FooArray<T, Buffer, OffsetItem, narrow::array::SparseLayout>
 where
     <<Foo<
@@ -222,7 +300,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooVariantFoo<T>>,
+    >: ::core::iter::Extend<FooVariantFoo<T>>,
     <<Foo<
         T,
     > as narrow::array::union::EnumVariant<
@@ -233,14 +311,14 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooVariantBar<T>>,
+    >: ::core::iter::Extend<FooVariantBar<T>>,
     <<Foo<
         T,
     > as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -250,36 +328,36 @@ where
             .for_each(|variant| {
                 match variant {
                     Foo::Foo { bar } => {
-                        self.0.extend(::std::iter::once(FooVariantFoo { bar }));
+                        self.0.extend(::core::iter::once(FooVariantFoo { bar }));
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     Foo::Bar(_0) => {
-                        self.1.extend(::std::iter::once(FooVariantBar(_0)));
+                        self.1.extend(::core::iter::once(FooVariantBar(_0)));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     Foo::None => {
-                        self.2.extend(::std::iter::once(()));
+                        self.2.extend(::core::iter::once(()));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                 }
@@ -386,7 +464,7 @@ where
     type Enum = Foo<T>;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -407,7 +485,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -464,7 +542,7 @@ where
     type Enum = Foo<T>;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -491,7 +569,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -563,6 +641,7 @@ where
     }
 }
 impl<T: narrow::array::ArrayType> narrow::array::UnionArrayType<3> for Foo<T> {
+    const TYPE_IDS: [::core::primitive::i8; 3] = [0, 1, 2];
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -4,8 +4,8 @@ enum FooBar {
     Bar { foo: bool },
     FooBar { foo: String, bar: Option<u8> },
 }
-impl ::std::convert::From<&FooBar> for ::std::primitive::i8 {
-    fn from(value: &FooBar) -> ::std::primitive::i8 {
+impl ::core::convert::From<&FooBar> for ::core::primitive::i8 {
+    fn from(value: &FooBar) -> ::core::primitive::i8 {
         match *value {
             FooBar::Unit => 0,
             FooBar::Foo { .. } => 1,
@@ -109,28 +109,28 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::clone::Clone for FooBarArray<Buffer, OffsetItem, UnionLayout>
+> ::core::clone::Clone for FooBarArray<Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar as narrow::array::union::EnumVariant<
         3,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<3>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone(), self.2.clone(), self.3.clone())
@@ -140,49 +140,126 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::default::Default for FooBarArray<Buffer, OffsetItem, UnionLayout>
+> ::core::default::Default for FooBarArray<Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar as narrow::array::union::EnumVariant<
         3,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<3>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
 {
     fn default() -> Self {
         Self(
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
         )
     }
 }
 impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar>
+    UnionLayout: narrow::array::UnionType,
+> narrow::MemoryUsage for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<3>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+            + narrow::MemoryUsage::buffer_bytes(&self.2)
+            + narrow::MemoryUsage::buffer_bytes(&self.3)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+            + narrow::MemoryUsage::allocated_bytes(&self.2)
+            + narrow::MemoryUsage::allocated_bytes(&self.3)
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::VariantLengths<4>
+for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<3>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+{
+    fn variant_lengths(&self) -> [usize; 4] {
+        [
+            narrow::Length::len(&self.0),
+            narrow::Length::len(&self.1),
+            narrow::Length::len(&self.2),
+            narrow::Length::len(&self.3),
+        ]
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<FooBar>
 for FooBarArray<Buffer, OffsetItem, narrow::array::DenseLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
@@ -191,7 +268,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantFoo>,
+    >: ::core::iter::Extend<FooBarVariantFoo>,
     <<FooBar as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
@@ -200,7 +277,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantBar>,
+    >: ::core::iter::Extend<FooBarVariantBar>,
     <<FooBar as narrow::array::union::EnumVariant<
         3,
     >>::Data as narrow::array::ArrayType<
@@ -209,7 +286,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantFooBar>,
+    >: ::core::iter::Extend<FooBarVariantFooBar>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -219,17 +296,19 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Unit => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                     }
                     FooBar::Foo { bar } => {
-                        self.1.extend(::std::iter::once(FooBarVariantFoo { bar }));
+                        self.1.extend(::core::iter::once(FooBarVariantFoo { bar }));
                     }
                     FooBar::Bar { foo } => {
-                        self.2.extend(::std::iter::once(FooBarVariantBar { foo }));
+                        self.2.extend(::core::iter::once(FooBarVariantBar { foo }));
                     }
                     FooBar::FooBar { foo, bar } => {
                         self.3
-                            .extend(::std::iter::once(FooBarVariantFooBar { foo, bar }));
+                            .extend(
+                                ::core::iter::once(FooBarVariantFooBar { foo, bar }),
+                            );
                     }
                 }
             });
@@ -238,14 +317,14 @@ where
 impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar>
+> ::core::iter::Extend<FooBar>
 for FooBarArray<Buffer, OffsetItem, narrow::array::SparseLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
@@ -254,7 +333,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantFoo>,
+    >: ::core::iter::Extend<FooBarVariantFoo>,
     <<FooBar as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
@@ -263,7 +342,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantBar>,
+    >: ::core::iter::Extend<FooBarVariantBar>,
     <<FooBar as narrow::array::union::EnumVariant<
         3,
     >>::Data as narrow::array::ArrayType<
@@ -272,7 +351,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantFooBar>,
+    >: ::core::iter::Extend<FooBarVariantFooBar>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -282,64 +361,66 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Unit => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.3
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::Foo { bar } => {
-                        self.1.extend(::std::iter::once(FooBarVariantFoo { bar }));
+                        self.1.extend(::core::iter::once(FooBarVariantFoo { bar }));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.3
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::Bar { foo } => {
-                        self.2.extend(::std::iter::once(FooBarVariantBar { foo }));
+                        self.2.extend(::core::iter::once(FooBarVariantBar { foo }));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.3
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::FooBar { foo, bar } => {
                         self.3
-                            .extend(::std::iter::once(FooBarVariantFooBar { foo, bar }));
+                            .extend(
+                                ::core::iter::once(FooBarVariantFooBar { foo, bar }),
+                            );
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                 }
@@ -447,7 +528,7 @@ where
     type Enum = FooBar;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -473,7 +554,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -534,7 +615,7 @@ where
     type Enum = FooBar;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -572,7 +653,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -646,6 +727,7 @@ where
     }
 }
 impl narrow::array::UnionArrayType<4> for FooBar {
+    const TYPE_IDS: [::core::primitive::i8; 4] = [0, 1, 2, 3];
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -2,8 +2,8 @@ enum FooBar<const X: bool> {
     Foo,
     Bar,
 }
-impl<const X: bool> ::std::convert::From<&FooBar<X>> for ::std::primitive::i8 {
-    fn from(value: &FooBar<X>) -> ::std::primitive::i8 {
+impl<const X: bool> ::core::convert::From<&FooBar<X>> for ::core::primitive::i8 {
+    fn from(value: &FooBar<X>) -> ::core::primitive::i8 {
         match *value {
             FooBar::Foo => 0,
             FooBar::Bar => 1,
@@ -48,7 +48,7 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::clone::Clone for FooBarArray<X, Buffer, OffsetItem, UnionLayout>
+> ::core::clone::Clone for FooBarArray<X, Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar<
         X,
@@ -56,14 +56,14 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar<
         X,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone())
@@ -74,7 +74,7 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::default::Default for FooBarArray<X, Buffer, OffsetItem, UnionLayout>
+> ::core::default::Default for FooBarArray<X, Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar<
         X,
@@ -82,24 +82,82 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar<
         X,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default(), ::std::default::Default::default())
+        Self(::core::default::Default::default(), ::core::default::Default::default())
     }
 }
 impl<
     const X: bool,
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar<X>>
+    UnionLayout: narrow::array::UnionType,
+> narrow::MemoryUsage for FooBarArray<X, Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+    }
+}
+impl<
+    const X: bool,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::VariantLengths<2>
+for FooBarArray<X, Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+{
+    fn variant_lengths(&self) -> [usize; 2] {
+        [narrow::Length::len(&self.0), narrow::Length::len(&self.1)]
+    }
+}
+impl<
+    const X: bool,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<FooBar<X>>
 for FooBarArray<X, Buffer, OffsetItem, narrow::array::DenseLayout>
 where
     <<FooBar<
@@ -108,14 +166,14 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
     <<FooBar<
         X,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -125,10 +183,10 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                     }
                     FooBar::Bar => {
-                        self.1.extend(::std::iter::once(()));
+                        self.1.extend(::core::iter::once(()));
                     }
                 }
             });
@@ -138,7 +196,7 @@ impl<
     const X: bool,
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar<X>>
+> ::core::iter::Extend<FooBar<X>>
 for FooBarArray<X, Buffer, OffsetItem, narrow::array::SparseLayout>
 where
     <<FooBar<
@@ -147,14 +205,14 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
     <<FooBar<
         X,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -164,17 +222,17 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::Bar => {
-                        self.1.extend(::std::iter::once(()));
+                        self.1.extend(::core::iter::once(()));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                 }
@@ -254,7 +312,7 @@ where
     type Enum = FooBar<X>;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -270,7 +328,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -314,7 +372,7 @@ where
     type Enum = FooBar<X>;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -332,7 +390,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -391,6 +449,7 @@ where
     }
 }
 impl<const X: bool> narrow::array::UnionArrayType<2> for FooBar<X> {
+    const TYPE_IDS: [::core::primitive::i8; 2] = [0, 1];
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -4,8 +4,8 @@ enum FooBar {
     FOO,
     Bar,
 }
-impl ::std::convert::From<&FooBar> for ::std::primitive::i8 {
-    fn from(value: &FooBar) -> ::std::primitive::i8 {
+impl ::core::convert::From<&FooBar> for ::core::primitive::i8 {
+    fn from(value: &FooBar) -> ::core::primitive::i8 {
         match *value {
             FooBar::Foo => 0,
             FooBar::FoO => 1,
@@ -68,28 +68,28 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::clone::Clone for FooBarArray<Buffer, OffsetItem, UnionLayout>
+> ::core::clone::Clone for FooBarArray<Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar as narrow::array::union::EnumVariant<
         3,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<3>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone(), self.2.clone(), self.3.clone())
@@ -99,64 +99,141 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::default::Default for FooBarArray<Buffer, OffsetItem, UnionLayout>
+> ::core::default::Default for FooBarArray<Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar as narrow::array::union::EnumVariant<
         3,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<3>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
 {
     fn default() -> Self {
         Self(
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
         )
     }
 }
 impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar>
+    UnionLayout: narrow::array::UnionType,
+> narrow::MemoryUsage for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<3>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+            + narrow::MemoryUsage::buffer_bytes(&self.2)
+            + narrow::MemoryUsage::buffer_bytes(&self.3)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+            + narrow::MemoryUsage::allocated_bytes(&self.2)
+            + narrow::MemoryUsage::allocated_bytes(&self.3)
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::VariantLengths<4>
+for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<3>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+{
+    fn variant_lengths(&self) -> [usize; 4] {
+        [
+            narrow::Length::len(&self.0),
+            narrow::Length::len(&self.1),
+            narrow::Length::len(&self.2),
+            narrow::Length::len(&self.3),
+        ]
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<FooBar>
 for FooBarArray<Buffer, OffsetItem, narrow::array::DenseLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
     <<FooBar as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
     <<FooBar as narrow::array::union::EnumVariant<
         3,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<3>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -166,16 +243,16 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                     }
                     FooBar::FoO => {
-                        self.1.extend(::std::iter::once(()));
+                        self.1.extend(::core::iter::once(()));
                     }
                     FooBar::FOO => {
-                        self.2.extend(::std::iter::once(()));
+                        self.2.extend(::core::iter::once(()));
                     }
                     FooBar::Bar => {
-                        self.3.extend(::std::iter::once(()));
+                        self.3.extend(::core::iter::once(()));
                     }
                 }
             });
@@ -184,29 +261,29 @@ where
 impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar>
+> ::core::iter::Extend<FooBar>
 for FooBarArray<Buffer, OffsetItem, narrow::array::SparseLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
     <<FooBar as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
     <<FooBar as narrow::array::union::EnumVariant<
         3,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<3>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -216,63 +293,63 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.3
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::FoO => {
-                        self.1.extend(::std::iter::once(()));
+                        self.1.extend(::core::iter::once(()));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.3
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::FOO => {
-                        self.2.extend(::std::iter::once(()));
+                        self.2.extend(::core::iter::once(()));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.3
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::Bar => {
-                        self.3.extend(::std::iter::once(()));
+                        self.3.extend(::core::iter::once(()));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                 }
@@ -380,7 +457,7 @@ where
     type Enum = FooBar;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -406,7 +483,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -467,7 +544,7 @@ where
     type Enum = FooBar;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -505,7 +582,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -579,6 +656,7 @@ where
     }
 }
 impl narrow::array::UnionArrayType<4> for FooBar {
+    const TYPE_IDS: [::core::primitive::i8; 4] = [0, 1, 2, 3];
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -0,0 +1,544 @@
+enum Version {
+    #[narrow(type_id = 3)]
+    V1,
+    V2,
+    #[narrow(type_id = 1)]
+    V3,
+}
+impl ::core::convert::From<&Version> for ::core::primitive::i8 {
+    fn from(value: &Version) -> ::core::primitive::i8 {
+        match *value {
+            Version::V1 => 3,
+            Version::V2 => 4,
+            Version::V3 => 1,
+        }
+    }
+}
+impl narrow::array::union::EnumVariant<0> for Version {
+    type Data = ();
+    fn from_data(value: Self::Data) -> Self {
+        Self::V1
+    }
+}
+impl narrow::array::union::EnumVariant<1> for Version {
+    type Data = ();
+    fn from_data(value: Self::Data) -> Self {
+        Self::V2
+    }
+}
+impl narrow::array::union::EnumVariant<2> for Version {
+    type Data = ();
+    fn from_data(value: Self::Data) -> Self {
+        Self::V3
+    }
+}
+struct VersionArray<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+>(
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>,
+);
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> ::core::clone::Clone for VersionArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), self.2.clone())
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> ::core::default::Default for VersionArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
+{
+    fn default() -> Self {
+        Self(
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+        )
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::MemoryUsage for VersionArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+            + narrow::MemoryUsage::buffer_bytes(&self.2)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+            + narrow::MemoryUsage::allocated_bytes(&self.2)
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::VariantLengths<3>
+for VersionArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+{
+    fn variant_lengths(&self) -> [usize; 3] {
+        [
+            narrow::Length::len(&self.0),
+            narrow::Length::len(&self.1),
+            narrow::Length::len(&self.2),
+        ]
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<Version>
+for VersionArray<Buffer, OffsetItem, narrow::array::DenseLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Version>,
+    {
+        iter.into_iter()
+            .for_each(|variant| {
+                match variant {
+                    Version::V1 => {
+                        self.0.extend(::core::iter::once(()));
+                    }
+                    Version::V2 => {
+                        self.1.extend(::core::iter::once(()));
+                    }
+                    Version::V3 => {
+                        self.2.extend(::core::iter::once(()));
+                    }
+                }
+            });
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<Version>
+for VersionArray<Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Version>,
+    {
+        iter.into_iter()
+            .for_each(|variant| {
+                match variant {
+                    Version::V1 => {
+                        self.0.extend(::core::iter::once(()));
+                        self.1
+                            .extend(
+                                ::core::iter::once(::core::default::Default::default()),
+                            );
+                        self.2
+                            .extend(
+                                ::core::iter::once(::core::default::Default::default()),
+                            );
+                    }
+                    Version::V2 => {
+                        self.1.extend(::core::iter::once(()));
+                        self.0
+                            .extend(
+                                ::core::iter::once(::core::default::Default::default()),
+                            );
+                        self.2
+                            .extend(
+                                ::core::iter::once(::core::default::Default::default()),
+                            );
+                    }
+                    Version::V3 => {
+                        self.2.extend(::core::iter::once(()));
+                        self.0
+                            .extend(
+                                ::core::iter::once(::core::default::Default::default()),
+                            );
+                        self.1
+                            .extend(
+                                ::core::iter::once(::core::default::Default::default()),
+                            );
+                    }
+                }
+            });
+    }
+}
+struct VersionArrayIntoIter<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+>(
+    <<<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout> as ::core::iter::IntoIterator>::IntoIter,
+    <<<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout> as ::core::iter::IntoIterator>::IntoIter,
+    <<<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout> as ::core::iter::IntoIterator>::IntoIter,
+)
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::iter::IntoIterator,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::iter::IntoIterator,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::iter::IntoIterator;
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::array::union::TypeIdIterator
+for VersionArrayIntoIter<Buffer, OffsetItem, narrow::array::DenseLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        narrow::array::DenseLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        narrow::array::DenseLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        narrow::array::DenseLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >,
+{
+    type Enum = Version;
+    fn next(
+        &mut self,
+        type_id: ::core::primitive::i8,
+    ) -> ::core::option::Option<Self::Enum> {
+        match type_id {
+            3 => {
+                self.0
+                    .next()
+                    .map(<Version as narrow::array::union::EnumVariant<0>>::from_data)
+            }
+            4 => {
+                self.1
+                    .next()
+                    .map(<Version as narrow::array::union::EnumVariant<1>>::from_data)
+            }
+            1 => {
+                self.2
+                    .next()
+                    .map(<Version as narrow::array::union::EnumVariant<2>>::from_data)
+            }
+            _ => {
+                {
+                    ::core::panicking::panic_fmt(
+                        format_args!("type id not mapped to a variant"),
+                    );
+                };
+            }
+        }
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::array::union::TypeIdIterator
+for VersionArrayIntoIter<Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        narrow::array::SparseLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        narrow::array::SparseLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        narrow::array::SparseLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >,
+{
+    type Enum = Version;
+    fn next(
+        &mut self,
+        type_id: ::core::primitive::i8,
+    ) -> ::core::option::Option<Self::Enum> {
+        match type_id {
+            3 => {
+                self.1.next();
+                self.2.next();
+                self.0
+                    .next()
+                    .map(<Version as narrow::array::union::EnumVariant<0>>::from_data)
+            }
+            4 => {
+                self.0.next();
+                self.2.next();
+                self.1
+                    .next()
+                    .map(<Version as narrow::array::union::EnumVariant<1>>::from_data)
+            }
+            1 => {
+                self.0.next();
+                self.1.next();
+                self.2
+                    .next()
+                    .map(<Version as narrow::array::union::EnumVariant<2>>::from_data)
+            }
+            _ => {
+                {
+                    ::core::panicking::panic_fmt(
+                        format_args!("type id not mapped to a variant"),
+                    );
+                };
+            }
+        }
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::UnionArrayIterators
+for VersionArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<Version as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<0>>::Data,
+    >,
+    <<Version as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<1>>::Data,
+    >,
+    <<Version as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: ::core::iter::IntoIterator<
+        Item = <Version as narrow::array::union::EnumVariant<2>>::Data,
+    >,
+    VersionArrayIntoIter<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::array::union::TypeIdIterator,
+{
+    type VariantIterators = VersionArrayIntoIter<Buffer, OffsetItem, UnionLayout>;
+    fn new_variant_iters(self) -> Self::VariantIterators {
+        VersionArrayIntoIter::<
+            Buffer,
+            OffsetItem,
+            UnionLayout,
+        >(self.0.into_iter(), self.1.into_iter(), self.2.into_iter())
+    }
+}
+impl narrow::array::UnionArrayType<3> for Version {
+    const TYPE_IDS: [::core::primitive::i8; 3] = [3, 4, 1];
+    type Array<
+        Buffer: narrow::buffer::BufferType,
+        OffsetItem: narrow::offset::OffsetElement,
+        UnionLayout: narrow::array::UnionType,
+    > = VersionArray<Buffer, OffsetItem, UnionLayout>;
+}
+impl narrow::array::ArrayType<Version> for Version {
+    type Array<
+        Buffer: narrow::buffer::BufferType,
+        OffsetItem: narrow::offset::OffsetElement,
+        UnionLayout: narrow::array::UnionType,
+    > = narrow::array::UnionArray<
+        Self,
+        { <Self as narrow::array::UnionArrayType<3>>::VARIANTS },
+        UnionLayout,
+        Buffer,
+        OffsetItem,
+    >;
+}
@@ -0,0 +1,8 @@
+#[derive(narrow_derive::ArrayType)]
+enum Version {
+    #[narrow(type_id = 3)]
+    V1,
+    V2,
+    #[narrow(type_id = 1)]
+    V3,
+}
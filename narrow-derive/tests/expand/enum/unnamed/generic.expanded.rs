@@ -3,8 +3,8 @@ enum FooBar<T: Default> {
     Bar(T),
     FooBar { foo_bar: T },
 }
-impl<T: Default> ::std::convert::From<&FooBar<T>> for ::std::primitive::i8 {
-    fn from(value: &FooBar<T>) -> ::std::primitive::i8 {
+impl<T: Default> ::core::convert::From<&FooBar<T>> for ::core::primitive::i8 {
+    fn from(value: &FooBar<T>) -> ::core::primitive::i8 {
         match *value {
             FooBar::Foo => 0,
             FooBar::Bar(..) => 1,
@@ -87,7 +87,7 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::clone::Clone for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+> ::core::clone::Clone for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar<
         T,
@@ -95,21 +95,21 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone(), self.2.clone())
@@ -120,7 +120,7 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::default::Default for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+> ::core::default::Default for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar<
         T,
@@ -128,27 +128,27 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
 {
     fn default() -> Self {
         Self(
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
         )
     }
 }
@@ -156,7 +156,85 @@ impl<
     T: Default + narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar<T>>
+    UnionLayout: narrow::array::UnionType,
+> narrow::MemoryUsage for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+            + narrow::MemoryUsage::buffer_bytes(&self.2)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+            + narrow::MemoryUsage::allocated_bytes(&self.2)
+    }
+}
+impl<
+    T: Default + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::VariantLengths<3>
+for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+{
+    fn variant_lengths(&self) -> [usize; 3] {
+        [
+            narrow::Length::len(&self.0),
+            narrow::Length::len(&self.1),
+            narrow::Length::len(&self.2),
+        ]
+    }
+}
+impl<
+    T: Default + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<FooBar<T>>
 for FooBarArray<T, Buffer, OffsetItem, narrow::array::DenseLayout>
 where
     <<FooBar<
@@ -165,7 +243,7 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
@@ -176,7 +254,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantBar<T>>,
+    >: ::core::iter::Extend<FooBarVariantBar<T>>,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
@@ -187,7 +265,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantFooBar<T>>,
+    >: ::core::iter::Extend<FooBarVariantFooBar<T>>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -197,14 +275,14 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                     }
                     FooBar::Bar(_0) => {
-                        self.1.extend(::std::iter::once(FooBarVariantBar(_0)));
+                        self.1.extend(::core::iter::once(FooBarVariantBar(_0)));
                     }
                     FooBar::FooBar { foo_bar } => {
                         self.2
-                            .extend(::std::iter::once(FooBarVariantFooBar { foo_bar }));
+                            .extend(::core::iter::once(FooBarVariantFooBar { foo_bar }));
                     }
                 }
             });
@@ -214,7 +292,7 @@ impl<
     T: Default + narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar<T>>
+> ::core::iter::Extend<FooBar<T>>
 for FooBarArray<T, Buffer, OffsetItem, narrow::array::SparseLayout>
 where
     <<FooBar<
@@ -223,7 +301,7 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
@@ -234,7 +312,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantBar<T>>,
+    >: ::core::iter::Extend<FooBarVariantBar<T>>,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
@@ -245,7 +323,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantFooBar<T>>,
+    >: ::core::iter::Extend<FooBarVariantFooBar<T>>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -255,37 +333,37 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::Bar(_0) => {
-                        self.1.extend(::std::iter::once(FooBarVariantBar(_0)));
+                        self.1.extend(::core::iter::once(FooBarVariantBar(_0)));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::FooBar { foo_bar } => {
                         self.2
-                            .extend(::std::iter::once(FooBarVariantFooBar { foo_bar }));
+                            .extend(::core::iter::once(FooBarVariantFooBar { foo_bar }));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                 }
@@ -392,7 +470,7 @@ where
     type Enum = FooBar<T>;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -413,7 +491,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -470,7 +548,7 @@ where
     type Enum = FooBar<T>;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -497,7 +575,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -570,6 +648,7 @@ where
 }
 impl<T: Default + narrow::array::ArrayType> narrow::array::UnionArrayType<3>
 for FooBar<T> {
+    const TYPE_IDS: [::core::primitive::i8; 3] = [0, 1, 2];
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -2,8 +2,8 @@ enum FooBar {
     Foo(bool),
     Bar(u8, u16),
 }
-impl ::std::convert::From<&FooBar> for ::std::primitive::i8 {
-    fn from(value: &FooBar) -> ::std::primitive::i8 {
+impl ::core::convert::From<&FooBar> for ::core::primitive::i8 {
+    fn from(value: &FooBar) -> ::core::primitive::i8 {
         match *value {
             FooBar::Foo(..) => 0,
             FooBar::Bar(..) => 1,
@@ -61,18 +61,18 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::clone::Clone for FooBarArray<Buffer, OffsetItem, UnionLayout>
+> ::core::clone::Clone for FooBarArray<Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone())
@@ -82,27 +82,75 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::default::Default for FooBarArray<Buffer, OffsetItem, UnionLayout>
+> ::core::default::Default for FooBarArray<Buffer, OffsetItem, UnionLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default(), ::std::default::Default::default())
+        Self(::core::default::Default::default(), ::core::default::Default::default())
     }
 }
 impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar>
+    UnionLayout: narrow::array::UnionType,
+> narrow::MemoryUsage for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::VariantLengths<2>
+for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+{
+    fn variant_lengths(&self) -> [usize; 2] {
+        [narrow::Length::len(&self.0), narrow::Length::len(&self.1)]
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<FooBar>
 for FooBarArray<Buffer, OffsetItem, narrow::array::DenseLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
@@ -113,7 +161,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantFoo>,
+    >: ::core::iter::Extend<FooBarVariantFoo>,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
@@ -122,7 +170,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantBar>,
+    >: ::core::iter::Extend<FooBarVariantBar>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -132,10 +180,10 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo(_0) => {
-                        self.0.extend(::std::iter::once(FooBarVariantFoo(_0)));
+                        self.0.extend(::core::iter::once(FooBarVariantFoo(_0)));
                     }
                     FooBar::Bar(_0, _1) => {
-                        self.1.extend(::std::iter::once(FooBarVariantBar(_0, _1)));
+                        self.1.extend(::core::iter::once(FooBarVariantBar(_0, _1)));
                     }
                 }
             });
@@ -144,7 +192,7 @@ where
 impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar>
+> ::core::iter::Extend<FooBar>
 for FooBarArray<Buffer, OffsetItem, narrow::array::SparseLayout>
 where
     <<FooBar as narrow::array::union::EnumVariant<
@@ -155,7 +203,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantFoo>,
+    >: ::core::iter::Extend<FooBarVariantFoo>,
     <<FooBar as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
@@ -164,7 +212,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantBar>,
+    >: ::core::iter::Extend<FooBarVariantBar>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -174,17 +222,17 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo(_0) => {
-                        self.0.extend(::std::iter::once(FooBarVariantFoo(_0)));
+                        self.0.extend(::core::iter::once(FooBarVariantFoo(_0)));
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::Bar(_0, _1) => {
-                        self.1.extend(::std::iter::once(FooBarVariantBar(_0, _1)));
+                        self.1.extend(::core::iter::once(FooBarVariantBar(_0, _1)));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                 }
@@ -250,7 +298,7 @@ where
     type Enum = FooBar;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -266,7 +314,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -305,7 +353,7 @@ where
     type Enum = FooBar;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -323,7 +371,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -375,6 +423,7 @@ where
     }
 }
 impl narrow::array::UnionArrayType<2> for FooBar {
+    const TYPE_IDS: [::core::primitive::i8; 2] = [0, 1];
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
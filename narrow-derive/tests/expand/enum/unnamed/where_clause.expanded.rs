@@ -7,12 +7,12 @@ where
     Bar(T),
     FooBar { foo_bar: T },
 }
-impl<T> ::std::convert::From<&FooBar<T>> for ::std::primitive::i8
+impl<T> ::core::convert::From<&FooBar<T>> for ::core::primitive::i8
 where
     T: Default,
     Self: Clone,
 {
-    fn from(value: &FooBar<T>) -> ::std::primitive::i8 {
+    fn from(value: &FooBar<T>) -> ::core::primitive::i8 {
         match *value {
             FooBar::Foo => 0,
             FooBar::Bar(..) => 1,
@@ -121,7 +121,7 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::clone::Clone for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+> ::core::clone::Clone for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
 where
     T: Default,
     FooBar<T>: Clone,
@@ -131,21 +131,21 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::clone::Clone,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone(), self.2.clone())
@@ -156,7 +156,7 @@ impl<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
     UnionLayout: narrow::array::UnionType,
-> ::std::default::Default for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+> ::core::default::Default for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
 where
     T: Default,
     FooBar<T>: Clone,
@@ -166,27 +166,27 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
         1,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
         2,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: ::std::default::Default,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: ::core::default::Default,
 {
     fn default() -> Self {
         Self(
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
         )
     }
 }
@@ -194,7 +194,89 @@ impl<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar<T>>
+    UnionLayout: narrow::array::UnionType,
+> narrow::MemoryUsage for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    T: Default,
+    FooBar<T>: Clone,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+            + narrow::MemoryUsage::buffer_bytes(&self.2)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+            + narrow::MemoryUsage::allocated_bytes(&self.2)
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::VariantLengths<3>
+for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    T: Default,
+    FooBar<T>: Clone,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: narrow::Length,
+{
+    fn variant_lengths(&self) -> [usize; 3] {
+        [
+            narrow::Length::len(&self.0),
+            narrow::Length::len(&self.1),
+            narrow::Length::len(&self.2),
+        ]
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> ::core::iter::Extend<FooBar<T>>
 for FooBarArray<T, Buffer, OffsetItem, narrow::array::DenseLayout>
 where
     T: Default,
@@ -205,7 +287,7 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::DenseLayout>: ::core::iter::Extend<()>,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
@@ -216,7 +298,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantBar<T>>,
+    >: ::core::iter::Extend<FooBarVariantBar<T>>,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
@@ -227,7 +309,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::DenseLayout,
-    >: ::std::iter::Extend<FooBarVariantFooBar<T>>,
+    >: ::core::iter::Extend<FooBarVariantFooBar<T>>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -237,14 +319,14 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                     }
                     FooBar::Bar(_0) => {
-                        self.1.extend(::std::iter::once(FooBarVariantBar(_0)));
+                        self.1.extend(::core::iter::once(FooBarVariantBar(_0)));
                     }
                     FooBar::FooBar { foo_bar } => {
                         self.2
-                            .extend(::std::iter::once(FooBarVariantFooBar { foo_bar }));
+                            .extend(::core::iter::once(FooBarVariantFooBar { foo_bar }));
                     }
                 }
             });
@@ -254,7 +336,7 @@ impl<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
-> ::std::iter::Extend<FooBar<T>>
+> ::core::iter::Extend<FooBar<T>>
 for FooBarArray<T, Buffer, OffsetItem, narrow::array::SparseLayout>
 where
     T: Default,
@@ -265,7 +347,7 @@ where
         0,
     >>::Data as narrow::array::ArrayType<
         <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
-    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::std::iter::Extend<()>,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: ::core::iter::Extend<()>,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
@@ -276,7 +358,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantBar<T>>,
+    >: ::core::iter::Extend<FooBarVariantBar<T>>,
     <<FooBar<
         T,
     > as narrow::array::union::EnumVariant<
@@ -287,7 +369,7 @@ where
         Buffer,
         OffsetItem,
         narrow::array::SparseLayout,
-    >: ::std::iter::Extend<FooBarVariantFooBar<T>>,
+    >: ::core::iter::Extend<FooBarVariantFooBar<T>>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -297,37 +379,37 @@ where
             .for_each(|variant| {
                 match variant {
                     FooBar::Foo => {
-                        self.0.extend(::std::iter::once(()));
+                        self.0.extend(::core::iter::once(()));
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::Bar(_0) => {
-                        self.1.extend(::std::iter::once(FooBarVariantBar(_0)));
+                        self.1.extend(::core::iter::once(FooBarVariantBar(_0)));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.2
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                     FooBar::FooBar { foo_bar } => {
                         self.2
-                            .extend(::std::iter::once(FooBarVariantFooBar { foo_bar }));
+                            .extend(::core::iter::once(FooBarVariantFooBar { foo_bar }));
                         self.0
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                         self.1
                             .extend(
-                                ::std::iter::once(::std::default::Default::default()),
+                                ::core::iter::once(::core::default::Default::default()),
                             );
                     }
                 }
@@ -438,7 +520,7 @@ where
     type Enum = FooBar<T>;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -459,7 +541,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -518,7 +600,7 @@ where
     type Enum = FooBar<T>;
     fn next(
         &mut self,
-        type_id: ::std::primitive::i8,
+        type_id: ::core::primitive::i8,
     ) -> ::core::option::Option<Self::Enum> {
         match type_id {
             0 => {
@@ -545,7 +627,7 @@ where
             _ => {
                 {
                     ::core::panicking::panic_fmt(
-                        format_args!("type id greater than number of variants"),
+                        format_args!("type id not mapped to a variant"),
                     );
                 };
             }
@@ -623,6 +705,7 @@ where
     T: Default,
     Self: Clone,
 {
+    const TYPE_IDS: [::core::primitive::i8; 3] = [0, 1, 2];
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
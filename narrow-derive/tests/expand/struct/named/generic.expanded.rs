@@ -16,7 +16,7 @@ where
     > = narrow::array::StructArray<Foo<'a, T>, false, Buffer>;
 }
 impl<'a, T: narrow::array::ArrayType<T>> narrow::array::ArrayType<Foo<'a, T>>
-for ::std::option::Option<Foo<'a, T>>
+for ::core::option::Option<Foo<'a, T>>
 where
     T: Copy,
 {
@@ -44,12 +44,16 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::clone::Clone for FooArray<'a, T, Buffer>
+> ::core::clone::Clone for FooArray<'a, T, Buffer>
 where
     T: Copy,
     <&'a T as narrow::array::ArrayType<
         &'a T,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self { a: self.a.clone() }
@@ -59,7 +63,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::default::Default for FooArray<'a, T, Buffer>
+> ::core::default::Default for FooArray<'a, T, Buffer>
 where
     T: Copy,
     <&'a T as narrow::array::ArrayType<
@@ -68,11 +72,11 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
         Self {
-            a: ::std::default::Default::default(),
+            a: ::core::default::Default::default(),
         }
     }
 }
@@ -95,7 +99,59 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Extend<Foo<'a, T>> for FooArray<'a, T, Buffer>
+> narrow::MemoryUsage for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType<
+        &'a T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.a)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.a)
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> FooArray<'a, T, Buffer>
+where
+    T: Copy,
+{
+    ///Returns a reference to the array with the `a` values.
+    fn a(
+        &self,
+    ) -> &<&'a T as narrow::array::ArrayType<
+        &'a T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA> {
+        &self.a
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayFields for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+{
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "a",
+            nullable: false,
+            type_name: "&'a T",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::GrowableBuffer,
+> ::core::iter::Extend<Foo<'a, T>> for FooArray<'a, T, Buffer>
 where
     T: Copy,
     <&'a T as narrow::array::ArrayType<
@@ -104,12 +160,12 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<&'a T>,
+    >: ::core::iter::Extend<&'a T>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo<'a, T>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Foo { a }| {
-                self.a.extend(::std::iter::once(a));
+                self.a.extend(::core::iter::once(a));
             });
     }
 }
@@ -117,7 +173,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<Foo<'a, T>> for FooArray<'a, T, Buffer>
+> ::core::iter::FromIterator<Foo<'a, T>> for FooArray<'a, T, Buffer>
 where
     T: Copy,
     <&'a T as narrow::array::ArrayType<
@@ -126,9 +182,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<&'a T>,
+    >: ::core::default::Default + ::core::iter::Extend<&'a T>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo<'a, T>>>(iter: _I) -> Self {
         let (a, ()) = iter.into_iter().map(|Foo { a }| (a, ())).unzip();
         Self { a }
     }
@@ -146,7 +202,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>,
+    >: ::core::iter::IntoIterator<Item = &'a T>,
 {
     a: <<&'a T as narrow::array::ArrayType<
         &'a T,
@@ -154,13 +210,13 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 }
 impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Iterator for FooArrayIter<'a, T, Buffer>
+> ::core::iter::Iterator for FooArrayIter<'a, T, Buffer>
 where
     T: Copy,
     <&'a T as narrow::array::ArrayType<
@@ -169,7 +225,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>,
+    >: ::core::iter::IntoIterator<Item = &'a T>,
 {
     type Item = Foo<'a, T>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -180,7 +236,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::IntoIterator for FooArray<'a, T, Buffer>
+> ::core::iter::IntoIterator for FooArray<'a, T, Buffer>
 where
     T: Copy,
     <&'a T as narrow::array::ArrayType<
@@ -189,7 +245,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>,
+    >: ::core::iter::IntoIterator<Item = &'a T>,
 {
     type Item = Foo<'a, T>;
     type IntoIter = FooArrayIter<'a, T, Buffer>;
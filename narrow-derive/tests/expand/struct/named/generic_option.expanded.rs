@@ -11,7 +11,7 @@ impl<T: narrow::array::ArrayType<T>> narrow::array::ArrayType<Bar<T>> for Bar<T>
     > = narrow::array::StructArray<Bar<T>, false, Buffer>;
 }
 impl<T: narrow::array::ArrayType<T>> narrow::array::ArrayType<Bar<T>>
-for ::std::option::Option<Bar<T>> {
+for ::core::option::Option<Bar<T>> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -39,21 +39,33 @@ struct BarArray<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::BufferTy
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::clone::Clone for BarArray<T, Buffer>
+> ::core::clone::Clone for BarArray<T, Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
     <Option<
         bool,
     > as narrow::array::ArrayType<
         bool,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
     <Option<
         T,
     > as narrow::array::ArrayType<
         T,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self {
@@ -66,7 +78,7 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::default::Default for BarArray<T, Buffer>
+> ::core::default::Default for BarArray<T, Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -74,7 +86,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
     <Option<
         bool,
     > as narrow::array::ArrayType<
@@ -83,7 +95,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
     <Option<
         T,
     > as narrow::array::ArrayType<
@@ -92,13 +104,13 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
         Self {
-            a: ::std::default::Default::default(),
-            b: ::std::default::Default::default(),
-            c: ::std::default::Default::default(),
+            a: ::core::default::Default::default(),
+            b: ::core::default::Default::default(),
+            c: ::core::default::Default::default(),
         }
     }
 }
@@ -126,7 +138,127 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Extend<Bar<T>> for BarArray<T, Buffer>
+> narrow::MemoryUsage for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+    <Option<
+        T,
+    > as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.a)
+            + narrow::MemoryUsage::buffer_bytes(&self.b)
+            + narrow::MemoryUsage::buffer_bytes(&self.c)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.a)
+            + narrow::MemoryUsage::allocated_bytes(&self.b)
+            + narrow::MemoryUsage::allocated_bytes(&self.c)
+    }
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> BarArray<T, Buffer> {
+    ///Returns a reference to the array with the `a` values.
+    fn a(
+        &self,
+    ) -> &<u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA> {
+        &self.a
+    }
+    ///Returns a reference to the array with the `b` values.
+    fn b(
+        &self,
+    ) -> &<Option<
+        bool,
+    > as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA> {
+        &self.b
+    }
+    ///Returns a reference to the array with the `c` values.
+    fn c(
+        &self,
+    ) -> &<Option<
+        T,
+    > as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA> {
+        &self.c
+    }
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for BarArray<T, Buffer>
+where
+    Buffer: 'static,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+    <Option<
+        T,
+    > as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        3
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.a),
+            1 => ::core::option::Option::Some(&self.b),
+            2 => ::core::option::Option::Some(&self.c),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayFields for BarArray<T, Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "a",
+            nullable: false,
+            type_name: "u32",
+            extension: ::core::option::Option::None,
+        },
+        narrow::array::FieldInfo {
+            name: "b",
+            nullable: true,
+            type_name: "Option<bool>",
+            extension: ::core::option::Option::None,
+        },
+        narrow::array::FieldInfo {
+            name: "c",
+            nullable: true,
+            type_name: "Option<T>",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::GrowableBuffer,
+> ::core::iter::Extend<Bar<T>> for BarArray<T, Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -134,7 +266,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<u32>,
+    >: ::core::iter::Extend<u32>,
     <Option<
         bool,
     > as narrow::array::ArrayType<
@@ -143,7 +275,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<Option<bool>>,
+    >: ::core::iter::Extend<Option<bool>>,
     <Option<
         T,
     > as narrow::array::ArrayType<
@@ -152,21 +284,21 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<Option<T>>,
+    >: ::core::iter::Extend<Option<T>>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Bar<T>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Bar<T>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Bar { a, b, c }| {
-                self.a.extend(::std::iter::once(a));
-                self.b.extend(::std::iter::once(b));
-                self.c.extend(::std::iter::once(c));
+                self.a.extend(::core::iter::once(a));
+                self.b.extend(::core::iter::once(b));
+                self.c.extend(::core::iter::once(c));
             });
     }
 }
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<Bar<T>> for BarArray<T, Buffer>
+> ::core::iter::FromIterator<Bar<T>> for BarArray<T, Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -174,7 +306,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<u32>,
+    >: ::core::default::Default + ::core::iter::Extend<u32>,
     <Option<
         bool,
     > as narrow::array::ArrayType<
@@ -183,7 +315,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<Option<bool>>,
+    >: ::core::default::Default + ::core::iter::Extend<Option<bool>>,
     <Option<
         T,
     > as narrow::array::ArrayType<
@@ -192,9 +324,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<Option<T>>,
+    >: ::core::default::Default + ::core::iter::Extend<Option<T>>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Bar<T>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Bar<T>>>(iter: _I) -> Self {
         let (a, (b, (c, ()))) = iter
             .into_iter()
             .map(|Bar { a, b, c }| (a, (b, (c, ()))))
@@ -210,7 +342,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <Option<
         bool,
     > as narrow::array::ArrayType<
@@ -219,7 +351,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<bool>>,
+    >: ::core::iter::IntoIterator<Item = Option<bool>>,
     <Option<
         T,
     > as narrow::array::ArrayType<
@@ -228,7 +360,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<T>>,
+    >: ::core::iter::IntoIterator<Item = Option<T>>,
 {
     a: <<u32 as narrow::array::ArrayType<
         u32,
@@ -236,7 +368,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
     b: <<Option<
         bool,
     > as narrow::array::ArrayType<
@@ -245,7 +377,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
     c: <<Option<
         T,
     > as narrow::array::ArrayType<
@@ -254,12 +386,12 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 }
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Iterator for BarArrayIter<T, Buffer>
+> ::core::iter::Iterator for BarArrayIter<T, Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -267,7 +399,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <Option<
         bool,
     > as narrow::array::ArrayType<
@@ -276,7 +408,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<bool>>,
+    >: ::core::iter::IntoIterator<Item = Option<bool>>,
     <Option<
         T,
     > as narrow::array::ArrayType<
@@ -285,7 +417,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<T>>,
+    >: ::core::iter::IntoIterator<Item = Option<T>>,
 {
     type Item = Bar<T>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -303,7 +435,7 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::IntoIterator for BarArray<T, Buffer>
+> ::core::iter::IntoIterator for BarArray<T, Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -311,7 +443,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <Option<
         bool,
     > as narrow::array::ArrayType<
@@ -320,7 +452,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<bool>>,
+    >: ::core::iter::IntoIterator<Item = Option<bool>>,
     <Option<
         T,
     > as narrow::array::ArrayType<
@@ -329,7 +461,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<T>>,
+    >: ::core::iter::IntoIterator<Item = Option<T>>,
 {
     type Item = Bar<T>;
     type IntoIter = BarArrayIter<T, Buffer>;
@@ -0,0 +1,203 @@
+struct Foo {
+    #[narrow(rename = "b")]
+    a: u32,
+}
+impl narrow::array::ArrayType<Foo> for Foo {
+    type Array<
+        Buffer: narrow::buffer::BufferType,
+        OffsetItem: narrow::offset::OffsetElement,
+        UnionLayout: narrow::array::UnionType,
+    > = narrow::array::StructArray<Foo, false, Buffer>;
+}
+impl narrow::array::ArrayType<Foo> for ::core::option::Option<Foo> {
+    type Array<
+        Buffer: narrow::buffer::BufferType,
+        OffsetItem: narrow::offset::OffsetElement,
+        UnionLayout: narrow::array::UnionType,
+    > = narrow::array::StructArray<Foo, true, Buffer>;
+}
+impl narrow::array::StructArrayType for Foo {
+    type Array<Buffer: narrow::buffer::BufferType> = FooArray<Buffer>;
+}
+struct FooArray<Buffer: narrow::buffer::BufferType> {
+    a: <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>,
+}
+impl<Buffer: narrow::buffer::BufferType> ::core::clone::Clone for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
+{
+    fn clone(&self) -> Self {
+        Self { a: self.a.clone() }
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> ::core::default::Default for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::default::Default,
+{
+    fn default() -> Self {
+        Self {
+            a: ::core::default::Default::default(),
+        }
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Length,
+{
+    fn len(&self) -> usize {
+        self.a.len()
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::MemoryUsage for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.a)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.a)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> FooArray<Buffer> {
+    ///Returns a reference to the array with the `a` values.
+    fn a(
+        &self,
+    ) -> &<u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA> {
+        &self.a
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for FooArray<Buffer>
+where
+    Buffer: 'static,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        1
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.a),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayFields
+for FooArray<Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "b",
+            nullable: false,
+            type_name: "u32",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Foo>
+for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::iter::Extend<u32>,
+{
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
+        iter.into_iter()
+            .for_each(|Foo { a }| {
+                self.a.extend(::core::iter::once(a));
+            });
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::FromIterator<Foo>
+for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::default::Default + ::core::iter::Extend<u32>,
+{
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
+        let (a, ()) = iter.into_iter().map(|Foo { a }| (a, ())).unzip();
+        Self { a }
+    }
+}
+struct FooArrayIter<Buffer: narrow::buffer::BufferType>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::iter::IntoIterator<Item = u32>,
+{
+    a: <<u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    > as ::core::iter::IntoIterator>::IntoIter,
+}
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::Iterator for FooArrayIter<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::iter::IntoIterator<Item = u32>,
+{
+    type Item = Foo;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.a.next().map(|a| { Foo { a } })
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::iter::IntoIterator<Item = u32>,
+{
+    type Item = Foo;
+    type IntoIter = FooArrayIter<Buffer>;
+    fn into_iter(self) -> Self::IntoIter {
+        FooArrayIter {
+            a: self.a.into_iter(),
+        }
+    }
+}
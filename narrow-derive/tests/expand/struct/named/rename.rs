@@ -0,0 +1,5 @@
+#[derive(narrow_derive::ArrayType)]
+struct Foo {
+    #[narrow(rename = "b")]
+    a: u32,
+}
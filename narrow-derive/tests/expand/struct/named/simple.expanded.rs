@@ -10,7 +10,7 @@ impl narrow::array::ArrayType<Foo> for Foo {
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<Foo, false, Buffer>;
 }
-impl narrow::array::ArrayType<Foo> for ::std::option::Option<Foo> {
+impl narrow::array::ArrayType<Foo> for ::core::option::Option<Foo> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -33,19 +33,31 @@ struct FooArray<Buffer: narrow::buffer::BufferType> {
         Vec<u8>,
     >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>,
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::clone::Clone for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::clone::Clone for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
     <bool as narrow::array::ArrayType<
         bool,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
     <Option<
         Vec<u8>,
     > as narrow::array::ArrayType<
         Vec<u8>,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self {
@@ -55,7 +67,7 @@ where
         }
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::default::Default for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::default::Default for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -63,14 +75,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
     <bool as narrow::array::ArrayType<
         bool,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
     <Option<
         Vec<u8>,
     > as narrow::array::ArrayType<
@@ -79,13 +91,13 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
         Self {
-            a: ::std::default::Default::default(),
-            b: ::std::default::Default::default(),
-            c: ::std::default::Default::default(),
+            a: ::core::default::Default::default(),
+            b: ::core::default::Default::default(),
+            c: ::core::default::Default::default(),
         }
     }
 }
@@ -107,7 +119,112 @@ where
         self.a.len()
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> narrow::MemoryUsage for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+    <bool as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType<
+        Vec<u8>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.a)
+            + narrow::MemoryUsage::buffer_bytes(&self.b)
+            + narrow::MemoryUsage::buffer_bytes(&self.c)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.a)
+            + narrow::MemoryUsage::allocated_bytes(&self.b)
+            + narrow::MemoryUsage::allocated_bytes(&self.c)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> FooArray<Buffer> {
+    ///Returns a reference to the array with the `a` values.
+    fn a(
+        &self,
+    ) -> &<u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA> {
+        &self.a
+    }
+    ///Returns a reference to the array with the `b` values.
+    fn b(
+        &self,
+    ) -> &<bool as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA> {
+        &self.b
+    }
+    ///Returns a reference to the array with the `c` values.
+    fn c(
+        &self,
+    ) -> &<Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType<
+        Vec<u8>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA> {
+        &self.c
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for FooArray<Buffer>
+where
+    Buffer: 'static,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+    <bool as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType<
+        Vec<u8>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        3
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.a),
+            1 => ::core::option::Option::Some(&self.b),
+            2 => ::core::option::Option::Some(&self.c),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayFields
+for FooArray<Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "a",
+            nullable: false,
+            type_name: "u32",
+            extension: ::core::option::Option::None,
+        },
+        narrow::array::FieldInfo {
+            name: "b",
+            nullable: false,
+            type_name: "bool",
+            extension: ::core::option::Option::None,
+        },
+        narrow::array::FieldInfo {
+            name: "c",
+            nullable: true,
+            type_name: "Option<Vec<u8>>",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Foo>
+for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -115,14 +232,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<u32>,
+    >: ::core::iter::Extend<u32>,
     <bool as narrow::array::ArrayType<
         bool,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<bool>,
+    >: ::core::iter::Extend<bool>,
     <Option<
         Vec<u8>,
     > as narrow::array::ArrayType<
@@ -131,18 +248,18 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<Option<Vec<u8>>>,
+    >: ::core::iter::Extend<Option<Vec<u8>>>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Foo { a, b, c }| {
-                self.a.extend(::std::iter::once(a));
-                self.b.extend(::std::iter::once(b));
-                self.c.extend(::std::iter::once(c));
+                self.a.extend(::core::iter::once(a));
+                self.b.extend(::core::iter::once(b));
+                self.c.extend(::core::iter::once(c));
             });
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Foo>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::FromIterator<Foo>
 for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
@@ -151,14 +268,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<u32>,
+    >: ::core::default::Default + ::core::iter::Extend<u32>,
     <bool as narrow::array::ArrayType<
         bool,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<bool>,
+    >: ::core::default::Default + ::core::iter::Extend<bool>,
     <Option<
         Vec<u8>,
     > as narrow::array::ArrayType<
@@ -167,9 +284,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<Option<Vec<u8>>>,
+    >: ::core::default::Default + ::core::iter::Extend<Option<Vec<u8>>>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
         let (a, (b, (c, ()))) = iter
             .into_iter()
             .map(|Foo { a, b, c }| (a, (b, (c, ()))))
@@ -185,14 +302,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <bool as narrow::array::ArrayType<
         bool,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = bool>,
+    >: ::core::iter::IntoIterator<Item = bool>,
     <Option<
         Vec<u8>,
     > as narrow::array::ArrayType<
@@ -201,7 +318,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<Vec<u8>>>,
+    >: ::core::iter::IntoIterator<Item = Option<Vec<u8>>>,
 {
     a: <<u32 as narrow::array::ArrayType<
         u32,
@@ -209,14 +326,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
     b: <<bool as narrow::array::ArrayType<
         bool,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
     c: <<Option<
         Vec<u8>,
     > as narrow::array::ArrayType<
@@ -225,9 +342,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Iterator for FooArrayIter<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::Iterator for FooArrayIter<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -235,14 +352,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <bool as narrow::array::ArrayType<
         bool,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = bool>,
+    >: ::core::iter::IntoIterator<Item = bool>,
     <Option<
         Vec<u8>,
     > as narrow::array::ArrayType<
@@ -251,7 +368,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<Vec<u8>>>,
+    >: ::core::iter::IntoIterator<Item = Option<Vec<u8>>>,
 {
     type Item = Foo;
     fn next(&mut self) -> Option<Self::Item> {
@@ -266,7 +383,7 @@ where
             })
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -274,14 +391,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <bool as narrow::array::ArrayType<
         bool,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = bool>,
+    >: ::core::iter::IntoIterator<Item = bool>,
     <Option<
         Vec<u8>,
     > as narrow::array::ArrayType<
@@ -290,7 +407,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Option<Vec<u8>>>,
+    >: ::core::iter::IntoIterator<Item = Option<Vec<u8>>>,
 {
     type Item = Foo;
     type IntoIter = FooArrayIter<Buffer>;
@@ -11,7 +11,8 @@ impl<const N: usize> narrow::array::ArrayType<Foo<N>> for Foo<N> {
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<Foo<N>, false, Buffer>;
 }
-impl<const N: usize> narrow::array::ArrayType<Foo<N>> for ::std::option::Option<Foo<N>> {
+impl<const N: usize> narrow::array::ArrayType<Foo<N>>
+for ::core::option::Option<Foo<N>> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -24,16 +25,16 @@ impl<const N: usize> narrow::array::StructArrayType for Foo<N> {
 pub struct FooArray<const N: usize, Buffer: narrow::buffer::BufferType>(
     pub narrow::array::NullArray<Foo<N>, false, Buffer>,
 );
-impl<const N: usize, Buffer: narrow::buffer::BufferType> ::std::clone::Clone
+impl<const N: usize, Buffer: narrow::buffer::BufferType> ::core::clone::Clone
 for FooArray<N, Buffer> {
     fn clone(&self) -> Self {
         Self(self.0.clone())
     }
 }
-impl<const N: usize, Buffer: narrow::buffer::BufferType> ::std::default::Default
+impl<const N: usize, Buffer: narrow::buffer::BufferType> ::core::default::Default
 for FooArray<N, Buffer> {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::Length
@@ -42,17 +43,26 @@ for FooArray<N, Buffer> {
         self.0.len()
     }
 }
-impl<const N: usize, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo<N>>
+impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::MemoryUsage
 for FooArray<N, Buffer> {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo<N>>>(&mut self, iter: _I) {
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<const N: usize, Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Foo<N>>
+for FooArray<N, Buffer> {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo<N>>>(&mut self, iter: _I) {
         self.0.extend(iter)
     }
 }
 impl<
     const N: usize,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<Foo<N>> for FooArray<N, Buffer> {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<N>>>(iter: _I) -> Self {
+> ::core::iter::FromIterator<Foo<N>> for FooArray<N, Buffer> {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo<N>>>(iter: _I) -> Self {
         Self(iter.into_iter().collect())
     }
 }
@@ -64,15 +74,15 @@ where
         Foo<N>,
         false,
         Buffer,
-    >: ::std::iter::IntoIterator<Item = Foo<N>>;
-impl<const N: usize, Buffer: narrow::buffer::BufferType> ::std::iter::Iterator
+    >: ::core::iter::IntoIterator<Item = Foo<N>>;
+impl<const N: usize, Buffer: narrow::buffer::BufferType> ::core::iter::Iterator
 for FooArrayIter<N, Buffer> {
     type Item = Foo<N>;
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next()
     }
 }
-impl<const N: usize, Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator
+impl<const N: usize, Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator
 for FooArray<N, Buffer> {
     type Item = Foo<N>;
     type IntoIter = FooArrayIter<N, Buffer>;
@@ -19,7 +19,7 @@ where
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<Foo, false, Buffer>;
 }
-impl narrow::array::ArrayType<Foo> for ::std::option::Option<Foo>
+impl narrow::array::ArrayType<Foo> for ::core::option::Option<Foo>
 where
     Self: Debug,
 {
@@ -40,7 +40,7 @@ struct FooArray<Buffer: narrow::buffer::BufferType>(
 )
 where
     Foo: Debug;
-impl<Buffer: narrow::buffer::BufferType> ::std::clone::Clone for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::clone::Clone for FooArray<Buffer>
 where
     Foo: Debug,
 {
@@ -48,12 +48,12 @@ where
         Self(self.0.clone())
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::default::Default for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::default::Default for FooArray<Buffer>
 where
     Foo: Debug,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer>
@@ -64,20 +64,32 @@ where
         self.0.len()
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> narrow::MemoryUsage for FooArray<Buffer>
+where
+    Foo: Debug,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Foo>
+for FooArray<Buffer>
 where
     Self: Debug,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
         self.0.extend(iter)
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Foo>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::FromIterator<Foo>
 for FooArray<Buffer>
 where
     Self: Debug,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
         Self(iter.into_iter().collect())
     }
 }
@@ -86,8 +98,8 @@ struct FooArrayIter<Buffer: narrow::buffer::BufferType>(
 )
 where
     Self: Debug,
-    narrow::array::NullArray<Foo, false, Buffer>: ::std::iter::IntoIterator<Item = Foo>;
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Iterator for FooArrayIter<Buffer>
+    narrow::array::NullArray<Foo, false, Buffer>: ::core::iter::IntoIterator<Item = Foo>;
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::Iterator for FooArrayIter<Buffer>
 where
     Self: Debug,
 {
@@ -96,7 +108,7 @@ where
         self.0.next()
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator for FooArray<Buffer>
 where
     Self: Debug,
 {
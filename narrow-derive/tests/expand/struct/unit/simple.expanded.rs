@@ -11,7 +11,7 @@ impl narrow::array::ArrayType<Foo> for Foo {
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<Foo, false, Buffer>;
 }
-impl narrow::array::ArrayType<Foo> for ::std::option::Option<Foo> {
+impl narrow::array::ArrayType<Foo> for ::core::option::Option<Foo> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -24,14 +24,14 @@ impl narrow::array::StructArrayType for Foo {
 struct FooArray<Buffer: narrow::buffer::BufferType>(
     narrow::array::NullArray<Foo, false, Buffer>,
 );
-impl<Buffer: narrow::buffer::BufferType> ::std::clone::Clone for FooArray<Buffer> {
+impl<Buffer: narrow::buffer::BufferType> ::core::clone::Clone for FooArray<Buffer> {
     fn clone(&self) -> Self {
         Self(self.0.clone())
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::default::Default for FooArray<Buffer> {
+impl<Buffer: narrow::buffer::BufferType> ::core::default::Default for FooArray<Buffer> {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer> {
@@ -39,14 +39,23 @@ impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer> {
         self.0.len()
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer> {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
+impl<Buffer: narrow::buffer::BufferType> narrow::MemoryUsage for FooArray<Buffer> {
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Foo>
+for FooArray<Buffer> {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
         self.0.extend(iter)
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Foo>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::FromIterator<Foo>
 for FooArray<Buffer> {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
         Self(iter.into_iter().collect())
     }
 }
@@ -54,14 +63,16 @@ struct FooArrayIter<Buffer: narrow::buffer::BufferType>(
     <narrow::array::NullArray<Foo, false, Buffer> as IntoIterator>::IntoIter,
 )
 where
-    narrow::array::NullArray<Foo, false, Buffer>: ::std::iter::IntoIterator<Item = Foo>;
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Iterator for FooArrayIter<Buffer> {
+    narrow::array::NullArray<Foo, false, Buffer>: ::core::iter::IntoIterator<Item = Foo>;
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::Iterator
+for FooArrayIter<Buffer> {
     type Item = Foo;
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next()
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<Buffer> {
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator
+for FooArray<Buffer> {
     type Item = Foo;
     type IntoIter = FooArrayIter<Buffer>;
     fn into_iter(self) -> Self::IntoIter {
@@ -22,7 +22,7 @@ where
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<Foo<N>, false, Buffer>;
 }
-impl<const N: bool> narrow::array::ArrayType<Foo<N>> for ::std::option::Option<Foo<N>>
+impl<const N: bool> narrow::array::ArrayType<Foo<N>> for ::core::option::Option<Foo<N>>
 where
     Self: Sized,
     (): From<Self>,
@@ -46,7 +46,7 @@ pub(super) struct FooArray<const N: bool, Buffer: narrow::buffer::BufferType>(
 where
     Foo<N>: Sized,
     (): From<Foo<N>>;
-impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::clone::Clone
+impl<const N: bool, Buffer: narrow::buffer::BufferType> ::core::clone::Clone
 for FooArray<N, Buffer>
 where
     Foo<N>: Sized,
@@ -56,14 +56,14 @@ where
         Self(self.0.clone())
     }
 }
-impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::default::Default
+impl<const N: bool, Buffer: narrow::buffer::BufferType> ::core::default::Default
 for FooArray<N, Buffer>
 where
     Foo<N>: Sized,
     (): From<Foo<N>>,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::Length
@@ -76,23 +76,38 @@ where
         self.0.len()
     }
 }
-impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo<N>>
+impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::MemoryUsage
+for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<const N: bool, Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Foo<N>>
 for FooArray<N, Buffer>
 where
     Self: Sized,
     (): From<Self>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo<N>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo<N>>>(&mut self, iter: _I) {
         self.0.extend(iter)
     }
 }
-impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Foo<N>>
-for FooArray<N, Buffer>
+impl<
+    const N: bool,
+    Buffer: narrow::buffer::BufferType,
+> ::core::iter::FromIterator<Foo<N>> for FooArray<N, Buffer>
 where
     Self: Sized,
     (): From<Self>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<N>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo<N>>>(iter: _I) -> Self {
         Self(iter.into_iter().collect())
     }
 }
@@ -110,8 +125,8 @@ where
         Foo<N>,
         false,
         Buffer,
-    >: ::std::iter::IntoIterator<Item = Foo<N>>;
-impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::iter::Iterator
+    >: ::core::iter::IntoIterator<Item = Foo<N>>;
+impl<const N: bool, Buffer: narrow::buffer::BufferType> ::core::iter::Iterator
 for FooArrayIter<N, Buffer>
 where
     Self: Sized,
@@ -122,7 +137,7 @@ where
         self.0.next()
     }
 }
-impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator
+impl<const N: bool, Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator
 for FooArray<N, Buffer>
 where
     Self: Sized,
@@ -17,7 +17,7 @@ where
     > = narrow::array::StructArray<Foo<'a, T>, false, Buffer>;
 }
 impl<'a, T: Add<Self> + narrow::array::ArrayType<T>> narrow::array::ArrayType<Foo<'a, T>>
-for ::std::option::Option<Foo<'a, T>>
+for ::core::option::Option<Foo<'a, T>>
 where
     Self: Sized,
     <T as Add<Self>>::Output: Debug,
@@ -52,13 +52,17 @@ impl<
     'a,
     T: Add<Foo<'a, T>> + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::clone::Clone for FooArray<'a, T, Buffer>
+> ::core::clone::Clone for FooArray<'a, T, Buffer>
 where
     Foo<'a, T>: Sized,
     <T as Add<Foo<'a, T>>>::Output: Debug,
     <&'a T as narrow::array::ArrayType<
         &'a T,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone())
@@ -68,7 +72,7 @@ impl<
     'a,
     T: Add<Foo<'a, T>> + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::default::Default for FooArray<'a, T, Buffer>
+> ::core::default::Default for FooArray<'a, T, Buffer>
 where
     Foo<'a, T>: Sized,
     <T as Add<Foo<'a, T>>>::Output: Debug,
@@ -78,10 +82,10 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<
@@ -102,9 +106,46 @@ where
 }
 impl<
     'a,
-    T: Add<Self> + narrow::array::ArrayType<T>,
+    T: Add<Foo<'a, T>> + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Extend<Foo<'a, T>> for FooArray<'a, T, Buffer>
+> narrow::MemoryUsage for FooArray<'a, T, Buffer>
+where
+    Foo<'a, T>: Sized,
+    <T as Add<Foo<'a, T>>>::Output: Debug,
+    <&'a T as narrow::array::ArrayType<
+        &'a T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<
+    'a,
+    T: Add<Foo<'a, T>> + narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayFields for FooArray<'a, T, Buffer>
+where
+    Foo<'a, T>: Sized,
+    <T as Add<Foo<'a, T>>>::Output: Debug,
+{
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "&'a T",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<
+    'a,
+    T: Add<Self> + narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::GrowableBuffer,
+> ::core::iter::Extend<Foo<'a, T>> for FooArray<'a, T, Buffer>
 where
     Self: Sized,
     <T as Add<Self>>::Output: Debug,
@@ -114,12 +155,12 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<&'a T>,
+    >: ::core::iter::Extend<&'a T>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo<'a, T>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Foo(_0)| {
-                self.0.extend(::std::iter::once(_0));
+                self.0.extend(::core::iter::once(_0));
             });
     }
 }
@@ -127,7 +168,7 @@ impl<
     'a,
     T: Add<Self> + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<Foo<'a, T>> for FooArray<'a, T, Buffer>
+> ::core::iter::FromIterator<Foo<'a, T>> for FooArray<'a, T, Buffer>
 where
     Self: Sized,
     <T as Add<Self>>::Output: Debug,
@@ -137,9 +178,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<&'a T>,
+    >: ::core::default::Default + ::core::iter::Extend<&'a T>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo<'a, T>>>(iter: _I) -> Self {
         let (_0, ()) = iter.into_iter().map(|Foo(_0)| (_0, ())).unzip();
         Self(_0)
     }
@@ -155,7 +196,7 @@ struct FooArrayIter<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     Self: Sized,
@@ -166,12 +207,12 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>;
+    >: ::core::iter::IntoIterator<Item = &'a T>;
 impl<
     'a,
     T: Add<Self> + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Iterator for FooArrayIter<'a, T, Buffer>
+> ::core::iter::Iterator for FooArrayIter<'a, T, Buffer>
 where
     Self: Sized,
     <T as Add<Self>>::Output: Debug,
@@ -181,7 +222,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>,
+    >: ::core::iter::IntoIterator<Item = &'a T>,
 {
     type Item = Foo<'a, T>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -192,7 +233,7 @@ impl<
     'a,
     T: Add<Self> + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::IntoIterator for FooArray<'a, T, Buffer>
+> ::core::iter::IntoIterator for FooArray<'a, T, Buffer>
 where
     Self: Sized,
     <T as Add<Self>>::Output: Debug,
@@ -202,7 +243,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>,
+    >: ::core::iter::IntoIterator<Item = &'a T>,
 {
     type Item = Foo<'a, T>;
     type IntoIter = FooArrayIter<'a, T, Buffer>;
@@ -219,7 +260,7 @@ impl<T: narrow::array::ArrayType<T>> narrow::array::ArrayType<FooBar<T>> for Foo
     > = narrow::array::StructArray<FooBar<T>, false, Buffer>;
 }
 impl<T: narrow::array::ArrayType<T>> narrow::array::ArrayType<FooBar<T>>
-for ::std::option::Option<FooBar<T>> {
+for ::core::option::Option<FooBar<T>> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -237,11 +278,15 @@ struct FooBarArray<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::Buffe
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::clone::Clone for FooBarArray<T, Buffer>
+> ::core::clone::Clone for FooBarArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone())
@@ -250,7 +295,7 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::default::Default for FooBarArray<T, Buffer>
+> ::core::default::Default for FooBarArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -258,10 +303,10 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::BufferType> narrow::Length
@@ -278,7 +323,56 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Extend<FooBar<T>> for FooBarArray<T, Buffer>
+> narrow::MemoryUsage for FooBarArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooBarArray<T, Buffer>
+where
+    Buffer: 'static,
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        1
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.0),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayFields for FooBarArray<T, Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "T",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::GrowableBuffer,
+> ::core::iter::Extend<FooBar<T>> for FooBarArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -286,19 +380,19 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<T>,
+    >: ::core::iter::Extend<T>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = FooBar<T>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = FooBar<T>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|FooBar(_0)| {
-                self.0.extend(::std::iter::once(_0));
+                self.0.extend(::core::iter::once(_0));
             });
     }
 }
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<FooBar<T>> for FooBarArray<T, Buffer>
+> ::core::iter::FromIterator<FooBar<T>> for FooBarArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -306,9 +400,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<T>,
+    >: ::core::default::Default + ::core::iter::Extend<T>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = FooBar<T>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = FooBar<T>>>(iter: _I) -> Self {
         let (_0, ()) = iter.into_iter().map(|FooBar(_0)| (_0, ())).unzip();
         Self(_0)
     }
@@ -323,7 +417,7 @@ struct FooBarArrayIter<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     <T as narrow::array::ArrayType<
@@ -332,11 +426,11 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>;
+    >: ::core::iter::IntoIterator<Item = T>;
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Iterator for FooBarArrayIter<T, Buffer>
+> ::core::iter::Iterator for FooBarArrayIter<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -344,7 +438,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>,
+    >: ::core::iter::IntoIterator<Item = T>,
 {
     type Item = FooBar<T>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -354,7 +448,7 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::IntoIterator for FooBarArray<T, Buffer>
+> ::core::iter::IntoIterator for FooBarArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -362,7 +456,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>,
+    >: ::core::iter::IntoIterator<Item = T>,
 {
     type Item = FooBar<T>;
     type IntoIter = FooBarArrayIter<T, Buffer>;
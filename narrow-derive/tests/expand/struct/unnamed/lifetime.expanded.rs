@@ -8,7 +8,7 @@ for Foo<'a, T> {
     > = narrow::array::StructArray<Foo<'a, T>, false, Buffer>;
 }
 impl<'a, T: narrow::array::ArrayType<T>> narrow::array::ArrayType<Foo<'a, T>>
-for ::std::option::Option<Foo<'a, T>> {
+for ::core::option::Option<Foo<'a, T>> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -27,11 +27,15 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::clone::Clone for FooArray<'a, T, Buffer>
+> ::core::clone::Clone for FooArray<'a, T, Buffer>
 where
     <&'a T as narrow::array::ArrayType<
         &'a T,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone())
@@ -41,7 +45,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::default::Default for FooArray<'a, T, Buffer>
+> ::core::default::Default for FooArray<'a, T, Buffer>
 where
     <&'a T as narrow::array::ArrayType<
         &'a T,
@@ -49,10 +53,10 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<
@@ -73,7 +77,38 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Extend<Foo<'a, T>> for FooArray<'a, T, Buffer>
+> narrow::MemoryUsage for FooArray<'a, T, Buffer>
+where
+    <&'a T as narrow::array::ArrayType<
+        &'a T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayFields for FooArray<'a, T, Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "&'a T",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::GrowableBuffer,
+> ::core::iter::Extend<Foo<'a, T>> for FooArray<'a, T, Buffer>
 where
     <&'a T as narrow::array::ArrayType<
         &'a T,
@@ -81,12 +116,12 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<&'a T>,
+    >: ::core::iter::Extend<&'a T>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo<'a, T>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Foo(_0)| {
-                self.0.extend(::std::iter::once(_0));
+                self.0.extend(::core::iter::once(_0));
             });
     }
 }
@@ -94,7 +129,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<Foo<'a, T>> for FooArray<'a, T, Buffer>
+> ::core::iter::FromIterator<Foo<'a, T>> for FooArray<'a, T, Buffer>
 where
     <&'a T as narrow::array::ArrayType<
         &'a T,
@@ -102,9 +137,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<&'a T>,
+    >: ::core::default::Default + ::core::iter::Extend<&'a T>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<'a, T>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo<'a, T>>>(iter: _I) -> Self {
         let (_0, ()) = iter.into_iter().map(|Foo(_0)| (_0, ())).unzip();
         Self(_0)
     }
@@ -120,7 +155,7 @@ struct FooArrayIter<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     <&'a T as narrow::array::ArrayType<
@@ -129,12 +164,12 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>;
+    >: ::core::iter::IntoIterator<Item = &'a T>;
 impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Iterator for FooArrayIter<'a, T, Buffer>
+> ::core::iter::Iterator for FooArrayIter<'a, T, Buffer>
 where
     <&'a T as narrow::array::ArrayType<
         &'a T,
@@ -142,7 +177,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>,
+    >: ::core::iter::IntoIterator<Item = &'a T>,
 {
     type Item = Foo<'a, T>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -153,7 +188,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::IntoIterator for FooArray<'a, T, Buffer>
+> ::core::iter::IntoIterator for FooArray<'a, T, Buffer>
 where
     <&'a T as narrow::array::ArrayType<
         &'a T,
@@ -161,7 +196,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a T>,
+    >: ::core::iter::IntoIterator<Item = &'a T>,
 {
     type Item = Foo<'a, T>;
     type IntoIter = FooArrayIter<'a, T, Buffer>;
@@ -6,7 +6,7 @@ impl narrow::array::ArrayType<Bar> for Bar {
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<Bar, false, Buffer>;
 }
-impl narrow::array::ArrayType<Bar> for ::std::option::Option<Bar> {
+impl narrow::array::ArrayType<Bar> for ::core::option::Option<Bar> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -30,26 +30,42 @@ struct BarArray<Buffer: narrow::buffer::BufferType>(
         u64,
     >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>,
 );
-impl<Buffer: narrow::buffer::BufferType> ::std::clone::Clone for BarArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::clone::Clone for BarArray<Buffer>
 where
     <u8 as narrow::array::ArrayType<
         u8,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
     <u16 as narrow::array::ArrayType<
         u16,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
     <u32 as narrow::array::ArrayType<
         u32,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
     <u64 as narrow::array::ArrayType<
         u64,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone(), self.2.clone(), self.3.clone())
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::default::Default for BarArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::default::Default for BarArray<Buffer>
 where
     <u8 as narrow::array::ArrayType<
         u8,
@@ -57,35 +73,35 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
     <u16 as narrow::array::ArrayType<
         u16,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
     <u64 as narrow::array::ArrayType<
         u64,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
         Self(
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
-            ::std::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
+            ::core::default::Default::default(),
         )
     }
 }
@@ -108,7 +124,95 @@ where
         self.0.len()
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Bar> for BarArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> narrow::MemoryUsage for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType<
+        u8,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+    <u16 as narrow::array::ArrayType<
+        u16,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+    <u64 as narrow::array::ArrayType<
+        u64,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+            + narrow::MemoryUsage::buffer_bytes(&self.2)
+            + narrow::MemoryUsage::buffer_bytes(&self.3)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+            + narrow::MemoryUsage::allocated_bytes(&self.2)
+            + narrow::MemoryUsage::allocated_bytes(&self.3)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for BarArray<Buffer>
+where
+    Buffer: 'static,
+    <u8 as narrow::array::ArrayType<
+        u8,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+    <u16 as narrow::array::ArrayType<
+        u16,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+    <u64 as narrow::array::ArrayType<
+        u64,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        4
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.0),
+            1 => ::core::option::Option::Some(&self.1),
+            2 => ::core::option::Option::Some(&self.2),
+            3 => ::core::option::Option::Some(&self.3),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayFields
+for BarArray<Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "u8",
+            extension: ::core::option::Option::None,
+        },
+        narrow::array::FieldInfo {
+            name: "_1",
+            nullable: false,
+            type_name: "u16",
+            extension: ::core::option::Option::None,
+        },
+        narrow::array::FieldInfo {
+            name: "_2",
+            nullable: false,
+            type_name: "u32",
+            extension: ::core::option::Option::None,
+        },
+        narrow::array::FieldInfo {
+            name: "_3",
+            nullable: false,
+            type_name: "u64",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Bar>
+for BarArray<Buffer>
 where
     <u8 as narrow::array::ArrayType<
         u8,
@@ -116,40 +220,40 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<u8>,
+    >: ::core::iter::Extend<u8>,
     <u16 as narrow::array::ArrayType<
         u16,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<u16>,
+    >: ::core::iter::Extend<u16>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<u32>,
+    >: ::core::iter::Extend<u32>,
     <u64 as narrow::array::ArrayType<
         u64,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<u64>,
+    >: ::core::iter::Extend<u64>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Bar>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Bar>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Bar(_0, _1, _2, _3)| {
-                self.0.extend(::std::iter::once(_0));
-                self.1.extend(::std::iter::once(_1));
-                self.2.extend(::std::iter::once(_2));
-                self.3.extend(::std::iter::once(_3));
+                self.0.extend(::core::iter::once(_0));
+                self.1.extend(::core::iter::once(_1));
+                self.2.extend(::core::iter::once(_2));
+                self.3.extend(::core::iter::once(_3));
             });
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Bar>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::FromIterator<Bar>
 for BarArray<Buffer>
 where
     <u8 as narrow::array::ArrayType<
@@ -158,30 +262,30 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<u8>,
+    >: ::core::default::Default + ::core::iter::Extend<u8>,
     <u16 as narrow::array::ArrayType<
         u16,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<u16>,
+    >: ::core::default::Default + ::core::iter::Extend<u16>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<u32>,
+    >: ::core::default::Default + ::core::iter::Extend<u32>,
     <u64 as narrow::array::ArrayType<
         u64,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<u64>,
+    >: ::core::default::Default + ::core::iter::Extend<u64>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Bar>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Bar>>(iter: _I) -> Self {
         let (_0, (_1, (_2, (_3, ())))) = iter
             .into_iter()
             .map(|Bar(_0, _1, _2, _3)| (_0, (_1, (_2, (_3, ())))))
@@ -196,28 +300,28 @@ struct BarArrayIter<Buffer: narrow::buffer::BufferType>(
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
     <<u16 as narrow::array::ArrayType<
         u16,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
     <<u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
     <<u64 as narrow::array::ArrayType<
         u64,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     <u8 as narrow::array::ArrayType<
@@ -226,29 +330,29 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u8>,
+    >: ::core::iter::IntoIterator<Item = u8>,
     <u16 as narrow::array::ArrayType<
         u16,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u16>,
+    >: ::core::iter::IntoIterator<Item = u16>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <u64 as narrow::array::ArrayType<
         u64,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u64>;
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Iterator for BarArrayIter<Buffer>
+    >: ::core::iter::IntoIterator<Item = u64>;
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::Iterator for BarArrayIter<Buffer>
 where
     <u8 as narrow::array::ArrayType<
         u8,
@@ -256,28 +360,28 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u8>,
+    >: ::core::iter::IntoIterator<Item = u8>,
     <u16 as narrow::array::ArrayType<
         u16,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u16>,
+    >: ::core::iter::IntoIterator<Item = u16>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <u64 as narrow::array::ArrayType<
         u64,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u64>,
+    >: ::core::iter::IntoIterator<Item = u64>,
 {
     type Item = Bar;
     fn next(&mut self) -> Option<Self::Item> {
@@ -293,7 +397,7 @@ where
             })
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for BarArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator for BarArray<Buffer>
 where
     <u8 as narrow::array::ArrayType<
         u8,
@@ -301,28 +405,28 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u8>,
+    >: ::core::iter::IntoIterator<Item = u8>,
     <u16 as narrow::array::ArrayType<
         u16,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u16>,
+    >: ::core::iter::IntoIterator<Item = u16>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
     <u64 as narrow::array::ArrayType<
         u64,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u64>,
+    >: ::core::iter::IntoIterator<Item = u64>,
 {
     type Item = Bar;
     type IntoIter = BarArrayIter<Buffer>;
@@ -6,7 +6,7 @@ impl narrow::array::ArrayType<Foo> for Foo {
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<Foo, false, Buffer>;
 }
-impl narrow::array::ArrayType<Foo> for ::std::option::Option<Foo> {
+impl narrow::array::ArrayType<Foo> for ::core::option::Option<Foo> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -21,17 +21,21 @@ struct FooArray<Buffer: narrow::buffer::BufferType>(
         u32,
     >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>,
 );
-impl<Buffer: narrow::buffer::BufferType> ::std::clone::Clone for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::clone::Clone for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone())
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::default::Default for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::default::Default for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -39,10 +43,10 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer>
@@ -55,7 +59,50 @@ where
         self.0.len()
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> narrow::MemoryUsage for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for FooArray<Buffer>
+where
+    Buffer: 'static,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        1
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.0),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayFields
+for FooArray<Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "u32",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Foo>
+for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -63,16 +110,16 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<u32>,
+    >: ::core::iter::Extend<u32>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Foo(_0)| {
-                self.0.extend(::std::iter::once(_0));
+                self.0.extend(::core::iter::once(_0));
             });
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Foo>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::FromIterator<Foo>
 for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
@@ -81,9 +128,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<u32>,
+    >: ::core::default::Default + ::core::iter::Extend<u32>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo>>(iter: _I) -> Self {
         let (_0, ()) = iter.into_iter().map(|Foo(_0)| (_0, ())).unzip();
         Self(_0)
     }
@@ -95,7 +142,7 @@ struct FooArrayIter<Buffer: narrow::buffer::BufferType>(
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     <u32 as narrow::array::ArrayType<
@@ -104,8 +151,8 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>;
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Iterator for FooArrayIter<Buffer>
+    >: ::core::iter::IntoIterator<Item = u32>;
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::Iterator for FooArrayIter<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -113,14 +160,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
 {
     type Item = Foo;
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|first| { Foo(first) })
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
         u32,
@@ -128,7 +175,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
 {
     type Item = Foo;
     type IntoIter = FooArrayIter<Buffer>;
@@ -144,7 +191,7 @@ impl narrow::array::ArrayType<Bar> for Bar {
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<Bar, false, Buffer>;
 }
-impl narrow::array::ArrayType<Bar> for ::std::option::Option<Bar> {
+impl narrow::array::ArrayType<Bar> for ::core::option::Option<Bar> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -159,17 +206,21 @@ struct BarArray<Buffer: narrow::buffer::BufferType>(
         Foo,
     >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>,
 );
-impl<Buffer: narrow::buffer::BufferType> ::std::clone::Clone for BarArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::clone::Clone for BarArray<Buffer>
 where
     <Foo as narrow::array::ArrayType<
         Foo,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone())
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::default::Default for BarArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::default::Default for BarArray<Buffer>
 where
     <Foo as narrow::array::ArrayType<
         Foo,
@@ -177,10 +228,10 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<Buffer: narrow::buffer::BufferType> narrow::Length for BarArray<Buffer>
@@ -193,7 +244,50 @@ where
         self.0.len()
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Bar> for BarArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> narrow::MemoryUsage for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType<
+        Foo,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayChildren
+for BarArray<Buffer>
+where
+    Buffer: 'static,
+    <Foo as narrow::array::ArrayType<
+        Foo,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        1
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.0),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::array::StructArrayFields
+for BarArray<Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "Foo",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<Bar>
+for BarArray<Buffer>
 where
     <Foo as narrow::array::ArrayType<
         Foo,
@@ -201,16 +295,16 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<Foo>,
+    >: ::core::iter::Extend<Foo>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Bar>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Bar>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Bar(_0)| {
-                self.0.extend(::std::iter::once(_0));
+                self.0.extend(::core::iter::once(_0));
             });
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<Bar>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::FromIterator<Bar>
 for BarArray<Buffer>
 where
     <Foo as narrow::array::ArrayType<
@@ -219,9 +313,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<Foo>,
+    >: ::core::default::Default + ::core::iter::Extend<Foo>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Bar>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Bar>>(iter: _I) -> Self {
         let (_0, ()) = iter.into_iter().map(|Bar(_0)| (_0, ())).unzip();
         Self(_0)
     }
@@ -233,7 +327,7 @@ struct BarArrayIter<Buffer: narrow::buffer::BufferType>(
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     <Foo as narrow::array::ArrayType<
@@ -242,8 +336,8 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Foo>;
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::Iterator for BarArrayIter<Buffer>
+    >: ::core::iter::IntoIterator<Item = Foo>;
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::Iterator for BarArrayIter<Buffer>
 where
     <Foo as narrow::array::ArrayType<
         Foo,
@@ -251,14 +345,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Foo>,
+    >: ::core::iter::IntoIterator<Item = Foo>,
 {
     type Item = Bar;
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|first| { Bar(first) })
     }
 }
-impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for BarArray<Buffer>
+impl<Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator for BarArray<Buffer>
 where
     <Foo as narrow::array::ArrayType<
         Foo,
@@ -266,7 +360,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Foo>,
+    >: ::core::iter::IntoIterator<Item = Foo>,
 {
     type Item = Bar;
     type IntoIter = BarArrayIter<Buffer>;
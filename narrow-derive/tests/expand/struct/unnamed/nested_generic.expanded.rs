@@ -14,7 +14,7 @@ where
     > = narrow::array::StructArray<Foo<T>, false, Buffer>;
 }
 impl<T: narrow::array::ArrayType<T>> narrow::array::ArrayType<Foo<T>>
-for ::std::option::Option<Foo<T>>
+for ::core::option::Option<Foo<T>>
 where
     T: Copy,
 {
@@ -40,12 +40,16 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::clone::Clone for FooArray<T, Buffer>
+> ::core::clone::Clone for FooArray<T, Buffer>
 where
     T: Copy,
     <T as narrow::array::ArrayType<
         T,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone())
@@ -54,7 +58,7 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::default::Default for FooArray<T, Buffer>
+> ::core::default::Default for FooArray<T, Buffer>
 where
     T: Copy,
     <T as narrow::array::ArrayType<
@@ -63,10 +67,10 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::BufferType> narrow::Length
@@ -84,7 +88,61 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Extend<Foo<T>> for FooArray<T, Buffer>
+> narrow::MemoryUsage for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooArray<T, Buffer>
+where
+    T: Copy,
+    Buffer: 'static,
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        1
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.0),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayFields for FooArray<T, Buffer>
+where
+    T: Copy,
+{
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "T",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::GrowableBuffer,
+> ::core::iter::Extend<Foo<T>> for FooArray<T, Buffer>
 where
     T: Copy,
     <T as narrow::array::ArrayType<
@@ -93,19 +151,19 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<T>,
+    >: ::core::iter::Extend<T>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo<T>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo<T>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Foo(_0)| {
-                self.0.extend(::std::iter::once(_0));
+                self.0.extend(::core::iter::once(_0));
             });
     }
 }
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<Foo<T>> for FooArray<T, Buffer>
+> ::core::iter::FromIterator<Foo<T>> for FooArray<T, Buffer>
 where
     T: Copy,
     <T as narrow::array::ArrayType<
@@ -114,9 +172,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<T>,
+    >: ::core::default::Default + ::core::iter::Extend<T>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<T>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo<T>>>(iter: _I) -> Self {
         let (_0, ()) = iter.into_iter().map(|Foo(_0)| (_0, ())).unzip();
         Self(_0)
     }
@@ -128,7 +186,7 @@ struct FooArrayIter<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::Buff
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     T: Copy,
@@ -138,11 +196,11 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>;
+    >: ::core::iter::IntoIterator<Item = T>;
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Iterator for FooArrayIter<T, Buffer>
+> ::core::iter::Iterator for FooArrayIter<T, Buffer>
 where
     T: Copy,
     <T as narrow::array::ArrayType<
@@ -151,7 +209,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>,
+    >: ::core::iter::IntoIterator<Item = T>,
 {
     type Item = Foo<T>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -161,7 +219,7 @@ where
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::IntoIterator for FooArray<T, Buffer>
+> ::core::iter::IntoIterator for FooArray<T, Buffer>
 where
     T: Copy,
     <T as narrow::array::ArrayType<
@@ -170,7 +228,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>,
+    >: ::core::iter::IntoIterator<Item = T>,
 {
     type Item = Foo<T>;
     type IntoIter = FooArrayIter<T, Buffer>;
@@ -188,7 +246,7 @@ for Bar<'a, T> {
     > = narrow::array::StructArray<Bar<'a, T>, false, Buffer>;
 }
 impl<'a, T: narrow::array::ArrayType<T>> narrow::array::ArrayType<Bar<'a, T>>
-for ::std::option::Option<Bar<'a, T>> {
+for ::core::option::Option<Bar<'a, T>> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -209,13 +267,17 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::clone::Clone for BarArray<'a, T, Buffer>
+> ::core::clone::Clone for BarArray<'a, T, Buffer>
 where
     <&'a Foo<
         T,
     > as narrow::array::ArrayType<
         &'a Foo<T>,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone())
@@ -225,7 +287,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::default::Default for BarArray<'a, T, Buffer>
+> ::core::default::Default for BarArray<'a, T, Buffer>
 where
     <&'a Foo<
         T,
@@ -235,10 +297,10 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<
@@ -261,7 +323,40 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Extend<Bar<'a, T>> for BarArray<'a, T, Buffer>
+> narrow::MemoryUsage for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType<
+        &'a Foo<T>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayFields for BarArray<'a, T, Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "&'a Foo<T>",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::GrowableBuffer,
+> ::core::iter::Extend<Bar<'a, T>> for BarArray<'a, T, Buffer>
 where
     <&'a Foo<
         T,
@@ -271,12 +366,12 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<&'a Foo<T>>,
+    >: ::core::iter::Extend<&'a Foo<T>>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Bar<'a, T>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Bar<'a, T>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Bar(_0)| {
-                self.0.extend(::std::iter::once(_0));
+                self.0.extend(::core::iter::once(_0));
             });
     }
 }
@@ -284,7 +379,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<Bar<'a, T>> for BarArray<'a, T, Buffer>
+> ::core::iter::FromIterator<Bar<'a, T>> for BarArray<'a, T, Buffer>
 where
     <&'a Foo<
         T,
@@ -294,9 +389,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<&'a Foo<T>>,
+    >: ::core::default::Default + ::core::iter::Extend<&'a Foo<T>>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Bar<'a, T>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Bar<'a, T>>>(iter: _I) -> Self {
         let (_0, ()) = iter.into_iter().map(|Bar(_0)| (_0, ())).unzip();
         Self(_0)
     }
@@ -314,7 +409,7 @@ struct BarArrayIter<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     <&'a Foo<
@@ -325,12 +420,12 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a Foo<T>>;
+    >: ::core::iter::IntoIterator<Item = &'a Foo<T>>;
 impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Iterator for BarArrayIter<'a, T, Buffer>
+> ::core::iter::Iterator for BarArrayIter<'a, T, Buffer>
 where
     <&'a Foo<
         T,
@@ -340,7 +435,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a Foo<T>>,
+    >: ::core::iter::IntoIterator<Item = &'a Foo<T>>,
 {
     type Item = Bar<'a, T>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -351,7 +446,7 @@ impl<
     'a,
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::IntoIterator for BarArray<'a, T, Buffer>
+> ::core::iter::IntoIterator for BarArray<'a, T, Buffer>
 where
     <&'a Foo<
         T,
@@ -361,7 +456,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = &'a Foo<T>>,
+    >: ::core::iter::IntoIterator<Item = &'a Foo<T>>,
 {
     type Item = Bar<'a, T>;
     type IntoIter = BarArrayIter<'a, T, Buffer>;
@@ -377,7 +472,7 @@ impl<'a> narrow::array::ArrayType<FooBar<'a>> for FooBar<'a> {
         UnionLayout: narrow::array::UnionType,
     > = narrow::array::StructArray<FooBar<'a>, false, Buffer>;
 }
-impl<'a> narrow::array::ArrayType<FooBar<'a>> for ::std::option::Option<FooBar<'a>> {
+impl<'a> narrow::array::ArrayType<FooBar<'a>> for ::core::option::Option<FooBar<'a>> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -395,7 +490,7 @@ struct FooBarArray<'a, Buffer: narrow::buffer::BufferType>(
         Bar<'a, u32>,
     >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>,
 );
-impl<'a, Buffer: narrow::buffer::BufferType> ::std::clone::Clone
+impl<'a, Buffer: narrow::buffer::BufferType> ::core::clone::Clone
 for FooBarArray<'a, Buffer>
 where
     <Bar<
@@ -403,13 +498,17 @@ where
         u32,
     > as narrow::array::ArrayType<
         Bar<'a, u32>,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone())
     }
 }
-impl<'a, Buffer: narrow::buffer::BufferType> ::std::default::Default
+impl<'a, Buffer: narrow::buffer::BufferType> ::core::default::Default
 for FooBarArray<'a, Buffer>
 where
     <Bar<
@@ -421,10 +520,10 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default())
+        Self(::core::default::Default::default())
     }
 }
 impl<'a, Buffer: narrow::buffer::BufferType> narrow::Length for FooBarArray<'a, Buffer>
@@ -440,7 +539,35 @@ where
         self.0.len()
     }
 }
-impl<'a, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<FooBar<'a>>
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::MemoryUsage
+for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType<
+        Bar<'a, u32>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+    }
+}
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::array::StructArrayFields
+for FooBarArray<'a, Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "Bar<'a, u32>",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<'a, Buffer: narrow::buffer::GrowableBuffer> ::core::iter::Extend<FooBar<'a>>
 for FooBarArray<'a, Buffer>
 where
     <Bar<
@@ -452,16 +579,16 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<Bar<'a, u32>>,
+    >: ::core::iter::Extend<Bar<'a, u32>>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = FooBar<'a>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = FooBar<'a>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|FooBar(_0)| {
-                self.0.extend(::std::iter::once(_0));
+                self.0.extend(::core::iter::once(_0));
             });
     }
 }
-impl<'a, Buffer: narrow::buffer::BufferType> ::std::iter::FromIterator<FooBar<'a>>
+impl<'a, Buffer: narrow::buffer::BufferType> ::core::iter::FromIterator<FooBar<'a>>
 for FooBarArray<'a, Buffer>
 where
     <Bar<
@@ -473,9 +600,9 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<Bar<'a, u32>>,
+    >: ::core::default::Default + ::core::iter::Extend<Bar<'a, u32>>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = FooBar<'a>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = FooBar<'a>>>(iter: _I) -> Self {
         let (_0, ()) = iter.into_iter().map(|FooBar(_0)| (_0, ())).unzip();
         Self(_0)
     }
@@ -490,7 +617,7 @@ struct FooBarArrayIter<'a, Buffer: narrow::buffer::BufferType>(
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     <Bar<
@@ -502,8 +629,8 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Bar<'a, u32>>;
-impl<'a, Buffer: narrow::buffer::BufferType> ::std::iter::Iterator
+    >: ::core::iter::IntoIterator<Item = Bar<'a, u32>>;
+impl<'a, Buffer: narrow::buffer::BufferType> ::core::iter::Iterator
 for FooBarArrayIter<'a, Buffer>
 where
     <Bar<
@@ -515,14 +642,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Bar<'a, u32>>,
+    >: ::core::iter::IntoIterator<Item = Bar<'a, u32>>,
 {
     type Item = FooBar<'a>;
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|first| { FooBar(first) })
     }
 }
-impl<'a, Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator
+impl<'a, Buffer: narrow::buffer::BufferType> ::core::iter::IntoIterator
 for FooBarArray<'a, Buffer>
 where
     <Bar<
@@ -534,7 +661,7 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = Bar<'a, u32>>,
+    >: ::core::iter::IntoIterator<Item = Bar<'a, u32>>,
 {
     type Item = FooBar<'a>;
     type IntoIter = FooBarArrayIter<'a, Buffer>;
@@ -8,7 +8,7 @@ for Foo<T> {
     > = narrow::array::StructArray<Foo<T>, false, Buffer>;
 }
 impl<T: Sized + narrow::array::ArrayType<T>> narrow::array::ArrayType<Foo<T>>
-for ::std::option::Option<Foo<T>> {
+for ::core::option::Option<Foo<T>> {
     type Array<
         Buffer: narrow::buffer::BufferType,
         OffsetItem: narrow::offset::OffsetElement,
@@ -32,14 +32,22 @@ struct FooArray<
 impl<
     T: Sized + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::clone::Clone for FooArray<T, Buffer>
+> ::core::clone::Clone for FooArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
     <u32 as narrow::array::ArrayType<
         u32,
-    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: ::std::clone::Clone,
+    >>::Array<
+        Buffer,
+        narrow::offset::NA,
+        narrow::array::union::NA,
+    >: ::core::clone::Clone,
 {
     fn clone(&self) -> Self {
         Self(self.0.clone(), self.1.clone())
@@ -48,7 +56,7 @@ where
 impl<
     T: Sized + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::default::Default for FooArray<T, Buffer>
+> ::core::default::Default for FooArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -56,17 +64,17 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default,
+    >: ::core::default::Default,
 {
     fn default() -> Self {
-        Self(::std::default::Default::default(), ::std::default::Default::default())
+        Self(::core::default::Default::default(), ::core::default::Default::default())
     }
 }
 impl<
@@ -88,7 +96,71 @@ where
 impl<
     T: Sized + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Extend<Foo<T>> for FooArray<T, Buffer>
+> narrow::MemoryUsage for FooArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        narrow::MemoryUsage::buffer_bytes(&self.0)
+            + narrow::MemoryUsage::buffer_bytes(&self.1)
+    }
+    fn allocated_bytes(&self) -> usize {
+        narrow::MemoryUsage::allocated_bytes(&self.0)
+            + narrow::MemoryUsage::allocated_bytes(&self.1)
+    }
+}
+impl<
+    T: Sized + narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayChildren for FooArray<T, Buffer>
+where
+    Buffer: 'static,
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: 'static,
+{
+    fn num_children(&self) -> usize {
+        2
+    }
+    fn child(&self, index: usize) -> ::core::option::Option<&dyn ::core::any::Any> {
+        match index {
+            0 => ::core::option::Option::Some(&self.0),
+            1 => ::core::option::Option::Some(&self.1),
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+impl<
+    T: Sized + narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::array::StructArrayFields for FooArray<T, Buffer> {
+    const FIELDS: &'static [narrow::array::FieldInfo] = &[
+        narrow::array::FieldInfo {
+            name: "_0",
+            nullable: false,
+            type_name: "T",
+            extension: ::core::option::Option::None,
+        },
+        narrow::array::FieldInfo {
+            name: "_1",
+            nullable: false,
+            type_name: "u32",
+            extension: ::core::option::Option::None,
+        },
+    ];
+}
+impl<
+    T: Sized + narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::GrowableBuffer,
+> ::core::iter::Extend<Foo<T>> for FooArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -96,27 +168,27 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<T>,
+    >: ::core::iter::Extend<T>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::Extend<u32>,
+    >: ::core::iter::Extend<u32>,
 {
-    fn extend<_I: ::std::iter::IntoIterator<Item = Foo<T>>>(&mut self, iter: _I) {
+    fn extend<_I: ::core::iter::IntoIterator<Item = Foo<T>>>(&mut self, iter: _I) {
         iter.into_iter()
             .for_each(|Foo(_0, _1)| {
-                self.0.extend(::std::iter::once(_0));
-                self.1.extend(::std::iter::once(_1));
+                self.0.extend(::core::iter::once(_0));
+                self.1.extend(::core::iter::once(_1));
             });
     }
 }
 impl<
     T: Sized + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::FromIterator<Foo<T>> for FooArray<T, Buffer>
+> ::core::iter::FromIterator<Foo<T>> for FooArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -124,16 +196,16 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<T>,
+    >: ::core::default::Default + ::core::iter::Extend<T>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::default::Default + ::std::iter::Extend<u32>,
+    >: ::core::default::Default + ::core::iter::Extend<u32>,
 {
-    fn from_iter<_I: ::std::iter::IntoIterator<Item = Foo<T>>>(iter: _I) -> Self {
+    fn from_iter<_I: ::core::iter::IntoIterator<Item = Foo<T>>>(iter: _I) -> Self {
         let (_0, (_1, ())) = iter.into_iter().map(|Foo(_0, _1)| (_0, (_1, ()))).unzip();
         Self(_0, _1)
     }
@@ -148,14 +220,14 @@ struct FooArrayIter<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
     <<u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    > as ::std::iter::IntoIterator>::IntoIter,
+    > as ::core::iter::IntoIterator>::IntoIter,
 )
 where
     <T as narrow::array::ArrayType<
@@ -164,18 +236,18 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>,
+    >: ::core::iter::IntoIterator<Item = T>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>;
+    >: ::core::iter::IntoIterator<Item = u32>;
 impl<
     T: Sized + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::Iterator for FooArrayIter<T, Buffer>
+> ::core::iter::Iterator for FooArrayIter<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -183,14 +255,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>,
+    >: ::core::iter::IntoIterator<Item = T>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
 {
     type Item = Foo<T>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -200,7 +272,7 @@ where
 impl<
     T: Sized + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
-> ::std::iter::IntoIterator for FooArray<T, Buffer>
+> ::core::iter::IntoIterator for FooArray<T, Buffer>
 where
     <T as narrow::array::ArrayType<
         T,
@@ -208,14 +280,14 @@ where
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = T>,
+    >: ::core::iter::IntoIterator<Item = T>,
     <u32 as narrow::array::ArrayType<
         u32,
     >>::Array<
         Buffer,
         narrow::offset::NA,
         narrow::array::union::NA,
-    >: ::std::iter::IntoIterator<Item = u32>,
+    >: ::core::iter::IntoIterator<Item = u32>,
 {
     type Item = Foo<T>;
     type IntoIter = FooArrayIter<T, Buffer>;
@@ -0,0 +1,151 @@
+//! [`Arbitrary`] implementations for fuzzing.
+//!
+//! Arrays consume a length, followed by their values and validity bits. The
+//! validity bitmap of a nullable array always has the length of its values.
+
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray, NullArray, Nulls, Unit},
+    bitmap::{bytes_for_bits, Bitmap},
+    nullable::Nullable,
+    FixedSize,
+};
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Returns a bitmap with `len` bits, packed from the bytes of `u`.
+///
+/// Bits are unset when `u` runs out of data.
+fn bitmap_with_len(u: &mut Unstructured<'_>, len: usize) -> Result<Bitmap> {
+    let bytes = (0..bytes_for_bits(len))
+        .map(|_| u8::arbitrary(u))
+        .collect::<Result<Vec<_>>>()?;
+    let mut bitmap = Bitmap::from_bytes(bytes, len).expect("enough bytes");
+    bitmap.normalize();
+    Ok(bitmap)
+}
+
+impl<'a> Arbitrary<'a> for Bitmap {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<bool>()?;
+        bitmap_with_len(u, len)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a, T: FixedSize + Arbitrary<'a>> Arbitrary<'a> for FixedSizePrimitiveArray<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Vec::<T>::arbitrary(u).map(Self::from)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a, T: FixedSize + Arbitrary<'a>> Arbitrary<'a> for FixedSizePrimitiveArray<T, true> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let values = Vec::<T>::arbitrary(u)?;
+        let validity = bitmap_with_len(u, values.len())?;
+        Ok(Self::try_from_parts(values, validity).expect("lengths match"))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a> Arbitrary<'a> for BooleanArray {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Bitmap::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a> Arbitrary<'a> for BooleanArray<true> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let values = Bitmap::arbitrary(u)?;
+        let validity = bitmap_with_len(u, values.bits)?;
+        Ok(Self::try_from_parts(values, validity).expect("lengths match"))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a, T: Unit> Arbitrary<'a> for NullArray<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_len::<u8>().map(|len| Self(Nulls::new(len)))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<'a, T: Unit> Arbitrary<'a> for NullArray<T, true> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.arbitrary_len::<bool>()?;
+        let validity = bitmap_with_len(u, len)?;
+        Ok(Self(Nullable {
+            data: Nulls::new(len),
+            validity,
+        }))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{array::Int32Array, Length, Validate};
+
+    /// Bytes with a length that is not a multiple of eight.
+    const DATA: [u8; 37] = [
+        1, 2, 3, 255, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 0xaa, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 21,
+    ];
+
+    #[test]
+    fn invariants() {
+        let mut u = Unstructured::new(&DATA);
+        let bitmap = Bitmap::arbitrary(&mut u).expect("bitmap");
+        assert_eq!(bitmap.validate(), Ok(()));
+
+        for len in 0..DATA.len() {
+            let mut input = Unstructured::new(&DATA[..len]);
+            let array = Int32Array::<true>::arbitrary(&mut input).expect("array");
+            assert_eq!(array.validate(), Ok(()));
+            let booleans = BooleanArray::<true>::arbitrary(&mut input).expect("array");
+            assert_eq!(booleans.validate(), Ok(()));
+            let nulls = NullArray::<(), true>::arbitrary(&mut input).expect("array");
+            assert_eq!(nulls.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut u = Unstructured::new(&DATA);
+        let array = Int32Array::<true>::arbitrary(&mut u).expect("array");
+        assert_eq!(
+            array.clone().into_iter().collect::<Int32Array<true>>(),
+            array
+        );
+        let booleans = BooleanArray::<false>::arbitrary(&mut u).expect("array");
+        assert_eq!(
+            booleans.clone().into_iter().collect::<BooleanArray>(),
+            booleans
+        );
+        let nulls = NullArray::<()>::arbitrary(&mut Unstructured::new(&DATA)).expect("array");
+        assert!(nulls.len() <= DATA.len());
+    }
+}
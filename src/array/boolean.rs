@@ -1,14 +1,19 @@
 //! Array with boolean values.
 
-use super::Array;
+use super::{Array, FormatOptions, TableDisplay};
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{BufferRef, BufferRefMut, BufferType, VecBuffer},
+    buffer::{BufferRef, BufferRefMut, BufferType, GrowableBuffer, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, LengthMismatch, MemoryUsage, Validate, ValidationError,
+};
+use alloc::{string::String, string::ToString, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result},
+    hash::{Hash, Hasher},
 };
-use std::fmt::{Debug, Formatter, Result};
 
 /// Array with boolean values.
 ///
@@ -33,6 +38,168 @@ where
     }
 }
 
+impl<Buffer: BufferType> BooleanArray<false, Buffer> {
+    /// Returns a new [`BooleanArray`] with `len` copies of `value`.
+    #[must_use]
+    pub fn new_repeated(value: bool, len: usize) -> Self
+    where
+        <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        Self(Bitmap::new_repeated(value, len))
+    }
+
+    /// Returns a new [`BooleanArray`] with the first `bits` bits of the
+    /// given bytes as values.
+    ///
+    /// See [`Bitmap::from_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError::BitmapBuffer`] when there are fewer than
+    /// `(bits + 7) / 8` bytes.
+    pub fn from_bytes(
+        bytes: <Buffer as BufferType>::Buffer<u8>,
+        bits: usize,
+    ) -> core::result::Result<Self, ValidationError> {
+        Bitmap::from_bytes(bytes, bits).map(Self)
+    }
+
+    /// Returns a reference to the bitmap with the values of this
+    /// [`BooleanArray`].
+    pub fn values(&self) -> &Bitmap<Buffer> {
+        &self.0
+    }
+
+    /// Returns the bitmap with the values of this [`BooleanArray`].
+    pub fn into_bitmap(self) -> Bitmap<Buffer> {
+        self.0
+    }
+}
+
+impl<Buffer: BufferType> BooleanArray<true, Buffer> {
+    /// Returns a new nullable [`BooleanArray`] from the given values and
+    /// validity bitmaps.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] when the array is not valid, e.g. when the
+    /// length of the values bitmap does not match the length of the validity
+    /// bitmap.
+    pub fn try_from_parts(
+        values: Bitmap<Buffer>,
+        validity: Bitmap<Buffer>,
+    ) -> core::result::Result<Self, ValidationError> {
+        let array = Self(Nullable::try_new(values, validity)?);
+        array.validate().map(|()| array)
+    }
+
+    /// Returns a new nullable [`BooleanArray`] from the given values and
+    /// validity bitmaps.
+    ///
+    /// Unlike [`BooleanArray::try_from_parts`], this only checks the lengths
+    /// of the bitmaps.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatch`] with the length of `values` as expected
+    /// length when the length of `validity` is different.
+    pub fn from_bitmaps(
+        values: Bitmap<Buffer>,
+        validity: Bitmap<Buffer>,
+    ) -> core::result::Result<Self, LengthMismatch> {
+        Nullable::try_new(values, validity).map(Self)
+    }
+
+    /// Returns a new nullable [`BooleanArray`] from the given values and
+    /// validity bitmaps, without checking their lengths.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the length of `values` equals the length of
+    /// `validity`. This is checked in debug builds.
+    pub unsafe fn from_parts_unchecked(values: Bitmap<Buffer>, validity: Bitmap<Buffer>) -> Self {
+        let array = Self(Nullable::from_parts_unchecked(values, validity));
+        debug_assert_eq!(array.validate(), Ok(()));
+        array
+    }
+
+    /// Returns a reference to the bitmap with the values of this
+    /// [`BooleanArray`].
+    ///
+    /// This includes the values stored in null slots, which are unspecified.
+    pub fn values(&self) -> &Bitmap<Buffer> {
+        &self.0.data
+    }
+
+    /// Returns an iterator over the values of this [`BooleanArray`] together
+    /// with their validity.
+    ///
+    /// Unlike [`BooleanArray::iter`], this does not wrap the values in an
+    /// [`Option`]: the values stored in null slots, which are unspecified,
+    /// are returned with `false`. Both bitmaps are read 64 bits at a time.
+    pub fn iter_with_validity(&self) -> impl Iterator<Item = (bool, bool)> + '_ {
+        self.0.data.word_bits().zip(self.0.validity.word_bits())
+    }
+
+    /// Returns the values of this [`BooleanArray`] and their validity.
+    ///
+    /// See [`BooleanArray::iter_with_validity`].
+    #[must_use]
+    pub fn to_parts_vec(&self) -> (Vec<bool>, Vec<bool>) {
+        self.iter_with_validity().unzip()
+    }
+
+    /// Returns the values and validity bitmaps of this [`BooleanArray`].
+    pub fn into_parts(self) -> (Bitmap<Buffer>, Bitmap<Buffer>) {
+        self.0.into_parts()
+    }
+}
+
+impl BooleanArray {
+    /// Appends the values of `array` to this [`BooleanArray`], a word at a
+    /// time.
+    pub fn extend_from_array(&mut self, array: &Self) {
+        self.0.extend_from_bitmap(&array.0);
+    }
+
+    /// Returns a [`BooleanArray`] with the values of the `arrays`, in order.
+    /// The total length is reserved once, before the arrays are appended with
+    /// [`BooleanArray::extend_from_array`].
+    #[must_use]
+    pub fn concat(arrays: &[Self]) -> Self {
+        let mut result = Self::default();
+        result.0.reserve(arrays.iter().map(Length::len).sum());
+        for array in arrays {
+            result.extend_from_array(array);
+        }
+        result
+    }
+}
+
+impl BooleanArray<true> {
+    /// Appends the values and validity of `array` to this [`BooleanArray`],
+    /// a word at a time.
+    pub fn extend_from_array(&mut self, array: &Self) {
+        self.0.data.extend_from_bitmap(&array.0.data);
+        self.0.validity.extend_from_bitmap(&array.0.validity);
+    }
+
+    /// Returns a [`BooleanArray`] with the values and validity of the
+    /// `arrays`, in order. The total length is reserved once, before the
+    /// arrays are appended with [`BooleanArray::extend_from_array`].
+    #[must_use]
+    pub fn concat(arrays: &[Self]) -> Self {
+        let len = arrays.iter().map(Length::len).sum();
+        let mut result = Self::default();
+        result.0.data.reserve(len);
+        result.0.validity.reserve(len);
+        for array in arrays {
+            result.extend_from_array(array);
+        }
+        result
+    }
+}
+
 impl<const NULLABLE: bool, Buffer: BufferType> Array for BooleanArray<NULLABLE, Buffer>
 where
     Bitmap<Buffer>: Validity<NULLABLE>,
@@ -80,10 +247,12 @@ where
 impl<const NULLABLE: bool, Buffer: BufferType> Debug for BooleanArray<NULLABLE, Buffer>
 where
     Bitmap<Buffer>: Validity<NULLABLE>,
-    <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Debug,
+    Self: Length,
+    for<'a> &'a Self: IntoIterator,
+    for<'a> <&'a Self as IntoIterator>::Item: Debug,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        f.debug_tuple("BooleanArray").field(&self.0).finish()
+        super::debug_array::<Self>(f, "BooleanArray", self)
     }
 }
 
@@ -97,7 +266,17 @@ where
     }
 }
 
-impl<U, const NULLABLE: bool, Buffer: BufferType> Extend<U> for BooleanArray<NULLABLE, Buffer>
+impl<const NULLABLE: bool, Buffer: BufferType> Display for BooleanArray<NULLABLE, Buffer>
+where
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    Self: TableDisplay,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.display_table(FormatOptions::default()))
+    }
+}
+
+impl<U, const NULLABLE: bool, Buffer: GrowableBuffer> Extend<U> for BooleanArray<NULLABLE, Buffer>
 where
     Bitmap<Buffer>: Validity<NULLABLE>,
     <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Extend<U>,
@@ -107,6 +286,18 @@ where
     }
 }
 
+impl<Buffer: BufferType> From<Bitmap<Buffer>> for BooleanArray<false, Buffer> {
+    fn from(value: Bitmap<Buffer>) -> Self {
+        Self(value)
+    }
+}
+
+impl<Buffer: BufferType> From<BooleanArray<false, Buffer>> for Bitmap<Buffer> {
+    fn from(value: BooleanArray<false, Buffer>) -> Self {
+        value.into_bitmap()
+    }
+}
+
 impl<Buffer: BufferType> From<BooleanArray<false, Buffer>> for BooleanArray<true, Buffer>
 where
     Bitmap<Buffer>: FromIterator<bool>,
@@ -126,6 +317,24 @@ where
     }
 }
 
+impl<Buffer: BufferType> Hash for BooleanArray<false, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<Buffer: BufferType> Hash for BooleanArray<true, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Values in null slots are masked out.
+        self.0.validity.hash(state);
+        self.0
+            .data
+            .words()
+            .zip(self.0.validity.words())
+            .for_each(|((data, _), (validity, _))| state.write_u64(data & validity));
+    }
+}
+
 impl<const NULLABLE: bool, Buffer: BufferType> Index for BooleanArray<NULLABLE, Buffer>
 where
     Bitmap<Buffer>: Validity<NULLABLE>,
@@ -180,6 +389,20 @@ where
     }
 }
 
+impl<Buffer: BufferType> TryFrom<BooleanArray<true, Buffer>> for BooleanArray<false, Buffer> {
+    type Error = BooleanArray<true, Buffer>;
+
+    /// Returns the non-nullable array when all values are valid, without
+    /// copying the values. Returns the given array otherwise.
+    fn try_from(value: BooleanArray<true, Buffer>) -> core::result::Result<Self, Self::Error> {
+        if value.all_valid() {
+            Ok(Self(value.0.data))
+        } else {
+            Err(value)
+        }
+    }
+}
+
 impl<Buffer: BufferType> BitmapRef for BooleanArray<true, Buffer> {
     type Buffer = Buffer;
 
@@ -196,21 +419,219 @@ impl<Buffer: BufferType> BitmapRefMut for BooleanArray<true, Buffer> {
 
 impl<Buffer: BufferType> ValidityBitmap for BooleanArray<true, Buffer> {}
 
-impl<Buffer: BufferType> PartialEq<[bool]> for BooleanArray<false, Buffer>
+impl<Buffer: BufferType> super::LogicalValidity for BooleanArray<false, Buffer> {
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<Buffer: BufferType> Validate for BooleanArray<false, Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
+impl<Buffer: BufferType> Validate for BooleanArray<true, Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.data.validate()?;
+        self.0.validate()
+    }
+}
+
+impl<Buffer: BufferType> TableDisplay for BooleanArray<false, Buffer> {
+    fn format_value(&self, index: usize) -> Option<String> {
+        Some(self.0[index].to_string())
+    }
+}
+
+impl<Buffer: BufferType> TableDisplay for BooleanArray<true, Buffer> {
+    fn format_value(&self, index: usize) -> Option<String> {
+        let value = self.0.data[index];
+        self.is_valid(index)
+            .unwrap_or_default()
+            .then(|| value.to_string())
+    }
+}
+
+impl<Buffer: BufferType, OtherBuffer: BufferType> PartialEq<BooleanArray<false, OtherBuffer>>
+    for BooleanArray<false, Buffer>
+{
+    fn eq(&self, other: &BooleanArray<false, OtherBuffer>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<Buffer: BufferType, OtherBuffer: BufferType> PartialEq<BooleanArray<true, OtherBuffer>>
+    for BooleanArray<true, Buffer>
+{
+    fn eq(&self, other: &BooleanArray<true, OtherBuffer>) -> bool {
+        // Values in null slots are ignored.
+        self.0.validity == other.0.validity
+            && self
+                .0
+                .data
+                .iter()
+                .zip(&other.0.data)
+                .zip(&self.0.validity)
+                .all(|((a, b), valid)| !valid || a == b)
+    }
+}
+
+impl<Buffer: BufferType, OtherBuffer: BufferType> PartialEq<BooleanArray<false, OtherBuffer>>
+    for BooleanArray<true, Buffer>
+{
+    fn eq(&self, other: &BooleanArray<false, OtherBuffer>) -> bool {
+        self.0.validity.count_zeros() == 0 && self.0.data == other.0
+    }
+}
+
+impl<Buffer: BufferType, OtherBuffer: BufferType> PartialEq<BooleanArray<true, OtherBuffer>>
+    for BooleanArray<false, Buffer>
+{
+    fn eq(&self, other: &BooleanArray<true, OtherBuffer>) -> bool {
+        other == self
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Eq for BooleanArray<NULLABLE, Buffer>
 where
-    Bitmap<Buffer>: PartialEq<[bool]>,
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    Self: PartialEq,
+{
+}
+
+impl<Buffer: BufferType, OtherBuffer: BufferType> PartialOrd<BooleanArray<false, OtherBuffer>>
+    for BooleanArray<false, Buffer>
+{
+    fn partial_cmp(&self, other: &BooleanArray<false, OtherBuffer>) -> Option<Ordering> {
+        self.0.iter().partial_cmp(other.0.iter())
+    }
+}
+
+impl<Buffer: BufferType> Ord for BooleanArray<false, Buffer> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().cmp(other.0.iter())
+    }
+}
+
+/// Nulls are ordered before all valid values.
+impl<Buffer: BufferType, OtherBuffer: BufferType> PartialOrd<BooleanArray<true, OtherBuffer>>
+    for BooleanArray<true, Buffer>
 {
+    fn partial_cmp(&self, other: &BooleanArray<true, OtherBuffer>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+/// Nulls are ordered before all valid values.
+impl<Buffer: BufferType> Ord for BooleanArray<true, Buffer> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<Buffer: BufferType> PartialEq<[bool]> for BooleanArray<false, Buffer> {
     fn eq(&self, other: &[bool]) -> bool {
-        self.0.eq(other)
+        self.len() == other.len() && self.0.iter().zip(other).all(|(a, &b)| a == b)
+    }
+}
+
+impl<Buffer: BufferType, const N: usize> PartialEq<[bool; N]> for BooleanArray<false, Buffer> {
+    fn eq(&self, other: &[bool; N]) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<Buffer: BufferType> PartialEq<Vec<bool>> for BooleanArray<false, Buffer> {
+    fn eq(&self, other: &Vec<bool>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<Buffer: BufferType> PartialEq<BooleanArray<false, Buffer>> for [bool] {
+    fn eq(&self, other: &BooleanArray<false, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<Buffer: BufferType, const N: usize> PartialEq<BooleanArray<false, Buffer>> for [bool; N] {
+    fn eq(&self, other: &BooleanArray<false, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<Buffer: BufferType> PartialEq<BooleanArray<false, Buffer>> for Vec<bool> {
+    fn eq(&self, other: &BooleanArray<false, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<Buffer: BufferType> PartialEq<[Option<bool>]> for BooleanArray<true, Buffer> {
+    fn eq(&self, other: &[Option<bool>]) -> bool {
+        self.len() == other.len()
+            && self
+                .0
+                .data
+                .iter()
+                .zip(&self.0.validity)
+                .zip(other)
+                .all(|((value, valid), &expected)| valid.then_some(value) == expected)
     }
 }
 
-impl<Buffer: BufferType> PartialEq<[Option<bool>]> for BooleanArray<true, Buffer>
+impl<Buffer: BufferType, const N: usize> PartialEq<[Option<bool>; N]>
+    for BooleanArray<true, Buffer>
+{
+    fn eq(&self, other: &[Option<bool>; N]) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<Buffer: BufferType> PartialEq<Vec<Option<bool>>> for BooleanArray<true, Buffer> {
+    fn eq(&self, other: &Vec<Option<bool>>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<Buffer: BufferType> PartialEq<BooleanArray<true, Buffer>> for [Option<bool>] {
+    fn eq(&self, other: &BooleanArray<true, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<Buffer: BufferType, const N: usize> PartialEq<BooleanArray<true, Buffer>>
+    for [Option<bool>; N]
+{
+    fn eq(&self, other: &BooleanArray<true, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<Buffer: BufferType> PartialEq<BooleanArray<true, Buffer>> for Vec<Option<bool>> {
+    fn eq(&self, other: &BooleanArray<true, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> MemoryUsage for BooleanArray<NULLABLE, Buffer>
 where
-    for<'a> &'a Self: IntoIterator<Item = Option<bool>>,
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: MemoryUsage,
 {
-    fn eq(&self, other: &[Option<bool>]) -> bool {
-        self.len() == other.len() && self.iter().zip(other).all(|(a, &b)| a == b)
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
     }
 }
 
@@ -218,7 +639,7 @@ where
 mod tests {
     use super::*;
     use crate::buffer::BoxBuffer;
-    use std::mem;
+    use std::{collections::HashSet, mem};
 
     #[test]
     fn from_iter() {
@@ -319,4 +740,296 @@ mod tests {
             mem::size_of::<BooleanArray>() + mem::size_of::<Bitmap>()
         );
     }
+
+    #[test]
+    fn eq() {
+        let array = [true, false, true].into_iter().collect::<BooleanArray>();
+        assert_eq!(
+            array,
+            [true, false, true]
+                .into_iter()
+                .collect::<BooleanArray<false, BoxBuffer>>()
+        );
+        assert_ne!(array, [true, false].into_iter().collect::<BooleanArray>());
+
+        let nullable = [Some(true), None, Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let mut other = BooleanArray::<true, BoxBuffer>(Nullable {
+            data: [true, false, false].into_iter().collect(),
+            validity: [true, false, true].into_iter().collect(),
+        });
+        other.0.data.buffer_ref_mut()[0] |= 0b1111_1010;
+        assert_eq!(nullable, other);
+        assert_ne!(nullable, array);
+        assert_eq!(
+            [Some(true), Some(false), Some(true)]
+                .into_iter()
+                .collect::<BooleanArray<true>>(),
+            array
+        );
+    }
+
+    #[test]
+    fn eq_slice() {
+        let array = [true, false].into_iter().collect::<BooleanArray>();
+        assert_eq!(array, [true, false]);
+        assert_eq!(vec![true, false], array);
+        assert_ne!([true, true], array);
+
+        let nullable = [Some(true), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(nullable, vec![Some(true), None]);
+        assert_eq!([Some(true), None], nullable);
+        assert_ne!(nullable, [Some(true), Some(false)]);
+    }
+
+    #[test]
+    fn hash() {
+        let nullable = [Some(true), None, Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let mut other = nullable.clone();
+        other.0.data.buffer_ref_mut()[0] |= 0b1111_1010;
+        other.bitmap_ref_mut().buffer_ref_mut()[0] |= 0b1111_1000;
+        let set = [nullable, other].into_iter().collect::<HashSet<_>>();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn ord() {
+        let array = |values: &[bool]| values.iter().copied().collect::<BooleanArray>();
+        assert!(array(&[false]) < array(&[true]));
+        assert!(array(&[true]) < array(&[true, false]));
+        assert!(array(&[true, false]) > array(&[false, true, true]));
+
+        let nullable = [None, Some(true)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert!(nullable < [Some(false)].into_iter().collect::<BooleanArray<true>>());
+    }
+
+    #[test]
+    fn parts() {
+        let array = [Some(true), None, Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let (values, validity) = array.into_parts();
+        assert_eq!(validity, [true, false, true]);
+        let roundtrip = BooleanArray::try_from_parts(values, validity).expect("same length");
+        assert_eq!(roundtrip, [Some(true), None, Some(false)]);
+
+        assert_eq!(
+            BooleanArray::<true>::try_from_parts(
+                [true].into_iter().collect(),
+                [true, true].into_iter().collect()
+            )
+            .err(),
+            Some(ValidationError::ValidityLength(LengthMismatch {
+                expected: 1,
+                actual: 2
+            }))
+        );
+    }
+
+    #[test]
+    fn extend_ref() {
+        let mut array = BooleanArray::<false>::default();
+        array.extend(&[true, false]);
+        assert_eq!(array, [true, false]);
+
+        let mut nullable = BooleanArray::<true>::default();
+        nullable.extend(&[Some(true), None]);
+        nullable.extend([Some(false)].iter());
+        assert_eq!(nullable, [Some(true), None, Some(false)]);
+    }
+
+    #[test]
+    fn try_from_nullable() {
+        let nullable = [Some(true), Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(
+            BooleanArray::<false>::try_from(nullable).expect("all valid"),
+            [true, false]
+        );
+
+        let with_null = [Some(true), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(
+            BooleanArray::<false>::try_from(with_null).expect_err("contains null"),
+            [Some(true), None]
+        );
+    }
+
+    #[test]
+    fn from_bytes() {
+        let mut array =
+            BooleanArray::<false>::from_bytes(vec![0b1010_1010, u8::MAX], 12).expect("valid");
+        assert_eq!(array.len(), 12);
+        assert_eq!(array.iter().count(), 12);
+        assert_eq!(array.iter().filter(|&value| value).count(), 8);
+        array.extend([true, false]);
+        assert_eq!(array.len(), 14);
+        assert_eq!(array.values().get(12), Some(true));
+        assert_eq!(array.values().get(13), Some(false));
+        assert_eq!(array.buffer_ref(), &[0b1010_1010, 0b0001_1111]);
+        assert!(BooleanArray::<false>::from_bytes(vec![0], 12).is_err());
+    }
+
+    #[test]
+    fn new_repeated() {
+        for len in [0, 1, 7, 9, 100] {
+            for value in [true, false] {
+                let array = BooleanArray::<false>::new_repeated(value, len);
+                assert_eq!(array.len(), len);
+                assert_eq!(
+                    array,
+                    std::iter::repeat(value).take(len).collect::<BooleanArray>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn validate() {
+        let array = [Some(true), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(array.validate(), Ok(()));
+
+        let validity_length = BooleanArray::<true>(Nullable {
+            data: [true, false].into_iter().collect(),
+            validity: [true].into_iter().collect(),
+        });
+        assert_eq!(
+            validity_length.validate(),
+            Err(ValidationError::ValidityLength(LengthMismatch {
+                expected: 2,
+                actual: 1
+            }))
+        );
+
+        // The buffer is too small for 9 bits.
+        let values = Bitmap::<VecBuffer> {
+            buffer: vec![0],
+            bits: 9,
+            offset: 0,
+        };
+        assert_eq!(
+            BooleanArray::<false>(values.clone()).validate(),
+            Err(ValidationError::BitmapBuffer {
+                required: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            BooleanArray::<true>::try_from_parts(values, Bitmap::new_valid(9)).err(),
+            Some(ValidationError::BitmapBuffer {
+                required: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn iter_with_validity() {
+        let input = (0..100)
+            .map(|value| (value % 5 != 0).then_some(value % 2 == 0))
+            .collect::<Vec<_>>();
+        let array = input.iter().copied().collect::<BooleanArray<true>>();
+        assert!(array
+            .iter_with_validity()
+            .map(|(value, valid)| valid.then_some(value))
+            .eq(array.clone().into_iter()));
+        // Null slots expose the stored default values.
+        assert!(array
+            .iter_with_validity()
+            .filter(|&(_, valid)| !valid)
+            .all(|(value, _)| !value));
+        let (values, validity) = array.to_parts_vec();
+        assert_eq!(values.len(), 100);
+        assert_eq!(
+            validity,
+            input.iter().map(Option::is_some).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn bitmap() {
+        let bitmap = [true, false, true].into_iter().collect::<Bitmap>();
+        let ptr = bitmap.buffer.as_ptr();
+        let array = BooleanArray::from(bitmap);
+        assert_eq!(array, [true, false, true]);
+        assert_eq!(array.values().buffer.as_ptr(), ptr);
+        let roundtrip = Bitmap::from(array);
+        assert_eq!(roundtrip.buffer.as_ptr(), ptr);
+        assert_eq!(
+            BooleanArray::from(roundtrip).into_bitmap().buffer.as_ptr(),
+            ptr
+        );
+
+        let values = [true, true, false].into_iter().collect::<Bitmap>();
+        let validity = [true, false, true].into_iter().collect::<Bitmap>();
+        let (values_ptr, validity_ptr) = (values.buffer.as_ptr(), validity.buffer.as_ptr());
+        let nullable = BooleanArray::from_bitmaps(values, validity).expect("same length");
+        assert_eq!(nullable, [Some(true), None, Some(false)]);
+        assert_eq!(nullable.values().buffer.as_ptr(), values_ptr);
+        assert_eq!(nullable.bitmap_ref().buffer.as_ptr(), validity_ptr);
+
+        assert_eq!(
+            BooleanArray::<true>::from_bitmaps(
+                [true].into_iter().collect(),
+                [true, true].into_iter().collect()
+            )
+            .err(),
+            Some(LengthMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn extend_arrays() {
+        let first = [true, false, true].into_iter().collect::<BooleanArray>();
+        // A bitmap with a bit offset.
+        let second = BooleanArray::from(
+            Bitmap::<VecBuffer>::try_from_raw_parts(vec![0b1011_0100, 0b1], 6, 2)
+                .expect("valid bitmap"),
+        );
+        let mut array = BooleanArray::<false>::concat(&[first.clone(), second.clone()]);
+        assert_eq!(
+            array,
+            [true, false, true, true, false, true, true, false, true]
+        );
+        array.extend_from_array(&first);
+        assert_eq!(array.len(), 12);
+        assert_eq!(array.values().buffer, [0b0110_1101, 0b0000_1011]);
+
+        let nullable = [Some(true), None, Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let merged =
+            BooleanArray::<true>::concat(&[nullable.clone(), BooleanArray::from(second), nullable]);
+        assert_eq!(
+            merged,
+            [
+                Some(true),
+                None,
+                Some(false),
+                Some(true),
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(true),
+                Some(true),
+                None,
+                Some(false)
+            ]
+        );
+    }
 }
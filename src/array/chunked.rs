@@ -0,0 +1,279 @@
+//! Arrays stored in multiple chunks.
+
+use super::Array;
+use crate::{Length, MemoryUsage};
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Formatter, Result},
+    iter::Flatten,
+};
+
+/// Policy that controls when a chunk of a chunked array is complete.
+///
+/// A chunk is complete when one of the configured limits is reached. Without
+/// limits, chunks are never cut.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChunkPolicy {
+    /// The maximum number of rows in a chunk.
+    pub max_rows: Option<usize>,
+
+    /// The maximum number of bytes in the buffers of a chunk, see
+    /// [`MemoryUsage::buffer_bytes`].
+    pub max_bytes: Option<usize>,
+}
+
+impl ChunkPolicy {
+    /// Returns a policy that cuts chunks at `max_rows` rows.
+    #[must_use]
+    pub const fn max_rows(max_rows: usize) -> Self {
+        Self {
+            max_rows: Some(max_rows),
+            max_bytes: None,
+        }
+    }
+
+    /// Returns a policy that cuts chunks at `max_bytes` bytes.
+    #[must_use]
+    pub const fn max_bytes(max_bytes: usize) -> Self {
+        Self {
+            max_rows: None,
+            max_bytes: Some(max_bytes),
+        }
+    }
+
+    /// Returns `true` if a chunk with `rows` rows and `bytes` bytes reached
+    /// one of the limits of this policy.
+    #[must_use]
+    pub fn is_complete(&self, rows: usize, bytes: usize) -> bool {
+        self.max_rows.is_some_and(|max_rows| rows >= max_rows)
+            || self.max_bytes.is_some_and(|max_bytes| bytes >= max_bytes)
+    }
+}
+
+/// Array stored in multiple chunks of arrays with the same type.
+///
+/// The items of this array are the items of the chunks, in order.
+pub struct ChunkedArray<A>(pub(crate) Vec<A>);
+
+impl<A> ChunkedArray<A> {
+    /// Returns a new [`ChunkedArray`] with the given chunks.
+    #[must_use]
+    pub fn new(chunks: Vec<A>) -> Self {
+        Self(chunks)
+    }
+
+    /// Returns the chunks of this array.
+    #[must_use]
+    pub fn chunks(&self) -> &[A] {
+        &self.0
+    }
+
+    /// Returns the number of chunks of this array.
+    #[must_use]
+    pub fn num_chunks(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Appends a chunk to this array.
+    pub fn push_chunk(&mut self, chunk: A) {
+        self.0.push(chunk);
+    }
+
+    /// Returns the chunks of this array.
+    #[must_use]
+    pub fn into_chunks(self) -> Vec<A> {
+        self.0
+    }
+
+    /// Returns a new [`ChunkedArray`] with the same items, stored in chunks
+    /// of `target_rows` rows. The last chunk can be shorter. Chunks without
+    /// rows are removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_rows` is zero.
+    #[must_use]
+    pub fn rechunk(&self, target_rows: usize) -> Self
+    where
+        A: Clone + Length + IntoIterator + FromIterator<<A as IntoIterator>::Item>,
+    {
+        assert_ne!(target_rows, 0, "target rows should be non-zero");
+        let mut chunks = Vec::new();
+        let mut items = self.0.iter().filter(|chunk| !chunk.is_empty()).peekable();
+        while let Some(chunk) = items.next_if(|chunk| chunk.len() == target_rows) {
+            // Chunks that already have the target size are not rebuilt.
+            chunks.push(chunk.clone());
+        }
+        let mut rest = items.cloned().flatten().peekable();
+        while rest.peek().is_some() {
+            chunks.push(rest.by_ref().take(target_rows).collect());
+        }
+        Self(chunks)
+    }
+
+    /// Returns an array with the items of all chunks.
+    ///
+    /// A single chunk is returned as-is.
+    #[must_use]
+    pub fn into_single(self) -> A
+    where
+        A: IntoIterator + FromIterator<<A as IntoIterator>::Item>,
+    {
+        let mut chunks = self.0.into_iter();
+        let first = chunks.next();
+        match first {
+            Some(chunk) if chunks.as_slice().is_empty() => chunk,
+            _ => first.into_iter().chain(chunks).flatten().collect(),
+        }
+    }
+
+    /// Returns an iterator over the items in this [`ChunkedArray`].
+    pub fn iter<'a>(&'a self) -> Flatten<core::slice::Iter<'a, A>>
+    where
+        &'a A: IntoIterator,
+    {
+        self.0.iter().flatten()
+    }
+}
+
+impl<A: Array> Array for ChunkedArray<A> {
+    type Item = <A as Array>::Item;
+}
+
+impl<A: Clone> Clone for ChunkedArray<A> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<A: Debug> Debug for ChunkedArray<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("ChunkedArray").field(&self.0).finish()
+    }
+}
+
+impl<A> Default for ChunkedArray<A> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<A> From<Vec<A>> for ChunkedArray<A> {
+    fn from(value: Vec<A>) -> Self {
+        Self(value)
+    }
+}
+
+impl<A: IntoIterator> IntoIterator for ChunkedArray<A> {
+    type Item = <A as IntoIterator>::Item;
+    type IntoIter = Flatten<alloc::vec::IntoIter<A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().flatten()
+    }
+}
+
+impl<'a, A> IntoIterator for &'a ChunkedArray<A>
+where
+    &'a A: IntoIterator,
+{
+    type Item = <&'a A as IntoIterator>::Item;
+    type IntoIter = Flatten<core::slice::Iter<'a, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<A: Length> Length for ChunkedArray<A> {
+    fn len(&self) -> usize {
+        self.0.iter().map(Length::len).sum()
+    }
+}
+
+impl<A: MemoryUsage> MemoryUsage for ChunkedArray<A> {
+    fn buffer_bytes(&self) -> usize {
+        self.0.iter().map(MemoryUsage::buffer_bytes).sum()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.iter().map(MemoryUsage::allocated_bytes).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{StringArray, Uint32Array};
+
+    #[test]
+    fn rechunk_tiny_chunks() {
+        let chunks = (0..10_u32)
+            .map(|chunk| (chunk * 3..chunk * 3 + chunk % 3).collect::<Uint32Array>())
+            .collect::<Vec<_>>();
+        let array = ChunkedArray::new(chunks);
+        assert_eq!(array.num_chunks(), 10);
+        let rechunked = array.rechunk(4);
+        assert_eq!(
+            rechunked
+                .chunks()
+                .iter()
+                .map(Length::len)
+                .collect::<Vec<_>>(),
+            [4, 4, 1]
+        );
+        assert_eq!(rechunked.len(), array.len());
+        assert!(array.iter().eq(rechunked.iter()));
+    }
+
+    #[test]
+    fn rechunk_huge_chunk() {
+        let array = ChunkedArray::new(vec![(0..1000_u32).collect::<Uint32Array>()]);
+        let rechunked = array.rechunk(300);
+        assert_eq!(
+            rechunked
+                .chunks()
+                .iter()
+                .map(Length::len)
+                .collect::<Vec<_>>(),
+            [300, 300, 300, 100]
+        );
+        assert!(rechunked.clone().into_iter().eq(0..1000));
+        assert_eq!(rechunked.into_single(), array.clone().into_single());
+    }
+
+    #[test]
+    fn rechunk_strings() {
+        let array = ChunkedArray::new(vec![
+            ["a", "bc"].into_iter().collect::<StringArray>(),
+            StringArray::default(),
+            ["d", "ef", "g"].into_iter().collect(),
+            StringArray::default(),
+        ]);
+        let rechunked = array.rechunk(2);
+        assert_eq!(rechunked.num_chunks(), 3);
+        assert!(rechunked.chunks().iter().all(|chunk| !chunk.is_empty()));
+        assert_eq!(
+            rechunked.iter().collect::<Vec<_>>(),
+            array.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            rechunked.into_single().into_iter().collect::<Vec<_>>(),
+            ["a", "bc", "d", "ef", "g"]
+        );
+        assert_eq!(
+            ChunkedArray::<StringArray>::default()
+                .rechunk(2)
+                .num_chunks(),
+            0
+        );
+    }
+
+    #[test]
+    fn policy() {
+        assert!(!ChunkPolicy::default().is_complete(usize::MAX, usize::MAX));
+        assert!(ChunkPolicy::max_rows(2).is_complete(2, 0));
+        assert!(!ChunkPolicy::max_rows(2).is_complete(1, 100));
+        assert!(ChunkPolicy::max_bytes(8).is_complete(1, 8));
+    }
+}
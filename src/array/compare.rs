@@ -0,0 +1,253 @@
+//! Comparison of arrays, reporting the first mismatch.
+
+use super::Array;
+use crate::Index;
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// Items of arrays that can be compared with [`compare`].
+///
+/// The default implementation of [`CompareItem::is_null`] returns `false`,
+/// so non-nullable item types can implement this trait without a body.
+pub trait CompareItem: Debug + PartialEq {
+    /// Returns `true` if this item is a null value.
+    fn is_null(&self) -> bool {
+        false
+    }
+}
+
+impl<T: CompareItem + ?Sized> CompareItem for &T {
+    fn is_null(&self) -> bool {
+        T::is_null(self)
+    }
+}
+
+impl<T: Debug + PartialEq> CompareItem for Option<T> {
+    fn is_null(&self) -> bool {
+        self.is_none()
+    }
+}
+
+/// Implements [`CompareItem`] for non-nullable item types.
+macro_rules! impl_compare_item {
+    ($($ty:ty),+) => {
+        $(
+            impl CompareItem for $ty {}
+        )+
+    };
+}
+
+impl_compare_item!(
+    (),
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    str,
+    String
+);
+
+impl<T: CompareItem> CompareItem for [T] {}
+
+impl<T: CompareItem, const N: usize> CompareItem for [T; N] {}
+
+impl<T: CompareItem> CompareItem for Vec<T> {}
+
+/// The kind of a [`Mismatch`] between two arrays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MismatchKind {
+    /// Both values are valid but not equal.
+    Value,
+    /// One of the values is null and the other is not.
+    Null,
+    /// The arrays have different lengths. All values up to the length of the
+    /// shortest array are equal.
+    Length {
+        /// The length of the left array.
+        left: usize,
+        /// The length of the right array.
+        right: usize,
+    },
+}
+
+/// The first mismatch between two arrays.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The index of the first differing value.
+    pub index: usize,
+    /// The kind of mismatch.
+    pub kind: MismatchKind,
+    /// The value of the left array at `index`, formatted with [`Debug`], or
+    /// `None` if the left array has no value at `index`.
+    pub left: Option<String>,
+    /// The value of the right array at `index`, formatted with [`Debug`], or
+    /// `None` if the right array has no value at `index`.
+    pub right: Option<String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = |value: &Option<String>| value.as_deref().unwrap_or("<none>").to_owned();
+        match self.kind {
+            MismatchKind::Value => write!(f, "values differ at index {}", self.index)?,
+            MismatchKind::Null => write!(f, "validity differs at index {}", self.index)?,
+            MismatchKind::Length { left, right } => {
+                write!(f, "lengths differ (left: {left}, right: {right})")?;
+            }
+        }
+        write!(
+            f,
+            "\n  left: {}\n right: {}",
+            value(&self.left),
+            value(&self.right)
+        )
+    }
+}
+
+/// The result of [`compare`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArrayCompare {
+    /// The arrays are equal.
+    Equal,
+    /// The arrays differ.
+    Mismatch(Mismatch),
+}
+
+impl ArrayCompare {
+    /// Returns `true` if the arrays are equal.
+    #[must_use]
+    pub fn is_equal(&self) -> bool {
+        matches!(*self, Self::Equal)
+    }
+}
+
+/// Compares the logical values of two arrays and returns the first mismatch,
+/// if any.
+pub fn compare<A>(left: &A, right: &A) -> ArrayCompare
+where
+    A: Array + Index,
+    for<'a> <A as Index>::Item<'a>: CompareItem,
+{
+    let mismatch = (0..left.len().min(right.len()))
+        .map(|index| (index, left.index_checked(index), right.index_checked(index)))
+        .find(|mismatch| mismatch.1 != mismatch.2)
+        .map(|(index, left_value, right_value)| Mismatch {
+            index,
+            kind: if left_value.is_null() == right_value.is_null() {
+                MismatchKind::Value
+            } else {
+                MismatchKind::Null
+            },
+            left: Some(format!("{left_value:?}")),
+            right: Some(format!("{right_value:?}")),
+        })
+        .or_else(|| {
+            let index = left.len().min(right.len());
+            (left.len() != right.len()).then(|| Mismatch {
+                index,
+                kind: MismatchKind::Length {
+                    left: left.len(),
+                    right: right.len(),
+                },
+                left: left.index(index).map(|value| format!("{value:?}")),
+                right: right.index(index).map(|value| format!("{value:?}")),
+            })
+        });
+    mismatch.map_or(ArrayCompare::Equal, ArrayCompare::Mismatch)
+}
+
+/// Asserts that two arrays are equal using [`compare`](crate::array::compare).
+///
+/// On failure, the panic message contains the index and values of the first
+/// mismatch.
+#[macro_export]
+macro_rules! assert_arrays_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let $crate::array::ArrayCompare::Mismatch(mismatch) =
+            $crate::array::compare(&$left, &$right)
+        {
+            panic!("assertion `left == right` failed: {mismatch}");
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{StringArray, Uint8Array};
+
+    #[test]
+    fn equal() {
+        let left: Uint8Array<true> = Uint8Array::from(vec![Some(1), None, Some(3)]);
+        let right: Uint8Array<true> = Uint8Array::from(vec![Some(1), None, Some(3)]);
+        assert!(compare(&left, &right).is_equal());
+        assert_arrays_eq!(left, right);
+    }
+
+    #[test]
+    fn value() {
+        let left: Uint8Array<false> = Uint8Array::from(vec![1, 2, 3]);
+        let right: Uint8Array<false> = Uint8Array::from(vec![1, 4, 3]);
+        assert_eq!(
+            compare(&left, &right),
+            ArrayCompare::Mismatch(Mismatch {
+                index: 1,
+                kind: MismatchKind::Value,
+                left: Some("2".to_owned()),
+                right: Some("4".to_owned()),
+            })
+        );
+    }
+
+    #[test]
+    fn null() {
+        let left: Uint8Array<true> = Uint8Array::from(vec![Some(1), Some(2)]);
+        let right: Uint8Array<true> = Uint8Array::from(vec![Some(1), None]);
+        assert_eq!(
+            compare(&left, &right),
+            ArrayCompare::Mismatch(Mismatch {
+                index: 1,
+                kind: MismatchKind::Null,
+                left: Some("Some(2)".to_owned()),
+                right: Some("None".to_owned()),
+            })
+        );
+    }
+
+    #[test]
+    fn length() {
+        let left = ["a", "b"].into_iter().collect::<StringArray>();
+        let right = ["a", "b", "c"].into_iter().collect::<StringArray>();
+        assert_eq!(
+            compare(&left, &right),
+            ArrayCompare::Mismatch(Mismatch {
+                index: 2,
+                kind: MismatchKind::Length { left: 2, right: 3 },
+                left: None,
+                right: Some(r#""c""#.to_owned()),
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion `left == right` failed: values differ at index 2\n  \
+                               left: 3\n right: 4"
+    )]
+    fn assert_arrays_eq() {
+        let left: Uint8Array<false> = Uint8Array::from(vec![1, 2, 3]);
+        let right: Uint8Array<false> = Uint8Array::from(vec![1, 2, 4]);
+        assert_arrays_eq!(left, right);
+    }
+}
@@ -0,0 +1,192 @@
+//! Array with dictionary-encoded values.
+
+use super::{Array, FixedSizePrimitiveArray};
+use crate::{buffer::BufferType, FixedSize, Length, MemoryUsage, Validate, ValidationError};
+use core::fmt::{Debug, Formatter, Result};
+
+/// Array with dictionary-encoded values.
+///
+/// The `keys` array stores, for every item, the index of its value in the
+/// `values` array (the dictionary). Null items have null keys.
+pub struct DictionaryArray<Keys, Values> {
+    /// The indices of the values of the items in the dictionary.
+    pub(crate) keys: Keys,
+    /// The dictionary.
+    pub(crate) values: Values,
+}
+
+impl<Keys, Values> DictionaryArray<Keys, Values> {
+    /// Returns a new [`DictionaryArray`] with the given keys and values.
+    ///
+    /// The keys are not checked against the length of the values. Use
+    /// [`DictionaryArray::try_new`] for keys from an untrusted source: the
+    /// kernels on dictionary arrays panic when a key is out of bounds.
+    pub fn new(keys: Keys, values: Values) -> Self {
+        Self { keys, values }
+    }
+
+    /// Returns a new [`DictionaryArray`] with the given keys and values,
+    /// checking that every non-null key is in bounds of the values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] when the keys or values are invalid, or
+    /// when a non-null key is out of bounds of the values.
+    pub fn try_new(keys: Keys, values: Values) -> core::result::Result<Self, ValidationError>
+    where
+        Self: Validate,
+    {
+        let array = Self::new(keys, values);
+        array.validate()?;
+        Ok(array)
+    }
+
+    /// Returns a reference to the keys of this [`DictionaryArray`].
+    pub fn keys(&self) -> &Keys {
+        &self.keys
+    }
+
+    /// Returns a reference to the values (the dictionary) of this
+    /// [`DictionaryArray`].
+    pub fn values(&self) -> &Values {
+        &self.values
+    }
+
+    /// Returns the keys and values of this [`DictionaryArray`].
+    pub fn into_parts(self) -> (Keys, Values) {
+        (self.keys, self.values)
+    }
+}
+
+impl<Keys, Values: Array> Array for DictionaryArray<Keys, Values> {
+    type Item = <Values as Array>::Item;
+}
+
+impl<Keys: Clone, Values: Clone> Clone for DictionaryArray<Keys, Values> {
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<Keys: Debug, Values: Debug> Debug for DictionaryArray<Keys, Values> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("DictionaryArray")
+            .field("keys", &self.keys)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<Keys: Default, Values: Default> Default for DictionaryArray<Keys, Values> {
+    fn default() -> Self {
+        Self {
+            keys: Keys::default(),
+            values: Values::default(),
+        }
+    }
+}
+
+impl<Keys: Length, Values> Length for DictionaryArray<Keys, Values> {
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+impl<Keys: MemoryUsage, Values: MemoryUsage> MemoryUsage for DictionaryArray<Keys, Values> {
+    fn buffer_bytes(&self) -> usize {
+        self.keys.buffer_bytes() + self.values.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.keys.allocated_bytes() + self.values.allocated_bytes()
+    }
+}
+
+/// Checks that the `keys` that are valid are in bounds of a dictionary with
+/// `len` values.
+fn validate_keys<K: Into<u64>>(
+    mut keys: impl Iterator<Item = (K, bool)>,
+    len: usize,
+) -> core::result::Result<(), ValidationError> {
+    keys.position(|(key, valid)| {
+        valid && !usize::try_from(key.into()).is_ok_and(|index| index < len)
+    })
+    .map_or(Ok(()), |index| {
+        Err(ValidationError::DictionaryKeyOutOfBounds { index, len })
+    })
+}
+
+impl<K: FixedSize + Into<u64>, Buffer: BufferType, Values: Length + Validate> Validate
+    for DictionaryArray<FixedSizePrimitiveArray<K, false, Buffer>, Values>
+{
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.values.validate()?;
+        validate_keys(
+            self.keys.as_slice().iter().map(|&key| (key, true)),
+            self.values.len(),
+        )
+    }
+}
+
+impl<K: FixedSize + Into<u64>, Buffer: BufferType, Values: Length + Validate> Validate
+    for DictionaryArray<FixedSizePrimitiveArray<K, true, Buffer>, Values>
+{
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.keys.validate()?;
+        self.values.validate()?;
+        validate_keys(self.keys.iter_with_validity(), self.values.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{StringArray, Uint32Array};
+
+    #[test]
+    fn parts() {
+        let keys = [0, 1, 0].into_iter().collect::<Uint32Array>();
+        let values = ["a", "b"].into_iter().collect::<StringArray>();
+        let dictionary = DictionaryArray::new(keys, values);
+        assert_eq!(dictionary.len(), 3);
+        assert_eq!(dictionary.values().len(), 2);
+        let (dictionary_keys, _) = dictionary.into_parts();
+        assert_eq!(dictionary_keys.as_slice(), [0, 1, 0]);
+    }
+
+    #[test]
+    fn try_new() {
+        let values = ["a", "b"].into_iter().collect::<StringArray>();
+        assert!(DictionaryArray::try_new(
+            [0, 1, 0].into_iter().collect::<Uint32Array>(),
+            values.clone()
+        )
+        .is_ok());
+        assert_eq!(
+            DictionaryArray::try_new(
+                [0, 2, 0].into_iter().collect::<Uint32Array>(),
+                values.clone()
+            )
+            .err(),
+            Some(ValidationError::DictionaryKeyOutOfBounds { index: 1, len: 2 })
+        );
+
+        // Null keys are not checked.
+        let nullable_keys = Uint32Array::<true>::try_from_parts(
+            [0, 7, 1].into_iter().collect(),
+            [true, false, true].into_iter().collect(),
+        )
+        .expect("valid");
+        assert!(DictionaryArray::try_new(nullable_keys, values.clone()).is_ok());
+        let out_of_bounds = [Some(1), None, Some(2)]
+            .into_iter()
+            .collect::<Uint32Array<true>>();
+        assert_eq!(
+            DictionaryArray::try_new(out_of_bounds, values).err(),
+            Some(ValidationError::DictionaryKeyOutOfBounds { index: 2, len: 2 })
+        );
+    }
+}
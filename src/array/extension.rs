@@ -0,0 +1,217 @@
+//! Extension type annotations of arrays.
+
+use super::Array;
+use crate::{
+    bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
+    Index, Length, MemoryUsage,
+};
+use alloc::borrow::Cow;
+use core::fmt::{Debug, Formatter, Result};
+
+/// A logical extension type, e.g. `uuid` or `geo.point`.
+///
+/// Extension types annotate arrays (see [`Extended`]) and fields of struct
+/// arrays (see [`FieldInfo`](super::FieldInfo)) without changing their
+/// layout. In Arrow schemas they are stored as the `ARROW:extension:name` and
+/// `ARROW:extension:metadata` field metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExtensionType {
+    /// The name of the extension type.
+    pub name: Cow<'static, str>,
+    /// The serialized metadata of the extension type.
+    pub metadata: Option<Cow<'static, str>>,
+}
+
+impl ExtensionType {
+    /// The field metadata key of the name of an extension type.
+    pub const NAME_KEY: &'static str = "ARROW:extension:name";
+
+    /// The field metadata key of the metadata of an extension type.
+    pub const METADATA_KEY: &'static str = "ARROW:extension:metadata";
+
+    /// Returns a new [`ExtensionType`] with the given name and without
+    /// metadata.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            metadata: None,
+        }
+    }
+
+    /// Returns this [`ExtensionType`] with the given metadata.
+    #[must_use]
+    pub fn with_metadata(self, metadata: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            metadata: Some(metadata.into()),
+            ..self
+        }
+    }
+}
+
+/// An array annotated with an [`ExtensionType`].
+///
+/// This wraps an array and forwards the array traits to it, so it can be used
+/// wherever the wrapped array can be used.
+pub struct Extended<A> {
+    /// The wrapped array.
+    array: A,
+    /// The extension type of the array.
+    extension: ExtensionType,
+}
+
+impl<A> Extended<A> {
+    /// Returns the array annotated with the given extension type.
+    pub fn new(array: A, extension: ExtensionType) -> Self {
+        Self { array, extension }
+    }
+
+    /// Returns the extension type of this array.
+    pub fn extension(&self) -> &ExtensionType {
+        &self.extension
+    }
+
+    /// Returns a reference to the wrapped array.
+    pub fn inner(&self) -> &A {
+        &self.array
+    }
+
+    /// Returns an iterator over the items of the wrapped array.
+    pub fn iter<'a>(&'a self) -> <&'a A as IntoIterator>::IntoIter
+    where
+        &'a A: IntoIterator,
+    {
+        self.array.into_iter()
+    }
+
+    /// Returns the wrapped array and the extension type.
+    pub fn into_parts(self) -> (A, ExtensionType) {
+        (self.array, self.extension)
+    }
+}
+
+impl<A: Array> Array for Extended<A> {
+    type Item = <A as Array>::Item;
+}
+
+impl<A: BitmapRef> BitmapRef for Extended<A> {
+    type Buffer = <A as BitmapRef>::Buffer;
+
+    fn bitmap_ref(&self) -> &Bitmap<Self::Buffer> {
+        self.array.bitmap_ref()
+    }
+}
+
+impl<A: BitmapRefMut> BitmapRefMut for Extended<A> {
+    fn bitmap_ref_mut(&mut self) -> &mut Bitmap<Self::Buffer> {
+        self.array.bitmap_ref_mut()
+    }
+}
+
+impl<A: Clone> Clone for Extended<A> {
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            extension: self.extension.clone(),
+        }
+    }
+}
+
+impl<A: Debug> Debug for Extended<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("Extended")
+            .field("array", &self.array)
+            .field("extension", &self.extension)
+            .finish()
+    }
+}
+
+impl<A: Index> Index for Extended<A> {
+    type Item<'a> = <A as Index>::Item<'a>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.array.index_unchecked(index)
+    }
+}
+
+impl<A: IntoIterator> IntoIterator for Extended<A> {
+    type Item = <A as IntoIterator>::Item;
+    type IntoIter = <A as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.array.into_iter()
+    }
+}
+
+impl<'a, A> IntoIterator for &'a Extended<A>
+where
+    &'a A: IntoIterator,
+{
+    type Item = <&'a A as IntoIterator>::Item;
+    type IntoIter = <&'a A as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<A: Length> Length for Extended<A> {
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+}
+
+impl<A: MemoryUsage> MemoryUsage for Extended<A> {
+    fn buffer_bytes(&self) -> usize {
+        self.array.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.array.allocated_bytes()
+    }
+}
+
+impl<A: PartialEq> PartialEq for Extended<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.extension == other.extension && self.array == other.array
+    }
+}
+
+impl<A: ValidityBitmap> ValidityBitmap for Extended<A> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{StringArray, Uint8Array};
+
+    #[test]
+    fn forward() {
+        let uuid = ExtensionType::new("uuid");
+        let array = Extended::new(
+            [Some(1_u8), None, Some(3)]
+                .into_iter()
+                .collect::<Uint8Array<true>>(),
+            uuid.clone(),
+        );
+        assert_eq!(array.extension(), &uuid);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.is_valid(1), Some(false));
+        assert_eq!(array.index(2), Some(Some(&3)));
+        assert_eq!(array.iter().collect::<Vec<_>>(), [Some(&1), None, Some(&3)]);
+        assert_eq!(array.buffer_bytes(), array.inner().buffer_bytes());
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(3)]
+        );
+
+        let point = ExtensionType::new("geo.point").with_metadata("{\"crs\":\"WGS84\"}");
+        let strings = Extended::new(["a", "b"].into_iter().collect::<StringArray>(), point);
+        assert_eq!((&strings).into_iter().collect::<Vec<_>>(), ["a", "b"]);
+        let (inner, extension) = strings.into_parts();
+        assert_eq!(inner.len(), 2);
+        assert_eq!(extension.metadata.as_deref(), Some("{\"crs\":\"WGS84\"}"));
+    }
+}
@@ -2,10 +2,11 @@
 
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{Buffer, BufferType, VecBuffer},
+    buffer::{Buffer, BufferType, GrowableBuffer, VecBuffer},
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, MemoryUsage,
 };
+use core::fmt::{Debug, Formatter, Result};
 
 use super::{Array, FixedSizeListArray, FixedSizePrimitiveArray};
 
@@ -66,6 +67,19 @@ where
     }
 }
 
+impl<const N: usize, const NULLABLE: bool, Buffer: BufferType> Debug
+    for FixedSizeBinaryArray<N, NULLABLE, Buffer>
+where
+    FixedSizePrimitiveArray<u8, false, Buffer>: Validity<NULLABLE>,
+    Self: Length,
+    for<'a> &'a Self: IntoIterator,
+    for<'a> <&'a Self as IntoIterator>::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        super::debug_array::<Self>(f, "FixedSizeBinaryArray", self)
+    }
+}
+
 impl<const N: usize, const NULLABLE: bool, Buffer: BufferType> Default
     for FixedSizeBinaryArray<N, NULLABLE, Buffer>
 where
@@ -77,7 +91,7 @@ where
     }
 }
 
-impl<T, const N: usize, Buffer: BufferType> Extend<T> for FixedSizeBinaryArray<N, false, Buffer>
+impl<T, const N: usize, Buffer: GrowableBuffer> Extend<T> for FixedSizeBinaryArray<N, false, Buffer>
 where
     T: Into<[u8; N]>,
     FixedSizeListArray<N, FixedSizePrimitiveArray<u8, false, Buffer>, false, Buffer>:
@@ -88,7 +102,7 @@ where
     }
 }
 
-impl<T, const N: usize, Buffer: BufferType> Extend<Option<T>>
+impl<T, const N: usize, Buffer: GrowableBuffer> Extend<Option<T>>
     for FixedSizeBinaryArray<N, true, Buffer>
 where
     T: Into<[u8; N]>,
@@ -271,6 +285,40 @@ where
 
 impl<const N: usize, Buffer: BufferType> ValidityBitmap for FixedSizeBinaryArray<N, true, Buffer> {}
 
+impl<const N: usize, Buffer: BufferType> super::LogicalValidity
+    for FixedSizeBinaryArray<N, false, Buffer>
+where
+    Self: Length,
+{
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize, const NULLABLE: bool, Buffer: BufferType> MemoryUsage
+    for FixedSizeBinaryArray<N, NULLABLE, Buffer>
+where
+    FixedSizePrimitiveArray<u8, false, Buffer>: Validity<NULLABLE>,
+    FixedSizeListArray<N, FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, Buffer>:
+        MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
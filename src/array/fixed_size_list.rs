@@ -1,19 +1,21 @@
 //! Array with fixed-size sequences of elements.
 
-use std::{
+use core::{
+    fmt::{Debug, Formatter, Result},
     iter::{self, Map, Zip},
     mem::{self, ManuallyDrop, MaybeUninit},
+    ops::Range,
 };
 
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{BufferMut, BufferType, VecBuffer},
+    buffer::{BufferMut, BufferType, GrowableBuffer, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    FixedSize, Index, Length, MemoryUsage,
 };
 
-use super::Array;
+use super::{Array, FixedSizePrimitiveArray};
 
 /// Array with fixed-size sequences of elements.
 pub struct FixedSizeListArray<
@@ -37,6 +39,134 @@ where
     }
 }
 
+impl<const N: usize, T: FixedSize, ChildBuffer: BufferType, Buffer: BufferType>
+    FixedSizeListArray<N, FixedSizePrimitiveArray<T, false, ChildBuffer>, false, Buffer>
+{
+    /// Returns the values of the row at the given `index` as a slice of the
+    /// child buffer, or `None` if the index is out of bounds.
+    ///
+    /// This does not copy the values.
+    pub fn row(&self, index: usize) -> Option<&[T]> {
+        row_range::<N>(index).and_then(|range| self.0.as_slice().get(range))
+    }
+}
+
+impl<const N: usize, T: FixedSize, ChildBuffer: BufferType, Buffer: BufferType>
+    FixedSizeListArray<N, FixedSizePrimitiveArray<T, false, ChildBuffer>, true, Buffer>
+{
+    /// Returns the values of the row at the given `index` as a slice of the
+    /// child buffer, or `None` if the index is out of bounds. The inner
+    /// option is `None` for a null row.
+    ///
+    /// This does not copy the values.
+    pub fn row(&self, index: usize) -> Option<Option<&[T]>> {
+        self.is_valid(index).map(|valid| {
+            valid
+                .then(|| row_range::<N>(index).and_then(|range| self.0.data.as_slice().get(range)))
+                .flatten()
+        })
+    }
+}
+
+impl<const N: usize, T: Array, Buffer: BufferType> FixedSizeListArray<N, T, false, Buffer>
+where
+    Self: Length,
+{
+    /// Returns a view of the row at the given `index` in the child array, or
+    /// `None` if the index is out of bounds.
+    pub fn row_array(&self, index: usize) -> Option<FixedSizeListRow<'_, N, T>> {
+        (index < self.len()).then(|| FixedSizeListRow {
+            array: &self.0,
+            start: index * N,
+        })
+    }
+}
+
+impl<const N: usize, T: Array, Buffer: BufferType> FixedSizeListArray<N, T, true, Buffer> {
+    /// Returns a view of the row at the given `index` in the child array, or
+    /// `None` if the index is out of bounds. The inner option is `None` for a
+    /// null row.
+    pub fn row_array(&self, index: usize) -> Option<Option<FixedSizeListRow<'_, N, T>>> {
+        self.is_valid(index).map(|valid| {
+            valid.then(|| FixedSizeListRow {
+                array: &self.0.data,
+                start: index * N,
+            })
+        })
+    }
+}
+
+/// Returns the range of the values of the row at `index` in the child array,
+/// or `None` if the range overflows.
+fn row_range<const N: usize>(index: usize) -> Option<Range<usize>> {
+    let start = index.checked_mul(N)?;
+    start.checked_add(N).map(|end| start..end)
+}
+
+/// A view of a row of a [`FixedSizeListArray`], i.e. `N` items of the child
+/// array.
+pub struct FixedSizeListRow<'a, const N: usize, T> {
+    /// The child array.
+    array: &'a T,
+    /// The index of the first item of this row in the child array.
+    start: usize,
+}
+
+impl<'a, const N: usize, T> FixedSizeListRow<'a, N, T> {
+    /// Returns the child array of this row.
+    #[must_use]
+    pub fn child(&self) -> &'a T {
+        self.array
+    }
+
+    /// Returns the range of the items of this row in the child array.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.start + N
+    }
+
+    /// Returns an iterator over the items in this row.
+    pub fn iter(&self) -> impl Iterator<Item = <T as Index>::Item<'a>> + '_
+    where
+        T: Index,
+    {
+        self.range().map(|index| self.array.index_checked(index))
+    }
+}
+
+impl<const N: usize, T> Clone for FixedSizeListRow<'_, N, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, T> Copy for FixedSizeListRow<'_, N, T> {}
+
+impl<const N: usize, T: Debug> Debug for FixedSizeListRow<'_, N, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("FixedSizeListRow")
+            .field("array", self.array)
+            .field("range", &self.range())
+            .finish()
+    }
+}
+
+impl<const N: usize, T: Index> Index for FixedSizeListRow<'_, N, T> {
+    type Item<'b> = <T as Index>::Item<'b>
+    where
+        Self: 'b;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.array.index_unchecked(self.start + index)
+    }
+}
+
+impl<const N: usize, T> Length for FixedSizeListRow<'_, N, T> {
+    fn len(&self) -> usize {
+        N
+    }
+}
+
 impl<const N: usize, T: Array, const NULLABLE: bool, Buffer: BufferType> Array
     for FixedSizeListArray<N, T, NULLABLE, Buffer>
 where
@@ -75,6 +205,19 @@ where
     }
 }
 
+impl<const N: usize, T: Array, const NULLABLE: bool, Buffer: BufferType> Debug
+    for FixedSizeListArray<N, T, NULLABLE, Buffer>
+where
+    T: Validity<NULLABLE>,
+    Self: Length,
+    for<'a> &'a Self: IntoIterator,
+    for<'a> <&'a Self as IntoIterator>::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        super::debug_array::<Self>(f, "FixedSizeListArray", self)
+    }
+}
+
 impl<const N: usize, T: Array, const NULLABLE: bool, Buffer: BufferType> Default
     for FixedSizeListArray<N, T, NULLABLE, Buffer>
 where
@@ -86,7 +229,7 @@ where
     }
 }
 
-impl<U, const N: usize, T: Array, Buffer: BufferType> Extend<[U; N]>
+impl<U, const N: usize, T: Array, Buffer: GrowableBuffer> Extend<[U; N]>
     for FixedSizeListArray<N, T, false, Buffer>
 where
     T: Extend<U>,
@@ -96,7 +239,7 @@ where
     }
 }
 
-impl<U, const N: usize, T: Array, Buffer: BufferType> Extend<Option<[U; N]>>
+impl<U, const N: usize, T: Array, Buffer: GrowableBuffer> Extend<Option<[U; N]>>
     for FixedSizeListArray<N, T, true, Buffer>
 where
     [U; N]: Default,
@@ -374,9 +517,42 @@ impl<const N: usize, T: Array, Buffer: BufferType> ValidityBitmap
 {
 }
 
+impl<const N: usize, T: Array, Buffer: BufferType> super::LogicalValidity
+    for FixedSizeListArray<N, T, false, Buffer>
+where
+    Self: Length,
+{
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize, T: Array, const NULLABLE: bool, Buffer: BufferType> MemoryUsage
+    for FixedSizeListArray<N, T, NULLABLE, Buffer>
+where
+    T: Validity<NULLABLE>,
+    <T as Validity<NULLABLE>>::Storage<Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::array::{FixedSizePrimitiveArray, StringArray};
+    use crate::array::StringArray;
 
     use super::*;
 
@@ -696,4 +872,47 @@ mod tests {
             [[[1, 2], [3, 4], [5, 6]], [[7, 8], [9, 0], [0, 0]]]
         );
     }
+
+    #[test]
+    fn row() {
+        let array = [[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0]]
+            .into_iter()
+            .collect::<FixedSizeListArray<3, FixedSizePrimitiveArray<f32>>>();
+        let row = array.row(1).expect("in bounds");
+        assert_eq!(row, [4.0, 5.0, 6.0]);
+        let values = array.0.as_slice().as_ptr_range();
+        let row_ptrs = row.as_ptr_range();
+        assert!(values.start <= row_ptrs.start && row_ptrs.end <= values.end);
+        assert_eq!(row.as_ptr(), array.0.as_slice()[3..].as_ptr());
+        assert_eq!(array.row(2), None);
+        assert_eq!(array.row(usize::MAX), None);
+    }
+
+    #[test]
+    fn row_nullable() {
+        let array = [Some([1_u8, 2]), None, Some([5, 6])]
+            .into_iter()
+            .collect::<FixedSizeListArray<2, FixedSizePrimitiveArray<u8>, true>>();
+        assert_eq!(array.row(0), Some(Some([1, 2].as_slice())));
+        assert_eq!(array.row(1), Some(None));
+        assert_eq!(array.row(2), Some(Some([5, 6].as_slice())));
+        assert_eq!(array.row(3), None);
+        assert!(array.row_array(1).expect("in bounds").is_none());
+        assert!(array.row_array(3).is_none());
+    }
+
+    #[test]
+    fn row_array() {
+        let array = [["a", "bc"], ["d", "ef"]]
+            .into_iter()
+            .collect::<FixedSizeListArray<2, StringArray>>();
+        let row = array.row_array(1).expect("in bounds");
+        assert_eq!(row.len(), 2);
+        assert_eq!(row.range(), 2..4);
+        assert_eq!(row.index(1), Some("ef"));
+        assert_eq!(row.index(2), None);
+        assert_eq!(row.iter().collect::<Vec<_>>(), ["d", "ef"]);
+        assert!(core::ptr::eq(row.child(), &array.0));
+        assert!(array.row_array(2).is_none());
+    }
 }
@@ -1,15 +1,18 @@
 //! Array with fixed-size primitive values.
 
-use super::Array;
+use super::{Array, FormatOptions, TableDisplay};
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{Buffer, BufferType, VecBuffer},
+    buffer::{Buffer, BufferMut, BufferType, GrowableBuffer, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    FixedSize, Index, Length,
+    FixedSize, Index, Length, MemoryUsage, Validate, ValidationError,
 };
-use std::{
-    fmt::{Debug, Formatter, Result},
+use alloc::{string::String, string::ToString, vec, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result},
+    hash::{Hash, Hasher},
     ops,
     slice::SliceIndex,
 };
@@ -66,6 +69,213 @@ where
     }
 }
 
+impl<T: FixedSize, Buffer: BufferType> FixedSizePrimitiveArray<T, false, Buffer> {
+    /// Returns a slice with the values of this [`FixedSizePrimitiveArray`].
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    /// Returns a mutable slice with the values of this
+    /// [`FixedSizePrimitiveArray`].
+    pub fn as_mut_slice(&mut self) -> &mut [T]
+    where
+        <Buffer as BufferType>::Buffer<T>: BufferMut<T>,
+    {
+        self.0.as_mut_slice()
+    }
+
+    /// Returns a new [`FixedSizePrimitiveArray`] with `len` copies of `value`.
+    ///
+    /// The values are filled in bulk in a [`Vec`], which is then converted
+    /// into the buffer.
+    #[must_use]
+    pub fn new_repeated(value: T, len: usize) -> Self
+    where
+        <Buffer as BufferType>::Buffer<T>: From<Vec<T>>,
+    {
+        Self(vec![value; len].into())
+    }
+}
+
+impl<T: FixedSize> FixedSizePrimitiveArray<T> {
+    /// Returns the values of this [`FixedSizePrimitiveArray`] as a [`Vec`],
+    /// without copying.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Appends the values of `array` to this [`FixedSizePrimitiveArray`],
+    /// copying them in bulk.
+    pub fn extend_from_array(&mut self, array: &Self) {
+        self.0.extend_from_slice(&array.0);
+    }
+
+    /// Returns a [`FixedSizePrimitiveArray`] with the values of the `arrays`,
+    /// in order. The total length is allocated once, before the values are
+    /// copied in bulk.
+    #[must_use]
+    pub fn concat(arrays: &[Self]) -> Self {
+        let mut values = Vec::with_capacity(arrays.iter().map(|array| array.0.len()).sum());
+        for array in arrays {
+            values.extend_from_slice(&array.0);
+        }
+        Self(values)
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> FixedSizePrimitiveArray<T, true, Buffer> {
+    /// Returns a new nullable [`FixedSizePrimitiveArray`] from the given data
+    /// buffer and validity bitmap.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] when the array is not valid, e.g. when the
+    /// length of the data buffer does not match the length of the validity
+    /// bitmap.
+    pub fn try_from_parts(
+        data: <Buffer as BufferType>::Buffer<T>,
+        validity: Bitmap<Buffer>,
+    ) -> core::result::Result<Self, ValidationError> {
+        let array = Self(Nullable::try_new(data, validity)?);
+        array.validate().map(|()| array)
+    }
+
+    /// Returns a new nullable [`FixedSizePrimitiveArray`] from the given data
+    /// buffer and validity bitmap, without checking their lengths.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the length of `data` equals the length of
+    /// `validity`. This is checked in debug builds.
+    pub unsafe fn from_parts_unchecked(
+        data: <Buffer as BufferType>::Buffer<T>,
+        validity: Bitmap<Buffer>,
+    ) -> Self {
+        let array = Self(Nullable::from_parts_unchecked(data, validity));
+        debug_assert_eq!(array.validate(), Ok(()));
+        array
+    }
+
+    /// Returns a new nullable [`FixedSizePrimitiveArray`] from the given data
+    /// buffer and the bytes of a validity bitmap with `len` bits.
+    ///
+    /// The validity bits are read LSB-first from `validity_bytes`. The values
+    /// of the bits after `len` in the last byte are unspecified and never
+    /// read.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    /// - the length of `data` equals `len`, and
+    /// - `validity_bytes` contains at least `(len + 7) / 8` bytes.
+    pub unsafe fn from_raw_parts(
+        data: <Buffer as BufferType>::Buffer<T>,
+        validity_bytes: <Buffer as BufferType>::Buffer<u8>,
+        len: usize,
+    ) -> Self {
+        Self::from_parts_unchecked(data, Bitmap::from_raw_parts(validity_bytes, len, 0))
+    }
+
+    /// Returns a new nullable [`FixedSizePrimitiveArray`] from the given data
+    /// buffer and the bytes of a validity bitmap with `len` bits, checking
+    /// the requirements of [`FixedSizePrimitiveArray::from_raw_parts`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] when `validity_bytes` contains too few
+    /// bytes, or when the length of `data` does not equal `len`.
+    pub fn try_from_raw_parts(
+        data: <Buffer as BufferType>::Buffer<T>,
+        validity_bytes: <Buffer as BufferType>::Buffer<u8>,
+        len: usize,
+    ) -> core::result::Result<Self, ValidationError> {
+        Self::try_from_parts(data, Bitmap::try_from_raw_parts(validity_bytes, len, 0)?)
+    }
+
+    /// Returns a new nullable [`FixedSizePrimitiveArray`] with `len` null
+    /// values.
+    ///
+    /// The data buffer is filled with default values in bulk, like
+    /// [`FixedSizePrimitiveArray::new_repeated`].
+    #[must_use]
+    pub fn new_null(len: usize) -> Self
+    where
+        T: Default,
+        <Buffer as BufferType>::Buffer<T>: From<Vec<T>>,
+        <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        Self(Nullable {
+            data: vec![T::default(); len].into(),
+            validity: Bitmap::new_null(len),
+        })
+    }
+
+    /// Returns a slice with the values of this [`FixedSizePrimitiveArray`].
+    ///
+    /// This includes the values stored in null slots, which are unspecified.
+    pub fn values(&self) -> &[T] {
+        self.0.data.as_slice()
+    }
+
+    /// Returns an iterator over the values of this [`FixedSizePrimitiveArray`]
+    /// together with their validity.
+    ///
+    /// Unlike [`FixedSizePrimitiveArray::iter`], this does not wrap the values
+    /// in an [`Option`]: the values stored in null slots, which are
+    /// unspecified, are returned with `false`. The validity bitmap is read 64
+    /// bits at a time.
+    pub fn iter_with_validity(&self) -> impl Iterator<Item = (T, bool)> + '_ {
+        self.values()
+            .iter()
+            .copied()
+            .zip(self.0.validity.word_bits())
+    }
+
+    /// Returns the values of this [`FixedSizePrimitiveArray`] and their
+    /// validity.
+    ///
+    /// See [`FixedSizePrimitiveArray::iter_with_validity`].
+    #[must_use]
+    pub fn to_parts_vec(&self) -> (Vec<T>, Vec<bool>) {
+        (
+            self.values().to_vec(),
+            self.0.validity.word_bits().collect(),
+        )
+    }
+
+    /// Returns the data buffer and validity bitmap of this
+    /// [`FixedSizePrimitiveArray`].
+    pub fn into_parts(self) -> (<Buffer as BufferType>::Buffer<T>, Bitmap<Buffer>) {
+        self.0.into_parts()
+    }
+}
+
+impl<T: FixedSize> FixedSizePrimitiveArray<T, true> {
+    /// Appends the values and validity of `array` to this
+    /// [`FixedSizePrimitiveArray`]. The values are copied in bulk and the
+    /// validity bitmap is appended a word at a time.
+    pub fn extend_from_array(&mut self, array: &Self) {
+        self.0.data.extend_from_slice(&array.0.data);
+        self.0.validity.extend_from_bitmap(&array.0.validity);
+    }
+
+    /// Returns a [`FixedSizePrimitiveArray`] with the values and validity of
+    /// the `arrays`, in order. The total length is reserved once, before the
+    /// arrays are appended with [`FixedSizePrimitiveArray::extend_from_array`].
+    #[must_use]
+    pub fn concat(arrays: &[Self]) -> Self {
+        let len = arrays.iter().map(|array| array.0.data.len()).sum();
+        let mut result = Self::default();
+        result.0.data.reserve_exact(len);
+        result.0.validity.reserve(len);
+        for array in arrays {
+            result.extend_from_array(array);
+        }
+        result
+    }
+}
+
 impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> Array
     for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
 where
@@ -97,12 +307,12 @@ impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> Debug
     for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
 where
     <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
-    <<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer>: Debug,
+    Self: Length,
+    for<'a> &'a Self: IntoIterator,
+    for<'a> <&'a Self as IntoIterator>::Item: Debug,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        f.debug_tuple("FixedSizePrimitiveArray")
-            .field(&self.0)
-            .finish()
+        super::debug_array::<Self>(f, "FixedSizePrimitiveArray", self)
     }
 }
 
@@ -117,7 +327,18 @@ where
     }
 }
 
-impl<T: FixedSize, U, const NULLABLE: bool, Buffer: BufferType> Extend<U>
+impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> Display
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: TableDisplay,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.display_table(FormatOptions::default()))
+    }
+}
+
+impl<T: FixedSize, U, const NULLABLE: bool, Buffer: GrowableBuffer> Extend<U>
     for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
 where
     <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
@@ -139,6 +360,32 @@ where
     }
 }
 
+impl<T: FixedSize> From<Vec<T>> for FixedSizePrimitiveArray<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: FixedSize> From<&[T]> for FixedSizePrimitiveArray<T> {
+    fn from(value: &[T]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl<T: FixedSize + Default> From<Vec<Option<T>>> for FixedSizePrimitiveArray<T, true> {
+    fn from(value: Vec<Option<T>>) -> Self {
+        let validity = value.iter().map(Option::is_some).collect();
+        let data = value.into_iter().map(Option::unwrap_or_default).collect();
+        Self(Nullable { data, validity })
+    }
+}
+
+impl<T: FixedSize> From<FixedSizePrimitiveArray<T>> for Vec<T> {
+    fn from(value: FixedSizePrimitiveArray<T>) -> Self {
+        value.into_vec()
+    }
+}
+
 impl<T: FixedSize, const NULLABLE: bool, U, Buffer: BufferType> FromIterator<U>
     for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
 where
@@ -150,6 +397,25 @@ where
     }
 }
 
+impl<T: FixedSize + Hash, Buffer: BufferType> Hash for FixedSizePrimitiveArray<T, false, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_slice().hash(state);
+    }
+}
+
+impl<T: FixedSize + Hash, Buffer: BufferType> Hash for FixedSizePrimitiveArray<T, true, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The validity covers the nulls, so values in null slots are skipped.
+        self.0.validity.hash(state);
+        let values = self.0.data.as_slice();
+        self.0
+            .validity
+            .runs()
+            .filter(|&(_, valid)| valid)
+            .for_each(|(range, _)| values[range].hash(state));
+    }
+}
+
 impl<T: FixedSize, I: SliceIndex<[T]>, Buffer: BufferType> ops::Index<I>
     for FixedSizePrimitiveArray<T, false, Buffer>
 where
@@ -217,6 +483,24 @@ where
     }
 }
 
+impl<T: FixedSize, Buffer: BufferType> TryFrom<FixedSizePrimitiveArray<T, true, Buffer>>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+{
+    type Error = FixedSizePrimitiveArray<T, true, Buffer>;
+
+    /// Returns the non-nullable array when all values are valid, without
+    /// copying the values. Returns the given array otherwise.
+    fn try_from(
+        value: FixedSizePrimitiveArray<T, true, Buffer>,
+    ) -> core::result::Result<Self, Self::Error> {
+        if value.all_valid() {
+            Ok(Self(value.0.data))
+        } else {
+            Err(value)
+        }
+    }
+}
+
 impl<T: FixedSize, Buffer: BufferType> BitmapRef for FixedSizePrimitiveArray<T, true, Buffer> {
     type Buffer = Buffer;
 
@@ -231,45 +515,288 @@ impl<T: FixedSize, Buffer: BufferType> BitmapRefMut for FixedSizePrimitiveArray<
     }
 }
 
-impl<T: FixedSize, Buffer: BufferType> PartialEq for FixedSizePrimitiveArray<T, false, Buffer> {
-    fn eq(&self, _other: &Self) -> bool {
-        todo!()
+impl<T: FixedSize, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialEq<FixedSizePrimitiveArray<T, false, OtherBuffer>>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, false, OtherBuffer>) -> bool {
+        self.0.as_slice() == other.0.as_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialEq<FixedSizePrimitiveArray<T, true, OtherBuffer>>
+    for FixedSizePrimitiveArray<T, true, Buffer>
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, true, OtherBuffer>) -> bool {
+        let (lhs, rhs) = (self.0.data.as_slice(), other.0.data.as_slice());
+        // Values in null slots are ignored, only the valid runs are compared.
+        self.0.validity == other.0.validity
+            && self
+                .0
+                .validity
+                .runs()
+                .filter(|&(_, valid)| valid)
+                .all(|(range, _)| lhs[range.clone()] == rhs[range])
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialEq<FixedSizePrimitiveArray<T, false, OtherBuffer>>
+    for FixedSizePrimitiveArray<T, true, Buffer>
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, false, OtherBuffer>) -> bool {
+        self.0.validity.count_zeros() == 0 && self.0.data.as_slice() == other.0.as_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialEq<FixedSizePrimitiveArray<T, true, OtherBuffer>>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, true, OtherBuffer>) -> bool {
+        other == self
     }
 }
 
-impl<T: FixedSize, Buffer: BufferType> PartialEq<[T]> for FixedSizePrimitiveArray<T, false, Buffer>
+impl<T: FixedSize + Eq, const NULLABLE: bool, Buffer: BufferType> Eq
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
 where
-    <Buffer as BufferType>::Buffer<T>: PartialEq<[T]>,
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: PartialEq,
+{
+}
+
+// Note: there is no `Ord` impl, because its provided `min` and `max` methods
+// would take precedence over the `min` and `max` aggregates for owned arrays.
+impl<T: FixedSize + PartialOrd, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialOrd<FixedSizePrimitiveArray<T, false, OtherBuffer>>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+{
+    fn partial_cmp(
+        &self,
+        other: &FixedSizePrimitiveArray<T, false, OtherBuffer>,
+    ) -> Option<Ordering> {
+        self.0.as_slice().partial_cmp(other.0.as_slice())
+    }
+}
+
+/// Returns an iterator over the optional values of a nullable array, without
+/// copying the values.
+fn nullable_values<T: FixedSize, Buffer: BufferType>(
+    array: &FixedSizePrimitiveArray<T, true, Buffer>,
+) -> impl Iterator<Item = Option<&T>> {
+    array
+        .0
+        .data
+        .as_slice()
+        .iter()
+        .zip(&array.0.validity)
+        .map(|(value, valid)| valid.then_some(value))
+}
+
+/// Nulls are ordered before all valid values.
+impl<T: FixedSize + PartialOrd, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialOrd<FixedSizePrimitiveArray<T, true, OtherBuffer>>
+    for FixedSizePrimitiveArray<T, true, Buffer>
+{
+    fn partial_cmp(
+        &self,
+        other: &FixedSizePrimitiveArray<T, true, OtherBuffer>,
+    ) -> Option<Ordering> {
+        nullable_values(self).partial_cmp(nullable_values(other))
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> PartialEq<[T]>
+    for FixedSizePrimitiveArray<T, false, Buffer>
 {
     fn eq(&self, other: &[T]) -> bool {
-        self.0.eq(other)
+        self.0.as_slice() == other
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType, const N: usize> PartialEq<[T; N]>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+{
+    fn eq(&self, other: &[T; N]) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> PartialEq<Vec<T>>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+{
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> PartialEq<FixedSizePrimitiveArray<T, false, Buffer>>
+    for [T]
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, false, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType, const N: usize>
+    PartialEq<FixedSizePrimitiveArray<T, false, Buffer>> for [T; N]
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, false, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> PartialEq<FixedSizePrimitiveArray<T, false, Buffer>>
+    for Vec<T>
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, false, Buffer>) -> bool {
+        other == self
     }
 }
 
 impl<T: FixedSize, Buffer: BufferType> PartialEq<[Option<T>]>
     for FixedSizePrimitiveArray<T, true, Buffer>
-where
-    for<'a> &'a Self: IntoIterator<Item = Option<T>>,
 {
     fn eq(&self, other: &[Option<T>]) -> bool {
-        self.len() == other.len() && self.into_iter().zip(other).all(|(a, &b)| a == b)
+        self.len() == other.len()
+            && self
+                .0
+                .data
+                .as_slice()
+                .iter()
+                .zip(&self.0.validity)
+                .zip(other)
+                .all(|((&value, valid), &expected)| valid.then_some(value) == expected)
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType, const N: usize> PartialEq<[Option<T>; N]>
+    for FixedSizePrimitiveArray<T, true, Buffer>
+{
+    fn eq(&self, other: &[Option<T>; N]) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> PartialEq<Vec<Option<T>>>
+    for FixedSizePrimitiveArray<T, true, Buffer>
+{
+    fn eq(&self, other: &Vec<Option<T>>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> PartialEq<FixedSizePrimitiveArray<T, true, Buffer>>
+    for [Option<T>]
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, true, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType, const N: usize>
+    PartialEq<FixedSizePrimitiveArray<T, true, Buffer>> for [Option<T>; N]
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, true, Buffer>) -> bool {
+        other == self
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> PartialEq<FixedSizePrimitiveArray<T, true, Buffer>>
+    for Vec<Option<T>>
+{
+    fn eq(&self, other: &FixedSizePrimitiveArray<T, true, Buffer>) -> bool {
+        other == self
     }
 }
 
 impl<T: FixedSize, Buffer: BufferType> ValidityBitmap for FixedSizePrimitiveArray<T, true, Buffer> {}
 
+impl<T: FixedSize, Buffer: BufferType> super::LogicalValidity
+    for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    Self: Length,
+{
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Validate for FixedSizePrimitiveArray<T, false, Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Validate for FixedSizePrimitiveArray<T, true, Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
+impl<T: FixedSize + Display, Buffer: BufferType> TableDisplay
+    for FixedSizePrimitiveArray<T, false, Buffer>
+{
+    fn format_value(&self, index: usize) -> Option<String> {
+        Some(self.as_slice()[index].to_string())
+    }
+}
+
+impl<T: FixedSize + Display, Buffer: BufferType> TableDisplay
+    for FixedSizePrimitiveArray<T, true, Buffer>
+{
+    fn format_value(&self, index: usize) -> Option<String> {
+        let value = &self.values()[index];
+        self.is_valid(index)
+            .unwrap_or_default()
+            .then(|| value.to_string())
+    }
+}
+
+impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> MemoryUsage
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    <<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::buffer::{BufferRef, BufferRefMut};
-    use std::mem;
+    use crate::{
+        buffer::{BoxBuffer, BufferRefMut},
+        LengthMismatch,
+    };
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        mem,
+    };
 
     #[test]
     fn from_iter() {
         let input = [1_u8, 2, 3, 4];
         let array = input.into_iter().collect::<FixedSizePrimitiveArray<_>>();
-        assert_eq!(array.0.as_slice(), &[1, 2, 3, 4]);
-        assert_eq!(array.0.as_slice(), array.0.as_bytes());
+        assert_eq!(array.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(array.as_slice(), array.0.as_bytes());
 
         #[cfg(not(feature = "arrow-rs"))]
         {
@@ -277,7 +804,7 @@ mod tests {
             let array_array = input_array
                 .into_iter()
                 .collect::<FixedSizePrimitiveArray<_>>();
-            assert_eq!(array_array.0.as_slice(), &[[1, 2], [3, 4]]);
+            assert_eq!(array_array.as_slice(), &[[1, 2], [3, 4]]);
             assert_eq!(<_ as Buffer<u8>>::as_bytes(&array_array.0), &[1, 2, 3, 4]);
         };
     }
@@ -286,7 +813,7 @@ mod tests {
     fn from_iter_nullable() {
         let input = [Some(1_u64), None, Some(3), Some(4)];
         let array = input.iter().collect::<FixedSizePrimitiveArray<_, true>>();
-        assert_eq!(array.0.buffer_ref().as_slice(), &[1, u64::default(), 3, 4]);
+        assert_eq!(array.values(), &[1, u64::default(), 3, 4]);
         assert_eq!(array.is_valid(0), Some(true));
         assert_eq!(array.is_null(1), Some(true));
         assert_eq!(array.is_valid(2), Some(true));
@@ -317,6 +844,21 @@ mod tests {
         assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
     }
 
+    #[test]
+    fn iter_nullable() {
+        let input = [Some(1_u64), None, Some(3), Some(4)];
+        let array = input.iter().collect::<FixedSizePrimitiveArray<_, true>>();
+        assert_eq!(array.iter().len(), 4);
+        assert_eq!(
+            array.iter().rev().collect::<Vec<_>>(),
+            [Some(&4), Some(&3), None, Some(&1)]
+        );
+        assert_eq!(
+            array.iter().map(Option::<&_>::copied).collect::<Vec<_>>(),
+            array.clone().into_iter().collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn length() {
         let input = [1_u8, 2, 3, 4];
@@ -375,4 +917,426 @@ mod tests {
             mem::size_of::<Int8Array>() + mem::size_of::<Bitmap>()
         );
     }
+
+    #[test]
+    fn debug() {
+        let array = [Some(1), None].into_iter().collect::<Int32Array<true>>();
+        assert_eq!(
+            format!("{array:?}"),
+            "FixedSizePrimitiveArray { len: 2, values: [Some(1), None] }"
+        );
+    }
+
+    #[test]
+    fn clone() {
+        let array = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let mut cloned = array.clone();
+        cloned.bitmap_ref_mut().buffer_ref_mut()[0] = 0b0000_0010;
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(3)]
+        );
+        assert_eq!(
+            cloned.into_iter().collect::<Vec<_>>(),
+            [None, Some(0), None]
+        );
+    }
+
+    #[test]
+    fn eq() {
+        let array = [1, 2, 3].into_iter().collect::<Int32Array>();
+        let boxed = [1, 2, 3]
+            .into_iter()
+            .collect::<Int32Array<false, BoxBuffer>>();
+        assert_eq!(array, boxed);
+        assert_ne!(array, [1, 2].into_iter().collect::<Int32Array>());
+
+        let nullable = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let other = FixedSizePrimitiveArray::<_, true, BoxBuffer>(Nullable {
+            data: vec![1, 42, 3].into_boxed_slice(),
+            validity: [true, false, true].into_iter().collect(),
+        });
+        assert_eq!(nullable, other);
+        assert_ne!(nullable, array);
+        assert_eq!(
+            [Some(1), Some(2), Some(3)]
+                .into_iter()
+                .collect::<Int32Array<true>>(),
+            array
+        );
+        assert_eq!(
+            boxed,
+            [Some(1), Some(2), Some(3)]
+                .into_iter()
+                .collect::<Int32Array<true>>()
+        );
+    }
+
+    #[test]
+    fn eq_random() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..80);
+            let lhs = (0..len)
+                .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..3)))
+                .collect::<Vec<Option<i32>>>();
+            let rhs = (0..len)
+                .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..3)))
+                .collect::<Vec<Option<i32>>>();
+            for (left, right) in [(&lhs, &rhs), (&lhs, &lhs)] {
+                let left_array = left.iter().copied().collect::<Int32Array<true>>();
+                let right_array = FixedSizePrimitiveArray::<_, true, BoxBuffer>(Nullable {
+                    data: right.iter().map(|opt| opt.unwrap_or(7)).collect(),
+                    validity: right.iter().map(Option::is_some).collect(),
+                });
+                assert_eq!(left_array == right_array, left == right);
+            }
+        }
+    }
+
+    #[test]
+    fn eq_slice() {
+        let array = [1, 2, 3].into_iter().collect::<Int32Array>();
+        assert_eq!(array, [1, 2, 3]);
+        assert_eq!([1, 2, 3], array);
+        assert_eq!(array, vec![1, 2, 3]);
+        assert_eq!(vec![1, 2, 3], array);
+        assert_eq!(array, [1, 2, 3][..]);
+        assert_ne!(array, [1, 2]);
+        assert_ne!([1, 2, 4], array);
+
+        let nullable = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(nullable, [Some(1), None, Some(3)]);
+        assert_eq!(vec![Some(1), None, Some(3)], nullable);
+        assert_ne!(nullable, [Some(1), Some(0), Some(3)]);
+        assert_ne!([Some(1), None], nullable);
+    }
+
+    #[test]
+    fn hash() {
+        fn hash_one(value: &impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let array = [1, 2, 3].into_iter().collect::<Int32Array>();
+        let boxed = [1, 2, 3]
+            .into_iter()
+            .collect::<Int32Array<false, BoxBuffer>>();
+        assert_eq!(hash_one(&array), hash_one(&boxed));
+
+        let nullable = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let mut other = nullable.clone();
+        other.0.data[1] = 42;
+        other.bitmap_ref_mut().buffer_ref_mut()[0] |= 0b1111_1000;
+        let boxed_nullable = FixedSizePrimitiveArray::<_, true, BoxBuffer>(Nullable {
+            data: vec![1, 7, 3].into_boxed_slice(),
+            validity: [true, false, true].into_iter().collect(),
+        });
+        assert_eq!(hash_one(&nullable), hash_one(&boxed_nullable));
+
+        let set = [
+            nullable,
+            other,
+            [Some(1), Some(0), Some(3)].into_iter().collect(),
+        ]
+        .into_iter()
+        .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn ord() {
+        let array = |values: &[u32]| values.iter().copied().collect::<Uint32Array>();
+        assert!(array(&[1, 2]) < array(&[1, 2, 3]));
+        assert!(array(&[1, 3]) > array(&[1, 2, 3]));
+        assert!(array(&[]) < array(&[0]));
+        assert_eq!(
+            array(&[1, 2]).partial_cmp(
+                &[1_u32, 2]
+                    .into_iter()
+                    .collect::<Uint32Array<false, BoxBuffer>>()
+            ),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            [0.5, f64::NAN]
+                .into_iter()
+                .collect::<Float64Array>()
+                .partial_cmp(&[0.5, 1.].into_iter().collect::<Float64Array>()),
+            None
+        );
+
+        let nullable =
+            |values: &[Option<u32>]| values.iter().copied().collect::<Uint32Array<true>>();
+        assert!(nullable(&[None]) < nullable(&[Some(0)]));
+        assert!(nullable(&[Some(1), None]) < nullable(&[Some(1), Some(0)]));
+        assert!(nullable(&[Some(1), None]) > nullable(&[Some(1)]));
+        assert!(nullable(&[None, Some(5)]) < nullable(&[Some(0)]));
+    }
+
+    #[test]
+    fn from_vec() {
+        let vec = vec![1_u64, 2, 3, 4];
+        let ptr = vec.as_ptr();
+        let array = Uint64Array::from(vec);
+        assert_eq!(array.0.as_ptr(), ptr);
+        assert_eq!(array, [1, 2, 3, 4]);
+        let roundtrip = Vec::from(array);
+        assert_eq!(roundtrip.as_ptr(), ptr);
+        let into_vec = Uint64Array::from(roundtrip).into_vec();
+        assert_eq!(into_vec.as_ptr(), ptr);
+
+        let slice: &[u64] = &[5, 6];
+        assert_eq!(Uint64Array::from(slice), [5, 6]);
+
+        let nullable = Uint64Array::<true>::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(nullable, [Some(1), None, Some(3)]);
+        assert_eq!(nullable.values(), [1, 0, 3]);
+    }
+
+    #[test]
+    fn parts() {
+        let array = Uint64Array::<true>::from(vec![Some(1), None, Some(3)]);
+        let (data, validity) = array.into_parts();
+        assert_eq!(data, [1, 0, 3]);
+        assert_eq!(validity, [true, false, true]);
+        let roundtrip = Uint64Array::<true>::try_from_parts(data, validity).expect("same length");
+        assert_eq!(roundtrip, [Some(1), None, Some(3)]);
+
+        assert_eq!(
+            Uint64Array::<true>::try_from_parts(vec![1, 2], [true].into_iter().collect()).err(),
+            Some(ValidationError::ValidityLength(LengthMismatch {
+                expected: 2,
+                actual: 1
+            }))
+        );
+
+        let boxed = Uint64Array::<true, BoxBuffer>::try_from_parts(
+            vec![4, 5].into_boxed_slice(),
+            [false, true].into_iter().collect(),
+        )
+        .expect("same length");
+        assert_eq!(boxed, [None, Some(5)]);
+    }
+
+    #[test]
+    fn extend_ref() {
+        let mut array = Uint16Array::from(vec![1]);
+        array.extend(&[2, 3]);
+        array.extend([4, 5].iter());
+        assert_eq!(array, [1, 2, 3, 4, 5]);
+
+        let mut nullable = Uint16Array::<true>::from(vec![Some(1)]);
+        nullable.extend(&[None, Some(3)]);
+        nullable.extend([Some(4), None].iter());
+        assert_eq!(nullable, [Some(1), None, Some(3), Some(4), None]);
+    }
+
+    #[test]
+    fn try_from_nullable() {
+        let nullable = Uint16Array::<true>::from(vec![Some(1), Some(2)]);
+        let ptr = nullable.values().as_ptr();
+        let array = Uint16Array::<false>::try_from(nullable).expect("all valid");
+        assert_eq!(array.as_slice().as_ptr(), ptr);
+        assert_eq!(array, [1, 2]);
+
+        let with_null = Uint16Array::<true>::from(vec![Some(1), None]);
+        let null_ptr = with_null.values().as_ptr();
+        let error = Uint16Array::<false>::try_from(with_null).expect_err("contains null");
+        assert_eq!(error.values().as_ptr(), null_ptr);
+        assert_eq!(error, [Some(1), None]);
+    }
+
+    #[test]
+    fn new_null() {
+        for len in [0, 1, 7, 9, 100] {
+            let array = Uint16Array::<true>::new_null(len);
+            assert_eq!(array.len(), len);
+            assert_eq!(array.null_count(), len);
+            assert_eq!(
+                array,
+                std::iter::repeat(None::<u16>)
+                    .take(len)
+                    .collect::<Uint16Array<true>>()
+            );
+        }
+    }
+
+    #[test]
+    fn new_repeated() {
+        for len in [0, 1, 7, 9, 100] {
+            let array = Uint16Array::<false>::new_repeated(42, len);
+            assert_eq!(array.len(), len);
+            assert_eq!(
+                array,
+                std::iter::repeat(42).take(len).collect::<Uint16Array>()
+            );
+        }
+        let boxed = FixedSizePrimitiveArray::<u16, false, BoxBuffer>::new_repeated(42, 3);
+        assert_eq!(boxed.as_slice(), [42; 3]);
+        let boxed_null = FixedSizePrimitiveArray::<u16, true, BoxBuffer>::new_null(3);
+        assert_eq!(boxed_null.values(), [0; 3]);
+    }
+
+    #[test]
+    fn validate() {
+        let array = Uint16Array::<true>::from(vec![Some(1), None]);
+        assert_eq!(array.validate(), Ok(()));
+
+        let broken = FixedSizePrimitiveArray::<u16, true>(Nullable {
+            data: vec![1, 2, 3],
+            validity: [true, false].into_iter().collect(),
+        });
+        assert_eq!(
+            broken.validate(),
+            Err(ValidationError::ValidityLength(LengthMismatch {
+                expected: 3,
+                actual: 2
+            }))
+        );
+
+        // The buffer is too small for 8 bits at offset 4.
+        let validity = Bitmap::<VecBuffer> {
+            buffer: vec![u8::MAX],
+            bits: 8,
+            offset: 4,
+        };
+        assert_eq!(
+            Uint16Array::<true>::try_from_parts(vec![0; 8], validity).err(),
+            Some(ValidationError::BitmapBuffer {
+                required: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn raw_parts() {
+        // Safety:
+        // - There are 3 values and 1 byte for 3 validity bits.
+        let array =
+            unsafe { Uint16Array::<true>::from_raw_parts(vec![1, 2, 3], vec![0b1111_0101], 3) };
+        assert_eq!(array, [Some(1), None, Some(3)]);
+
+        assert_eq!(
+            Uint16Array::<true>::try_from_raw_parts(vec![1; 9], vec![u8::MAX], 9).err(),
+            Some(ValidationError::BitmapBuffer {
+                required: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            Uint16Array::<true>::try_from_raw_parts(vec![1, 2], vec![u8::MAX], 3).err(),
+            Some(ValidationError::ValidityLength(LengthMismatch {
+                expected: 2,
+                actual: 3
+            }))
+        );
+        assert_eq!(
+            Uint16Array::<true>::try_from_raw_parts(vec![1, 2], vec![0b10], 2).ok(),
+            Some(Uint16Array::<true>::from(vec![None, Some(2)]))
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "ValidityLength")]
+    fn from_parts_unchecked_debug_validate() {
+        // Safety:
+        // - This violates the contract on purpose, which is caught in debug
+        //   builds.
+        let _array = unsafe {
+            Uint16Array::<true>::from_parts_unchecked(vec![1, 2], [true].into_iter().collect())
+        };
+    }
+
+    #[test]
+    fn memory_usage() {
+        let array = (0..1000)
+            .map(|value| (value % 3 != 0).then_some(value))
+            .collect::<Int64Array<true>>();
+        assert_eq!(array.buffer_bytes(), 8000 + 125);
+        assert!(array.allocated_bytes() >= array.buffer_bytes());
+
+        let boxed = Uint8Array::<false, BoxBuffer>::from_iter([1, 2, 3]);
+        assert_eq!(boxed.buffer_bytes(), 3);
+        assert_eq!(boxed.allocated_bytes(), 3);
+    }
+
+    #[test]
+    fn iter_with_validity() {
+        let input = (0..100_u32)
+            .map(|value| (value % 3 != 0).then_some(value))
+            .collect::<Vec<_>>();
+        let array = input
+            .iter()
+            .copied()
+            .collect::<FixedSizePrimitiveArray<_, true>>();
+        assert!(array
+            .iter_with_validity()
+            .map(|(value, valid)| valid.then_some(value))
+            .eq(array.clone().into_iter()));
+        // Null slots expose the stored default values.
+        assert!(array
+            .iter_with_validity()
+            .filter(|&(_, valid)| !valid)
+            .all(|(value, _)| value == 0));
+        let (values, validity) = array.to_parts_vec();
+        assert_eq!(values, array.values());
+        assert_eq!(
+            validity,
+            input.iter().map(Option::is_some).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn extend_arrays() {
+        let chunks = [3, 5, 1, 7, 0, 9]
+            .into_iter()
+            .scan(0_i64, |start, len| {
+                let values = (*start..*start + len).collect::<Vec<_>>();
+                *start += len;
+                Some(values)
+            })
+            .collect::<Vec<_>>();
+        let nullable = chunks
+            .iter()
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|&value| (value % 3 != 0).then_some(value))
+                    .collect::<Int64Array<true>>()
+            })
+            .collect::<Vec<_>>();
+        let expected = nullable
+            .iter()
+            .flat_map(|array| array.clone().into_iter())
+            .collect::<Vec<_>>();
+        let merged = Int64Array::<true>::concat(&nullable);
+        assert_eq!(merged.len(), 25);
+        assert_eq!(merged, expected);
+        assert_eq!(merged.null_count(), 9);
+        assert!(merged.0.validity.buffer.capacity() >= 4);
+
+        let arrays = chunks.into_iter().map(Int64Array::from).collect::<Vec<_>>();
+        let mut extended: Int64Array = Int64Array::default();
+        for array in &arrays {
+            extended.extend_from_array(array);
+        }
+        // The total length is allocated once, before the values are copied.
+        let merged_values = Int64Array::<false>::concat(&arrays);
+        assert_eq!(merged_values.0.capacity(), 25);
+        assert!(merged_values.iter().copied().eq(0..25));
+        assert_eq!(merged_values, extended);
+    }
 }
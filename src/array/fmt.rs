@@ -0,0 +1,277 @@
+//! Table and debug formatting of arrays.
+
+use crate::Length;
+use alloc::{borrow::ToOwned, string::String, string::ToString, vec, vec::Vec};
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// The text used for null values.
+const NULL: &str = "null";
+
+/// The text used for omitted rows and truncated values.
+const ELLIPSIS: &str = "…";
+
+/// Options for formatting arrays as tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FormatOptions {
+    /// The maximum number of rows to show. When an array has more rows, the
+    /// first and last rows are shown, separated by an ellipsis row.
+    pub max_rows: usize,
+
+    /// The maximum width (in characters) of a column. Longer values are
+    /// truncated and end with an ellipsis.
+    pub column_width: usize,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: 20,
+            column_width: 32,
+        }
+    }
+}
+
+/// Arrays that can be formatted as a table.
+pub trait TableDisplay: Length {
+    /// Returns the formatted value at `index`, or `None` if the value is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn format_value(&self, index: usize) -> Option<String>;
+
+    /// Returns the formatted values of (at most) the first `limit` rows of
+    /// this array, with null values formatted as `null`.
+    fn to_string_rows(&self, limit: usize) -> Vec<String> {
+        (0..self.len().min(limit))
+            .map(|index| self.format_value(index).unwrap_or_else(|| NULL.to_owned()))
+            .collect()
+    }
+
+    /// Returns a wrapper around this array that formats it as a table using
+    /// the given options.
+    fn display_table(&self, options: FormatOptions) -> Table<'_, Self> {
+        Table {
+            array: self,
+            options,
+        }
+    }
+}
+
+/// A wrapper around an array that implements [`Display`] by formatting the
+/// array as a table with an index and a value column.
+///
+/// Returned by [`TableDisplay::display_table`].
+#[derive(Debug)]
+pub struct Table<'a, T: ?Sized> {
+    /// The array.
+    array: &'a T,
+    /// The format options.
+    options: FormatOptions,
+}
+
+impl<T: TableDisplay + ?Sized> Display for Table<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let len = self.array.len();
+        let row = |index: usize| {
+            vec![
+                index.to_string(),
+                self.array
+                    .format_value(index)
+                    .unwrap_or_else(|| NULL.to_owned()),
+            ]
+        };
+        let rows = if len > self.options.max_rows {
+            let tail = self.options.max_rows / 2;
+            let head = self.options.max_rows - tail;
+            (0..head)
+                .map(row)
+                .chain([vec![ELLIPSIS.to_owned(), ELLIPSIS.to_owned()]])
+                .chain((len - tail..len).map(row))
+                .collect::<Vec<_>>()
+        } else {
+            (0..len).map(row).collect()
+        };
+        write_table(f, &["index", "value"], &rows, self.options.column_width)
+    }
+}
+
+/// Returns `value` truncated to at most `width` characters.
+fn truncate(value: &str, width: usize) -> String {
+    if value.chars().count() > width {
+        value
+            .chars()
+            .take(width.saturating_sub(1))
+            .chain(ELLIPSIS.chars())
+            .collect()
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Writes a table with the given headers and rows, with the cells truncated
+/// to `column_width` characters.
+pub(crate) fn write_table(
+    f: &mut Formatter<'_>,
+    headers: &[&str],
+    rows: &[Vec<String>],
+    column_width: usize,
+) -> fmt::Result {
+    let header_cells = headers
+        .iter()
+        .map(|header| truncate(header, column_width))
+        .collect::<Vec<_>>();
+    let row_cells = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| truncate(cell, column_width))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let widths = header_cells
+        .iter()
+        .enumerate()
+        .map(|(column, header)| {
+            row_cells
+                .iter()
+                .filter_map(|row| row.get(column))
+                .chain([header])
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let separator = |out: &mut Formatter<'_>| {
+        widths
+            .iter()
+            .try_for_each(|width| write!(out, "+{}", "-".repeat(width + 2)))?;
+        writeln!(out, "+")
+    };
+    let line = |out: &mut Formatter<'_>, cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .try_for_each(|(cell, width)| write!(out, "| {cell:width$} "))?;
+        writeln!(out, "|")
+    };
+
+    separator(f)?;
+    line(f, &header_cells)?;
+    separator(f)?;
+    row_cells.iter().try_for_each(|row| line(f, row))?;
+    separator(f)
+}
+
+/// The logical values of an array, formatted as a list.
+struct DebugValues<'a, T: ?Sized>(&'a T);
+
+impl<'a, T: ?Sized> Debug for DebugValues<'a, T>
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0).finish()
+    }
+}
+
+/// Writes the [`Debug`] representation of an array: a struct named `name`
+/// with the length and the logical values of the array, where null values
+/// are `None`.
+pub(crate) fn debug_array<'a, T: Length + ?Sized>(
+    f: &mut Formatter<'_>,
+    name: &str,
+    array: &'a T,
+) -> fmt::Result
+where
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Debug,
+{
+    f.debug_struct(name)
+        .field("len", &array.len())
+        .field("values", &DebugValues(array))
+        .finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{StringArray, Uint16Array};
+
+    #[test]
+    fn display() {
+        let array = [Some(1), None, Some(3), Some(4), None]
+            .into_iter()
+            .collect::<Uint16Array<true>>();
+        assert_eq!(
+            array.to_string(),
+            "\
++-------+-------+
+| index | value |
++-------+-------+
+| 0     | 1     |
+| 1     | null  |
+| 2     | 3     |
+| 3     | 4     |
+| 4     | null  |
++-------+-------+
+"
+        );
+    }
+
+    #[test]
+    fn display_table() {
+        let array = (0..10).collect::<Uint16Array>();
+        assert_eq!(
+            array
+                .display_table(FormatOptions {
+                    max_rows: 3,
+                    column_width: 32
+                })
+                .to_string(),
+            "\
++-------+-------+
+| index | value |
++-------+-------+
+| 0     | 0     |
+| 1     | 1     |
+| …     | …     |
+| 9     | 9     |
++-------+-------+
+"
+        );
+
+        let strings = ["a", "narrow"]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect::<StringArray>();
+        assert_eq!(
+            strings
+                .display_table(FormatOptions {
+                    max_rows: 3,
+                    column_width: 4
+                })
+                .to_string(),
+            "\
++------+------+
+| ind… | val… |
++------+------+
+| 0    | a    |
+| 1    | nar… |
++------+------+
+"
+        );
+    }
+
+    #[test]
+    fn to_string_rows() {
+        let array = [Some(true), None, Some(false)]
+            .into_iter()
+            .collect::<crate::array::BooleanArray<true>>();
+        assert_eq!(array.to_string_rows(2), ["true", "null"]);
+        assert_eq!(array.to_string_rows(5), ["true", "null", "false"]);
+        assert!(array.to_string_rows(0).is_empty());
+    }
+}
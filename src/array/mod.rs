@@ -5,11 +5,32 @@ use crate::{
     offset::{self, OffsetElement},
     Length,
 };
-use std::{collections::VecDeque, marker::PhantomData};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::VecDeque,
+    rc::Rc,
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
+use core::marker::PhantomData;
 
 mod boolean;
 pub use boolean::*;
 
+mod chunked;
+pub use chunked::*;
+
+mod compare;
+pub use compare::*;
+
+mod dictionary;
+pub use dictionary::*;
+
+mod extension;
+pub use extension::*;
+
 mod fixed_size_binary;
 pub use fixed_size_binary::*;
 
@@ -19,18 +40,30 @@ pub use fixed_size_list::*;
 mod fixed_size_primitive;
 pub use fixed_size_primitive::*;
 
+mod fmt;
+pub use fmt::*;
+
 mod null;
 pub use null::*;
 
+mod option;
+pub use option::*;
+
 mod string;
 pub use string::*;
 
+mod run_end_encoded;
+pub use run_end_encoded::*;
+
 mod r#struct;
 pub use r#struct::*;
 
 pub mod union;
 pub use union::*;
 
+mod validity;
+pub use validity::*;
+
 mod variable_size_binary;
 pub use variable_size_binary::*;
 
@@ -43,6 +76,44 @@ pub trait Array {
     type Item;
 }
 
+/// Implements [`Array`] for wrappers that dereference to an [`Array`].
+macro_rules! impl_array_deref {
+    ($($ty:ty),+) => {
+        $(
+            impl<T: Array + ?Sized> Array for $ty {
+                type Item = <T as Array>::Item;
+            }
+        )+
+    };
+}
+
+impl_array_deref!(&T, Box<T>, Rc<T>, Arc<T>);
+
+impl<T: Array + ToOwned + ?Sized> Array for Cow<'_, T> {
+    type Item = <T as Array>::Item;
+}
+
+/// Fallible construction of arrays from iterators.
+///
+/// This is implemented for all arrays that implement [`FromIterator`].
+pub trait TryFromIterator<T>: Sized {
+    /// Returns an array with the items of the iterator, or the first error
+    /// yielded by the iterator.
+    ///
+    /// The iterator is not advanced after an error is yielded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error yielded by the iterator.
+    fn try_from_iter<E, I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Self, E>;
+}
+
+impl<T, A: Array + FromIterator<T>> TryFromIterator<T> for A {
+    fn try_from_iter<E, I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Self, E> {
+        iter.into_iter().collect()
+    }
+}
+
 /// Types that can be stored in Arrow arrays.
 // Note: the generic `T` is required to allow impls on foreign wrappers e.g.
 // Option. (https://rust-lang.github.io/rfcs/2451-re-rebalancing-coherence.html)
@@ -200,7 +271,7 @@ impl Length for VariableSizeBinary {
 
 impl IntoIterator for VariableSizeBinary {
     type Item = u8;
-    type IntoIter = std::vec::IntoIter<u8>;
+    type IntoIter = alloc::vec::IntoIter<u8>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -249,6 +320,13 @@ where
             Buffer,
         >;
 }
+impl<T> ArrayType<Option<T>> for Option<Option<T>>
+where
+    Option<T>: ArrayType<T>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        OptionArray<<Option<T> as ArrayType<T>>::Array<Buffer, OffsetItem, UnionLayout>, Buffer>;
+}
 impl ArrayType<str> for str {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         StringArray<false, OffsetItem, Buffer>;
@@ -401,3 +479,35 @@ where
 impl<T> ArrayType<PhantomData<T>> for PhantomData<T> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> = NullArray;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_iter() {
+        let input = ["1", "2", "3"];
+        let array: Int32Array =
+            TryFromIterator::try_from_iter(input.iter().map(|value| value.parse::<i32>()))
+                .expect("valid input");
+        assert_eq!(array, [1, 2, 3].into_iter().collect::<Int32Array>());
+
+        let mut consumed = 0;
+        let result: Result<StringArray<true>, _> = TryFromIterator::try_from_iter(
+            ["a", "b", "", "c"]
+                .into_iter()
+                .inspect(|_| consumed += 1)
+                .map(|value| {
+                    (!value.is_empty())
+                        .then_some(Some(value))
+                        .ok_or(value.len())
+                }),
+        );
+        assert_eq!(result.err(), Some(0));
+        assert_eq!(consumed, 3);
+
+        let nullable: Result<Uint8Array<true>, ()> =
+            TryFromIterator::try_from_iter([Ok(Some(1)), Ok(None)]);
+        assert_eq!(nullable, Ok([Some(1), None].into_iter().collect()));
+    }
+}
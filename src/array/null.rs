@@ -3,12 +3,14 @@
 use super::{Array, ArrayType};
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{BufferType, VecBuffer},
+    buffer::{BufferType, GrowableBuffer, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, LengthMismatch, MemoryUsage, Validate, ValidationError,
 };
-use std::{
+use core::{
+    fmt::{Debug, Formatter, Result},
+    hash::{Hash, Hasher},
     iter::{self, Repeat, Take},
     marker::PhantomData,
 };
@@ -32,7 +34,7 @@ where
 }
 
 // # Safety:
-// - std::mem::size_of::<()> == 0
+// - core::mem::size_of::<()> == 0
 unsafe impl Unit for () {
     type Item = Self;
 }
@@ -44,6 +46,71 @@ pub struct NullArray<T: Unit = (), const NULLABLE: bool = false, Buffer: BufferT
 where
     Nulls<T>: Validity<NULLABLE>;
 
+impl<T: Unit, Buffer: BufferType> NullArray<T, false, Buffer> {
+    /// Returns a new [`NullArray`] with `len` elements.
+    ///
+    /// This is O(1): only the length is stored.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self(Nulls::new(len))
+    }
+
+    /// Returns a new [`NullArray`] with the length of the given iterator,
+    /// without driving the iterator.
+    ///
+    /// Unlike [`FromIterator`], this does not count the items one by one.
+    pub fn from_exact_size_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        Self(Nulls::from_exact_size_iter(iter))
+    }
+
+    /// Extends this [`NullArray`] with the length of the given iterator,
+    /// without driving the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new length overflows.
+    pub fn extend_exact_size<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        self.0.extend_exact_size(iter);
+    }
+}
+
+impl<T: Unit, Buffer: BufferType> NullArray<T, true, Buffer> {
+    /// Returns a new nullable [`NullArray`] with `len` elements and the given
+    /// validity bitmap.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatch`] with `len` as expected length when the
+    /// length of `validity` is different.
+    pub fn new_with_validity(
+        len: usize,
+        validity: Bitmap<Buffer>,
+    ) -> core::result::Result<Self, LengthMismatch> {
+        Nullable::try_new(Nulls::new(len), validity).map(Self)
+    }
+}
+
+impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> NullArray<T, NULLABLE, Buffer>
+where
+    Nulls<T>: Validity<NULLABLE>,
+    for<'a> &'a <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: IntoIterator,
+{
+    /// Returns an iterator over the items in this [`NullArray`].
+    pub fn iter(
+        &self,
+    ) -> <&'_ <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer> as IntoIterator>::IntoIter {
+        <&Self as IntoIterator>::into_iter(self)
+    }
+}
+
 impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> Array for NullArray<T, NULLABLE, Buffer>
 where
     Nulls<T>: Validity<NULLABLE>,
@@ -62,6 +129,18 @@ where
     }
 }
 
+impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> Debug for NullArray<T, NULLABLE, Buffer>
+where
+    Nulls<T>: Validity<NULLABLE>,
+    Self: Length,
+    for<'a> &'a Self: IntoIterator,
+    for<'a> <&'a Self as IntoIterator>::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        super::debug_array::<Self>(f, "NullArray", self)
+    }
+}
+
 impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> Default for NullArray<T, NULLABLE, Buffer>
 where
     Nulls<T>: Validity<NULLABLE>,
@@ -72,7 +151,7 @@ where
     }
 }
 
-impl<T: Unit, U, const NULLABLE: bool, Buffer: BufferType> Extend<U>
+impl<T: Unit, U, const NULLABLE: bool, Buffer: GrowableBuffer> Extend<U>
     for NullArray<T, NULLABLE, Buffer>
 where
     Nulls<T>: Validity<NULLABLE>,
@@ -106,6 +185,18 @@ where
     }
 }
 
+impl<T: Unit, Buffer: BufferType> Hash for NullArray<T, false, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+    }
+}
+
+impl<T: Unit, Buffer: BufferType> Hash for NullArray<T, true, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.validity.hash(state);
+    }
+}
+
 impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> Index for NullArray<T, NULLABLE, Buffer>
 where
     Nulls<T>: Validity<NULLABLE>,
@@ -144,28 +235,60 @@ where
     }
 }
 
-// TODO(mbrobbel): figure out why autotrait fails here
-/// Safety:
-/// - The inner field has a `Send` bound in the where clause.
-unsafe impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> Send
-    for NullArray<T, NULLABLE, Buffer>
+impl<'a, T: Unit, const NULLABLE: bool, Buffer: BufferType> IntoIterator
+    for &'a NullArray<T, NULLABLE, Buffer>
 where
     Nulls<T>: Validity<NULLABLE>,
-    <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: Send,
+    &'a <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: IntoIterator,
 {
+    type Item = <&'a <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer> as IntoIterator>::Item;
+    type IntoIter =
+        <&'a <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
 }
 
-// TODO(mbrobbel): figure out why autotrait fails here
-/// Safety:
-/// - The inner field has a `Sync` bound in the where clause.
-unsafe impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> Sync
-    for NullArray<T, NULLABLE, Buffer>
+impl<T: Unit, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialEq<NullArray<T, false, OtherBuffer>> for NullArray<T, false, Buffer>
+{
+    fn eq(&self, other: &NullArray<T, false, OtherBuffer>) -> bool {
+        self.len() == other.len()
+    }
+}
+
+impl<T: Unit, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialEq<NullArray<T, true, OtherBuffer>> for NullArray<T, true, Buffer>
+{
+    fn eq(&self, other: &NullArray<T, true, OtherBuffer>) -> bool {
+        self.0.validity == other.0.validity
+    }
+}
+
+impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> Eq for NullArray<T, NULLABLE, Buffer>
 where
     Nulls<T>: Validity<NULLABLE>,
-    <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: Sync,
+    Self: PartialEq,
 {
 }
 
+impl<T: Unit, Buffer: BufferType> TryFrom<NullArray<T, true, Buffer>>
+    for NullArray<T, false, Buffer>
+{
+    type Error = NullArray<T, true, Buffer>;
+
+    /// Returns the non-nullable array when all values are valid. Returns the
+    /// given array otherwise.
+    fn try_from(value: NullArray<T, true, Buffer>) -> core::result::Result<Self, Self::Error> {
+        if value.all_valid() {
+            Ok(Self(value.0.data))
+        } else {
+            Err(value)
+        }
+    }
+}
+
 impl<T: Unit, Buffer: BufferType> BitmapRef for NullArray<T, true, Buffer> {
     type Buffer = Buffer;
 
@@ -182,8 +305,34 @@ impl<T: Unit, Buffer: BufferType> BitmapRefMut for NullArray<T, true, Buffer> {
 
 impl<T: Unit, Buffer: BufferType> ValidityBitmap for NullArray<T, true, Buffer> {}
 
+impl<T: Unit, Buffer: BufferType> super::LogicalValidity for NullArray<T, false, Buffer> {
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Unit, Buffer: BufferType> Validate for NullArray<T, false, Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+impl<T: Unit, Buffer: BufferType> Validate for NullArray<T, true, Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
 /// New type wrapper for null elements that implements Length.
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Nulls<T: Unit> {
     /// The number of null elements
     len: usize,
@@ -193,7 +342,6 @@ pub struct Nulls<T: Unit> {
 }
 
 impl<T: Unit> Nulls<T> {
-    #[cfg(feature = "arrow-rs")]
     /// Constructs a Nulls from a given length.
     pub(crate) fn new(len: usize) -> Self {
         Self {
@@ -201,12 +349,52 @@ impl<T: Unit> Nulls<T> {
             _ty: PhantomData,
         }
     }
+
+    /// Returns a new [`Nulls`] with the length of the given iterator, without
+    /// driving the iterator.
+    pub fn from_exact_size_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        Self::new(iter.into_iter().len())
+    }
+
+    /// Returns an iterator over the (null) elements of this [`Nulls`].
+    pub fn iter(&self) -> Take<Repeat<T::Item>> {
+        iter::repeat(T::default().into()).take(self.len)
+    }
+
+    /// Extends the length of this [`Nulls`] with the length of the given
+    /// iterator, without driving the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new length overflows.
+    pub fn extend_exact_size<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        self.len = self
+            .len
+            .checked_add(iter.into_iter().len())
+            .expect("len overflow");
+    }
+}
+
+impl<T: Unit> From<usize> for Nulls<T> {
+    fn from(len: usize) -> Self {
+        Self::new(len)
+    }
 }
 
 impl<T: Unit> FromIterator<T> for Nulls<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Self {
-            // TODO(mbrobbel): ExactSizeIterator
+            // This drives the iterator, even when its length is known, because
+            // other arrays rely on the side effects of the iterators that are
+            // used to extend their data. Use `from_exact_size_iter` to skip it.
             len: iter.into_iter().count(),
             _ty: PhantomData,
         }
@@ -241,6 +429,15 @@ impl<T: Unit> IntoIterator for Nulls<T> {
     }
 }
 
+impl<T: Unit> IntoIterator for &Nulls<T> {
+    type IntoIter = Take<Repeat<T::Item>>;
+    type Item = T::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<T: Unit> Length for Nulls<T> {
     #[inline]
     fn len(&self) -> usize {
@@ -248,11 +445,120 @@ impl<T: Unit> Length for Nulls<T> {
     }
 }
 
+impl<T: Unit> MemoryUsage for Nulls<T> {
+    fn buffer_bytes(&self) -> usize {
+        0
+    }
+}
+
+impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> MemoryUsage
+    for NullArray<T, NULLABLE, Buffer>
+where
+    Nulls<T>: Validity<NULLABLE>,
+    <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{array::UnionType, offset::OffsetElement};
-    use std::mem;
+    use crate::{
+        array::{union, UnionType},
+        offset::{self, OffsetElement},
+    };
+    use std::{iter, mem};
+
+    #[test]
+    fn from_exact_size_iter() {
+        // Counting this iterator element by element would not finish.
+        let len = usize::MAX / 2;
+        let nulls = Nulls::from_exact_size_iter(iter::repeat(()).take(len));
+        assert_eq!(nulls.len(), len);
+
+        let mut array = NullArray::<()>::from_exact_size_iter(iter::repeat(()).take(len));
+        array.extend_exact_size(iter::repeat(()).take(len));
+        assert_eq!(array.len(), usize::MAX - 1);
+    }
+
+    #[test]
+    fn new() {
+        let array = NullArray::<()>::new(1_000_000);
+        assert_eq!(array.len(), 1_000_000);
+        assert_eq!(array.buffer_bytes(), 0);
+        assert_eq!(Nulls::<()>::from(3).len(), 3);
+
+        let nullable =
+            NullArray::<(), true>::new_with_validity(3, [true, false, true].into_iter().collect())
+                .expect("same length");
+        assert_eq!(nullable.len(), 3);
+        assert_eq!(nullable.null_count(), 1);
+        assert_eq!(
+            NullArray::<(), true>::new_with_validity(3, [true].into_iter().collect()).map(|_| ()),
+            Err(LengthMismatch {
+                expected: 3,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn option_unit_array_type() {
+        let array = [Some(()), None, Some(())]
+            .into_iter()
+            .collect::<<Option<()> as ArrayType<()>>::Array<VecBuffer, offset::NA, union::NA>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [Some(()), None, Some(())]
+        );
+    }
+
+    #[test]
+    fn from_iter_counted() {
+        let mut next_calls = 0;
+        let nulls = iter::repeat(())
+            .take(10)
+            .inspect(|&()| next_calls += 1)
+            .collect::<Nulls<()>>();
+        assert_eq!(nulls.len(), 10);
+        assert_eq!(next_calls, 10);
+
+        let mut array = NullArray::<()>::default();
+        array.extend([(); 3].into_iter().filter(|&()| true));
+        array.extend([(); 4]);
+        assert_eq!(array.len(), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "len overflow")]
+    fn extend_overflow() {
+        let mut nulls = Nulls::from_exact_size_iter(iter::repeat(()).take(usize::MAX));
+        nulls.extend(iter::once(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "len overflow")]
+    fn extend_exact_size_overflow() {
+        let mut nulls = Nulls::<()>::new(usize::MAX - 1);
+        nulls.extend_exact_size([(); 2]);
+    }
+
+    #[test]
+    fn auto_traits() {
+        fn assert_send_sync<T: Send + Sync + Clone + Debug>() {}
+        assert_send_sync::<NullArray<(), true>>();
+        assert_send_sync::<NullArray<(), false>>();
+        assert_send_sync::<Nulls<()>>();
+        assert_eq!(Nulls::<()>::new(3), Nulls::new(3));
+        assert_ne!(Nulls::<()>::new(3), Nulls::new(4));
+    }
 
     #[test]
     fn unit_types() {
@@ -317,6 +623,41 @@ mod tests {
         assert_eq!(input, array.into_iter().collect::<Vec<_>>().as_slice());
     }
 
+    #[test]
+    fn iter() {
+        let array = [(); 3].into_iter().collect::<NullArray>();
+        assert_eq!(array.iter().collect::<Vec<_>>(), [(); 3]);
+        let input = [Some(()), None, Some(())];
+        let nullable = input.into_iter().collect::<NullArray<_, true>>();
+        assert_eq!((&nullable).into_iter().collect::<Vec<_>>(), input);
+        assert_eq!(nullable.iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn borrowed_iter_generic() {
+        use crate::array::{StringArray, Uint8Array};
+
+        /// Returns the number of items of an array, iterating over a
+        /// reference to the array.
+        fn count<A>(array: &A) -> usize
+        where
+            for<'a> &'a A: IntoIterator,
+        {
+            array.into_iter().count()
+        }
+
+        let nulls: NullArray = [(); 2].into_iter().collect();
+        let nullable: NullArray<(), true> = [Some(()), None].into_iter().collect();
+        let values: Uint8Array = [1, 2, 3].into_iter().collect();
+        let strings: StringArray = ["a"].into_iter().collect();
+        // The type arguments are required: inferring them from the bound
+        // overflows on the borrowed `Nullable` impl.
+        assert_eq!(count::<NullArray>(&nulls), 2);
+        assert_eq!(count::<NullArray<(), true>>(&nullable), 2);
+        assert_eq!(count::<Uint8Array>(&values), 3);
+        assert_eq!(count::<StringArray>(&strings), 1);
+    }
+
     #[test]
     fn size_of() {
         assert_eq!(mem::size_of::<NullArray<()>>(), mem::size_of::<usize>());
@@ -325,4 +666,53 @@ mod tests {
             mem::size_of::<NullArray<()>>() + mem::size_of::<Bitmap>()
         );
     }
+
+    #[test]
+    fn debug() {
+        let array = [Some(()), None]
+            .into_iter()
+            .collect::<NullArray<(), true>>();
+        assert_eq!(
+            format!("{array:?}"),
+            "NullArray { len: 2, values: [Some(()), None] }"
+        );
+    }
+
+    #[test]
+    fn try_from_nullable() {
+        let nullable = [Some(()), Some(())]
+            .into_iter()
+            .collect::<NullArray<(), true>>();
+        assert_eq!(
+            NullArray::<(), false>::try_from(nullable)
+                .expect("all valid")
+                .len(),
+            2
+        );
+
+        let with_null = [Some(()), None]
+            .into_iter()
+            .collect::<NullArray<(), true>>();
+        assert!(NullArray::<(), false>::try_from(with_null).is_err());
+    }
+
+    #[test]
+    fn validate() {
+        let array = [Some(()), None]
+            .into_iter()
+            .collect::<NullArray<(), true>>();
+        assert_eq!(array.validate(), Ok(()));
+
+        let broken = NullArray::<(), true>(Nullable {
+            data: Nulls::new(3),
+            validity: [true].into_iter().collect(),
+        });
+        assert_eq!(
+            broken.validate(),
+            Err(ValidationError::ValidityLength(crate::LengthMismatch {
+                expected: 3,
+                actual: 1
+            }))
+        );
+    }
 }
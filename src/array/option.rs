@@ -0,0 +1,250 @@
+//! Array for nested optional items.
+
+use super::Array;
+use crate::{
+    bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
+    buffer::{BufferType, GrowableBuffer, VecBuffer},
+    nullable::Nullable,
+    Index, Length, MemoryUsage, Validate, ValidationError,
+};
+use core::fmt::{Debug, Formatter, Result};
+
+/// Array for nested optional items, e.g. `Option<Option<T>>`.
+///
+/// The outer option is stored in a validity bitmap of this array, and the
+/// inner option is stored in the validity bitmap of the nullable child array
+/// `T`. This preserves the difference between `None` and `Some(None)`: both
+/// are null in this array, but only `Some(None)` is a null item of the child
+/// array. The child array has a (default) null item for every outer `None`.
+pub struct OptionArray<T: Array, Buffer: BufferType = VecBuffer>(pub Nullable<T, Buffer>);
+
+impl<T: Array, Buffer: BufferType> OptionArray<T, Buffer> {
+    /// Returns a reference to the child array with the inner options.
+    pub fn child(&self) -> &T {
+        &self.0.data
+    }
+
+    /// Returns an iterator over the items in this [`OptionArray`].
+    pub fn iter<'a>(&'a self) -> <&'a Self as IntoIterator>::IntoIter
+    where
+        &'a Self: IntoIterator,
+    {
+        self.into_iter()
+    }
+}
+
+impl<T: Array, Buffer: BufferType> Array for OptionArray<T, Buffer> {
+    type Item = Option<<T as Array>::Item>;
+}
+
+impl<T: Array + Clone, Buffer: BufferType> Clone for OptionArray<T, Buffer>
+where
+    Nullable<T, Buffer>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Array + Debug, Buffer: BufferType> Debug for OptionArray<T, Buffer> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("OptionArray").field(&self.0).finish()
+    }
+}
+
+impl<T: Array + Default, Buffer: BufferType> Default for OptionArray<T, Buffer>
+where
+    Nullable<T, Buffer>: Default,
+{
+    fn default() -> Self {
+        Self(Nullable::default())
+    }
+}
+
+impl<T: Array, U, Buffer: GrowableBuffer> Extend<Option<U>> for OptionArray<T, Buffer>
+where
+    Nullable<T, Buffer>: Extend<Option<U>>,
+{
+    fn extend<I: IntoIterator<Item = Option<U>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T: Array, U, Buffer: BufferType> FromIterator<Option<U>> for OptionArray<T, Buffer>
+where
+    Nullable<T, Buffer>: FromIterator<Option<U>>,
+{
+    fn from_iter<I: IntoIterator<Item = Option<U>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: Array, Buffer: BufferType> Index for OptionArray<T, Buffer>
+where
+    T: Index,
+{
+    type Item<'a> = Option<<T as Index>::Item<'a>>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0.index_unchecked(index)
+    }
+}
+
+impl<T: Array, Buffer: BufferType> IntoIterator for OptionArray<T, Buffer>
+where
+    Nullable<T, Buffer>: IntoIterator,
+{
+    type Item = <Nullable<T, Buffer> as IntoIterator>::Item;
+    type IntoIter = <Nullable<T, Buffer> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T: Array, Buffer: BufferType> IntoIterator for &'a OptionArray<T, Buffer>
+where
+    &'a Nullable<T, Buffer>: IntoIterator,
+{
+    type Item = <&'a Nullable<T, Buffer> as IntoIterator>::Item;
+    type IntoIter = <&'a Nullable<T, Buffer> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.0).into_iter()
+    }
+}
+
+impl<T: Array, Buffer: BufferType> Length for OptionArray<T, Buffer> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: Array, Buffer: BufferType> BitmapRef for OptionArray<T, Buffer> {
+    type Buffer = Buffer;
+
+    fn bitmap_ref(&self) -> &Bitmap<Self::Buffer> {
+        self.0.bitmap_ref()
+    }
+}
+
+impl<T: Array, Buffer: BufferType> BitmapRefMut for OptionArray<T, Buffer> {
+    fn bitmap_ref_mut(&mut self) -> &mut Bitmap<Self::Buffer> {
+        self.0.bitmap_ref_mut()
+    }
+}
+
+impl<T: Array, Buffer: BufferType> ValidityBitmap for OptionArray<T, Buffer> {}
+
+impl<T: Array + MemoryUsage, Buffer: BufferType> MemoryUsage for OptionArray<T, Buffer>
+where
+    Nullable<T, Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
+impl<T: Array + PartialEq, Buffer: BufferType> PartialEq for OptionArray<T, Buffer> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Array + Length + Validate, Buffer: BufferType> Validate for OptionArray<T, Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()?;
+        self.0.data.validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{ArrayType, StringArray, Uint32Array};
+
+    /// The array type for `Option<Option<u32>>`.
+    type NestedArray = <Option<Option<u32>> as ArrayType<Option<u32>>>::Array<
+        VecBuffer,
+        crate::offset::NA,
+        crate::array::union::NA,
+    >;
+
+    #[test]
+    fn from_iter() {
+        let input = [Some(Some(1_u32)), Some(None), None, Some(Some(4))];
+        let array = input.into_iter().collect::<NestedArray>();
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.child().null_count(), 2);
+        assert_eq!(array.validate(), Ok(()));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn some_none_vs_none() {
+        // `Some(None)` is valid in the outer array and null in the child.
+        let some_none = [Some(None::<u32>)].into_iter().collect::<NestedArray>();
+        assert!(some_none.is_valid(0).expect("in bounds"));
+        assert!(some_none.child().is_null(0).expect("in bounds"));
+        assert_eq!(some_none.index(0), Some(Some(None)));
+
+        // `None` is null in the outer array, and the child has a null item.
+        let none = [None::<Option<u32>>].into_iter().collect::<NestedArray>();
+        assert!(none.is_null(0).expect("in bounds"));
+        assert!(none.child().is_null(0).expect("in bounds"));
+        assert_eq!(none.index(0), Some(None));
+
+        assert_ne!(some_none, none);
+        assert_eq!(none.into_iter().next(), Some(None));
+        assert_eq!(some_none.into_iter().next(), Some(Some(None)));
+    }
+
+    #[test]
+    fn extend() {
+        let mut array = OptionArray::<StringArray<true>>::default();
+        array.extend([Some(Some("a")), None, Some(None)]);
+        array.extend([Some(Some("bc"))]);
+        assert_eq!(
+            array.iter().collect::<Vec<_>>(),
+            [Some(Some("a")), None, Some(None), Some(Some("bc"))]
+        );
+        let child = array.child().iter().collect::<Vec<_>>();
+        assert_eq!(child, [Some("a"), None, None, Some("bc")]);
+        let _: &Uint32Array<true> = NestedArray::default().child();
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive() {
+        use crate::array::StructArray;
+
+        #[allow(clippy::option_option)]
+        #[derive(crate::ArrayType, Clone, Debug, PartialEq)]
+        struct Join {
+            id: u32,
+            score: Option<Option<u32>>,
+        }
+
+        let input = [
+            Join {
+                id: 1,
+                score: Some(Some(2)),
+            },
+            Join {
+                id: 2,
+                score: Some(None),
+            },
+            Join { id: 3, score: None },
+        ];
+        let array = input.clone().into_iter().collect::<StructArray<Join>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+}
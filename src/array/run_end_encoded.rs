@@ -0,0 +1,289 @@
+//! Array with run-end encoded values.
+
+use super::{Array, FixedSizePrimitiveArray};
+use crate::{buffer::BufferType, FixedSize, Index, Length, MemoryUsage};
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Formatter, Result},
+    ops::Range,
+};
+
+/// Array with run-end encoded values.
+///
+/// The `values` array stores the value of every run, and the `run_ends` array
+/// stores the (exclusive) logical end index of every run. Run ends are
+/// strictly increasing.
+///
+/// Like other Arrow implementations, a slice of this array keeps the runs
+/// and stores a logical offset and length instead.
+pub struct RunEndEncodedArray<RunEnds, Values> {
+    /// The logical end indices of the runs.
+    pub(crate) run_ends: RunEnds,
+    /// The values of the runs.
+    pub(crate) values: Values,
+    /// The logical offset of this array in the runs.
+    pub(crate) offset: usize,
+    /// The logical length of this array.
+    pub(crate) len: usize,
+}
+
+/// Converts a run end to an index.
+///
+/// # Panics
+///
+/// Panics if the run end does not fit in a `usize`.
+fn run_end_index<E: TryInto<usize>>(run_end: E) -> usize {
+    run_end
+        .try_into()
+        .ok()
+        .expect("run end should be a valid index")
+}
+
+impl<E: FixedSize + TryInto<usize>, Buffer: BufferType, Values>
+    RunEndEncodedArray<FixedSizePrimitiveArray<E, false, Buffer>, Values>
+{
+    /// Returns a new [`RunEndEncodedArray`] with the given run ends and
+    /// values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a run end does not fit in a `usize`.
+    pub fn new(run_ends: FixedSizePrimitiveArray<E, false, Buffer>, values: Values) -> Self {
+        let len = run_ends.as_slice().last().copied().map_or(0, run_end_index);
+        Self {
+            run_ends,
+            values,
+            offset: 0,
+            len,
+        }
+    }
+
+    /// Returns the index of the run that contains the item at the given
+    /// logical `index`, or `None` if the index is out of bounds.
+    pub fn run_index(&self, index: usize) -> Option<usize> {
+        (index < self.len).then(|| {
+            let physical = self.offset + index;
+            self.run_ends
+                .as_slice()
+                .partition_point(|&run_end| run_end_index(run_end) <= physical)
+        })
+    }
+
+    /// Returns the item at the given `index`, or `None` if the index is out
+    /// of bounds.
+    ///
+    /// This is a binary search over the run ends.
+    pub fn get(&self, index: usize) -> Option<<Values as Index>::Item<'_>>
+    where
+        Values: Index,
+    {
+        self.run_index(index).and_then(|run| self.values.index(run))
+    }
+
+    /// Returns a slice of this array with `len` items starting at `offset`.
+    ///
+    /// The runs are not expanded: the slice keeps the runs of this array
+    /// with an adjusted logical offset and length, so a slice boundary may
+    /// be in the middle of a run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is out of bounds.
+    #[must_use]
+    pub fn slice(&self, offset: usize, len: usize) -> Self
+    where
+        Self: Clone,
+    {
+        assert!(
+            offset.checked_add(len).is_some_and(|end| end <= self.len),
+            "slice out of bounds"
+        );
+        Self {
+            offset: self.offset + offset,
+            len,
+            ..self.clone()
+        }
+    }
+
+    /// Returns an iterator over the runs of this array, with their logical
+    /// index range and value.
+    ///
+    /// The ranges of the runs cover the length of this array exactly. Runs
+    /// at the boundaries of a slice are clipped to the slice.
+    pub fn run_iter(&self) -> impl Iterator<Item = (Range<usize>, <Values as Index>::Item<'_>)>
+    where
+        Values: Index,
+    {
+        let first = self.run_index(0).unwrap_or_default();
+        let end = self.offset + self.len;
+        self.run_ends.as_slice()[first..]
+            .iter()
+            .map(|&run_end| run_end_index(run_end))
+            .enumerate()
+            .scan(self.offset, move |start, (run, run_end)| {
+                (*start < end).then(|| {
+                    let range = (*start - self.offset)..(run_end.min(end) - self.offset);
+                    *start = run_end;
+                    (range, self.values.index_checked(first + run))
+                })
+            })
+    }
+}
+
+impl<E: FixedSize + TryInto<usize>, Buffer: BufferType, Values, V: PartialEq> FromIterator<V>
+    for RunEndEncodedArray<FixedSizePrimitiveArray<E, false, Buffer>, Values>
+where
+    E: TryFrom<usize>,
+    FixedSizePrimitiveArray<E, false, Buffer>: FromIterator<E>,
+    Values: FromIterator<V>,
+{
+    /// Returns a run-end encoded array with a run for every sequence of
+    /// equal items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a run end does not fit in the run end type.
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut run_ends = Vec::new();
+        let mut values = Vec::<V>::new();
+        for (index, item) in iter.into_iter().enumerate() {
+            if values.last() == Some(&item) {
+                run_ends.pop();
+            } else {
+                values.push(item);
+            }
+            run_ends.push(index + 1);
+        }
+        Self::new(
+            run_ends
+                .into_iter()
+                .map(|run_end| {
+                    E::try_from(run_end)
+                        .ok()
+                        .expect("run end should fit in the run end type")
+                })
+                .collect(),
+            values.into_iter().collect(),
+        )
+    }
+}
+
+impl<RunEnds, Values: Array> Array for RunEndEncodedArray<RunEnds, Values> {
+    type Item = <Values as Array>::Item;
+}
+
+impl<RunEnds: Clone, Values: Clone> Clone for RunEndEncodedArray<RunEnds, Values> {
+    fn clone(&self) -> Self {
+        Self {
+            run_ends: self.run_ends.clone(),
+            values: self.values.clone(),
+            offset: self.offset,
+            len: self.len,
+        }
+    }
+}
+
+impl<RunEnds: Debug, Values: Debug> Debug for RunEndEncodedArray<RunEnds, Values> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("RunEndEncodedArray")
+            .field("run_ends", &self.run_ends)
+            .field("values", &self.values)
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<RunEnds: Default, Values: Default> Default for RunEndEncodedArray<RunEnds, Values> {
+    fn default() -> Self {
+        Self {
+            run_ends: RunEnds::default(),
+            values: Values::default(),
+            offset: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<RunEnds, Values> Length for RunEndEncodedArray<RunEnds, Values> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<RunEnds: MemoryUsage, Values: MemoryUsage> MemoryUsage
+    for RunEndEncodedArray<RunEnds, Values>
+{
+    fn buffer_bytes(&self) -> usize {
+        self.run_ends.buffer_bytes() + self.values.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.run_ends.allocated_bytes() + self.values.allocated_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, StringArray, Uint8Array};
+
+    /// A run-end encoded array of strings.
+    type Runs = RunEndEncodedArray<Int32Array, StringArray>;
+
+    #[test]
+    fn get() {
+        let items = ["a", "a", "b", "c", "c", "c", "a"];
+        let array = items.into_iter().collect::<Runs>();
+        assert_eq!(array.len(), 7);
+        assert_eq!(array.run_ends.0, [2, 3, 6, 7]);
+        assert_eq!(
+            array.values.iter().collect::<Vec<_>>(),
+            ["a", "b", "c", "a"]
+        );
+        for (index, item) in items.into_iter().enumerate() {
+            assert_eq!(array.get(index), Some(item));
+        }
+        assert_eq!(array.get(7), None);
+        assert_eq!(Runs::default().get(0), None);
+    }
+
+    #[test]
+    fn slice() {
+        let items = [1_u8, 1, 1, 2, 2, 3, 3, 3];
+        let array = items
+            .into_iter()
+            .collect::<RunEndEncodedArray<Int32Array, Uint8Array>>();
+        let slice = array.slice(2, 5);
+        assert_eq!(slice.len(), 5);
+        for (index, item) in items[2..7].iter().enumerate() {
+            assert_eq!(slice.get(index), Some(item));
+        }
+        assert_eq!(slice.get(5), None);
+        assert_eq!(
+            slice.run_iter().collect::<Vec<_>>(),
+            [(0..1, &1), (1..3, &2), (3..5, &3)]
+        );
+        let nested = slice.slice(1, 1);
+        assert_eq!(nested.run_iter().collect::<Vec<_>>(), [(0..1, &2)]);
+        assert_eq!(slice.slice(5, 0).run_iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice out of bounds")]
+    fn slice_out_of_bounds() {
+        let array = ["a", "b"].into_iter().collect::<Runs>();
+        let _ = array.slice(1, 2);
+    }
+
+    #[test]
+    fn run_iter() {
+        let array = ["x", "x", "y", "z", "z"].into_iter().collect::<Runs>();
+        let runs = array.run_iter().collect::<Vec<_>>();
+        assert_eq!(runs, [(0..2, "x"), (2..3, "y"), (3..5, "z")]);
+        assert_eq!(runs.first().map(|run| run.0.start), Some(0));
+        assert_eq!(runs.last().map(|run| run.0.end), Some(array.len()));
+        assert!(runs.windows(2).all(|pair| pair[0].0.end == pair[1].0.start));
+        assert_eq!(Runs::default().run_iter().count(), 0);
+    }
+}
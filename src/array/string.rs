@@ -1,24 +1,38 @@
 //! Array with string values.
 
-use std::str;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
+};
+use core::{
+    fmt::{Debug, Display, Formatter, Result},
+    iter, str,
+};
 
-use super::{Array, VariableSizeBinaryArray};
+use super::{Array, FixedSizePrimitiveArray, FormatOptions, TableDisplay, VariableSizeBinaryArray};
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{BufferType, VecBuffer},
-    offset::OffsetElement,
+    buffer::{Buffer as _, BufferType, GrowableBuffer, VecBuffer},
+    offset::{Offset, OffsetElement, Offsets},
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, MemoryUsage, Validate, ValidationError,
 };
 
 /// Array with string values.
+///
+/// String arrays can be collected from and extended with string slices,
+/// [`String`]s and [`Cow<str>`] items, or options of those for the nullable
+/// variant. There is no [`FromIterator<char>`] implementation because it is
+/// ambiguous whether that should be one string or one string per character:
+/// collect the characters into a [`String`] first.
 pub struct StringArray<
     const NULLABLE: bool = false,
     OffsetItem: OffsetElement = i32,
     Buffer: BufferType = VecBuffer,
 >(pub VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>)
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>;
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>;
 
 /// Array with string values, using `i32` offset values.
 pub type Utf8Array<const NULLABLE: bool = false, Buffer = VecBuffer> =
@@ -31,7 +45,7 @@ pub type LargeUtf8Array<const NULLABLE: bool = false, Buffer = VecBuffer> =
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
     StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     StringArray<NULLABLE, OffsetItem, Buffer>: Index + Length,
 {
     /// Returns an iterator over the items in this [`StringArray`].
@@ -40,10 +54,186 @@ where
     }
 }
 
+/// Returns the string at `index` given the `offsets` and `data` of a string
+/// array, without bounds checking and UTF-8 validation.
+///
+/// # Safety
+///
+/// The caller must ensure that `index + 1` is in bounds of `offsets`, that
+/// these offsets are in bounds of `data`, and that the bytes between them are
+/// valid UTF-8.
+unsafe fn str_unchecked<'a, OffsetItem: OffsetElement>(
+    offsets: &[OffsetItem],
+    data: &'a [u8],
+    index: usize,
+) -> &'a str {
+    // Safety:
+    // - The caller ensures that `index + 1` is in bounds.
+    let window = unsafe { offsets.get_unchecked(index..index + 2) };
+    let start: usize = window[0].try_into().expect("offset value out of range");
+    let end: usize = window[1].try_into().expect("offset value out of range");
+    // Safety:
+    // - The caller ensures that the offsets are in bounds.
+    let bytes = unsafe { data.get_unchecked(start..end) };
+    // Safety:
+    // - The caller ensures that the bytes are valid UTF-8.
+    unsafe { str::from_utf8_unchecked(bytes) }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<false, OffsetItem, Buffer> {
+    /// Returns a new [`StringArray`] from the given offsets and data buffers.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] when the offsets are not valid for the
+    /// data (see [`Offsets::validate_len`]), when an offset is not on a UTF-8
+    /// character boundary, or when the data is not valid UTF-8.
+    ///
+    /// [`Offsets::validate_len`]: crate::offset::Offsets::validate_len
+    pub fn try_from_parts(
+        offsets: <Buffer as BufferType>::Buffer<OffsetItem>,
+        data: <Buffer as BufferType>::Buffer<u8>,
+    ) -> core::result::Result<Self, ValidationError> {
+        let array = Self(VariableSizeBinaryArray(Offset {
+            data: FixedSizePrimitiveArray(data),
+            offsets: Offsets(offsets),
+        }));
+        array.validate().map(|()| array)
+    }
+
+    /// Returns a new [`StringArray`] from the given offsets and data buffers,
+    /// replacing invalid UTF-8 sequences in the strings with
+    /// [`char::REPLACEMENT_CHARACTER`], and the number of replaced sequences.
+    ///
+    /// The returned array owns new buffers if a sequence is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] when the offsets are not valid for the
+    /// data (see [`Offsets::validate_len`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the replacements make the data too large for the offset
+    /// type.
+    ///
+    /// [`Offsets::validate_len`]: crate::offset::Offsets::validate_len
+    pub fn from_parts_lossy(
+        offsets: <Buffer as BufferType>::Buffer<OffsetItem>,
+        data: <Buffer as BufferType>::Buffer<u8>,
+    ) -> core::result::Result<(Self, usize), ValidationError>
+    where
+        Self: for<'a> FromIterator<Cow<'a, str>>,
+    {
+        let array = Self(VariableSizeBinaryArray(Offset {
+            data: FixedSizePrimitiveArray(data),
+            offsets: Offsets(offsets),
+        }));
+        array.0.validate()?;
+        if validate_utf8(array.0 .0.offsets.as_slice(), array.0 .0.data.0.as_slice()).is_ok() {
+            return Ok((array, 0));
+        }
+        let mut repairs = 0;
+        let bytes = array.0 .0.data.0.as_slice();
+        let repaired = array
+            .0
+             .0
+            .offsets
+            .as_slice()
+            .windows(2)
+            .map(|window| {
+                let start: usize = window[0].try_into().expect("offset value out of range");
+                let end: usize = window[1].try_into().expect("offset value out of range");
+                from_utf8_lossy(&bytes[start..end], &mut repairs)
+            })
+            .collect();
+        Ok((repaired, repairs))
+    }
+
+    /// Returns a new [`StringArray`] from the given offsets and data buffers,
+    /// without validation.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the offsets are valid for the data (see
+    /// [`Offsets::validate_len`]), that they are on UTF-8 character
+    /// boundaries, and that the data is valid UTF-8, before the strings are
+    /// accessed. Use [`Validate::validate`] to check these requirements.
+    ///
+    /// [`Offsets::validate_len`]: crate::offset::Offsets::validate_len
+    pub unsafe fn from_parts_unchecked(
+        offsets: <Buffer as BufferType>::Buffer<OffsetItem>,
+        data: <Buffer as BufferType>::Buffer<u8>,
+    ) -> Self {
+        Self(VariableSizeBinaryArray(Offset {
+            data: FixedSizePrimitiveArray(data),
+            offsets: Offsets(offsets),
+        }))
+    }
+
+    /// Returns the string at `index`, without bounds checking and UTF-8
+    /// validation.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `index` is less than the length of this
+    /// array, and that the offsets and data of this array are valid.
+    pub unsafe fn value_unchecked(&self, index: usize) -> &str {
+        str_unchecked(
+            self.0 .0.offsets.as_slice(),
+            self.0 .0.data.0.as_slice(),
+            index,
+        )
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<true, OffsetItem, Buffer> {
+    /// Returns the string at `index`, without bounds checking, UTF-8
+    /// validation and checking the validity of the value. The value of a null
+    /// item is unspecified, but usually empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `index` is less than the length of this
+    /// array, and that the offsets and data of this array are valid.
+    pub unsafe fn value_unchecked(&self, index: usize) -> &str {
+        str_unchecked(
+            self.0 .0.offsets.data.as_slice(),
+            self.0 .0.data.0.as_slice(),
+            index,
+        )
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<false, OffsetItem, Buffer>
+where
+    Self: for<'a> Extend<&'a str>,
+{
+    /// Appends a string to the end of this array.
+    pub fn push_str(&mut self, value: &str) {
+        self.extend(iter::once(value));
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<true, OffsetItem, Buffer>
+where
+    Self: for<'a> Extend<Option<&'a str>>,
+{
+    /// Appends a string to the end of this array.
+    pub fn push_str(&mut self, value: &str) {
+        self.extend(iter::once(Some(value)));
+    }
+
+    /// Appends a null value to the end of this array.
+    pub fn push_null(&mut self) {
+        self.extend(iter::once(None::<&str>));
+    }
+}
+
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Array
     for StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     String: Nullability<NULLABLE>,
 {
     type Item = <String as Nullability<NULLABLE>>::Item;
@@ -52,7 +242,7 @@ where
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Clone
     for StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: Clone,
 {
     fn clone(&self) -> Self {
@@ -60,10 +250,23 @@ where
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Debug
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Self: Length,
+    for<'a> &'a Self: IntoIterator,
+    for<'a> <&'a Self as IntoIterator>::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        super::debug_array::<Self>(f, "StringArray", self)
+    }
+}
+
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Default
     for StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: Default,
 {
     fn default() -> Self {
@@ -71,7 +274,18 @@ where
     }
 }
 
-impl<'a, T: ?Sized, OffsetItem: OffsetElement, Buffer: BufferType> Extend<&'a T>
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Display
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Self: TableDisplay,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.display_table(FormatOptions::default()))
+    }
+}
+
+impl<'a, T: ?Sized, OffsetItem: OffsetElement, Buffer: GrowableBuffer> Extend<&'a T>
     for StringArray<false, OffsetItem, Buffer>
 where
     T: AsRef<str>,
@@ -83,7 +297,7 @@ where
     }
 }
 
-impl<'a, T: ?Sized, OffsetItem: OffsetElement, Buffer: BufferType> Extend<Option<&'a T>>
+impl<'a, T: ?Sized, OffsetItem: OffsetElement, Buffer: GrowableBuffer> Extend<Option<&'a T>>
     for StringArray<true, OffsetItem, Buffer>
 where
     T: AsRef<str>,
@@ -97,7 +311,7 @@ where
     }
 }
 
-impl<OffsetItem: OffsetElement, Buffer: BufferType> Extend<String>
+impl<OffsetItem: OffsetElement, Buffer: GrowableBuffer> Extend<String>
     for StringArray<false, OffsetItem, Buffer>
 where
     VariableSizeBinaryArray<false, OffsetItem, Buffer>: Extend<Vec<u8>>,
@@ -107,7 +321,7 @@ where
     }
 }
 
-impl<OffsetItem: OffsetElement, Buffer: BufferType> Extend<Option<String>>
+impl<OffsetItem: OffsetElement, Buffer: GrowableBuffer> Extend<Option<String>>
     for StringArray<true, OffsetItem, Buffer>
 where
     VariableSizeBinaryArray<true, OffsetItem, Buffer>: Extend<Option<Vec<u8>>>,
@@ -118,6 +332,29 @@ where
     }
 }
 
+impl<'a, OffsetItem: OffsetElement, Buffer: GrowableBuffer> Extend<Cow<'a, str>>
+    for StringArray<false, OffsetItem, Buffer>
+where
+    Self: for<'b> Extend<&'b str>,
+{
+    fn extend<I: IntoIterator<Item = Cow<'a, str>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.push_str(&item));
+    }
+}
+
+impl<'a, OffsetItem: OffsetElement, Buffer: GrowableBuffer> Extend<Option<Cow<'a, str>>>
+    for StringArray<true, OffsetItem, Buffer>
+where
+    Self: for<'b> Extend<Option<&'b str>>,
+{
+    fn extend<I: IntoIterator<Item = Option<Cow<'a, str>>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|opt| match opt {
+            Some(item) => self.push_str(&item),
+            None => self.push_null(),
+        });
+    }
+}
+
 impl<OffsetItem: OffsetElement, Buffer: BufferType> From<StringArray<false, OffsetItem, Buffer>>
     for StringArray<true, OffsetItem, Buffer>
 where
@@ -129,6 +366,50 @@ where
     }
 }
 
+impl<OffsetItem: OffsetElement, Buffer: BufferType> From<Vec<String>>
+    for StringArray<false, OffsetItem, Buffer>
+where
+    Self: FromIterator<String>,
+{
+    fn from(value: Vec<String>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<'a, OffsetItem: OffsetElement, Buffer: BufferType> From<&[&'a str]>
+    for StringArray<false, OffsetItem, Buffer>
+where
+    Self: FromIterator<&'a str>,
+{
+    fn from(value: &[&'a str]) -> Self {
+        value.iter().copied().collect()
+    }
+}
+
+impl<'a, OffsetItem: OffsetElement, Buffer: BufferType> FromIterator<Cow<'a, str>>
+    for StringArray<false, OffsetItem, Buffer>
+where
+    Self: Default + Extend<Cow<'a, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = Cow<'a, str>>>(iter: I) -> Self {
+        let mut array = Self::default();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<'a, OffsetItem: OffsetElement, Buffer: BufferType> FromIterator<Option<Cow<'a, str>>>
+    for StringArray<true, OffsetItem, Buffer>
+where
+    Self: Default + Extend<Option<Cow<'a, str>>>,
+{
+    fn from_iter<I: IntoIterator<Item = Option<Cow<'a, str>>>>(iter: I) -> Self {
+        let mut array = Self::default();
+        array.extend(iter);
+        array
+    }
+}
+
 impl<'a, T: ?Sized, OffsetItem: OffsetElement, Buffer: BufferType> FromIterator<&'a T>
     for StringArray<false, OffsetItem, Buffer>
 where
@@ -210,39 +491,72 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
 }
 
 /// An iterator over strings in a [`StringArray`].
+///
+/// The strings are borrowed from the data buffer of the array.
 pub struct StringIter<'a, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
 {
     /// Reference to the array.
     array: &'a StringArray<NULLABLE, OffsetItem, Buffer>,
     /// Current index.
     index: usize,
+    /// The index after the last item that is not yet yielded.
+    end: usize,
 }
 
 impl<'a, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Iterator
     for StringIter<'a, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     StringArray<NULLABLE, OffsetItem, Buffer>: Length + Index,
 {
     type Item = <StringArray<NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.array
-            .index(self.index)
-            .into_iter()
-            .inspect(|_| {
-                self.index += 1;
-            })
-            .next()
+        (self.index < self.end).then(|| {
+            // Safety:
+            // - The index is less than the end, which is at most the length.
+            let item = unsafe { self.array.index_unchecked(self.index) };
+            self.index += 1;
+            item
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> DoubleEndedIterator
+    for StringIter<'_, NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    StringArray<NULLABLE, OffsetItem, Buffer>: Length + Index,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.index < self.end).then(|| {
+            self.end -= 1;
+            // Safety:
+            // - The end is less than the length.
+            unsafe { self.array.index_unchecked(self.end) }
+        })
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> ExactSizeIterator
+    for StringIter<'_, NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    StringArray<NULLABLE, OffsetItem, Buffer>: Length + Index,
+{
+}
+
 /// An iterator over items in a [`StringArray`].
 pub struct StringIntoIter<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
 {
     /// Reference to the array.
     array: StringArray<NULLABLE, OffsetItem, Buffer>,
@@ -287,7 +601,7 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Iterator
 impl<'a, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> IntoIterator
     for &'a StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     StringArray<NULLABLE, OffsetItem, Buffer>: Index + Length,
 {
     type Item = <StringArray<NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>;
@@ -297,6 +611,7 @@ where
         StringIter {
             array: self,
             index: 0,
+            end: self.len(),
         }
     }
 }
@@ -304,7 +619,7 @@ where
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> IntoIterator
     for StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     StringIntoIter<NULLABLE, OffsetItem, Buffer>: Iterator,
 {
     type Item = <StringIntoIter<NULLABLE, OffsetItem, Buffer> as Iterator>::Item;
@@ -321,7 +636,7 @@ where
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
     for StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: Length,
 {
     fn len(&self) -> usize {
@@ -352,6 +667,136 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> ValidityBitmap
 {
 }
 
+impl<OffsetItem: OffsetElement, Buffer: BufferType> super::LogicalValidity
+    for StringArray<false, OffsetItem, Buffer>
+where
+    Self: Length,
+{
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> TableDisplay
+    for StringArray<false, OffsetItem, Buffer>
+{
+    fn format_value(&self, index: usize) -> Option<String> {
+        Some(self.index_checked(index).to_owned())
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> TableDisplay
+    for StringArray<true, OffsetItem, Buffer>
+{
+    fn format_value(&self, index: usize) -> Option<String> {
+        self.index_checked(index).map(ToOwned::to_owned)
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> MemoryUsage
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
+/// Checks that the `offsets` are on UTF-8 character boundaries of `data`,
+/// and that the data between the first and last offset is valid UTF-8.
+///
+/// The data is validated once, instead of per string. The offsets must be
+/// valid for `data`.
+fn validate_utf8<OffsetItem: OffsetElement>(
+    offsets: &[OffsetItem],
+    data: &[u8],
+) -> core::result::Result<(), ValidationError> {
+    let position =
+        |offset: OffsetItem| -> usize { offset.try_into().expect("offset value out of range") };
+    if let Some(index) = offsets.iter().position(|&offset| {
+        // Continuation bytes have the bit pattern `0b10xx_xxxx`.
+        data.get(position(offset))
+            .is_some_and(|&byte| (byte & 0b1100_0000) == 0b1000_0000)
+    }) {
+        return Err(ValidationError::Utf8Boundary { index });
+    }
+    match (offsets.first(), offsets.last()) {
+        (Some(&first), Some(&last)) => {
+            let start = position(first);
+            str::from_utf8(&data[start..position(last)])
+                .map(|_| ())
+                .map_err(|error| ValidationError::InvalidUtf8 {
+                    index: start + error.valid_up_to(),
+                })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns `bytes` as a string, replacing invalid UTF-8 sequences with
+/// [`char::REPLACEMENT_CHARACTER`] like [`String::from_utf8_lossy`], and
+/// adding the number of replaced sequences to `repairs`.
+fn from_utf8_lossy<'a>(bytes: &'a [u8], repairs: &mut usize) -> Cow<'a, str> {
+    if let Ok(value) = str::from_utf8(bytes) {
+        return Cow::Borrowed(value);
+    }
+    let mut value = String::with_capacity(bytes.len());
+    let mut rest = bytes;
+    loop {
+        match str::from_utf8(rest) {
+            Ok(valid) => {
+                value.push_str(valid);
+                break;
+            }
+            Err(error) => {
+                let (valid, invalid) = rest.split_at(error.valid_up_to());
+                // Safety:
+                // - The bytes up to `valid_up_to` are valid UTF-8.
+                value.push_str(unsafe { str::from_utf8_unchecked(valid) });
+                value.push(char::REPLACEMENT_CHARACTER);
+                *repairs += 1;
+                rest = &invalid[error.error_len().unwrap_or(invalid.len())..];
+            }
+        }
+    }
+    Cow::Owned(value)
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Validate
+    for StringArray<false, OffsetItem, Buffer>
+{
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()?;
+        validate_utf8(self.0 .0.offsets.as_slice(), self.0 .0.data.0.as_slice())
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Validate
+    for StringArray<true, OffsetItem, Buffer>
+{
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()?;
+        validate_utf8(
+            self.0 .0.offsets.data.as_slice(),
+            self.0 .0.data.0.as_slice(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,15 +812,165 @@ mod tests {
             .into_iter()
             .collect::<<String as ArrayType<String>>::Array<VecBuffer, i64, union::NA>>();
         assert_eq!(array.len(), 4);
-        assert_eq!(array.0 .0.data.0, b"1234567890");
+        assert_eq!(array.0 .0.data.as_slice(), b"1234567890");
 
         let input_string = vec!["a".to_owned(), "sd".to_owned(), "f".to_owned()];
         let array_string = input_string.into_iter().collect::<StringArray>();
         assert_eq!(array_string.len(), 3);
-        assert_eq!(array_string.0 .0.data.0, &[97, 115, 100, 102]);
+        assert_eq!(array_string.0 .0.data.as_slice(), &[97, 115, 100, 102]);
         assert_eq!(array_string.0 .0.offsets, &[0, 1, 3, 4]);
     }
 
+    #[test]
+    fn try_from_parts() {
+        let array =
+            <StringArray>::try_from_parts(vec![0, 2, 2, 5], "hiyou".into()).expect("valid offsets");
+        assert_eq!(array.iter().collect::<Vec<_>>(), ["hi", "", "you"]);
+        assert_eq!(
+            <StringArray>::try_from_parts(vec![0, 6], "hi".into()).map(|_| ()),
+            Err(ValidationError::OffsetOutOfBounds { index: 1, len: 2 })
+        );
+        // The offset at index 1 splits the two bytes of `é`.
+        assert_eq!(
+            <StringArray>::try_from_parts(vec![0, 2, 3], "aé".into()).map(|_| ()),
+            Err(ValidationError::Utf8Boundary { index: 1 })
+        );
+
+        let mut nullable = [Some("é"), None].into_iter().collect::<StringArray<true>>();
+        assert_eq!(nullable.validate(), Ok(()));
+        nullable.0 .0.offsets.data.0[1] = 1;
+        assert_eq!(
+            nullable.validate(),
+            Err(ValidationError::Utf8Boundary { index: 1 })
+        );
+    }
+
+    #[test]
+    fn utf8_modes() {
+        // `0xff` is never valid UTF-8.
+        let data = b"ok\xffbad\xff\xfe";
+        let offsets = vec![0, 2, 8];
+        assert_eq!(
+            <StringArray>::try_from_parts(offsets.clone(), data.to_vec()).map(|_| ()),
+            Err(ValidationError::InvalidUtf8 { index: 2 })
+        );
+
+        let (repaired, repairs) =
+            <StringArray>::from_parts_lossy(offsets.clone(), data.to_vec()).expect("valid offsets");
+        assert_eq!(repairs, 3);
+        assert_eq!(
+            repaired.iter().collect::<Vec<_>>(),
+            ["ok", "\u{fffd}bad\u{fffd}\u{fffd}"]
+        );
+        let (valid, no_repairs) =
+            <StringArray>::from_parts_lossy(vec![0, 2], b"ok".to_vec()).expect("valid offsets");
+        assert_eq!(no_repairs, 0);
+        assert_eq!(valid.iter().collect::<Vec<_>>(), ["ok"]);
+        assert_eq!(
+            <StringArray>::from_parts_lossy(vec![0, 3], b"ok".to_vec()).map(|_| ()),
+            Err(ValidationError::OffsetOutOfBounds { index: 1, len: 2 })
+        );
+
+        // Safety:
+        // - The strings are not accessed.
+        let unchecked = unsafe { <StringArray>::from_parts_unchecked(offsets, data.to_vec()) };
+        assert_eq!(
+            unchecked.validate(),
+            Err(ValidationError::InvalidUtf8 { index: 2 })
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let strings = vec!["a".to_owned(), String::new(), "bcd".to_owned()];
+        let expected = strings.iter().collect::<StringArray>();
+        let from_vec = StringArray::from(strings);
+        let from_slice = StringArray::from(&["a", "", "bcd"][..]);
+        let from_cow = [Cow::Borrowed("a"), Cow::Owned(String::new()), "bcd".into()]
+            .into_iter()
+            .collect::<StringArray>();
+        let mut pushed = StringArray::<false>::default();
+        pushed.push_str("a");
+        pushed.extend([""]);
+        pushed.extend([Cow::Borrowed("bcd")]);
+        for array in [from_vec, from_slice, from_cow, pushed] {
+            assert_eq!(array.0 .0.data.as_slice(), expected.0 .0.data.as_slice());
+            assert_eq!(array.0 .0.offsets, expected.0 .0.offsets);
+        }
+    }
+
+    #[test]
+    fn conversions_nullable() {
+        let expected = [Some("a"), None, Some("bc")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let from_string = [Some("a".to_owned()), None, Some("bc".to_owned())]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let from_cow = [
+            Some(Cow::Borrowed("a")),
+            None,
+            Some(Cow::Owned("bc".to_owned())),
+        ]
+        .into_iter()
+        .collect::<StringArray<true>>();
+        let mut pushed = StringArray::<true>::default();
+        pushed.push_str("a");
+        pushed.push_null();
+        pushed.extend([Some("bc")]);
+        for array in [from_string, from_cow, pushed] {
+            assert_eq!(array.0 .0.data.as_slice(), expected.0 .0.data.as_slice());
+            assert_eq!(array.0 .0.offsets.data, expected.0 .0.offsets.data);
+            assert_eq!(array.0 .0.offsets.validity, expected.0 .0.offsets.validity);
+        }
+    }
+
+    #[test]
+    fn iter_borrows() {
+        let input = ["héllo", "", "日本", "🎉a", "z"];
+        let array = input.into_iter().collect::<StringArray>();
+        let data = array.0 .0.data.0.as_slice().as_ptr_range();
+        for (item, expected) in array.iter().zip(input) {
+            assert_eq!(item, expected);
+            let range = item.as_bytes().as_ptr_range();
+            assert!(data.start <= range.start && range.end <= data.end);
+        }
+        assert_eq!(array.iter().len(), 5);
+        assert_eq!(
+            array.iter().rev().collect::<Vec<_>>(),
+            ["z", "🎉a", "日本", "", "héllo"]
+        );
+        let mut iter = array.iter();
+        assert_eq!(iter.next_back(), Some("z"));
+        assert_eq!(iter.next(), Some("héllo"));
+        assert_eq!(iter.len(), 3);
+        // Safety:
+        // - The index is in bounds and the array is valid.
+        assert_eq!(unsafe { array.value_unchecked(2) }, "日本");
+    }
+
+    #[test]
+    fn iter_borrows_nullable() {
+        let input = [Some("ß"), None, Some("€uro")];
+        let array = input.into_iter().collect::<StringArray<true>>();
+        let data = array.0 .0.data.0.as_slice().as_ptr_range();
+        for item in array.iter().flatten() {
+            let range = item.as_bytes().as_ptr_range();
+            assert!(data.start <= range.start && range.end <= data.end);
+        }
+        assert_eq!(
+            array.iter().rev().collect::<Vec<_>>(),
+            [Some("€uro"), None, Some("ß")]
+        );
+        assert_eq!((&array).into_iter().len(), 3);
+        // Safety:
+        // - The index is in bounds and the array is valid.
+        assert_eq!(unsafe { array.value_unchecked(1) }, "");
+        // Safety:
+        // - The index is in bounds and the array is valid.
+        assert_eq!(unsafe { array.value_unchecked(2) }, "€uro");
+    }
+
     #[test]
     fn from_iter_nullable() {
         let input = vec![Some("a"), None, Some("sd"), Some("f"), None];
@@ -387,7 +982,7 @@ mod tests {
         assert_eq!(array.is_valid(3), Some(true));
         assert_eq!(array.is_valid(4), Some(false));
         assert_eq!(array.is_valid(5), None);
-        assert_eq!(array.0 .0.data.0, "asdf".as_bytes());
+        assert_eq!(array.0 .0.data.as_slice(), "asdf".as_bytes());
         assert_eq!(array.0 .0.offsets.as_ref(), &[0, 1, 1, 3, 4, 4]);
         assert_eq!(
             array.bitmap_ref().into_iter().collect::<Vec<_>>(),
@@ -426,4 +1021,29 @@ mod tests {
         let nullable: StringArray<true> = array.into();
         assert_eq!(nullable.bitmap_ref().buffer_ref(), &[0b0000_0111]);
     }
+
+    #[test]
+    fn debug() {
+        let array = ["a", "bc"]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect::<StringArray>();
+        assert_eq!(
+            format!("{array:?}"),
+            r#"StringArray { len: 2, values: ["a", "bc"] }"#
+        );
+    }
+
+    #[test]
+    fn clone() {
+        let array = ["a", "bc"]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect::<StringArray>();
+        let cloned = array.clone();
+        assert_eq!(
+            cloned.into_iter().collect::<Vec<_>>(),
+            array.into_iter().collect::<Vec<_>>()
+        );
+    }
 }
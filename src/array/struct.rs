@@ -1,12 +1,17 @@
 //! Array for product types.
 
-use super::{Array, ArrayType};
+use super::{Array, ArrayType, ExtensionType};
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{BufferType, VecBuffer},
+    buffer::{BufferType, GrowableBuffer, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Length,
+    Length, MemoryUsage,
+};
+use alloc::vec::Vec;
+use core::{
+    any::Any,
+    fmt::{Debug, Formatter, Result},
 };
 
 /// Struct array types.
@@ -18,6 +23,143 @@ pub trait StructArrayType: ArrayType<Self> {
     type Array<Buffer: BufferType>; // into<fields> this then requires all arraytype impls to provide a field
 }
 
+/// Access to the child arrays of struct arrays.
+///
+/// [`Array`] has an associated item type, so the children are returned as
+/// [`Any`] trait objects. Use [`StructArrayChildren::child_as`] to get a
+/// reference to a child with a known array type.
+///
+/// This is implemented by the arrays generated by the [`ArrayType`] derive
+/// macro for structs with fields and without lifetime parameters.
+pub trait StructArrayChildren {
+    /// Returns the number of child arrays.
+    fn num_children(&self) -> usize;
+
+    /// Returns a reference to the child array at `index`, or `None` if
+    /// `index` is out of bounds.
+    fn child(&self, index: usize) -> Option<&dyn Any>;
+
+    /// Returns a reference to the child array at `index` as an `A`, or `None`
+    /// if `index` is out of bounds or the child is not an `A`.
+    fn child_as<A: Any>(&self, index: usize) -> Option<&A>
+    where
+        Self: Sized,
+    {
+        self.child(index)?.downcast_ref()
+    }
+
+    /// Returns a reference to the child array of the field with the given
+    /// `name`, or `None` if there is no such field.
+    fn child_by_name(&self, name: &str) -> Option<&dyn Any>
+    where
+        Self: StructArrayFields + Sized,
+    {
+        self.child(Self::field_index(name)?)
+    }
+}
+
+/// Information about a field of a struct.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// Whether the field is nullable, i.e. an [`Option`].
+    pub nullable: bool,
+    /// The type of the field as written in the struct definition.
+    pub type_name: &'static str,
+    /// The extension type of the field, set with the
+    /// `#[narrow(extension = "...")]` attribute.
+    pub extension: Option<ExtensionType>,
+}
+
+/// The fields of struct arrays.
+///
+/// This is implemented by the arrays generated by the [`ArrayType`] derive
+/// macro for structs with fields. The field names can be changed with the
+/// `#[narrow(rename = "...")]` attribute.
+pub trait StructArrayFields {
+    /// The fields, in the order of the child arrays.
+    const FIELDS: &'static [FieldInfo];
+
+    /// Returns the index of the field with the given `name`, or `None` if
+    /// there is no such field.
+    #[must_use]
+    fn field_index(name: &str) -> Option<usize> {
+        Self::FIELDS.iter().position(|field| field.name == name)
+    }
+}
+
+/// A projection of a struct array to a subset of its child arrays.
+///
+/// The projection borrows the child arrays and the validity bitmap of the
+/// struct array, so no buffers are copied.
+pub struct StructProjection<'a, Buffer: BufferType = VecBuffer> {
+    /// The number of items in the struct array.
+    len: usize,
+    /// The validity bitmap of nullable struct arrays.
+    validity: Option<&'a Bitmap<Buffer>>,
+    /// The projected child arrays.
+    children: Vec<&'a dyn Any>,
+}
+
+impl<'a, Buffer: BufferType> StructProjection<'a, Buffer> {
+    /// Returns a projection with the children at `indices` of `array`, or
+    /// `None` if one of the indices is out of bounds.
+    fn new(
+        array: &'a (impl StructArrayChildren + Length),
+        validity: Option<&'a Bitmap<Buffer>>,
+        indices: &[usize],
+    ) -> Option<Self> {
+        indices
+            .iter()
+            .map(|&index| array.child(index))
+            .collect::<Option<_>>()
+            .map(|children| Self {
+                len: array.len(),
+                validity,
+                children,
+            })
+    }
+
+    /// Returns the validity bitmap of the projected struct array, or `None`
+    /// if the struct array is not nullable.
+    #[must_use]
+    pub fn validity(&self) -> Option<&'a Bitmap<Buffer>> {
+        self.validity
+    }
+
+    /// Returns a projection with the children at `indices` of this
+    /// projection, or `None` if one of the indices is out of bounds.
+    #[must_use]
+    pub fn project(&self, indices: &[usize]) -> Option<Self> {
+        indices
+            .iter()
+            .map(|&index| self.children.get(index).copied())
+            .collect::<Option<_>>()
+            .map(|children| Self {
+                len: self.len,
+                validity: self.validity,
+                children,
+            })
+    }
+}
+
+impl<Buffer: BufferType> Length for StructProjection<'_, Buffer> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<Buffer: BufferType> StructArrayChildren for StructProjection<'_, Buffer> {
+    fn num_children(&self) -> usize {
+        self.children.len()
+    }
+
+    fn child(&self, index: usize) -> Option<&dyn Any> {
+        self.children.get(index).copied()
+    }
+}
+
 /// Array for product types.
 pub struct StructArray<
     T: StructArrayType,
@@ -38,6 +180,64 @@ where
     }
 }
 
+impl<T: StructArrayType, Buffer: BufferType> StructArray<T, false, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: StructArrayChildren + Length,
+{
+    /// Returns a projection of this [`StructArray`] with the children at
+    /// `indices`, or `None` if one of the indices is out of bounds.
+    #[must_use]
+    pub fn project(&self, indices: &[usize]) -> Option<StructProjection<'_, Buffer>> {
+        StructProjection::new(&self.0, None, indices)
+    }
+}
+
+impl<T: StructArrayType, Buffer: BufferType> StructArray<T, true, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: StructArrayChildren,
+{
+    /// Returns a projection of this [`StructArray`] with the children at
+    /// `indices`, or `None` if one of the indices is out of bounds.
+    #[must_use]
+    pub fn project(&self, indices: &[usize]) -> Option<StructProjection<'_, Buffer>> {
+        StructProjection::new(self, Some(self.bitmap_ref()), indices)
+    }
+}
+
+impl<T: StructArrayType, Buffer: BufferType> StructArrayChildren for StructArray<T, false, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: StructArrayChildren,
+{
+    fn num_children(&self) -> usize {
+        self.0.num_children()
+    }
+
+    fn child(&self, index: usize) -> Option<&dyn Any> {
+        self.0.child(index)
+    }
+}
+
+impl<T: StructArrayType, Buffer: BufferType> StructArrayChildren for StructArray<T, true, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: StructArrayChildren,
+{
+    fn num_children(&self) -> usize {
+        self.0.data.num_children()
+    }
+
+    fn child(&self, index: usize) -> Option<&dyn Any> {
+        self.0.data.child(index)
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> StructArrayFields
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE> + StructArrayFields,
+{
+    const FIELDS: &'static [FieldInfo] = <T as StructArrayType>::Array::<Buffer>::FIELDS;
+}
+
 impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Array
     for StructArray<T, NULLABLE, Buffer>
 where
@@ -58,6 +258,17 @@ where
     }
 }
 
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Debug
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("StructArray").field(&self.0).finish()
+    }
+}
+
 impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Default
     for StructArray<T, NULLABLE, Buffer>
 where
@@ -91,7 +302,7 @@ where
     }
 }
 
-impl<T: StructArrayType, U, Buffer: BufferType> Extend<U> for StructArray<T, false, Buffer>
+impl<T: StructArrayType, U, Buffer: GrowableBuffer> Extend<U> for StructArray<T, false, Buffer>
 where
     <T as StructArrayType>::Array<Buffer>: Extend<U>,
 {
@@ -100,7 +311,8 @@ where
     }
 }
 
-impl<T: StructArrayType, U, Buffer: BufferType> Extend<Option<U>> for StructArray<T, true, Buffer>
+impl<T: StructArrayType, U, Buffer: GrowableBuffer> Extend<Option<U>>
+    for StructArray<T, true, Buffer>
 where
     Nullable<<T as StructArrayType>::Array<Buffer>, Buffer>: Extend<Option<U>>,
 {
@@ -172,6 +384,39 @@ impl<T: StructArrayType, Buffer: BufferType> BitmapRefMut for StructArray<T, tru
 
 impl<T: StructArrayType, Buffer: BufferType> ValidityBitmap for StructArray<T, true, Buffer> {}
 
+impl<T: StructArrayType, Buffer: BufferType> super::LogicalValidity
+    for StructArray<T, false, Buffer>
+where
+    Self: Length,
+{
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> MemoryUsage
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -315,7 +560,7 @@ mod tests {
         type Array<Buffer: BufferType> = FooArray<'a, Buffer>;
     }
 
-    impl<'a, Buffer: BufferType> Length for FooArray<'a, Buffer>
+    impl<Buffer: BufferType> Length for FooArray<'_, Buffer>
     where
         <u32 as ArrayType<u32>>::Array<Buffer, offset::NA, union::NA>: Length,
     {
@@ -385,7 +630,7 @@ mod tests {
             &[1, 2, 3, 4]
         );
         assert_eq!(
-            array.0.f.0.offsets.into_iter().collect::<Vec<_>>(),
+            array.0.f.0.offsets.0.into_iter().collect::<Vec<_>>(),
             &[0, 1, 3, 4, 4]
         );
         assert_eq!(
@@ -393,7 +638,7 @@ mod tests {
             &[97, 115, 100, 102] // a s d f
         );
         assert_eq!(
-            array.0.g.0 .0.offsets.into_iter().collect::<Vec<_>>(),
+            array.0.g.0 .0.offsets.0.into_iter().collect::<Vec<_>>(),
             &[0, 1, 2, 3, 4]
         );
 
@@ -557,4 +802,127 @@ mod tests {
             // assert_eq!(input.as_slice(), output);
         };
     }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn children() {
+        use crate::array::{BooleanArray, StringArray, Uint32Array};
+
+        #[derive(crate::ArrayType, Clone, Debug, Default, PartialEq)]
+        struct Row {
+            a: u32,
+            b: bool,
+            c: String,
+        }
+
+        let input = [
+            Row {
+                a: 1,
+                b: true,
+                c: "x".to_owned(),
+            },
+            Row {
+                a: 2,
+                b: false,
+                c: "yz".to_owned(),
+            },
+        ];
+        let array = input.clone().into_iter().collect::<StructArray<Row>>();
+        assert_eq!(array.num_children(), 3);
+        assert_eq!(array.0.a().as_slice(), [1, 2]);
+        assert_eq!(array.child_as::<BooleanArray>(1).map(Length::len), Some(2));
+        assert!(array.child_as::<Uint32Array>(1).is_none());
+        assert!(array.child(3).is_none());
+
+        let projection = array.project(&[2, 0]).expect("in bounds");
+        assert_eq!(projection.len(), 2);
+        assert_eq!(projection.num_children(), 2);
+        assert!(projection.validity().is_none());
+        assert!(core::ptr::eq(
+            projection.child_as::<StringArray>(0).expect("string array"),
+            array.0.c()
+        ));
+        assert_eq!(
+            projection
+                .child_as::<Uint32Array>(1)
+                .map(Uint32Array::as_slice),
+            Some([1, 2].as_slice())
+        );
+        assert!(array.project(&[0, 3]).is_none());
+
+        let nullable = [Some(input[0].clone()), None]
+            .into_iter()
+            .collect::<StructArray<Row, true>>();
+        let projection_nullable = nullable.project(&[1]).expect("in bounds");
+        assert_eq!(projection_nullable.len(), 2);
+        assert_eq!(
+            projection_nullable.validity().map(Bitmap::count_zeros),
+            Some(1)
+        );
+        let reprojection = projection_nullable.project(&[0]).expect("in bounds");
+        assert!(reprojection.child_as::<BooleanArray>(0).is_some());
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn fields() {
+        use crate::array::StringArray;
+
+        #[derive(crate::ArrayType, Clone, Debug, Default, PartialEq)]
+        struct Row {
+            a: u32,
+            b: Option<bool>,
+            #[narrow(rename = "name")]
+            c: String,
+            #[narrow(extension = "uuid")]
+            d: [u8; 16],
+        }
+
+        assert_eq!(
+            <Row as StructArrayType>::Array::<VecBuffer>::FIELDS,
+            [
+                FieldInfo {
+                    name: "a",
+                    nullable: false,
+                    type_name: "u32",
+                    extension: None,
+                },
+                FieldInfo {
+                    name: "b",
+                    nullable: true,
+                    type_name: "Option<bool>",
+                    extension: None,
+                },
+                FieldInfo {
+                    name: "name",
+                    nullable: false,
+                    type_name: "String",
+                    extension: None,
+                },
+                FieldInfo {
+                    name: "d",
+                    nullable: false,
+                    type_name: "[u8; 16]",
+                    extension: Some(ExtensionType::new("uuid")),
+                },
+            ]
+        );
+        assert_eq!(StructArray::<Row>::field_index("b"), Some(1));
+        assert_eq!(StructArray::<Row, true>::field_index("name"), Some(2));
+        assert_eq!(StructArray::<Row>::field_index("c"), None);
+
+        let array = [Row {
+            a: 1,
+            b: None,
+            c: "x".to_owned(),
+            d: [0; 16],
+        }]
+        .into_iter()
+        .collect::<StructArray<Row>>();
+        assert!(array
+            .child_by_name("name")
+            .and_then(<dyn Any>::downcast_ref::<StringArray>)
+            .is_some());
+        assert!(array.child_by_name("e").is_none());
+    }
 }
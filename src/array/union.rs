@@ -1,11 +1,14 @@
 //! Array for sum types.
 
-use std::iter;
+use core::{
+    fmt::{Debug, Formatter, Result},
+    iter,
+};
 
 use crate::{
-    buffer::{BufferType, VecBuffer},
+    buffer::{BufferType, GrowableBuffer, VecBuffer},
     offset::{self, OffsetElement},
-    Length,
+    Length, LengthMismatch, MemoryUsage, Validate, ValidationError,
 };
 
 use super::{Array, ArrayType, Int32Array, Int8Array};
@@ -48,6 +51,21 @@ impl UnionType for SparseLayout {
 /// to prevent that.
 pub type NA = SparseLayout;
 
+/// Returns the type ids `0..VARIANTS`.
+const fn contiguous_type_ids<const VARIANTS: usize>() -> [i8; VARIANTS] {
+    let mut type_ids = [0; VARIANTS];
+    let mut index = 0;
+    let mut type_id = 0;
+    while index < VARIANTS {
+        type_ids[index] = type_id;
+        index += 1;
+        if index < VARIANTS {
+            type_id += 1;
+        }
+    }
+    type_ids
+}
+
 /// Union array types.
 pub trait UnionArrayType<const VARIANTS: usize>
 where
@@ -57,8 +75,54 @@ where
     /// The number of variants.
     const VARIANTS: usize = VARIANTS;
 
+    /// The type id of every variant, in the order of the child arrays.
+    ///
+    /// Type ids must be unique and non-negative, and they don't have to be
+    /// contiguous. This allows adding variants without changing the type ids
+    /// of existing variants. The default is `0..VARIANTS`.
+    ///
+    /// The `i8` conversion of `Self` and the `TypeIdIterator` of the variant
+    /// arrays must use the same type ids.
+    const TYPE_IDS: [i8; VARIANTS] = contiguous_type_ids::<VARIANTS>();
+
     /// The array type storing the variants of the union array.
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType>;
+
+    /// Returns the index of the variant (child array) with the given type id,
+    /// or `None` if the type id is not mapped to a variant.
+    #[must_use]
+    fn variant_index(type_id: i8) -> Option<usize> {
+        Self::TYPE_IDS.iter().position(|&id| id == type_id)
+    }
+}
+
+/// The lengths of the variant (child) arrays of a union array.
+///
+/// This is used to validate union arrays. It is implemented by the variant
+/// arrays generated by the `ArrayType` derive macro for enums.
+pub trait VariantLengths<const VARIANTS: usize> {
+    /// Returns the length of every variant array, in the order of the child
+    /// arrays.
+    fn variant_lengths(&self) -> [usize; VARIANTS];
+}
+
+/// Returns an error for the first type id in `types` that is not mapped to a
+/// variant of `T`.
+fn validate_type_ids<const VARIANTS: usize, T: UnionArrayType<VARIANTS>>(
+    types: &[i8],
+) -> core::result::Result<(), ValidationError>
+where
+    for<'a> i8: From<&'a T>,
+{
+    types
+        .iter()
+        .position(|&type_id| T::variant_index(type_id).is_none())
+        .map_or(Ok(()), |index| {
+            Err(ValidationError::UnmappedTypeId {
+                index,
+                type_id: types[index],
+            })
+        })
 }
 
 /// The array data for enum variants stored in union array wrappers.
@@ -116,6 +180,22 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        UnionLayout: UnionType,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Debug for UnionArray<T, VARIANTS, UnionLayout, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <UnionLayout as UnionType>::Array<T, VARIANTS, Buffer, OffsetItem>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("UnionArray").field(&self.0).finish()
+    }
+}
+
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -219,6 +299,27 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Debug for DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>: Debug,
+    Int8Array<false, Buffer>: Debug,
+    Int32Array<false, Buffer>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("DenseUnionArray")
+            .field("variants", &self.variants)
+            .field("types", &self.types)
+            .field("offsets", &self.offsets)
+            .finish()
+    }
+}
+
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -257,7 +358,7 @@ where
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
-        Buffer: BufferType,
+        Buffer: GrowableBuffer,
         OffsetItem: OffsetElement,
     > FromIterator<T> for DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
 where
@@ -272,9 +373,7 @@ where
             .into_iter()
             .map(|item| {
                 let type_id = i8::from(&item);
-                let idx = usize::try_from(type_id).expect("bad type id");
-
-                assert!(idx < VARIANTS, "type id greater than number of variants");
+                let idx = T::variant_index(type_id).expect("type id should be mapped to a variant");
                 let result = ((type_id, lens[idx]), item);
 
                 lens[idx] += 1;
@@ -289,6 +388,80 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>:
+        VariantLengths<VARIANTS>,
+{
+    /// Returns a dense union array from its parts, after validating the type
+    /// ids and the offsets.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if a type id is not mapped to a variant
+    /// (see [`UnionArrayType::TYPE_IDS`]), if the number of offsets does not
+    /// match the number of type ids, or if an offset is out of bounds for the
+    /// variant array of its type id.
+    pub fn try_from_parts(
+        variants: <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>,
+        types: Int8Array<false, Buffer>,
+        offsets: Int32Array<false, Buffer>,
+    ) -> core::result::Result<Self, ValidationError> {
+        let array = Self {
+            variants,
+            types,
+            offsets,
+        };
+        array.validate()?;
+        Ok(array)
+    }
+}
+
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Validate for DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>:
+        VariantLengths<VARIANTS>,
+{
+    /// Checks that every type id is mapped to a variant, that there is an
+    /// offset for every type id, and that every offset is in bounds for the
+    /// variant array of its type id. The variant arrays are not validated.
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        validate_type_ids::<VARIANTS, T>(self.types.as_slice())?;
+        if self.offsets.len() != self.types.len() {
+            return Err(ValidationError::UnionOffsetsLength(LengthMismatch {
+                expected: self.types.len(),
+                actual: self.offsets.len(),
+            }));
+        }
+        let lengths = self.variants.variant_lengths();
+        self.types
+            .as_slice()
+            .iter()
+            .zip(self.offsets.as_slice())
+            .enumerate()
+            .try_for_each(|(index, (&type_id, &offset))| {
+                let len = T::variant_index(type_id).map_or(0, |variant| lengths[variant]);
+                if usize::try_from(offset).is_ok_and(|position| position < len) {
+                    Ok(())
+                } else {
+                    Err(ValidationError::OffsetOutOfBounds { index, len })
+                }
+            })
+    }
+}
+
 /// A sparse union array.
 pub struct SparseUnionArray<
     T: UnionArrayType<VARIANTS>,
@@ -323,6 +496,25 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Debug for SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>: Debug,
+    Int8Array<false, Buffer>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("SparseUnionArray")
+            .field("variants", &self.variants)
+            .field("types", &self.types)
+            .finish()
+    }
+}
+
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -378,7 +570,7 @@ where
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
-        Buffer: BufferType,
+        Buffer: GrowableBuffer,
         OffsetItem: OffsetElement,
     > FromIterator<T> for SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
 where
@@ -392,6 +584,87 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>:
+        VariantLengths<VARIANTS>,
+{
+    /// Returns a sparse union array from its parts, after validating the
+    /// type ids and the lengths of the variant arrays.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if a type id is not mapped to a variant
+    /// (see [`UnionArrayType::TYPE_IDS`]), or if the length of a variant
+    /// array does not match the number of type ids.
+    pub fn try_from_parts(
+        variants: <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>,
+        types: Int8Array<false, Buffer>,
+    ) -> core::result::Result<Self, ValidationError> {
+        let array = Self { variants, types };
+        array.validate()?;
+        Ok(array)
+    }
+}
+
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Validate for SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>:
+        VariantLengths<VARIANTS>,
+{
+    /// Checks that every type id is mapped to a variant, and that every
+    /// variant array has an element for every type id. The variant arrays are
+    /// not validated.
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        validate_type_ids::<VARIANTS, T>(self.types.as_slice())?;
+        self.variants
+            .variant_lengths()
+            .into_iter()
+            .enumerate()
+            .try_for_each(|(variant, len)| {
+                if len == self.types.len() {
+                    Ok(())
+                } else {
+                    Err(ValidationError::UnionVariantLength {
+                        variant,
+                        mismatch: LengthMismatch {
+                            expected: self.types.len(),
+                            actual: len,
+                        },
+                    })
+                }
+            })
+    }
+}
+
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        UnionLayout: UnionType,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Validate for UnionArray<T, VARIANTS, UnionLayout, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <UnionLayout as UnionType>::Array<T, VARIANTS, Buffer, OffsetItem>: Validate,
+{
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
 /// Types that return a constructed `enum` by advancing
 /// iterator(s) of variants of a union array given the
 /// `type_id` for the variant.
@@ -513,6 +786,69 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        UnionLayout: UnionType,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > MemoryUsage for UnionArray<T, VARIANTS, UnionLayout, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <UnionLayout as UnionType>::Array<T, VARIANTS, Buffer, OffsetItem>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > MemoryUsage for DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>: MemoryUsage,
+    Int8Array<false, Buffer>: MemoryUsage,
+    Int32Array<false, Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.variants.buffer_bytes() + self.types.buffer_bytes() + self.offsets.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.variants.allocated_bytes()
+            + self.types.allocated_bytes()
+            + self.offsets.allocated_bytes()
+    }
+}
+
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > MemoryUsage for SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>: MemoryUsage,
+    Int8Array<false, Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.variants.buffer_bytes() + self.types.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.variants.allocated_bytes() + self.types.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1105,4 +1441,146 @@ mod tests {
         assert_eq!(sparse_array.0.variants.2 .0.len(), 4);
         assert_eq!(sparse_array.into_iter().collect::<Vec<_>>(), input);
     }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    #[rustversion::attr(nightly, allow(non_local_definitions))]
+    fn type_ids() {
+        use crate::ArrayType;
+
+        #[derive(ArrayType, Clone, Copy, Debug, PartialEq, Eq)]
+        enum Versioned {
+            #[narrow(type_id = 7)]
+            A(u32),
+            #[narrow(type_id = 2)]
+            B {
+                b: bool,
+            },
+            C,
+        }
+
+        assert_eq!(<Versioned as UnionArrayType<3>>::TYPE_IDS, [7, 2, 3]);
+        assert_eq!(<Versioned as UnionArrayType<3>>::variant_index(3), Some(2));
+        assert_eq!(<Versioned as UnionArrayType<3>>::variant_index(0), None);
+
+        let input = vec![
+            Versioned::A(1),
+            Versioned::B { b: true },
+            Versioned::C,
+            Versioned::A(2),
+        ];
+        let dense_array = input
+            .clone()
+            .into_iter()
+            .collect::<UnionArray<Versioned, 3>>();
+        assert_eq!(dense_array.0.types.0, [7, 2, 3, 7]);
+        assert_eq!(dense_array.0.offsets.0, [0, 0, 0, 1]);
+        assert_eq!(dense_array.validate(), Ok(()));
+        assert_eq!(dense_array.into_iter().collect::<Vec<_>>(), input);
+
+        let sparse_array = input
+            .clone()
+            .into_iter()
+            .collect::<UnionArray<Versioned, 3, SparseLayout>>();
+        assert_eq!(sparse_array.0.types.0, [7, 2, 3, 7]);
+        assert_eq!(sparse_array.validate(), Ok(()));
+        assert_eq!(sparse_array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    #[rustversion::attr(nightly, allow(non_local_definitions))]
+    fn try_from_parts() {
+        use crate::ArrayType;
+
+        #[derive(ArrayType, Clone, Copy, Debug, PartialEq, Eq)]
+        enum Versioned {
+            #[narrow(type_id = 7)]
+            A(u32),
+            #[narrow(type_id = 2)]
+            B {
+                b: bool,
+            },
+            C,
+        }
+
+        let input = vec![
+            Versioned::A(1),
+            Versioned::B { b: true },
+            Versioned::C,
+            Versioned::A(2),
+        ];
+        let UnionArray(dense) = input
+            .clone()
+            .into_iter()
+            .collect::<UnionArray<Versioned, 3>>();
+        let variants = dense.variants.clone();
+        assert_eq!(
+            DenseUnionArray::<Versioned, 3>::try_from_parts(
+                variants,
+                [7, 1].into_iter().collect(),
+                [0, 0].into_iter().collect(),
+            )
+            .map(|array| array.len()),
+            Err(ValidationError::UnmappedTypeId {
+                index: 1,
+                type_id: 1
+            })
+        );
+        assert_eq!(
+            DenseUnionArray::<Versioned, 3>::try_from_parts(
+                dense.variants.clone(),
+                dense.types.clone(),
+                [0, 0, 0, 2].into_iter().collect(),
+            )
+            .map(|array| array.len()),
+            Err(ValidationError::OffsetOutOfBounds { index: 3, len: 2 })
+        );
+        assert_eq!(
+            DenseUnionArray::<Versioned, 3>::try_from_parts(
+                dense.variants,
+                dense.types,
+                [0].into_iter().collect(),
+            )
+            .map(|array| array.len()),
+            Err(ValidationError::UnionOffsetsLength(LengthMismatch {
+                expected: 4,
+                actual: 1
+            }))
+        );
+
+        let UnionArray(short) = input[..3]
+            .iter()
+            .copied()
+            .collect::<UnionArray<Versioned, 3, SparseLayout>>();
+        assert_eq!(
+            SparseUnionArray::<Versioned, 3>::try_from_parts(
+                short.variants,
+                [7, 2, 3, 7].into_iter().collect(),
+            )
+            .map(|array| array.len()),
+            Err(ValidationError::UnionVariantLength {
+                variant: 0,
+                mismatch: LengthMismatch {
+                    expected: 4,
+                    actual: 3
+                }
+            })
+        );
+
+        let UnionArray(sparse) = input
+            .into_iter()
+            .collect::<UnionArray<Versioned, 3, SparseLayout>>();
+        assert_eq!(
+            SparseUnionArray::<Versioned, 3>::try_from_parts(
+                sparse.variants,
+                [7, 2, -1, 3].into_iter().collect(),
+            )
+            .map(|array| array.len()),
+            Err(ValidationError::UnmappedTypeId {
+                index: 2,
+                type_id: -1
+            })
+        );
+    }
 }
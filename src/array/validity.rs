@@ -0,0 +1,118 @@
+//! Validity queries of nullable and non-nullable arrays.
+
+use crate::{bitmap::ValidityBitmap, Length};
+
+/// Validity queries of nullable and non-nullable arrays.
+///
+/// This allows generic code over the nullability of arrays to query their
+/// validity without special-casing nullability. Types with a validity bitmap
+/// implement this via their [`ValidityBitmap`] implementation. Non-nullable
+/// arrays implement this without a bitmap: all their elements are valid.
+///
+/// The methods are prefixed with `logical_` so they don't clash with the
+/// methods of [`ValidityBitmap`] when both traits are in scope.
+pub trait LogicalValidity: Length {
+    /// Returns `true` if the element at position `index` is valid, or `None`
+    /// if `index` is out of bounds.
+    fn logical_is_valid(&self, index: usize) -> Option<bool>;
+
+    /// Returns the number of null elements.
+    fn logical_null_count(&self) -> usize;
+
+    /// Returns `true` if all the elements are valid.
+    fn logical_all_valid(&self) -> bool {
+        self.logical_null_count() == 0
+    }
+}
+
+impl<T: ValidityBitmap + Length + ?Sized> LogicalValidity for T {
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        self.is_valid(index)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        self.null_count()
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        self.all_valid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogicalValidity;
+    use crate::{
+        array::{
+            BooleanArray, FixedSizeListArray, Int32Array, NullArray, StringArray,
+            VariableSizeBinaryArray, VariableSizeListArray,
+        },
+        Index,
+    };
+
+    /// Returns the sum of the valid values of an array of either
+    /// nullability.
+    fn sum_valid<A>(array: &A) -> i32
+    where
+        A: LogicalValidity + Index,
+        for<'a> <A as Index>::Item<'a>: Into<Option<&'a i32>>,
+    {
+        (0..array.len())
+            .filter(|&index| array.logical_is_valid(index) == Some(true))
+            .filter_map(|index| array.index(index).and_then(Into::into))
+            .sum()
+    }
+
+    #[test]
+    fn non_nullable() {
+        let array = [1, 2, 3].into_iter().collect::<Int32Array>();
+        assert_eq!(array.logical_is_valid(2), Some(true));
+        assert_eq!(array.logical_is_valid(3), None);
+        assert_eq!(array.logical_null_count(), 0);
+        assert!(array.logical_all_valid());
+        assert_eq!(sum_valid(&array), 6);
+
+        let booleans = [true, false].into_iter().collect::<BooleanArray>();
+        assert_eq!(booleans.logical_is_valid(1), Some(true));
+        assert_eq!(booleans.logical_null_count(), 0);
+        let nulls = [(), ()].into_iter().collect::<NullArray>();
+        assert_eq!(nulls.logical_is_valid(1), Some(true));
+        assert!(nulls.logical_all_valid());
+
+        let strings = ["a", "b"].into_iter().collect::<StringArray>();
+        assert_eq!(strings.logical_is_valid(1), Some(true));
+        assert_eq!(strings.logical_is_valid(2), None);
+        let binary = [[1_u8].as_slice(), &[2, 3]]
+            .into_iter()
+            .collect::<VariableSizeBinaryArray>();
+        assert_eq!(binary.logical_null_count(), 0);
+        let lists = [vec![1], vec![]]
+            .into_iter()
+            .collect::<VariableSizeListArray<Int32Array>>();
+        assert_eq!(lists.logical_is_valid(1), Some(true));
+        assert!(lists.logical_all_valid());
+        let fixed_lists = [[1, 2], [3, 4]]
+            .into_iter()
+            .collect::<FixedSizeListArray<2, Int32Array>>();
+        assert_eq!(fixed_lists.logical_is_valid(1), Some(true));
+        assert_eq!(fixed_lists.logical_is_valid(2), None);
+        assert_eq!(fixed_lists.logical_null_count(), 0);
+    }
+
+    #[test]
+    fn nullable() {
+        let array = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(array.logical_is_valid(1), Some(false));
+        assert_eq!(array.logical_is_valid(3), None);
+        assert_eq!(array.logical_null_count(), 1);
+        assert!(!array.logical_all_valid());
+        assert_eq!(sum_valid(&array), 4);
+
+        let booleans = [Some(true), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(booleans.logical_null_count(), 1);
+    }
+}
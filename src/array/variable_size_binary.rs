@@ -3,11 +3,13 @@
 use super::{Array, FixedSizePrimitiveArray, StringArray, VariableSizeListArray};
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{Buffer, BufferType, VecBuffer},
-    offset::{Offset, OffsetElement},
+    buffer::{Buffer, BufferType, GrowableBuffer, VecBuffer},
+    offset::{Offset, OffsetElement, Offsets},
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, MemoryUsage, Validate, ValidationError,
 };
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::fmt::{Debug, Formatter, Result};
 
 /// Variable-size binary elements.
 pub struct VariableSizeBinaryArray<
@@ -16,7 +18,7 @@ pub struct VariableSizeBinaryArray<
     Buffer: BufferType = VecBuffer,
 >(pub Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>)
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>;
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>;
 
 /// Variable-size binary elements, using `i32` offset values.
 pub type BinaryArray<const NULLABLE: bool = false, Buffer = VecBuffer> =
@@ -29,7 +31,7 @@ pub type LargeBinaryArray<const NULLABLE: bool = false, Buffer = VecBuffer> =
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Array
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Vec<u8>: Nullability<NULLABLE>,
 {
     type Item = <Vec<u8> as Nullability<NULLABLE>>::Item;
@@ -38,7 +40,7 @@ where
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Clone
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: Clone,
 {
     fn clone(&self) -> Self {
@@ -46,10 +48,23 @@ where
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Debug
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: Length,
+    for<'a> &'a Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: IntoIterator,
+    for<'a> <&'a Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer> as IntoIterator>::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        super::debug_array::<Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>>(f, "VariableSizeBinaryArray", &self.0)
+    }
+}
+
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Default
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: Default,
 {
     fn default() -> Self {
@@ -57,10 +72,10 @@ where
     }
 }
 
-impl<T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Extend<T>
+impl<T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: GrowableBuffer> Extend<T>
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: Extend<T>,
 {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
@@ -78,7 +93,7 @@ impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
         >,
     > for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
 {
     fn from(
         value: VariableSizeListArray<
@@ -108,7 +123,7 @@ impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
     From<StringArray<NULLABLE, OffsetItem, Buffer>>
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
 {
     fn from(value: StringArray<NULLABLE, OffsetItem, Buffer>) -> Self {
         Self(value.0 .0)
@@ -119,7 +134,7 @@ impl<T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Fro
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
     T: IntoIterator,
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>:
         FromIterator<<T as IntoIterator>::IntoIter>,
 {
@@ -195,7 +210,7 @@ where
 impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
     for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: Length,
 {
     fn len(&self) -> usize {
@@ -226,6 +241,50 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> ValidityBitmap
 {
 }
 
+impl<OffsetItem: OffsetElement, Buffer: BufferType> super::LogicalValidity
+    for VariableSizeBinaryArray<false, OffsetItem, Buffer>
+where
+    Self: Length,
+{
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> MemoryUsage
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Validate
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: Validate,
+{
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
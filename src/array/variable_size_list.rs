@@ -1,14 +1,19 @@
 //!Array with variable-size list elements.
 
 use crate::{
-    array::Array,
+    array::{Array, Uint32Array},
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{BufferType, VecBuffer},
-    offset::{Offset, OffsetElement},
+    buffer::{BufferType, GrowableBuffer, VecBuffer},
+    offset::{next_offset, Offset, OffsetElement, OffsetOverflow, Offsets},
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, MemoryUsage, Validate, ValidationError,
+};
+use alloc::vec::Vec;
+use core::{
+    fmt::{Debug, Formatter, Result},
+    iter,
+    ops::Range,
 };
-use std::fmt::{Debug, Formatter, Result};
 
 /// Array with variable-size list elements.
 pub struct VariableSizeListArray<
@@ -18,12 +23,267 @@ pub struct VariableSizeListArray<
     Buffer: BufferType = VecBuffer,
 >(pub Offset<T, NULLABLE, OffsetItem, Buffer>)
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>;
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>;
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType>
+    VariableSizeListArray<T, false, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+{
+    /// Returns an array with lists of the given `lengths`, with the items of
+    /// the lists in the flat `values` iterator.
+    ///
+    /// The offsets are computed from the lengths and the child array is
+    /// extended with all values at once, instead of per list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OffsetOverflow`] error if the total length of the lists
+    /// does not fit in the offset type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values does not equal the total length of the
+    /// lists.
+    pub fn from_lengths_and_values<L: IntoIterator<Item = usize>, V: IntoIterator>(
+        lengths: L,
+        values: V,
+    ) -> core::result::Result<Self, OffsetOverflow>
+    where
+        Self: Default,
+        <L as IntoIterator>::IntoIter: ExactSizeIterator,
+        T: Extend<<V as IntoIterator>::Item>,
+    {
+        let mut array = Self::default();
+        array.extend_from_lengths_and_values(lengths, values)?;
+        Ok(array)
+    }
+
+    /// Extends this array with lists of the given `lengths`, with the items of
+    /// the lists in the flat `values` iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OffsetOverflow`] error, without modifying this array, if
+    /// the total length of the lists does not fit in the offset type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values does not equal the total length of the
+    /// lists.
+    pub fn extend_from_lengths_and_values<L: IntoIterator<Item = usize>, V: IntoIterator>(
+        &mut self,
+        lengths: L,
+        values: V,
+    ) -> core::result::Result<(), OffsetOverflow>
+    where
+        <L as IntoIterator>::IntoIter: ExactSizeIterator,
+        T: Extend<<V as IntoIterator>::Item>,
+    {
+        let start = self.0.offsets.last();
+        let lens = lengths.into_iter();
+        let mut offsets = Vec::with_capacity(lens.len());
+        let mut end = start;
+        for len in lens {
+            end = next_offset(end, len)?;
+            offsets.push(end);
+        }
+        let expected: usize = (end - start).try_into().expect("offset value out of range");
+
+        let mut count = 0_usize;
+        self.0
+            .data
+            .extend(values.into_iter().inspect(|_| count += 1));
+        assert_eq!(
+            count, expected,
+            "number of values should equal the total length of the lists"
+        );
+        self.0.offsets.0.extend(offsets);
+        Ok(())
+    }
+}
+
+/// Returns the ranges of the values of lists with the given `offsets`.
+///
+/// # Panics
+///
+/// Panics if an offset value does not fit in a `usize`.
+fn list_ranges<OffsetItem: OffsetElement>(
+    offsets: &[OffsetItem],
+) -> impl Iterator<Item = Range<usize>> + '_ {
+    offsets.windows(2).map(|window| {
+        let start = window[0].try_into().expect("offset value out of range");
+        let end = window[1].try_into().expect("offset value out of range");
+        start..end
+    })
+}
+
+/// Returns the parent list index of the values of lists with the given
+/// `offsets` and `validity`.
+///
+/// Null lists contribute nothing, or one index when `outer` is set.
+///
+/// # Panics
+///
+/// Panics if a list index does not fit in a `u32`.
+fn parent_indices<OffsetItem: OffsetElement>(
+    offsets: &[OffsetItem],
+    validity: impl Iterator<Item = bool>,
+    outer: bool,
+) -> Uint32Array {
+    let mut indices = Vec::new();
+    for ((index, range), valid) in list_ranges(offsets).enumerate().zip(validity) {
+        let parent = u32::try_from(index).expect("list index should fit in u32");
+        if valid {
+            indices.extend(iter::repeat(parent).take(range.len()));
+        } else if outer {
+            indices.push(parent);
+        }
+    }
+    Uint32Array::from(indices)
+}
+
+/// Returns the values of the lists with the given `offsets` and `validity`,
+/// taken from the values in `data`.
+///
+/// Only the values in the ranges of valid lists are taken, so values before
+/// the first offset and the values of null lists are skipped. Null lists
+/// contribute one `None` value when `outer` is set.
+///
+/// # Panics
+///
+/// Panics if an offset value does not fit in a `usize`, or if the offsets
+/// are decreasing.
+fn flatten_values<'a, T: ?Sized, U, OffsetItem: OffsetElement>(
+    data: &'a T,
+    offsets: &[OffsetItem],
+    validity: impl Iterator<Item = bool>,
+    outer: bool,
+) -> U
+where
+    &'a T: IntoIterator,
+    U: Default + Extend<Option<<&'a T as IntoIterator>::Item>>,
+{
+    let mut values = data.into_iter();
+    let mut position = 0;
+    let mut child = U::default();
+    for (range, valid) in list_ranges(offsets).zip(validity) {
+        if valid {
+            values.by_ref().take(range.start - position).for_each(drop);
+            child.extend(values.by_ref().take(range.len()).map(Some));
+            position = range.end;
+        } else if outer {
+            child.extend(iter::once(None));
+        }
+    }
+    child
+}
+
+/// Adapts an [`Extend`] of values to an [`Extend`] of optional values that
+/// are always `Some`.
+struct ExtendSome<U>(U);
+
+impl<U: Default> Default for ExtendSome<U> {
+    fn default() -> Self {
+        Self(U::default())
+    }
+}
+
+impl<U: Extend<V>, V> Extend<Option<V>> for ExtendSome<U> {
+    fn extend<I: IntoIterator<Item = Option<V>>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().flatten());
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType>
+    VariableSizeListArray<T, false, OffsetItem, Buffer>
+{
+    /// Returns the concatenated values of the lists in this array, with the
+    /// index of the list of every value.
+    ///
+    /// Empty lists contribute no values, and values outside the ranges of the
+    /// lists are skipped. The values can be combined with other arrays of the
+    /// same length as this array by taking the items at the returned indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of lists does not fit in a `u32`.
+    pub fn flatten<'a, U>(&'a self) -> (U, Uint32Array)
+    where
+        &'a T: IntoIterator,
+        U: Default + Extend<<&'a T as IntoIterator>::Item>,
+    {
+        let offsets = self.0.offsets.as_slice();
+        let ExtendSome(values) =
+            flatten_values::<T, ExtendSome<U>, _>(&self.0.data, offsets, iter::repeat(true), false);
+        (values, parent_indices(offsets, iter::repeat(true), false))
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType>
+    VariableSizeListArray<T, true, OffsetItem, Buffer>
+{
+    /// Returns the concatenated values of the lists in this array, with the
+    /// index of the list of every value.
+    ///
+    /// Null lists and empty lists contribute no values, and values outside
+    /// the ranges of the valid lists are skipped. To emit a null value for
+    /// null lists use [`Self::flatten_outer`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of lists does not fit in a `u32`.
+    pub fn flatten<'a, U>(&'a self) -> (U, Uint32Array)
+    where
+        &'a T: IntoIterator,
+        U: Default + Extend<<&'a T as IntoIterator>::Item>,
+    {
+        let offsets = self.0.offsets.data.as_slice();
+        let ExtendSome(values) = flatten_values::<T, ExtendSome<U>, _>(
+            &self.0.data,
+            offsets,
+            self.0.offsets.validity.into_iter(),
+            false,
+        );
+        (
+            values,
+            parent_indices(offsets, self.0.offsets.validity.into_iter(), false),
+        )
+    }
+
+    /// Returns the concatenated values of the lists in this array, with the
+    /// index of the list of every value, where every null list contributes
+    /// one null value.
+    ///
+    /// This matches an outer join of the lists with their values. Empty lists
+    /// contribute no values, and values outside the ranges of the valid lists
+    /// are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of lists does not fit in a `u32`.
+    pub fn flatten_outer<'a, U>(&'a self) -> (U, Uint32Array)
+    where
+        &'a T: IntoIterator,
+        U: Default + Extend<Option<<&'a T as IntoIterator>::Item>>,
+    {
+        let offsets = self.0.offsets.data.as_slice();
+        (
+            flatten_values::<T, U, _>(
+                &self.0.data,
+                offsets,
+                self.0.offsets.validity.into_iter(),
+                true,
+            ),
+            parent_indices(offsets, self.0.offsets.validity.into_iter(), true),
+        )
+    }
+}
 
 impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Array
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Vec<T>: Nullability<NULLABLE>,
 {
     type Item = <Vec<T> as Nullability<NULLABLE>>::Item;
@@ -32,20 +292,24 @@ where
 impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Debug
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    Offset<T, NULLABLE, OffsetItem, Buffer>: Debug,
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Offset<T, NULLABLE, OffsetItem, Buffer>: Length,
+    for<'a> &'a Offset<T, NULLABLE, OffsetItem, Buffer>: IntoIterator,
+    for<'a> <&'a Offset<T, NULLABLE, OffsetItem, Buffer> as IntoIterator>::Item: Debug,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        f.debug_tuple("VariableSizeListArray")
-            .field(&self.0)
-            .finish()
+        super::debug_array::<Offset<T, NULLABLE, OffsetItem, Buffer>>(
+            f,
+            "VariableSizeListArray",
+            &self.0,
+        )
     }
 }
 
 impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Clone
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Clone,
 {
     fn clone(&self) -> Self {
@@ -56,7 +320,7 @@ where
 impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Default
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Default,
 {
     fn default() -> Self {
@@ -64,10 +328,10 @@ where
     }
 }
 
-impl<T: Array, U, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Extend<U>
+impl<T: Array, U, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: GrowableBuffer> Extend<U>
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Extend<U>,
 {
     fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
@@ -89,7 +353,7 @@ where
 impl<T: Array, U, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
     FromIterator<U> for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: FromIterator<U>,
 {
     fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
@@ -100,7 +364,7 @@ where
 impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> IntoIterator
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: IntoIterator,
 {
     type IntoIter = <Offset<T, NULLABLE, OffsetItem, Buffer> as IntoIterator>::IntoIter;
@@ -114,7 +378,7 @@ where
 impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Index
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Index,
 {
     type Item<'a> = <Offset<T, NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>
@@ -129,7 +393,7 @@ where
 impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
     for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Length,
 {
     fn len(&self) -> usize {
@@ -160,10 +424,57 @@ impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> ValidityBitmap
 {
 }
 
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> super::LogicalValidity
+    for VariableSizeListArray<T, false, OffsetItem, Buffer>
+where
+    Self: Length,
+{
+    fn logical_is_valid(&self, index: usize) -> Option<bool> {
+        (index < self.len()).then_some(true)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        0
+    }
+
+    fn logical_all_valid(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> MemoryUsage
+    for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Offset<T, NULLABLE, OffsetItem, Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Validate
+    for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Offset<T, NULLABLE, OffsetItem, Buffer>: Validate,
+{
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.0.validate()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::array::FixedSizePrimitiveArray;
+    use crate::{
+        array::{FixedSizePrimitiveArray, StringArray},
+        nullable::Nullable,
+    };
 
     #[test]
     fn from_iter() {
@@ -171,10 +482,148 @@ mod tests {
         let array = input
             .into_iter()
             .collect::<VariableSizeListArray<FixedSizePrimitiveArray<u8>>>();
-        assert_eq!(array.0.data.0, &[1, 2, 3, 4]);
+        assert_eq!(array.0.data.as_slice(), &[1, 2, 3, 4]);
         assert_eq!(array.0.offsets, &[0, 1, 3, 4]);
     }
 
+    #[test]
+    fn debug() {
+        let array = [Some(vec![1]), None, Some(vec![2, 3])]
+            .into_iter()
+            .collect::<VariableSizeListArray<FixedSizePrimitiveArray<u8>, true>>();
+        assert_eq!(
+            format!("{array:?}"),
+            "VariableSizeListArray { len: 3, values: [Some([1]), None, Some([2, 3])] }"
+        );
+    }
+
+    #[test]
+    fn from_lengths_and_values() {
+        let input = vec![vec![1, 2], vec![], vec![3], vec![], vec![4, 5, 6]];
+        let array = VariableSizeListArray::<FixedSizePrimitiveArray<u8>>::from_lengths_and_values(
+            input.iter().map(Vec::len),
+            input.iter().flatten().copied(),
+        )
+        .expect("no overflow");
+        let naive = input
+            .into_iter()
+            .collect::<VariableSizeListArray<FixedSizePrimitiveArray<u8>>>();
+        assert_eq!(array.0.offsets, &[0, 2, 2, 3, 3, 6]);
+        assert_eq!(array.0.offsets, naive.0.offsets);
+        assert_eq!(array.0.data, naive.0.data);
+
+        let empty = VariableSizeListArray::<FixedSizePrimitiveArray<u8>>::from_lengths_and_values(
+            [0, 0],
+            [0_u8; 0],
+        )
+        .expect("no overflow");
+        assert_eq!(empty.len(), 2);
+        assert_eq!(
+            empty.into_iter().collect::<Vec<_>>(),
+            [Vec::<u8>::new(), vec![]]
+        );
+    }
+
+    #[test]
+    fn extend_from_lengths_and_values() {
+        let mut array =
+            [vec![1_u8]]
+                .into_iter()
+                .collect::<VariableSizeListArray<FixedSizePrimitiveArray<u8>, false, i64>>();
+        array
+            .extend_from_lengths_and_values([2], [2, 3])
+            .expect("no overflow");
+        array
+            .extend_from_lengths_and_values([0, 1], [4])
+            .expect("no overflow");
+        assert_eq!(array.0.offsets, &[0, 1, 3, 3, 4]);
+        assert_eq!(array.0.data.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn from_lengths_and_values_overflow() {
+        let max = usize::try_from(i32::MAX).expect("fits");
+        let mut array = VariableSizeListArray::<FixedSizePrimitiveArray<u8>>::default();
+        assert_eq!(
+            array.extend_from_lengths_and_values([max, 1], [0_u8; 0]),
+            Err(OffsetOverflow)
+        );
+        // The array is not modified.
+        assert_eq!(array.len(), 0);
+        assert_eq!(array.0.offsets, &[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of values should equal the total length of the lists")]
+    fn from_lengths_and_values_mismatch() {
+        let _ =
+            VariableSizeListArray::<FixedSizePrimitiveArray<u8>>::from_lengths_and_values([2], [1]);
+    }
+
+    #[test]
+    fn flatten() {
+        let input = vec![vec![1, 2], vec![], vec![3]];
+        let array = input
+            .into_iter()
+            .collect::<VariableSizeListArray<FixedSizePrimitiveArray<u8>>>();
+        let (values, parents) = array.flatten::<FixedSizePrimitiveArray<u8>>();
+        assert_eq!(values.as_slice(), &[1, 2, 3]);
+        assert_eq!(parents.as_slice(), &[0, 0, 2]);
+
+        // The offsets of a slice of an array don't start at 0.
+        let sliced = VariableSizeListArray::<FixedSizePrimitiveArray<u8>>(Offset {
+            data: [9, 1, 2, 3, 9].into_iter().collect(),
+            offsets: Offsets(vec![1, 3, 3, 4]),
+        });
+        let (sliced_values, sliced_parents) = sliced.flatten::<FixedSizePrimitiveArray<u8>>();
+        assert_eq!(sliced_values.as_slice(), &[1, 2, 3]);
+        assert_eq!(sliced_parents.as_slice(), &[0, 0, 2]);
+    }
+
+    #[test]
+    fn flatten_nullable() {
+        let input = vec![
+            Some(vec!["a", "b"]),
+            None,
+            Some(vec![]),
+            Some(vec!["c"]),
+            None,
+        ];
+        let array = input
+            .into_iter()
+            .collect::<VariableSizeListArray<StringArray, true>>();
+
+        let (values, parents) = array.flatten::<StringArray>();
+        assert_eq!(values.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(parents.as_slice(), &[0, 0, 3]);
+
+        let (outer, outer_parents) = array.flatten_outer::<StringArray<true>>();
+        assert_eq!(
+            outer.iter().collect::<Vec<_>>(),
+            [Some("a"), Some("b"), None, Some("c"), None]
+        );
+        assert_eq!(outer_parents.as_slice(), &[0, 0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn flatten_nullable_offsets() {
+        // The null list is not empty, and the offsets don't start at 0.
+        let array = VariableSizeListArray::<FixedSizePrimitiveArray<u8>, true>(Offset {
+            data: [9, 1, 2, 8, 8, 3].into_iter().collect(),
+            offsets: Nullable {
+                data: Offsets(vec![1, 3, 5, 6]),
+                validity: [true, false, true].into_iter().collect(),
+            },
+        });
+        let (values, parents) = array.flatten::<FixedSizePrimitiveArray<u8>>();
+        assert_eq!(values.as_slice(), &[1, 2, 3]);
+        assert_eq!(parents.as_slice(), &[0, 0, 2]);
+
+        let (outer, outer_parents) = array.flatten_outer::<Vec<_>>();
+        assert_eq!(outer, [Some(&1), Some(&2), None, Some(&3)]);
+        assert_eq!(outer_parents.as_slice(), &[0, 0, 1, 2]);
+    }
+
     #[test]
     fn from_iter_nullable() {
         let input = vec![Some(vec![1]), None, Some(vec![2, 3]), Some(vec![4])];
@@ -182,7 +631,7 @@ mod tests {
             .into_iter()
             .collect::<VariableSizeListArray<FixedSizePrimitiveArray<u8>, true>>();
         assert_eq!(array.len(), 4);
-        assert_eq!(array.0.data.0, &[1, 2, 3, 4]);
+        assert_eq!(array.0.data.as_slice(), &[1, 2, 3, 4]);
         assert_eq!(array.0.offsets.bitmap_ref().is_valid(0), Some(true));
         assert_eq!(array.0.offsets.bitmap_ref().is_null(1), Some(true));
         assert_eq!(array.0.offsets.bitmap_ref().is_valid(2), Some(true));
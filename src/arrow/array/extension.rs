@@ -0,0 +1,89 @@
+//! Interop with [`arrow-rs`] field metadata for extension types.
+
+use std::{collections::HashMap, sync::Arc};
+
+use arrow_schema::Field;
+
+use crate::array::{Extended, ExtensionType};
+
+impl ExtensionType {
+    /// Returns the field metadata of this extension type.
+    #[must_use]
+    pub fn field_metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::with_capacity(2);
+        metadata.insert(Self::NAME_KEY.to_owned(), self.name.clone().into_owned());
+        if let Some(value) = self.metadata.as_ref() {
+            metadata.insert(Self::METADATA_KEY.to_owned(), value.clone().into_owned());
+        }
+        metadata
+    }
+
+    /// Returns the field with the metadata of this extension type added to
+    /// its metadata.
+    #[must_use]
+    pub fn annotate_field(&self, field: Field) -> Field {
+        let mut metadata = field.metadata().clone();
+        metadata.extend(self.field_metadata());
+        field.with_metadata(metadata)
+    }
+
+    /// Returns the extension type stored in the metadata of the field, or
+    /// `None` if the field has no extension type.
+    #[must_use]
+    pub fn from_field(field: &Field) -> Option<Self> {
+        let metadata = field.metadata();
+        metadata.get(Self::NAME_KEY).map(|name| Self {
+            name: name.clone().into(),
+            metadata: metadata.get(Self::METADATA_KEY).cloned().map(Into::into),
+        })
+    }
+}
+
+impl<A: crate::arrow::Array> Extended<A> {
+    /// Returns the field of this array, with the metadata of its extension
+    /// type.
+    #[must_use]
+    pub fn as_field(&self, name: &str) -> Field {
+        self.extension().annotate_field(A::as_field(name))
+    }
+}
+
+impl<A> From<Extended<A>> for Arc<dyn arrow_array::Array>
+where
+    Arc<dyn arrow_array::Array>: From<A>,
+{
+    fn from(value: Extended<A>) -> Self {
+        let (array, _) = value.into_parts();
+        array.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Uint8Array;
+
+    #[test]
+    fn field_metadata() {
+        let extension = ExtensionType::new("geo.point").with_metadata("{}");
+        let array = Extended::new([1_u8, 2].into_iter().collect::<Uint8Array>(), extension);
+        let field = array.as_field("point");
+        assert_eq!(
+            field
+                .metadata()
+                .get(ExtensionType::NAME_KEY)
+                .map(String::as_str),
+            Some("geo.point")
+        );
+        assert_eq!(
+            ExtensionType::from_field(&field).as_ref(),
+            Some(array.extension())
+        );
+        assert_eq!(
+            ExtensionType::from_field(&<Uint8Array as crate::arrow::Array>::as_field("x")),
+            None
+        );
+        let arrow_array: Arc<dyn arrow_array::Array> = array.into();
+        assert_eq!(arrow_array.len(), 2);
+    }
+}
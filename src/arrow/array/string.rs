@@ -12,14 +12,14 @@ use crate::{
     bitmap::Bitmap,
     buffer::BufferType,
     nullable::Nullable,
-    offset::Offset,
+    offset::{Offset, Offsets},
     validity::{Nullability, Validity},
 };
 
 impl<const NULLABLE: bool, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
     crate::arrow::Array for StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     String: Nullability<NULLABLE>,
 {
     type Array = arrow_array::GenericStringArray<OffsetItem>;
@@ -40,7 +40,7 @@ where
 impl<const NULLABLE: bool, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
     From<Arc<dyn arrow_array::Array>> for StringArray<NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Self: From<arrow_array::GenericStringArray<OffsetItem>>,
 {
     fn from(value: Arc<dyn arrow_array::Array>) -> Self {
@@ -85,7 +85,7 @@ where
         arrow_array::GenericStringArray::new(
             // Safety:
             // - The narrow offfset buffer contains valid offset data
-            unsafe { OffsetBuffer::new_unchecked(value.0 .0.offsets.into()) },
+            unsafe { OffsetBuffer::new_unchecked(value.0 .0.offsets.0.into()) },
             value.0 .0.data.into().into_inner(),
             None,
         )
@@ -103,7 +103,7 @@ where
         arrow_array::GenericStringArray::new(
             // Safety:
             // - The narrow offfset buffer contains valid offset data
-            unsafe { OffsetBuffer::new_unchecked(value.0 .0.offsets.data.into()) },
+            unsafe { OffsetBuffer::new_unchecked(value.0 .0.offsets.data.0.into()) },
             value.0 .0.data.into().into_inner(),
             Some(value.0 .0.offsets.validity.into()),
         )
@@ -123,7 +123,7 @@ where
             Some(_) => panic!("expected array without a null buffer"),
             None => StringArray(VariableSizeBinaryArray(Offset {
                 data: ScalarBuffer::from(values).into(),
-                offsets: offsets.into_inner().into(),
+                offsets: Offsets(offsets.into_inner().into()),
             })),
         }
     }
@@ -139,7 +139,7 @@ where
     fn from(value: arrow_array::GenericStringArray<OffsetItem>) -> Self {
         let (offsets_buffer, values, nulls_opt) = value.into_parts();
         let data = ScalarBuffer::from(values).into();
-        let offsets = offsets_buffer.into_inner().into();
+        let offsets = Offsets(offsets_buffer.into_inner().into());
         match nulls_opt {
             Some(null_buffer) => StringArray(VariableSizeBinaryArray(Offset {
                 data,
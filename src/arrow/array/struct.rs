@@ -439,4 +439,30 @@ mod tests {
             )))
         );
     }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn extension() {
+        #[derive(narrow_derive::ArrayType)]
+        struct Baz {
+            #[narrow(extension = "uuid")]
+            id: [u8; 16],
+            name: String,
+        }
+
+        let schema = StructArray::<Baz>::schema();
+        let fields = schema.fields();
+        assert_eq!(
+            crate::array::ExtensionType::from_field(&fields[0]),
+            Some(crate::array::ExtensionType::new("uuid"))
+        );
+        assert_eq!(
+            fields[0]
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("uuid")
+        );
+        assert!(fields[1].metadata().is_empty());
+    }
 }
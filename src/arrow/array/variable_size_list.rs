@@ -12,7 +12,7 @@ use crate::{
     bitmap::Bitmap,
     buffer::BufferType,
     nullable::Nullable,
-    offset::Offset,
+    offset::{Offset, Offsets},
     validity::{Nullability, Validity},
 };
 
@@ -23,7 +23,7 @@ impl<
         Buffer: BufferType,
     > crate::arrow::Array for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Vec<T>: Nullability<NULLABLE>,
 {
     type Array = arrow_array::GenericListArray<OffsetItem>;
@@ -48,7 +48,7 @@ impl<
         Buffer: BufferType,
     > From<Arc<dyn arrow_array::Array>> for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Self: From<arrow_array::GenericListArray<OffsetItem>>,
 {
     fn from(value: Arc<dyn arrow_array::Array>) -> Self {
@@ -104,7 +104,7 @@ where
             Arc::new(T::as_field("item")),
             // Safety:
             // - The narrow offfset buffer contains valid offset data
-            unsafe { OffsetBuffer::new_unchecked(value.0.offsets.into()) },
+            unsafe { OffsetBuffer::new_unchecked(value.0.offsets.0.into()) },
             Arc::<<T as crate::arrow::Array>::Array>::new(value.0.data.into()),
             None,
         )
@@ -127,7 +127,7 @@ where
             Arc::new(T::as_field("item")),
             // Safety:
             // - The narrow offfset buffer contains valid offset data
-            unsafe { OffsetBuffer::new_unchecked(value.0.offsets.data.into()) },
+            unsafe { OffsetBuffer::new_unchecked(value.0.offsets.data.0.into()) },
             Arc::<<T as crate::arrow::Array>::Array>::new(value.0.data.into()),
             Some(value.0.offsets.validity.into()),
         )
@@ -148,7 +148,7 @@ where
             Some(_) => panic!("expected array without a null buffer"),
             None => VariableSizeListArray(Offset {
                 data: values.into(),
-                offsets: offsets.into_inner().into(),
+                offsets: Offsets(offsets.into_inner().into()),
             }),
         }
     }
@@ -165,7 +165,7 @@ where
     fn from(value: arrow_array::GenericListArray<OffsetItem>) -> Self {
         let (_field, offsets_buffer, values, nulls_opt) = value.into_parts();
         let data = values.into();
-        let offsets = offsets_buffer.into_inner().into();
+        let offsets = Offsets(offsets_buffer.into_inner().into());
         match nulls_opt {
             Some(null_buffer) => VariableSizeListArray(Offset {
                 data,
@@ -230,17 +230,19 @@ mod tests {
     fn into_non_nullable() {
         let mut list_builder =
             ListBuilder::with_capacity(StringBuilder::new(), INPUT_NULLABLE.len());
-        INPUT_NULLABLE.into_iter().for_each(|opt| match opt {
-            Some(items) => {
-                for item in items {
-                    list_builder.values().append_value(item);
+        for opt in INPUT_NULLABLE {
+            match opt {
+                Some(items) => {
+                    for item in items {
+                        list_builder.values().append_value(item);
+                    }
+                    list_builder.append(true);
+                }
+                None => {
+                    list_builder.append(false);
                 }
-                list_builder.append(true);
-            }
-            None => {
-                list_builder.append(false);
             }
-        });
+        }
         let list_array_nullable = list_builder.finish();
         let _: VariableSizeListArray<
             StringArray<false, i32, ScalarBuffer>,
@@ -263,17 +265,19 @@ mod tests {
 
         let mut list_builder =
             ListBuilder::with_capacity(StringBuilder::new(), INPUT_NULLABLE.len());
-        INPUT_NULLABLE.into_iter().for_each(|opt| match opt {
-            Some(items) => {
-                for item in items {
-                    list_builder.values().append_value(item);
+        for opt in INPUT_NULLABLE {
+            match opt {
+                Some(items) => {
+                    for item in items {
+                        list_builder.values().append_value(item);
+                    }
+                    list_builder.append(true);
+                }
+                None => {
+                    list_builder.append(false);
                 }
-                list_builder.append(true);
-            }
-            None => {
-                list_builder.append(false);
             }
-        });
+        }
         let list_array_nullable = list_builder.finish();
         let _: VariableSizeListArray<
             StringArray<false, i32, ScalarBuffer>,
@@ -1,7 +1,7 @@
 //! Interop with [`arrow-rs`] buffer builder.
 
 use crate::{
-    buffer::{Buffer, BufferMut, BufferType},
+    buffer::{Buffer, BufferMut, BufferType, GrowableBuffer},
     FixedSize, Index, Length,
 };
 
@@ -13,6 +13,8 @@ impl BufferType for BufferBuilder {
     type Buffer<T: FixedSize> = arrow_buffer::BufferBuilder<T>;
 }
 
+impl GrowableBuffer for BufferBuilder {}
+
 impl<T: FixedSize> Buffer<T> for arrow_buffer::BufferBuilder<T> {
     fn as_slice(&self) -> &[T] {
         arrow_buffer::BufferBuilder::as_slice(self)
@@ -1,7 +1,8 @@
 //! Bitmap formatting.
 
 use crate::buffer::Buffer;
-use std::fmt::{Display, Formatter, Result};
+use alloc::format;
+use core::fmt::{Display, Formatter, Result};
 
 /// A slice wrapper with a [Display] implementation to format bytes as bits.
 pub(super) struct BitsDisplay<'a>(&'a [u8]);
@@ -1,6 +1,6 @@
 //! An iterator that packs boolean values.
 
-use std::borrow::Borrow;
+use core::borrow::Borrow;
 
 /// An iterator that packs boolean values as bits in bytes using
 /// least-significant bit (LSB) numbering.
@@ -1,14 +1,14 @@
 //! An iterator that unpacks boolean values.
 
-use std::borrow::Borrow;
+use core::borrow::Borrow;
 
 /// An iterator that unpacks boolean values from an iterator (`I`) over items
 /// (`T`) that can be borrowed as bytes, by interpreting the bits of these bytes
 /// with least-significant bit (LSB) numbering as boolean values i.e. `1` maps
 /// to `true` and `0` maps to `false`.
 ///
-// note: add to docs that users should combine this with std::iter::skip and
-// std::iter::take if needed for padding
+// note: add to docs that users should combine this with core::iter::skip and
+// core::iter::take if needed for padding
 pub struct BitUnpacked<I, T>
 where
     I: Iterator<Item = T>,
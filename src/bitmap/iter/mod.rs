@@ -1,6 +1,6 @@
 //! Iterators for bitmaps.
 
-use std::{
+use core::{
     iter::{Skip, Take},
     slice,
 };
@@ -11,6 +11,12 @@ pub use self::bit_packed::*;
 mod bit_unpacked;
 pub use self::bit_unpacked::*;
 
+mod runs;
+pub use self::runs::*;
+
+mod words;
+pub use self::words::*;
+
 /// An iterator over the bits in a Bitmap.
 ///
 /// This iterator returns boolean values that represent the bits stored in a
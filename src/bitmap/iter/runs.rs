@@ -0,0 +1,112 @@
+//! An iterator over runs of equal bits.
+
+use core::ops::Range;
+
+/// Returns the (at most) 64 bits starting at bit position `index` in `bytes`,
+/// using least-significant bit (LSB) numbering. Bits beyond the end of `bytes`
+/// are zero.
+#[inline]
+pub(super) fn load_word(bytes: &[u8], index: usize) -> u64 {
+    let start = index / 8;
+    if index % 8 == 0 {
+        // Byte-aligned words can be read directly.
+        if let Some(src) = bytes.get(start..start.saturating_add(8)) {
+            let mut word = [0; 8];
+            word.copy_from_slice(src);
+            return u64::from_le_bytes(word);
+        }
+    }
+    let end = bytes.len().min(start.saturating_add(9));
+    let mut word = [0; 16];
+    if let Some(src) = bytes.get(start..end) {
+        word[..src.len()].copy_from_slice(src);
+    }
+    let shifted = (u128::from_le_bytes(word) >> (index % 8)).to_le_bytes();
+    let mut low = [0; 8];
+    low.copy_from_slice(&shifted[..8]);
+    u64::from_le_bytes(low)
+}
+
+/// An iterator over maximal runs of equal bits in a bit-packed buffer.
+///
+/// Yields half-open ranges of bit indices together with the value of the bits
+/// in that range. Consecutive runs alternate between `true` and `false`. The
+/// bits are scanned a word at a time, so long runs are cheap to skip.
+#[derive(Clone, Debug)]
+pub struct BitmapRuns<'a> {
+    /// The bit-packed buffer.
+    bytes: &'a [u8],
+    /// The offset (in bits) of the first bit in the buffer.
+    offset: usize,
+    /// The number of bits.
+    len: usize,
+    /// The current position.
+    position: usize,
+}
+
+impl<'a> BitmapRuns<'a> {
+    /// Returns a new iterator over the runs in the `len` bits starting at bit
+    /// `offset` of `bytes`.
+    pub(crate) fn new(bytes: &'a [u8], offset: usize, len: usize) -> Self {
+        Self {
+            bytes,
+            offset,
+            len,
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for BitmapRuns<'_> {
+    type Item = (Range<usize>, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.position < self.len).then(|| {
+            let start = self.position;
+            let value = load_word(self.bytes, self.offset + start) & 1 == 1;
+            loop {
+                let word = load_word(self.bytes, self.offset + self.position);
+                let bits = if value {
+                    word.trailing_ones()
+                } else {
+                    word.trailing_zeros()
+                };
+                let remaining = self.len - self.position;
+                let run = usize::try_from(bits)
+                    .expect("run length fits in usize")
+                    .min(remaining);
+                self.position += run;
+                if run < 64 || run == remaining {
+                    break;
+                }
+            }
+            (start..self.position, value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs() {
+        assert_eq!(BitmapRuns::new(&[], 0, 0).count(), 0);
+        assert_eq!(
+            BitmapRuns::new(&[0b0011_0111], 0, 8).collect::<Vec<_>>(),
+            [(0..3, true), (3..4, false), (4..6, true), (6..8, false)]
+        );
+        assert_eq!(
+            BitmapRuns::new(&[0b0011_0111], 1, 4).collect::<Vec<_>>(),
+            [(0..2, true), (2..3, false), (3..4, true)]
+        );
+        assert_eq!(
+            BitmapRuns::new(&[0xff; 17], 3, 130).collect::<Vec<_>>(),
+            [(0..130, true)]
+        );
+        assert_eq!(
+            BitmapRuns::new(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0b10], 0, 80).collect::<Vec<_>>(),
+            [(0..73, false), (73..74, true), (74..80, false)]
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! An iterator over the words of a bitmap.
+
+use super::runs::load_word;
+
+/// Returns a mask with the `bits` least-significant bits set.
+#[inline]
+pub(crate) fn mask(bits: usize) -> u64 {
+    if bits >= 64 {
+        u64::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// Returns the number of set bits in `word`.
+#[inline]
+pub(crate) fn popcount(word: u64) -> usize {
+    usize::try_from(word.count_ones()).expect("popcount fits in usize")
+}
+
+/// An iterator over the bits in a bit-packed buffer, 64 bits at a time.
+///
+/// Yields words using least-significant bit (LSB) numbering, together with the
+/// number of meaningful bits in the word. This is 64 for all words except
+/// possibly the last one. Bits that are not meaningful (padding) are zero.
+#[derive(Clone, Debug)]
+pub struct BitmapWords<'a> {
+    /// The bit-packed buffer.
+    bytes: &'a [u8],
+    /// The offset (in bits) of the first bit in the buffer.
+    offset: usize,
+    /// The number of bits.
+    len: usize,
+    /// The current position.
+    position: usize,
+}
+
+impl<'a> BitmapWords<'a> {
+    /// Returns a new iterator over the words in the `len` bits starting at bit
+    /// `offset` of `bytes`.
+    pub(crate) fn new(bytes: &'a [u8], offset: usize, len: usize) -> Self {
+        Self {
+            bytes,
+            offset,
+            len,
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for BitmapWords<'_> {
+    type Item = (u64, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.position < self.len).then(|| {
+            let bits = (self.len - self.position).min(64);
+            let word = load_word(self.bytes, self.offset + self.position) & mask(bits);
+            self.position += bits;
+            (word, bits)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.position + 63) / 64;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BitmapWords<'_> {}
+
+/// An iterator over the bits in a bit-packed buffer, loaded 64 bits at a
+/// time via [`BitmapWords`].
+#[derive(Clone, Debug)]
+pub struct BitmapWordBits<'a> {
+    /// The words of the buffer.
+    words: BitmapWords<'a>,
+    /// The remaining bits of the current word, shifted to the lowest bits.
+    word: u64,
+    /// The number of remaining bits in the current word.
+    bits: usize,
+}
+
+impl<'a> BitmapWordBits<'a> {
+    /// Returns a new iterator over the bits of the `words`.
+    pub(crate) fn new(words: BitmapWords<'a>) -> Self {
+        Self {
+            words,
+            word: 0,
+            bits: 0,
+        }
+    }
+}
+
+impl Iterator for BitmapWordBits<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            (self.word, self.bits) = self.words.next()?;
+        }
+        let bit = self.word & 1 == 1;
+        self.word >>= 1;
+        self.bits -= 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bits + (self.words.len - self.words.position);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BitmapWordBits<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words() {
+        assert_eq!(BitmapWords::new(&[], 0, 0).count(), 0);
+        assert_eq!(
+            BitmapWords::new(&[0xff, 0xff], 0, 9).collect::<Vec<_>>(),
+            [(0x1ff, 9)]
+        );
+        assert_eq!(
+            BitmapWords::new(&[0b1010_1010, 0b1], 1, 8).collect::<Vec<_>>(),
+            [(0b1101_0101, 8)]
+        );
+        let words = BitmapWords::new(&[0xff; 17], 4, 130);
+        assert_eq!(words.len(), 3);
+        assert_eq!(
+            words.collect::<Vec<_>>(),
+            [(u64::MAX, 64), (u64::MAX, 64), (0b11, 2)]
+        );
+    }
+
+    #[test]
+    fn word_bits() {
+        let bytes = [0b1010_1010, 0xff, 0, 0xff, 0, 0xff, 0, 0xff, 0b1];
+        let bits = BitmapWordBits::new(BitmapWords::new(&bytes, 1, 68));
+        assert_eq!(bits.len(), 68);
+        assert!(bits.eq((1..69).map(|index| bytes[index / 8] & (1 << (index % 8)) != 0)));
+    }
+}
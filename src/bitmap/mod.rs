@@ -2,24 +2,33 @@
 
 use crate::{
     buffer::{Buffer, BufferMut, BufferRef, BufferRefMut, BufferType, VecBuffer},
-    Index, Length,
+    Index, Length, MemoryUsage, Validate, ValidationError,
 };
-use std::{
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    format,
+    rc::Rc,
+    sync::Arc,
+};
+use core::{
     any,
     borrow::Borrow,
     fmt::{Debug, Formatter, Result},
+    hash::{Hash, Hasher},
     ops,
 };
 
 mod iter;
+pub(crate) use self::iter::{mask, popcount, BitmapWordBits, BitmapWords};
 use self::iter::{BitPackedExt, BitUnpackedExt};
-pub use self::iter::{BitmapIntoIter, BitmapIter};
+pub use self::iter::{BitmapIntoIter, BitmapIter, BitmapRuns};
 
 mod fmt;
 use self::fmt::BitsDisplayExt;
 
 mod validity;
-pub use self::validity::ValidityBitmap;
+pub use self::validity::{combine_validities, combine_validity, ValidityBitmap};
 
 /// An immutable reference to a bitmap.
 pub trait BitmapRef {
@@ -36,9 +45,49 @@ pub trait BitmapRefMut: BitmapRef {
     fn bitmap_ref_mut(&mut self) -> &mut Bitmap<Self::Buffer>;
 }
 
+/// Implements [`BitmapRef`] for wrappers that dereference to a [`BitmapRef`].
+macro_rules! impl_bitmap_ref_deref {
+    ($($ty:ty),+) => {
+        $(
+            impl<T: BitmapRef + ?Sized> BitmapRef for $ty {
+                type Buffer = <T as BitmapRef>::Buffer;
+
+                fn bitmap_ref(&self) -> &Bitmap<Self::Buffer> {
+                    T::bitmap_ref(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_bitmap_ref_deref!(&T, Box<T>, Rc<T>, Arc<T>);
+
+impl<T: BitmapRef + ToOwned + ?Sized> BitmapRef for Cow<'_, T> {
+    type Buffer = <T as BitmapRef>::Buffer;
+
+    fn bitmap_ref(&self) -> &Bitmap<Self::Buffer> {
+        T::bitmap_ref(self)
+    }
+}
+
+/// Returns the number of bytes required to store `bits` bits.
+///
+/// Unlike `(bits + 7) / 8`, this does not overflow for lengths close to
+/// `usize::MAX`.
+pub(crate) const fn bytes_for_bits(bits: usize) -> usize {
+    bits / 8 + if bits % 8 == 0 { 0 } else { 1 }
+}
+
 /// A collection of bits.
 ///
 /// The validity bits are stored LSB-first in the bytes of the `Buffer`.
+///
+/// The bits in the last byte after the last bit are padding bits. Bitmaps
+/// that are built or mutated by this crate keep their padding bits unset.
+/// Bitmaps that are formed from raw parts, or whose buffer is mutated via
+/// [`BufferRefMut`], may have set padding bits, which [`Bitmap::normalize`]
+/// unsets. Reads never depend on the padding bits, and appending bits unsets
+/// them first.
 // todo(mb): implement ops
 pub struct Bitmap<Buffer: BufferType = VecBuffer> {
     /// The bits are stored in this buffer of bytes.
@@ -61,18 +110,131 @@ impl<Buffer: BufferType> BitmapRef for Bitmap<Buffer> {
 }
 
 impl<Buffer: BufferType> Bitmap<Buffer> {
+    /// Returns a new [`Bitmap`] from words yielded by a [`BitmapWords`]-like
+    /// iterator. Bits beyond the number of meaningful bits of a word are
+    /// cleared.
+    pub(crate) fn from_words(words: impl Iterator<Item = (u64, usize)>) -> Self
+    where
+        <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        let mut bits = 0;
+        let buffer = words
+            .flat_map(|(word, len)| {
+                bits = usize::checked_add(bits, len).expect("bitmap length overflow");
+                (word & mask(len))
+                    .to_le_bytes()
+                    .into_iter()
+                    .take(bytes_for_bits(len))
+            })
+            .collect();
+        Self {
+            buffer,
+            bits,
+            offset: 0,
+        }
+    }
+
+    /// Unsets the padding bits after the last bit in the last byte of the
+    /// buffer.
+    ///
+    /// This restores the padding invariant (see [`Bitmap`]) after mutating
+    /// the buffer via [`BufferRefMut`].
+    pub fn normalize(&mut self)
+    where
+        <Buffer as BufferType>::Buffer<u8>: BufferMut<u8>,
+    {
+        let free_bits = self.trailing_bits();
+        if free_bits != 0 {
+            let last_byte_index = self.byte_index(self.bits);
+            self.buffer.as_mut_slice()[last_byte_index] &= u8::MAX >> free_bits;
+        }
+    }
+
+    /// Sets this bitmap to the bitwise AND of this bitmap and `other`, in
+    /// place.
+    ///
+    /// The words of `other` are shifted to the offset of this bitmap and
+    /// combined with its buffer, so the bits before the offset and the padding
+    /// bits are not modified.
+    pub(crate) fn and_assign<OtherBuffer: BufferType>(&mut self, other: &Bitmap<OtherBuffer>)
+    where
+        <Buffer as BufferType>::Buffer<u8>: BufferMut<u8>,
+    {
+        debug_assert_eq!(self.bits, other.bits);
+        let offset = self.offset;
+        let bytes = self.buffer.as_mut_slice();
+        for (index, (word, bits)) in other.words().enumerate() {
+            let start = offset + index * 64;
+            let shift = start % 8;
+            // The bits outside of the `bits` bits at `start` are kept.
+            let keep = (u128::from(word | !mask(bits)) << shift)
+                | ((1 << shift) - 1)
+                | (u128::MAX << (shift + 64));
+            for (byte, keep_byte) in bytes[start / 8..]
+                .iter_mut()
+                .zip(keep.to_le_bytes())
+                .take((shift + bits + 7) / 8)
+            {
+                *byte &= keep_byte;
+            }
+        }
+    }
+
+    /// Returns `true` if the padding bits after the last bit are unset.
+    fn padding_is_unset(&self) -> bool {
+        let free_bits = self.trailing_bits();
+        free_bits == 0
+            || self
+                .buffer
+                .as_slice()
+                .get(self.byte_index(self.bits))
+                .map_or(true, |&last_byte| last_byte >> (8 - free_bits) == 0)
+    }
+
     /// Returns an iterator over the bits in this [`Bitmap`].
     pub fn iter(&self) -> BitmapIter<'_> {
         <&Self as IntoIterator>::into_iter(self)
     }
 
+    /// Returns an iterator over the runs of equal bits in this [`Bitmap`].
+    pub(crate) fn runs(&self) -> BitmapRuns<'_> {
+        BitmapRuns::new(self.buffer.as_slice(), self.offset, self.bits)
+    }
+
+    /// Returns an iterator over the bits in this [`Bitmap`], 64 bits at a
+    /// time.
+    pub(crate) fn words(&self) -> BitmapWords<'_> {
+        BitmapWords::new(self.buffer.as_slice(), self.offset, self.bits)
+    }
+
+    /// Returns an iterator over the bits in this [`Bitmap`], loaded 64 bits
+    /// at a time.
+    pub(crate) fn word_bits(&self) -> BitmapWordBits<'_> {
+        BitmapWordBits::new(self.words())
+    }
+
+    /// Returns the number of set bits in this [`Bitmap`].
+    pub fn count_ones(&self) -> usize {
+        self.words().map(|(word, _)| popcount(word)).sum()
+    }
+
+    /// Returns the number of unset bits in this [`Bitmap`].
+    pub fn count_zeros(&self) -> usize {
+        self.bits - self.count_ones()
+    }
+
     /// Forms a Bitmap from a buffer, a number of bits and an offset (in
     /// bits).
     ///
+    /// The bits are read LSB-first, starting at bit `offset` of the first
+    /// byte. The values of the bits in the buffer before `offset` and after
+    /// `offset + bits` are unspecified and never read.
+    ///
     /// # Safety
     ///
     /// Caller must ensure that the buffer contains enough bytes for the
-    /// specified number of bits including the offset.
+    /// specified number of bits including the offset, i.e. at least
+    /// `(offset + bits + 7) / 8` bytes.
     pub unsafe fn from_raw_parts(
         buffer: <Buffer as BufferType>::Buffer<u8>,
         bits: usize,
@@ -85,6 +247,45 @@ impl<Buffer: BufferType> Bitmap<Buffer> {
         }
     }
 
+    /// Forms a Bitmap from a buffer, a number of bits and an offset (in
+    /// bits), checking that the buffer contains enough bytes.
+    ///
+    /// See [`Bitmap::from_raw_parts`] for the layout of the bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError::BitmapBuffer`] when the buffer has fewer
+    /// than `(offset + bits + 7) / 8` bytes.
+    pub fn try_from_raw_parts(
+        buffer: <Buffer as BufferType>::Buffer<u8>,
+        bits: usize,
+        offset: usize,
+    ) -> core::result::Result<Self, ValidationError> {
+        let bitmap = Bitmap {
+            buffer,
+            bits,
+            offset,
+        };
+        bitmap.validate().map(|()| bitmap)
+    }
+
+    /// Forms a Bitmap from a buffer with packed bits and the number of bits
+    /// in the buffer.
+    ///
+    /// The length of the bitmap is `bits`, not the number of bits in the
+    /// buffer: bits after the first `bits` bits are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError::BitmapBuffer`] when the buffer has fewer
+    /// than `(bits + 7) / 8` bytes.
+    pub fn from_bytes(
+        bytes: <Buffer as BufferType>::Buffer<u8>,
+        bits: usize,
+    ) -> core::result::Result<Self, ValidationError> {
+        Self::try_from_raw_parts(bytes, bits, 0)
+    }
+
     /// Returns the bit at given bit index. Returns `None` when the index is out
     /// of bounds.
     #[inline]
@@ -148,7 +349,39 @@ impl<Buffer: BufferType> Bitmap<Buffer> {
         Self: FromIterator<bool>,
     {
         // TODO: optimize
-        std::iter::repeat(true).take(len).collect::<Self>()
+        core::iter::repeat(true).take(len).collect::<Self>()
+    }
+
+    /// Returns a new [`Bitmap`] with `len` bits all set to `value`.
+    ///
+    /// The buffer is filled a byte at a time, and the unused bits in the last
+    /// byte are cleared.
+    #[must_use]
+    pub fn new_repeated(value: bool, len: usize) -> Self
+    where
+        <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        let fill = if value { u8::MAX } else { 0 };
+        let trailing = len % 8;
+        let buffer = core::iter::repeat(fill)
+            .take(len / 8)
+            .chain((trailing != 0).then(|| fill & (u8::MAX >> (8 - trailing))))
+            .collect();
+        Self {
+            buffer,
+            bits: len,
+            offset: 0,
+        }
+    }
+
+    /// Returns a new [`Bitmap`] with `len` unset bits, i.e. a validity bitmap
+    /// where all positions are null.
+    #[must_use]
+    pub fn new_null(len: usize) -> Self
+    where
+        <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        Self::new_repeated(false, len)
     }
 }
 
@@ -216,26 +449,95 @@ where
     where
         I: IntoIterator<Item = T>,
     {
-        let mut additional_bits = 0;
-        let mut items = iter.into_iter().inspect(|_| {
-            additional_bits += 1;
-        });
-
-        let trailing_bits = self.trailing_bits();
-        if trailing_bits != 0 {
-            let last_byte_index = self.byte_index(self.bits);
-            let last_byte = &mut self.buffer.as_mut_slice()[last_byte_index];
-            for bit_position in 8 - trailing_bits..8 {
-                if let Some(x) = items.next() {
-                    if *x.borrow() {
-                        *last_byte |= 1 << bit_position;
-                    }
-                }
+        let mut items = iter.into_iter();
+        // The bits are buffered in a word before they are appended, so when
+        // the iterator panics only the bits of the current word are dropped,
+        // and the length of the bitmap matches its buffer.
+        loop {
+            let mut word = 0_u64;
+            let mut bits = 0;
+            for item in items.by_ref().take(64) {
+                word |= u64::from(*item.borrow()) << bits;
+                bits += 1;
+            }
+            if bits == 0 {
+                break;
+            }
+            self.extend_word(word, bits);
+            if bits < 64 {
+                break;
             }
         }
+    }
+}
 
-        self.buffer.extend(items.bit_packed());
-        self.bits += additional_bits;
+impl<Buffer: BufferType> Bitmap<Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Extend<u8>,
+{
+    /// Appends the `bits` least significant bits of `word`.
+    ///
+    /// The other bits of `word` must be unset. The padding bits of this bitmap
+    /// are unset before the bits are appended, and remain unset afterwards.
+    ///
+    /// Bytes of the buffer after the last bit (e.g. when the bitmap was
+    /// constructed from a larger buffer) are overwritten before the buffer is
+    /// extended, so the bits are always appended at bit index `len`.
+    pub(crate) fn extend_word(&mut self, word: u64, bits: usize) {
+        debug_assert!(bits <= 64 && word & !mask(bits) == 0);
+        let start = self.offset + self.bits;
+        let end = start.checked_add(bits).expect("bitmap length overflow");
+        let shift = start % 8;
+        let bytes = (u128::from(word) << shift).to_le_bytes();
+        let byte_count = (shift + bits + 7) / 8;
+        let tail = &mut self.buffer.as_mut_slice()[start / 8..];
+        let existing = tail.len().min(byte_count);
+        tail.iter_mut()
+            .zip(bytes)
+            .take(existing)
+            .enumerate()
+            .for_each(|(index, (slot, byte))| {
+                // Keep the bits before the start in the first byte.
+                let keep = if index == 0 {
+                    *slot & !(u8::MAX << shift)
+                } else {
+                    0
+                };
+                *slot = keep | byte;
+            });
+        self.buffer
+            .extend(bytes[existing..byte_count].iter().copied());
+        self.bits = end - self.offset;
+        debug_assert!(self.padding_is_unset());
+    }
+
+    /// Appends the bits of `other`, a word at a time.
+    ///
+    /// The words of `other` are read from its offset and shifted to the end
+    /// of this bitmap, so neither bitmap has to be byte-aligned.
+    pub(crate) fn extend_from_bitmap<OtherBuffer: BufferType>(
+        &mut self,
+        other: &Bitmap<OtherBuffer>,
+    ) {
+        other
+            .words()
+            .for_each(|(word, bits)| self.extend_word(word, bits));
+    }
+}
+
+impl Bitmap<VecBuffer> {
+    /// Reserves capacity for at least `additional` more bits.
+    ///
+    /// Like [`Vec::reserve`], this may reserve more space to avoid frequent
+    /// reallocations.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        let required = bytes_for_bits(
+            self.offset
+                .saturating_add(self.bits)
+                .saturating_add(additional),
+        );
+        self.buffer
+            .reserve(required.saturating_sub(self.buffer.len()));
     }
 }
 
@@ -264,6 +566,15 @@ where
     }
 }
 
+impl<Buffer: BufferType> Hash for Bitmap<Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Padding bits are zero in the yielded words, so they don't affect the
+        // hash.
+        state.write_usize(self.bits);
+        self.words().for_each(|(word, _)| state.write_u64(word));
+    }
+}
+
 impl<Buffer: BufferType> Index for Bitmap<Buffer> {
     type Item<'a> = bool
     where
@@ -336,12 +647,16 @@ impl<Buffer: BufferType> Length for Bitmap<Buffer> {
     }
 }
 
-impl<Buffer: BufferType> PartialEq for Bitmap<Buffer> {
-    fn eq(&self, other: &Self) -> bool {
+impl<Buffer: BufferType, OtherBuffer: BufferType> PartialEq<Bitmap<OtherBuffer>>
+    for Bitmap<Buffer>
+{
+    fn eq(&self, other: &Bitmap<OtherBuffer>) -> bool {
         self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
     }
 }
 
+impl<Buffer: BufferType> Eq for Bitmap<Buffer> {}
+
 impl<const N: usize, Buffer: BufferType> PartialEq<[bool; N]> for Bitmap<Buffer> {
     fn eq(&self, other: &[bool; N]) -> bool {
         self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == *b)
@@ -350,12 +665,40 @@ impl<const N: usize, Buffer: BufferType> PartialEq<[bool; N]> for Bitmap<Buffer>
 
 impl<Buffer: BufferType> ValidityBitmap for Bitmap<Buffer> {}
 
+impl<Buffer: BufferType> Validate for Bitmap<Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        let required = bytes_for_bits(self.offset.saturating_add(self.bits));
+        let actual = self.buffer.as_slice().len();
+        if actual < required {
+            Err(ValidationError::BitmapBuffer { required, actual })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<Buffer: BufferType> MemoryUsage for Bitmap<Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.buffer.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.buffer.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::buffer::{ArrayBuffer, BoxBuffer, SliceBuffer};
 
     use super::*;
-    use std::mem;
+    use std::{
+        mem,
+        panic::{self, AssertUnwindSafe},
+    };
 
     #[test]
     fn offset_byte_slice() {
@@ -384,6 +727,8 @@ mod tests {
         assert_eq!(bitmap.get(2), Some(false));
         assert_eq!((&bitmap).into_iter().filter(|x| !x).count(), 2);
         assert_eq!((&bitmap).into_iter().filter(|x| *x).count(), 1);
+        assert_eq!(bitmap.count_zeros(), 2);
+        assert_eq!(bitmap.count_ones(), 1);
         assert_eq!(
             (&bitmap).into_iter().collect::<Vec<_>>(),
             [false, true, false]
@@ -522,4 +867,186 @@ mod tests {
             mem::size_of::<Box<[u8]>>() + 2 * mem::size_of::<usize>()
         );
     }
+
+    #[test]
+    fn try_from_raw_parts() {
+        let bitmap =
+            Bitmap::<VecBuffer>::try_from_raw_parts(vec![0b1010_0000], 3, 4).expect("valid");
+        assert_eq!(bitmap, [false, true, false]);
+        assert_eq!(
+            Bitmap::<VecBuffer>::try_from_raw_parts(vec![0; 2], 17, 0).err(),
+            Some(ValidationError::BitmapBuffer {
+                required: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn from_bytes() {
+        let mut bitmap =
+            Bitmap::<VecBuffer>::from_bytes(vec![u8::MAX, u8::MAX, u8::MAX], 4).expect("valid");
+        assert_eq!(bitmap.len(), 4);
+        assert_eq!(bitmap.count_ones(), 4);
+        bitmap.extend([false; 6]);
+        bitmap.extend_word(0b1, 8);
+        assert_eq!(bitmap.len(), 18);
+        assert_eq!(bitmap.count_ones(), 5);
+        assert_eq!(bitmap.get(10), Some(true));
+        assert_eq!(bitmap.buffer.len(), 3);
+        assert!(Bitmap::<VecBuffer>::from_bytes(vec![0], 9).is_err());
+    }
+
+    #[test]
+    fn bytes_for_bits() {
+        assert_eq!(super::bytes_for_bits(0), 0);
+        assert_eq!(super::bytes_for_bits(1), 1);
+        assert_eq!(super::bytes_for_bits(8), 1);
+        assert_eq!(super::bytes_for_bits(9), 2);
+        assert_eq!(super::bytes_for_bits(usize::MAX), usize::MAX / 8 + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "bitmap length overflow")]
+    fn extend_overflow() {
+        // The buffer is not accessed before the length is checked.
+        let mut bitmap = Bitmap::<VecBuffer> {
+            buffer: Vec::new(),
+            bits: usize::MAX - 3,
+            offset: 2,
+        };
+        bitmap.extend([true; 2]);
+    }
+
+    #[test]
+    fn validate_overflow() {
+        let bitmap = Bitmap::<VecBuffer> {
+            buffer: vec![0; 2],
+            bits: usize::MAX,
+            offset: 4,
+        };
+        assert_eq!(
+            bitmap.validate(),
+            Err(ValidationError::BitmapBuffer {
+                required: usize::MAX / 8 + 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn extend_word() {
+        let mut bitmap =
+            Bitmap::<VecBuffer>::try_from_raw_parts(vec![0b0010_0000], 3, 4).expect("valid");
+        bitmap.extend_word(0b1_1011, 5);
+        assert_eq!(bitmap, [false, true, false, true, true, false, true, true]);
+        bitmap.extend_word(u64::MAX, 64);
+        bitmap.extend_word(0, 0);
+        assert_eq!(bitmap.len(), 72);
+        assert_eq!(bitmap.count_ones(), 69);
+        assert_eq!(bitmap.buffer.len(), 10);
+    }
+
+    #[test]
+    fn and_assign() {
+        let bits = (0..150).map(|index| index % 3 != 0).collect::<Vec<_>>();
+        let other = (0..150).map(|index| index % 5 != 0).collect::<Bitmap>();
+        // The bits are stored at offset 3, after 3 set bits.
+        let mut bitmap = [true; 3].iter().chain(&bits).collect::<Bitmap>();
+        bitmap.offset = 3;
+        bitmap.bits = 150;
+        bitmap.and_assign(&other);
+        assert!(bitmap
+            .iter()
+            .eq(bits.iter().zip(&other).map(|(&lhs, rhs)| lhs && rhs)));
+        assert_eq!(bitmap.buffer[0] & 0b111, 0b111);
+        assert!(bitmap.padding_is_unset());
+    }
+
+    #[test]
+    fn extend_panic() {
+        let mut bitmap = [true, false, true].into_iter().collect::<Bitmap>();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            bitmap.extend((0..).map(|index: usize| {
+                assert!(index != 100, "iterator panic");
+                index % 2 == 0
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(bitmap.len(), 67);
+        assert_eq!(bitmap.validate(), Ok(()));
+        assert_eq!(bitmap.count_ones(), 34);
+    }
+
+    #[test]
+    fn padding_bits() {
+        let clean = [true, false, true].into_iter().collect::<Bitmap>();
+        assert_eq!(clean.buffer, [0b101]);
+        assert!(clean.padding_is_unset());
+
+        // Set the padding bits through the buffer.
+        let mut dirty = clean.clone();
+        dirty.buffer_ref_mut()[0] |= 0b1111_1000;
+        assert!(!dirty.padding_is_unset());
+        assert_eq!(dirty.count_ones(), 2);
+        assert_eq!(dirty, clean);
+        assert_eq!(dirty.iter().collect::<Vec<_>>(), [true, false, true]);
+
+        // Appending unsets the padding bits first.
+        let mut extended = dirty.clone();
+        extended.extend([false, true]);
+        assert_eq!(extended, [true, false, true, false, true]);
+        assert_eq!(extended.buffer, [0b1_0101]);
+
+        dirty.normalize();
+        assert_eq!(dirty.buffer, clean.buffer);
+
+        // Padding bits of a bitmap formed from raw parts, with an offset.
+        let mut raw = Bitmap::<VecBuffer>::try_from_raw_parts(vec![0xff], 3, 2).expect("valid");
+        assert_eq!(raw.count_ones(), 3);
+        raw.extend([false]);
+        assert_eq!(raw, [true, true, true, false]);
+        assert_eq!(raw.count_ones(), 3);
+        raw.normalize();
+        assert_eq!(raw.buffer, [0b0001_1111]);
+
+        // Bitmaps that are built by the crate have unset padding bits.
+        for len in 0..20 {
+            assert!(Bitmap::<VecBuffer>::new_valid(len).padding_is_unset());
+        }
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!([true; 9].iter().collect::<Bitmap>().validate(), Ok(()));
+        // The buffer is too small for 6 bits at offset 3.
+        let bitmap = Bitmap::<VecBuffer> {
+            buffer: vec![0],
+            bits: 6,
+            offset: 3,
+        };
+        assert_eq!(
+            bitmap.validate(),
+            Err(ValidationError::BitmapBuffer {
+                required: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn new_repeated() {
+        for len in [0, 1, 7, 8, 9, 64, 100] {
+            let valid = Bitmap::<VecBuffer>::new_repeated(true, len);
+            assert_eq!(valid, std::iter::repeat(true).take(len).collect::<Bitmap>());
+            assert_eq!(
+                valid.buffer,
+                std::iter::repeat(true).take(len).collect::<Bitmap>().buffer
+            );
+            assert_eq!(valid.count_ones(), len);
+            let null = Bitmap::<VecBuffer>::new_null(len);
+            assert_eq!(null, std::iter::repeat(false).take(len).collect::<Bitmap>());
+            assert_eq!(null.count_zeros(), len);
+        }
+    }
 }
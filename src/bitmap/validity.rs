@@ -1,8 +1,19 @@
 //! Store validity information in a bitmap.
 
-use super::BitmapRef;
-use crate::length::Length;
-use std::ops::Not;
+use super::{iter::mask, Bitmap, BitmapRef, BitmapRefMut, BitmapRuns};
+use crate::{
+    array::BooleanArray,
+    buffer::{BufferMut, BufferType, VecBuffer},
+    length::Length,
+    LengthMismatch,
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    rc::Rc,
+    sync::Arc,
+};
+use core::ops::Not;
 
 /// A validity bitmap storing the validity information (null-ness) of elements
 /// in a collection in a bitmap.
@@ -28,10 +39,7 @@ pub trait ValidityBitmap: BitmapRef {
     /// Returns the number of null elements.
     #[inline]
     fn null_count(&self) -> usize {
-        self.bitmap_ref()
-            .len()
-            .checked_sub(self.valid_count())
-            .expect("null count underflow")
+        self.bitmap_ref().count_zeros()
     }
 
     /// Returns `true` if the element at position `index` is valid.
@@ -58,36 +66,440 @@ pub trait ValidityBitmap: BitmapRef {
     /// Returns the number of valid elements.
     #[inline]
     fn valid_count(&self) -> usize {
-        (0..self.bitmap_ref().len())
-            .filter(|&index|
-                // Safety:
-                // - The index is always in range by iterating over the range
-                //   with length upper bound.
-                unsafe { self.is_valid_unchecked(index) })
-            .count()
+        self.bitmap_ref().count_ones()
     }
 
     /// Returns `true` if the array contains at least one null element.
     #[inline]
     fn any_null(&self) -> bool {
-        self.null_count() > 0
+        !self.all_valid()
     }
 
     /// Returns `true` if all the elements are null.
+    ///
+    /// This compares the bitmap a word at a time and returns at the first
+    /// word with a valid element.
     #[inline]
     fn all_null(&self) -> bool {
-        self.null_count() == self.bitmap_ref().len()
+        self.bitmap_ref().words().all(|(word, _)| word == 0)
     }
 
     /// Returns `true` if the array contains at least one valid element.
     #[inline]
     fn any_valid(&self) -> bool {
-        self.valid_count() > 0
+        !self.all_null()
     }
 
     /// Returns `true` if all the elements are valid.
+    ///
+    /// This compares the bitmap a word at a time, with the padding bits of
+    /// the final word masked, and returns at the first word with a null.
     #[inline]
     fn all_valid(&self) -> bool {
-        self.valid_count() == self.bitmap_ref().len()
+        self.bitmap_ref()
+            .words()
+            .all(|(word, bits)| word == mask(bits))
+    }
+
+    /// Returns an iterator over the maximal runs of consecutive valid and
+    /// null elements.
+    ///
+    /// Yields half-open ranges of element indices, together with `true` for
+    /// runs of valid elements and `false` for runs of nulls. Consecutive runs
+    /// alternate between valid and null. The bitmap is scanned a word at a
+    /// time, so long runs are cheap to skip.
+    #[inline]
+    fn valid_runs(&self) -> BitmapRuns<'_> {
+        self.bitmap_ref().runs()
+    }
+
+    /// Returns a [`BooleanArray`] that is `true` for the null elements.
+    #[inline]
+    fn is_null_mask(&self) -> BooleanArray<false> {
+        BooleanArray(Bitmap::from_words(
+            self.bitmap_ref().words().map(|(word, bits)| (!word, bits)),
+        ))
+    }
+
+    /// Returns a [`BooleanArray`] that is `true` for the valid elements.
+    #[inline]
+    fn is_valid_mask(&self) -> BooleanArray<false> {
+        BooleanArray(Bitmap::from_words(self.bitmap_ref().words()))
+    }
+
+    /// Replaces the validity bitmap with the validity yielded by `validity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatch`] when the number of items yielded by
+    /// `validity` is not equal to the number of elements. The validity is
+    /// unchanged in that case.
+    fn set_validity<I: IntoIterator<Item = bool>>(
+        &mut self,
+        validity: I,
+    ) -> Result<(), LengthMismatch>
+    where
+        Self: BitmapRefMut,
+        Bitmap<Self::Buffer>: FromIterator<bool>,
+    {
+        let bitmap = validity.into_iter().collect::<Bitmap<Self::Buffer>>();
+        check_validity_len(self.bitmap_ref().len(), bitmap.len())?;
+        *self.bitmap_ref_mut() = bitmap;
+        Ok(())
+    }
+
+    /// Replaces the validity bitmap with a copy of `validity`, for example the
+    /// values of a [`BooleanArray`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatch`] when the length of `validity` is not equal
+    /// to the number of elements. The validity is unchanged in that case.
+    fn set_validity_bitmap<OtherBuffer: BufferType>(
+        &mut self,
+        validity: &Bitmap<OtherBuffer>,
+    ) -> Result<(), LengthMismatch>
+    where
+        Self: BitmapRefMut,
+        <Self::Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        check_validity_len(self.bitmap_ref().len(), validity.len())?;
+        *self.bitmap_ref_mut() = Bitmap::from_words(validity.words());
+        Ok(())
+    }
+
+    /// Marks the elements for which `mask` is unset as null, i.e. ANDs
+    /// `mask` into the validity bitmap in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `mask` is not equal to the number of elements.
+    fn mask<OtherBuffer: BufferType>(&mut self, mask: &Bitmap<OtherBuffer>)
+    where
+        Self: BitmapRefMut,
+        <Self::Buffer as BufferType>::Buffer<u8>: BufferMut<u8>,
+    {
+        if let Err(mismatch) = check_validity_len(self.bitmap_ref().len(), mask.len()) {
+            panic!("mask length mismatch: {mismatch}");
+        }
+        self.bitmap_ref_mut().and_assign(mask);
+    }
+}
+
+impl<T: ValidityBitmap + ?Sized> ValidityBitmap for &T {}
+
+impl<T: ValidityBitmap + ?Sized> ValidityBitmap for Box<T> {}
+
+impl<T: ValidityBitmap + ?Sized> ValidityBitmap for Rc<T> {}
+
+impl<T: ValidityBitmap + ?Sized> ValidityBitmap for Arc<T> {}
+
+impl<T: ValidityBitmap + ToOwned + ?Sized> ValidityBitmap for Cow<'_, T> {}
+
+/// Returns the combined validity of two validity bitmaps, i.e. their bitwise
+/// AND, where `None` means that all elements are valid.
+///
+/// Returns `None` when both bitmaps are `None`, and a copy of the other bitmap
+/// when one of them is `None`. The returned bitmap has no offset and its
+/// padding bits are unset.
+///
+/// # Panics
+///
+/// Panics if the lengths of the bitmaps are not equal.
+#[must_use]
+pub fn combine_validity<A: BufferType, B: BufferType>(
+    a: Option<&Bitmap<A>>,
+    b: Option<&Bitmap<B>>,
+) -> Option<Bitmap<VecBuffer>> {
+    match (a, b) {
+        (Some(left), Some(right)) => {
+            assert_eq!(left.len(), right.len(), "validity length mismatch");
+            Some(Bitmap::from_words(left.words().zip(right.words()).map(
+                |((left_word, bits), (right_word, _))| (left_word & right_word, bits),
+            )))
+        }
+        (Some(bitmap), None) => Some(Bitmap::from_words(bitmap.words())),
+        (None, Some(bitmap)) => Some(Bitmap::from_words(bitmap.words())),
+        (None, None) => None,
+    }
+}
+
+/// Returns the combined validity of the given validity bitmaps, i.e. their
+/// bitwise AND, where `None` means that all elements are valid.
+///
+/// Returns `None` when all bitmaps are `None`. See [`combine_validity`].
+///
+/// # Panics
+///
+/// Panics if the lengths of the bitmaps are not equal.
+#[must_use]
+pub fn combine_validities<Buffer: BufferType>(
+    validities: &[Option<&Bitmap<Buffer>>],
+) -> Option<Bitmap<VecBuffer>> {
+    validities
+        .iter()
+        .flatten()
+        .fold(None, |combined, validity| {
+            combine_validity(combined.as_ref(), Some(*validity))
+        })
+}
+
+/// Returns a [`LengthMismatch`] error when the length of a new validity bitmap
+/// differs from the number of elements.
+fn check_validity_len(expected: usize, actual: usize) -> Result<(), LengthMismatch> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(LengthMismatch { expected, actual })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proptest::bitmap;
+    use crate::{
+        array::{BooleanArray, Uint8Array},
+        buffer::BufferRef,
+    };
+    use proptest::{prop_assert_eq, proptest, strategy::Strategy};
+    use std::iter;
+
+    #[test]
+    fn set_validity() {
+        let mut array: Uint8Array<true> = Uint8Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(
+            array.set_validity([true, true]),
+            Err(LengthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.set_validity([false, true, true]), Ok(()));
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [None, Some(0), Some(3)]
+        );
+    }
+
+    #[test]
+    fn set_validity_bitmap() {
+        let mut array: Uint8Array<true> = Uint8Array::from(vec![Some(1), None, Some(3)]);
+        let mask = [false, true, true, false]
+            .into_iter()
+            .collect::<BooleanArray>();
+        assert!(array.set_validity_bitmap(mask.values()).is_err());
+
+        // A sliced bitmap with an offset is copied without the offset.
+        let sliced =
+            Bitmap::<crate::buffer::VecBuffer>::try_from_raw_parts(vec![0b1111_0110], 3, 1)
+                .expect("valid bitmap");
+        array.set_validity_bitmap(&sliced).expect("equal lengths");
+        assert_eq!(array.bitmap_ref().buffer_ref(), &[0b0000_0011]);
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [Some(1), Some(0), None]
+        );
+    }
+
+    #[test]
+    fn mask() {
+        let mut array: Uint8Array<true> = Uint8Array::from(vec![
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            None,
+            Some(6),
+            Some(7),
+            Some(8),
+            Some(9),
+        ]);
+        let mask = [true, true, false, true, false, true, true, true, false]
+            .into_iter()
+            .collect::<Bitmap>();
+        array.mask(&mask);
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [
+                Some(1),
+                None,
+                None,
+                Some(4),
+                None,
+                Some(6),
+                Some(7),
+                Some(8),
+                None
+            ]
+        );
+
+        // AND semantics: a set mask bit does not make a null valid.
+        let mut nulls: Uint8Array<true> = Uint8Array::from(vec![None, Some(2)]);
+        nulls.mask(&[true, true].into_iter().collect::<Bitmap>());
+        assert_eq!(nulls.null_count(), 1);
+        assert_eq!(nulls.bitmap_ref().buffer_ref(), &[0b0000_0010]);
+    }
+
+    proptest! {
+        #[test]
+        fn counts(
+            source in (0..4_usize).prop_flat_map(|index| bitmap(0..=257, [0., 0.5, 0.99, 1.][index])),
+            offset in 0..16_usize,
+        ) {
+            let bits = source.iter().collect::<Vec<_>>();
+            let len = bits.len();
+            // Set the bits before the offset and the padding bits, which must
+            // not affect the results.
+            let mut shifted = iter::repeat(true)
+                .take(offset)
+                .chain(bits.iter().copied())
+                .chain(iter::repeat(true).take(7))
+                .collect::<Bitmap>();
+            shifted.offset = offset;
+            shifted.bits = len;
+
+            let valid = bits.iter().filter(|&&valid| valid).count();
+            prop_assert_eq!(shifted.valid_count(), valid);
+            prop_assert_eq!(shifted.null_count(), len - valid);
+            prop_assert_eq!(shifted.all_valid(), valid == len);
+            prop_assert_eq!(shifted.any_null(), valid != len);
+            prop_assert_eq!(shifted.all_null(), valid == 0);
+            prop_assert_eq!(shifted.any_valid(), valid != 0);
+        }
+
+        #[test]
+        fn combine_validity(
+            (left, right) in (0..200_usize).prop_flat_map(|len| (bitmap(len, 0.7), bitmap(len, 0.7))),
+            offset in 0..8_usize,
+        ) {
+            let a = left.iter().collect::<Vec<_>>();
+            let b = right.iter().collect::<Vec<_>>();
+            let len = a.len();
+            let expected = a.iter().zip(&b).map(|(x, y)| *x && *y).collect::<Vec<_>>();
+
+            // Store `a` at a bit offset to exercise unaligned words.
+            let a_bitmap = Bitmap::<VecBuffer>::try_from_raw_parts(
+                iter::repeat(false)
+                    .take(offset)
+                    .chain(a.iter().copied())
+                    .collect::<Bitmap>()
+                    .buffer,
+                len,
+                offset,
+            )
+            .expect("valid bitmap");
+
+            let combined =
+                super::combine_validity(Some(&a_bitmap), Some(&right)).expect("combined bitmap");
+            prop_assert_eq!(combined.iter().collect::<Vec<_>>(), expected);
+            prop_assert_eq!(combined.offset, 0);
+            if len % 8 != 0 {
+                let last = combined.buffer.last().copied().unwrap_or_default();
+                prop_assert_eq!(last >> (len % 8), 0, "padding bits must be unset");
+            }
+
+            let copied = super::combine_validity::<VecBuffer, VecBuffer>(Some(&a_bitmap), None)
+                .expect("copied bitmap");
+            prop_assert_eq!(copied.iter().collect::<Vec<_>>(), a);
+            prop_assert_eq!(
+                combine_validities(&[None, Some(&right), None, Some(&right)])
+                    .map(|bitmap| bitmap.iter().collect::<Vec<_>>()),
+                Some(b)
+            );
+        }
+    }
+
+    #[test]
+    fn combine_validity_none() {
+        assert!(super::combine_validity::<VecBuffer, VecBuffer>(None, None).is_none());
+        assert!(combine_validities::<VecBuffer>(&[None, None]).is_none());
+        assert!(combine_validities::<VecBuffer>(&[]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "validity length mismatch")]
+    fn combine_validity_length_mismatch() {
+        let _ = super::combine_validity(
+            Some(&[true].into_iter().collect::<Bitmap>()),
+            Some(&[true, false].into_iter().collect::<Bitmap>()),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "mask length mismatch")]
+    fn mask_length_mismatch() {
+        let mut array: Uint8Array<true> = Uint8Array::from(vec![None, Some(2)]);
+        array.mask(&[true].into_iter().collect::<Bitmap>());
+    }
+
+    #[test]
+    fn wrappers() {
+        /// Returns the length and null count of a generic array.
+        fn summary<A: Length + ValidityBitmap>(array: A) -> (usize, usize) {
+            (array.len(), array.null_count())
+        }
+
+        let array: Uint8Array<true> = Uint8Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(summary(&array), (3, 1));
+        assert_eq!(summary(Arc::new(array.clone())), (3, 1));
+        assert_eq!(summary(Box::new(array.clone())), (3, 1));
+        assert_eq!(summary(Rc::new(array.clone())), (3, 1));
+        assert_eq!(summary(Cow::Borrowed(&array)), (3, 1));
+        assert_eq!(
+            summary(Cow::<Uint8Array<true>>::Owned(array.clone())),
+            (3, 1)
+        );
+        assert_eq!(summary(&&array), (3, 1));
+        assert!(Arc::new(array).is_null(1).expect("in bounds"));
+    }
+
+    #[test]
+    fn valid_runs() {
+        let all_valid = Bitmap::<VecBuffer>::new_repeated(true, 130);
+        assert_eq!(all_valid.valid_runs().collect::<Vec<_>>(), [(0..130, true)]);
+
+        let alternating = (0..100).map(|index| index % 2 == 0).collect::<Bitmap>();
+        assert_eq!(alternating.valid_runs().count(), 100);
+        assert!(alternating
+            .valid_runs()
+            .enumerate()
+            .all(
+                |(index, (range, valid))| range == (index..index + 1) && valid == (index % 2 == 0)
+            ));
+
+        // Runs spanning word boundaries, read from a bit offset.
+        let bits = iter::repeat(false)
+            .take(60)
+            .chain(iter::repeat(true).take(70))
+            .chain(iter::repeat(false).take(5))
+            .collect::<Vec<_>>();
+        let shifted = Bitmap::<VecBuffer>::try_from_raw_parts(
+            iter::repeat(true)
+                .take(3)
+                .chain(bits.iter().copied())
+                .collect::<Bitmap>()
+                .buffer,
+            bits.len(),
+            3,
+        )
+        .expect("valid bitmap");
+        assert_eq!(
+            shifted.valid_runs().collect::<Vec<_>>(),
+            [(0..60, false), (60..130, true), (130..135, false)]
+        );
+        let reconstructed = shifted
+            .valid_runs()
+            .flat_map(|(range, valid)| iter::repeat(valid).take(range.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(reconstructed, bits);
+
+        let array: Uint8Array<true> = Uint8Array::from(vec![Some(1), None, None, Some(4)]);
+        assert_eq!(
+            array.valid_runs().collect::<Vec<_>>(),
+            [(0..1, true), (1..3, false), (3..4, true)]
+        );
+        assert_eq!(Bitmap::<VecBuffer>::default().valid_runs().count(), 0);
     }
 }
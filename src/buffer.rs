@@ -1,7 +1,10 @@
 //! Traits for memory buffers.
 
 use crate::{FixedSize, Index, Length};
-use std::{marker::PhantomData, mem, rc::Rc, slice, sync::Arc};
+use alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec};
+use core::marker::PhantomData;
+#[cfg(not(feature = "bytemuck"))]
+use core::{mem, slice};
 
 /// A memory buffer type constructor for Arrow data.
 ///
@@ -18,6 +21,33 @@ pub trait BufferType {
     type Buffer<T: FixedSize>: Buffer<T>;
 }
 
+/// A [`BufferType`] with buffers that can grow after construction.
+///
+/// Arrays only implement [`Extend`] when their buffer type implements this
+/// trait. Buffer types with a fixed-size allocation, like [`BoxBuffer`], and
+/// read-only buffer types, like [`SliceBuffer`] and [`ArcBuffer`], don't,
+/// while their buffers can still be read (and, for [`BoxBuffer`], mutated in
+/// place).
+///
+/// ```compile_fail
+/// use narrow::{array::Uint8Array, buffer::BoxBuffer};
+///
+/// let mut array = [1, 2].into_iter().collect::<Uint8Array<false, BoxBuffer>>();
+/// array.extend([3]);
+/// ```
+///
+/// ```compile_fail
+/// use narrow::{array::BooleanArray, bitmap::Bitmap, buffer::ArcBuffer};
+///
+/// let mut array = BooleanArray::<true, ArcBuffer>::from_bitmaps(
+///     [true].into_iter().collect::<Bitmap<ArcBuffer>>(),
+///     [true].into_iter().collect(),
+/// )
+/// .expect("same length");
+/// array.extend([None]);
+/// ```
+pub trait GrowableBuffer: BufferType {}
+
 /// An immutable reference to a buffer.
 ///
 /// This can be used to provide immutable access to an internal buffer.
@@ -47,16 +77,7 @@ pub trait Buffer<T: FixedSize>: Index + Length {
 
     /// Returns the contents of the entire buffer as a byte slice.
     fn as_bytes(&self) -> &[u8] {
-        // Safety:
-        // - The pointer returned by slice::as_ptr (via Borrow) points to slice::len()
-        //   consecutive properly initialized values of type T, with size_of::<T> bytes
-        //   per element.
-        unsafe {
-            slice::from_raw_parts(
-                self.as_slice().as_ptr().cast(),
-                mem::size_of_val(self.as_slice()),
-            )
-        }
+        bytes(self.as_slice())
     }
 }
 
@@ -67,19 +88,42 @@ pub trait BufferMut<T: FixedSize>: Buffer<T> {
 
     /// Returns the contents of the entire buffer as a mutable byte slice.
     fn as_mut_bytes(&mut self) -> &mut [u8] {
-        // Safety:
-        // - The pointer returned by slice::as_mut_ptr (via Borrow) points to slice::len()
-        //   consecutive properly initialized values of type T, with size_of::<T> bytes
-        //   per element.
-        unsafe {
-            slice::from_raw_parts_mut(
-                self.as_mut_slice().as_mut_ptr().cast(),
-                mem::size_of_val(self.as_slice()),
-            )
-        }
+        bytes_mut(self.as_mut_slice())
     }
 }
 
+/// Returns the given items as a byte slice.
+#[cfg(feature = "bytemuck")]
+fn bytes<T: FixedSize>(items: &[T]) -> &[u8] {
+    bytemuck::cast_slice(items)
+}
+
+/// Returns the given items as a byte slice.
+#[cfg(not(feature = "bytemuck"))]
+fn bytes<T: FixedSize>(items: &[T]) -> &[u8] {
+    // Safety:
+    // - The pointer returned by slice::as_ptr points to slice::len()
+    //   consecutive properly initialized values of type T, with size_of::<T> bytes
+    //   per element.
+    unsafe { slice::from_raw_parts(items.as_ptr().cast(), mem::size_of_val(items)) }
+}
+
+/// Returns the given items as a mutable byte slice.
+#[cfg(feature = "bytemuck")]
+fn bytes_mut<T: FixedSize>(items: &mut [T]) -> &mut [u8] {
+    bytemuck::cast_slice_mut(items)
+}
+
+/// Returns the given items as a mutable byte slice.
+#[cfg(not(feature = "bytemuck"))]
+fn bytes_mut<T: FixedSize>(items: &mut [T]) -> &mut [u8] {
+    // Safety:
+    // - The pointer returned by slice::as_mut_ptr points to slice::len()
+    //   consecutive properly initialized values of type T, with size_of::<T> bytes
+    //   per element.
+    unsafe { slice::from_raw_parts_mut(items.as_mut_ptr().cast(), mem::size_of_val(items)) }
+}
+
 /// A [`BufferType`] for a single item.
 #[derive(Clone, Copy, Debug)]
 pub struct SingleBuffer;
@@ -124,7 +168,7 @@ impl<T: FixedSize, const M: usize, const N: usize> Buffer<T> for [[T; M]; N] {
     fn as_slice(&self) -> &[T] {
         // self.flatten() is nightly
         // SAFETY: `[T]` is layout-identical to `[[T; M]; N]`
-        unsafe { std::slice::from_raw_parts(self.as_ptr().cast(), M * N) }
+        unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), M * N) }
     }
 }
 
@@ -132,7 +176,7 @@ impl<T: FixedSize, const M: usize, const N: usize> BufferMut<T> for [[T; M]; N]
     fn as_mut_slice(&mut self) -> &mut [T] {
         // self.flatten() is nightly
         // SAFETY: `[T]` is layout-identical to `[[T; M]; N]`
-        unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), M * N) }
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), M * N) }
     }
 }
 
@@ -188,7 +232,7 @@ impl<T: FixedSize, const N: usize> Buffer<T> for &[[T; N]] {
     fn as_slice(&self) -> &[T] {
         // self.flatten() is nightly
         // SAFETY: `[T]` is layout-identical to `[T; N]`
-        unsafe { std::slice::from_raw_parts(self.as_ptr().cast(), <[[T; N]]>::len(self) * N) }
+        unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), <[[T; N]]>::len(self) * N) }
     }
 }
 
@@ -206,7 +250,7 @@ impl<T: FixedSize, const N: usize> Buffer<T> for &mut [[T; N]] {
     fn as_slice(&self) -> &[T] {
         // self.flatten() is nightly
         // SAFETY: `[T]` is layout-identical to `[T; N]`
-        unsafe { std::slice::from_raw_parts(self.as_ptr().cast(), <[[T; N]]>::len(self) * N) }
+        unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), <[[T; N]]>::len(self) * N) }
     }
 }
 
@@ -215,7 +259,7 @@ impl<T: FixedSize, const N: usize> BufferMut<T> for &mut [[T; N]] {
         // self.flatten() is nightly
         // SAFETY: `[T]` is layout-identical to `[T; N]`
         unsafe {
-            std::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), <[[T; N]]>::len(self) * N)
+            core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), <[[T; N]]>::len(self) * N)
         }
     }
 }
@@ -230,6 +274,8 @@ impl BufferType for VecBuffer {
     type Buffer<T: FixedSize> = Vec<T>;
 }
 
+impl GrowableBuffer for VecBuffer {}
+
 impl<T: FixedSize> Buffer<T> for Vec<T> {
     fn as_slice(&self) -> &[T] {
         self.as_slice()
@@ -256,7 +302,7 @@ impl<T: FixedSize, const N: usize> Buffer<T> for Vec<[T; N]> {
     fn as_slice(&self) -> &[T] {
         // self.flatten() is nightly
         // SAFETY: `[T]` is layout-identical to `[T; N]`
-        unsafe { std::slice::from_raw_parts(self.as_ptr().cast(), Vec::<[T; N]>::len(self) * N) }
+        unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), Vec::<[T; N]>::len(self) * N) }
     }
 }
 
@@ -265,7 +311,7 @@ impl<T: FixedSize, const N: usize> BufferMut<T> for Vec<[T; N]> {
         // self.flatten() is nightly
         // SAFETY: `[T]` is layout-identical to `[T; N]`
         unsafe {
-            std::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), Vec::<[T; N]>::len(self) * N)
+            core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), Vec::<[T; N]>::len(self) * N)
         }
     }
 }
@@ -389,8 +435,8 @@ mod tests {
         let slice: <SliceBuffer as BufferType>::Buffer<u16> = &[1234, 4321];
         assert_eq!(slice.as_bytes(), &[210, 4, 225, 16]);
         let mut slice_mut: <SliceMutBuffer as BufferType>::Buffer<u16> = &mut [4321, 1234];
-        slice_mut.as_mut_slice()[0] = 1234;
-        slice_mut.as_mut_slice()[1] = 4321;
+        BufferMut::as_mut_slice(&mut slice_mut)[0] = 1234;
+        BufferMut::as_mut_slice(&mut slice_mut)[1] = 4321;
         assert_eq!(slice, slice_mut);
     }
 
@@ -400,7 +446,7 @@ mod tests {
         assert_eq!(<_ as Buffer<u32>>::as_slice(&slice_array), [1, 2, 3, 4]);
         let mut slice_array_mut: <SliceArrayMutBuffer<3> as BufferType>::Buffer<u8> =
             &mut [[1, 2, 3], [4, 5, 6]];
-        slice_array_mut.as_mut_slice()[0] = 0;
+        BufferMut::as_mut_slice(&mut slice_array_mut)[0] = 0;
         assert_eq!(
             <_ as Buffer<u8>>::as_bytes(&slice_array_mut),
             &[0, 2, 3, 4, 5, 6]
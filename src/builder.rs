@@ -0,0 +1,818 @@
+//! Builders for arrays.
+
+use crate::{
+    array::{BooleanArray, ChunkPolicy, FixedSizePrimitiveArray, NullArray, Nulls},
+    bitmap::{bytes_for_bits, Bitmap},
+    buffer::{BufferType, VecBuffer},
+    nullable::Nullable,
+    FixedSize, Length, MemoryUsage,
+};
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use core::{
+    any::Any,
+    fmt::{self, Debug, Formatter},
+    iter, mem,
+};
+
+/// Dynamically typed array builders.
+///
+/// This allows driving builders for different array types uniformly, for
+/// example when the types of the columns are only known at runtime. Values are
+/// appended after downcasting via [`ArrayBuilder::as_any_mut`].
+pub trait ArrayBuilder: Length {
+    /// Appends a null.
+    fn append_null(&mut self);
+
+    /// Returns the array with the appended values as [`Any`] and resets this
+    /// builder.
+    fn finish_any(&mut self) -> Box<dyn Any>;
+
+    /// Returns this builder as a mutable [`Any`], to allow downcasting to the
+    /// concrete builder type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+/// Returns a new, empty [`Bitmap`] with room for `capacity` bits.
+fn bitmap_with_capacity(capacity: usize) -> Bitmap {
+    Bitmap {
+        buffer: Vec::with_capacity(bytes_for_bits(capacity)),
+        bits: 0,
+        offset: 0,
+    }
+}
+
+/// Builds the validity bitmap of the values appended to a builder.
+///
+/// The bitmap is only allocated when the first null is appended.
+#[derive(Debug, Default)]
+struct ValidityBuilder {
+    /// The validity bitmap, or `None` when all values are valid.
+    bitmap: Option<Bitmap>,
+    /// The number of appended values.
+    len: usize,
+    /// The initial capacity of the builder, used when the bitmap is allocated.
+    capacity: usize,
+}
+
+impl ValidityBuilder {
+    /// Returns a new [`ValidityBuilder`] for `capacity` values.
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bitmap: None,
+            len: 0,
+            capacity,
+        }
+    }
+
+    /// Appends `additional` values with the given validity.
+    fn append_n(&mut self, additional: usize, valid: bool) {
+        match self.bitmap.as_mut() {
+            Some(bitmap) => bitmap.extend(iter::repeat(valid).take(additional)),
+            None if valid => {}
+            None => {
+                let mut bitmap = bitmap_with_capacity(self.capacity.max(self.len + additional));
+                bitmap.extend(iter::repeat(true).take(self.len));
+                bitmap.extend(iter::repeat(false).take(additional));
+                self.bitmap = Some(bitmap);
+            }
+        }
+        self.len += additional;
+    }
+
+    /// Returns the validity bitmap and resets this builder.
+    fn finish(&mut self) -> Bitmap {
+        let len = mem::take(&mut self.len);
+        self.bitmap.take().unwrap_or_else(|| Bitmap::new_valid(len))
+    }
+}
+
+/// A builder for nullable [`FixedSizePrimitiveArray`]s.
+#[derive(Debug)]
+pub struct PrimitiveBuilder<T: FixedSize> {
+    /// The appended values.
+    values: Vec<T>,
+    /// The validity of the appended values.
+    validity: ValidityBuilder,
+}
+
+impl<T: FixedSize> PrimitiveBuilder<T> {
+    /// Returns a new, empty [`PrimitiveBuilder`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Returns a new, empty [`PrimitiveBuilder`] with room for `capacity`
+    /// values.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            validity: ValidityBuilder::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of values this builder can hold without
+    /// reallocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    /// Appends a valid value.
+    pub fn append_value(&mut self, value: T) {
+        self.values.push(value);
+        self.validity.append_n(1, true);
+    }
+
+    /// Appends a null.
+    #[allow(clippy::same_name_method)]
+    pub fn append_null(&mut self)
+    where
+        T: Default,
+    {
+        self.values.push(T::default());
+        self.validity.append_n(1, false);
+    }
+
+    /// Appends a valid value for `Some` and a null for `None`.
+    pub fn append_option(&mut self, value: Option<T>)
+    where
+        T: Default,
+    {
+        match value {
+            Some(item) => self.append_value(item),
+            None => self.append_null(),
+        }
+    }
+
+    /// Appends a slice of valid values.
+    pub fn append_slice(&mut self, values: &[T]) {
+        self.values.extend_from_slice(values);
+        self.validity.append_n(values.len(), true);
+    }
+
+    /// Returns an array with the appended values and resets this builder.
+    pub fn finish(&mut self) -> FixedSizePrimitiveArray<T, true> {
+        FixedSizePrimitiveArray(Nullable {
+            data: mem::take(&mut self.values),
+            validity: self.validity.finish(),
+        })
+    }
+}
+
+impl<T: FixedSize> Default for PrimitiveBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FixedSize + Default> ArrayBuilder for PrimitiveBuilder<T> {
+    fn append_null(&mut self) {
+        PrimitiveBuilder::append_null(self);
+    }
+
+    fn finish_any(&mut self) -> Box<dyn Any> {
+        Box::new(self.finish())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl<T: FixedSize> Length for PrimitiveBuilder<T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+/// A builder for nullable [`BooleanArray`]s.
+#[derive(Debug)]
+pub struct BooleanBuilder {
+    /// The appended values.
+    values: Bitmap,
+    /// The validity of the appended values.
+    validity: ValidityBuilder,
+}
+
+impl BooleanBuilder {
+    /// Returns a new, empty [`BooleanBuilder`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Returns a new, empty [`BooleanBuilder`] with room for `capacity`
+    /// values.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: bitmap_with_capacity(capacity),
+            validity: ValidityBuilder::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a valid value.
+    pub fn append_value(&mut self, value: bool) {
+        self.values.extend(iter::once(value));
+        self.validity.append_n(1, true);
+    }
+
+    /// Appends a null.
+    #[allow(clippy::same_name_method)]
+    pub fn append_null(&mut self) {
+        self.values.extend(iter::once(false));
+        self.validity.append_n(1, false);
+    }
+
+    /// Appends a valid value for `Some` and a null for `None`.
+    pub fn append_option(&mut self, value: Option<bool>) {
+        match value {
+            Some(item) => self.append_value(item),
+            None => self.append_null(),
+        }
+    }
+
+    /// Appends a slice of valid values.
+    pub fn append_slice(&mut self, values: &[bool]) {
+        self.values.extend(values);
+        self.validity.append_n(values.len(), true);
+    }
+
+    /// Returns an array with the appended values and resets this builder.
+    pub fn finish(&mut self) -> BooleanArray<true> {
+        BooleanArray(Nullable {
+            data: mem::take(&mut self.values),
+            validity: self.validity.finish(),
+        })
+    }
+}
+
+impl Default for BooleanBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayBuilder for BooleanBuilder {
+    fn append_null(&mut self) {
+        BooleanBuilder::append_null(self);
+    }
+
+    fn finish_any(&mut self) -> Box<dyn Any> {
+        Box::new(self.finish())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Length for BooleanBuilder {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+/// A builder for [`NullArray`]s.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullBuilder {
+    /// The number of appended nulls.
+    len: usize,
+}
+
+impl NullBuilder {
+    /// Returns a new, empty [`NullBuilder`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a null.
+    #[allow(clippy::same_name_method)]
+    pub fn append_null(&mut self) {
+        self.append_nulls(1);
+    }
+
+    /// Appends `additional` nulls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of nulls overflows `usize`.
+    pub fn append_nulls(&mut self, additional: usize) {
+        self.len = self.len.checked_add(additional).expect("len overflow");
+    }
+
+    /// Returns an array with the appended nulls and resets this builder.
+    pub fn finish(&mut self) -> NullArray {
+        NullArray(Nulls::new(mem::take(&mut self.len)))
+    }
+}
+
+impl ArrayBuilder for NullBuilder {
+    fn append_null(&mut self) {
+        NullBuilder::append_null(self);
+    }
+
+    fn finish_any(&mut self) -> Box<dyn Any> {
+        Box::new(self.finish())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Length for NullBuilder {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// A builder that cuts the items appended to an array into chunks.
+///
+/// Items are appended to the in-progress array `A`. When the in-progress
+/// array reaches a limit of the [`ChunkPolicy`], it is completed and queued,
+/// and a new array is started. Limits are checked after every item, so a
+/// chunk exceeds the byte limit by at most one row.
+#[derive(Debug)]
+pub struct ChunkingBuilder<A> {
+    /// The policy that controls when a chunk is complete.
+    policy: ChunkPolicy,
+    /// The in-progress array.
+    current: A,
+    /// The completed chunks that were not popped.
+    chunks: VecDeque<A>,
+}
+
+impl<A: Default> ChunkingBuilder<A> {
+    /// Returns a new, empty [`ChunkingBuilder`] with the given policy.
+    #[must_use]
+    pub fn new(policy: ChunkPolicy) -> Self {
+        Self {
+            policy,
+            current: A::default(),
+            chunks: VecDeque::new(),
+        }
+    }
+
+    /// Returns the policy of this builder.
+    #[must_use]
+    pub fn policy(&self) -> ChunkPolicy {
+        self.policy
+    }
+
+    /// Returns the in-progress array.
+    #[must_use]
+    pub fn current(&self) -> &A {
+        &self.current
+    }
+
+    /// Appends an item, completing the in-progress array if it reaches a
+    /// limit.
+    pub fn append<T>(&mut self, item: T)
+    where
+        A: Extend<T> + Length + MemoryUsage,
+    {
+        self.current.extend(iter::once(item));
+        if self
+            .policy
+            .is_complete(self.current.len(), self.current.buffer_bytes())
+        {
+            self.chunks.push_back(mem::take(&mut self.current));
+        }
+    }
+
+    /// Returns the number of completed chunks that were not popped.
+    #[must_use]
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Removes and returns the first completed chunk, or `None` if there are
+    /// no completed chunks.
+    pub fn pop_chunk(&mut self) -> Option<A> {
+        self.chunks.pop_front()
+    }
+
+    /// Returns the completed chunks that were not popped, followed by the
+    /// in-progress array if it is not empty.
+    #[must_use]
+    pub fn finish(self) -> Vec<A>
+    where
+        A: Length,
+    {
+        let mut chunks = Vec::from(self.chunks);
+        if !self.current.is_empty() {
+            chunks.push(self.current);
+        }
+        chunks
+    }
+}
+
+impl<A: Default + Extend<T> + Length + MemoryUsage, T> Extend<T> for ChunkingBuilder<A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.append(item));
+    }
+}
+
+/// A non-nullable or a nullable [`FixedSizePrimitiveArray`], returned by
+/// [`MaybeNullable::finish`].
+pub enum MaybeNullableArray<T: FixedSize, Buffer: BufferType = VecBuffer> {
+    /// An array without nulls.
+    NonNullable(FixedSizePrimitiveArray<T, false, Buffer>),
+    /// An array with at least one null.
+    Nullable(FixedSizePrimitiveArray<T, true, Buffer>),
+}
+
+impl<T: FixedSize, Buffer: BufferType> MaybeNullableArray<T, Buffer> {
+    /// Returns `true` if this is a nullable array.
+    #[must_use]
+    pub fn is_nullable(&self) -> bool {
+        matches!(*self, Self::Nullable(_))
+    }
+
+    /// Returns this array as a nullable array.
+    ///
+    /// Non-nullable arrays are wrapped with an all-valid bitmap.
+    #[must_use]
+    pub fn into_nullable(self) -> FixedSizePrimitiveArray<T, true, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<T>: Length,
+        <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+    {
+        match self {
+            Self::NonNullable(array) => upgrade(array),
+            Self::Nullable(array) => array,
+        }
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Debug for MaybeNullableArray<T, Buffer>
+where
+    FixedSizePrimitiveArray<T, false, Buffer>: Debug,
+    FixedSizePrimitiveArray<T, true, Buffer>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::NonNullable(ref array) => f.debug_tuple("NonNullable").field(array).finish(),
+            Self::Nullable(ref array) => f.debug_tuple("Nullable").field(array).finish(),
+        }
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Length for MaybeNullableArray<T, Buffer>
+where
+    FixedSizePrimitiveArray<T, false, Buffer>: Length,
+    FixedSizePrimitiveArray<T, true, Buffer>: Length,
+{
+    fn len(&self) -> usize {
+        match *self {
+            Self::NonNullable(ref array) => array.len(),
+            Self::Nullable(ref array) => array.len(),
+        }
+    }
+}
+
+/// Returns the nullable array with the values of `array` and an all-valid
+/// bitmap, which is filled a byte at a time.
+fn upgrade<T: FixedSize, Buffer: BufferType>(
+    array: FixedSizePrimitiveArray<T, false, Buffer>,
+) -> FixedSizePrimitiveArray<T, true, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Length,
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    let validity = Bitmap::new_repeated(true, array.0.len());
+    FixedSizePrimitiveArray(Nullable {
+        data: array.0,
+        validity,
+    })
+}
+
+/// A builder for [`FixedSizePrimitiveArray`]s that only become nullable when
+/// a null is appended.
+///
+/// The builder starts with a non-nullable array, so no validity bitmap is
+/// allocated for arrays without nulls. The first time a `None` is appended,
+/// the values appended so far are wrapped with an all-valid bitmap and the
+/// builder continues with a nullable array.
+pub struct MaybeNullable<T: FixedSize, Buffer: BufferType = VecBuffer>(
+    MaybeNullableArray<T, Buffer>,
+);
+
+impl<T: FixedSize, Buffer: BufferType> MaybeNullable<T, Buffer> {
+    /// Returns a new, empty, non-nullable [`MaybeNullable`] builder.
+    #[must_use]
+    pub fn new() -> Self
+    where
+        FixedSizePrimitiveArray<T, false, Buffer>: Default,
+    {
+        Self(MaybeNullableArray::NonNullable(
+            FixedSizePrimitiveArray::default(),
+        ))
+    }
+
+    /// Returns `true` if a null was appended to this builder.
+    #[must_use]
+    pub fn is_nullable(&self) -> bool {
+        self.0.is_nullable()
+    }
+
+    /// Returns the array with the appended items.
+    #[must_use]
+    pub fn finish(self) -> MaybeNullableArray<T, Buffer> {
+        self.0
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Debug for MaybeNullable<T, Buffer>
+where
+    MaybeNullableArray<T, Buffer>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MaybeNullable").field(&self.0).finish()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Default for MaybeNullable<T, Buffer>
+where
+    FixedSizePrimitiveArray<T, false, Buffer>: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Extend<T> for MaybeNullable<T, Buffer>
+where
+    FixedSizePrimitiveArray<T, false, Buffer>: Extend<T>,
+    FixedSizePrimitiveArray<T, true, Buffer>: Extend<Option<T>>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        match self.0 {
+            MaybeNullableArray::NonNullable(ref mut array) => array.extend(iter),
+            MaybeNullableArray::Nullable(ref mut array) => array.extend(iter.into_iter().map(Some)),
+        }
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Extend<Option<T>> for MaybeNullable<T, Buffer>
+where
+    FixedSizePrimitiveArray<T, false, Buffer>: Default + Extend<T>,
+    FixedSizePrimitiveArray<T, true, Buffer>: Extend<Option<T>>,
+    <Buffer as BufferType>::Buffer<T>: Length,
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    fn extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) {
+        let mut items = iter.into_iter();
+        match self.0 {
+            MaybeNullableArray::NonNullable(ref mut array) => {
+                let mut null = false;
+                // Append the values until the first null.
+                array.extend(items.by_ref().map_while(|item| {
+                    null = item.is_none();
+                    item
+                }));
+                if null {
+                    let mut nullable = upgrade(mem::take(array));
+                    nullable.extend(iter::once(None).chain(items));
+                    self.0 = MaybeNullableArray::Nullable(nullable);
+                }
+            }
+            MaybeNullableArray::Nullable(ref mut array) => array.extend(items),
+        }
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Length for MaybeNullable<T, Buffer>
+where
+    MaybeNullableArray<T, Buffer>: Length,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{Int32Array, StringArray},
+        bitmap::ValidityBitmap,
+    };
+
+    #[test]
+    fn primitive() {
+        let mut builder = PrimitiveBuilder::<u32>::new();
+        builder.append_value(1);
+        builder.append_slice(&[2, 3]);
+        builder.append_null();
+        builder.append_option(Some(5));
+        builder.append_option(None);
+        assert_eq!(builder.len(), 6);
+        let array = builder.finish();
+        assert_eq!(array, [Some(1), Some(2), Some(3), None, Some(5), None]);
+        assert!(builder.is_empty());
+
+        builder.append_value(6);
+        assert_eq!(builder.finish(), [Some(6)]);
+    }
+
+    #[test]
+    fn primitive_capacity() {
+        let mut builder = PrimitiveBuilder::<u8>::with_capacity(100);
+        let capacity = builder.capacity();
+        let ptr = builder.values.as_ptr();
+        (0..100_u8).for_each(|value| builder.append_option((value % 3 != 0).then_some(value)));
+        assert_eq!(builder.capacity(), capacity);
+        assert_eq!(builder.values.as_ptr(), ptr);
+        let array = builder.finish();
+        assert_eq!(array.null_count(), 34);
+        assert_eq!(array.values().as_ptr(), ptr);
+    }
+
+    #[test]
+    fn boolean() {
+        let mut builder = BooleanBuilder::with_capacity(4);
+        builder.append_value(true);
+        builder.append_null();
+        builder.append_slice(&[false, true]);
+        builder.append_option(None);
+        assert_eq!(
+            builder.finish(),
+            [Some(true), None, Some(false), Some(true), None]
+        );
+    }
+
+    #[test]
+    fn null() {
+        let mut builder = NullBuilder::new();
+        builder.append_null();
+        builder.append_nulls(2);
+        assert_eq!(builder.finish().len(), 3);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(PrimitiveBuilder::<i64>::new().finish().is_empty());
+        assert!(BooleanBuilder::new().finish().is_empty());
+        assert!(NullBuilder::new().finish().is_empty());
+    }
+
+    #[test]
+    fn dyn_array_builder() {
+        let mut columns: Vec<Box<dyn ArrayBuilder>> = vec![
+            Box::new(PrimitiveBuilder::<i32>::with_capacity(2)),
+            Box::new(BooleanBuilder::new()),
+            Box::new(NullBuilder::new()),
+        ];
+        for row in 0..3 {
+            for column in &mut columns {
+                if row == 1 {
+                    column.append_null();
+                } else if let Some(builder) =
+                    column.as_any_mut().downcast_mut::<PrimitiveBuilder<i32>>()
+                {
+                    builder.append_value(row);
+                } else if let Some(builder) = column.as_any_mut().downcast_mut::<BooleanBuilder>() {
+                    builder.append_value(row == 0);
+                } else {
+                    column.append_null();
+                }
+            }
+        }
+        assert!(columns.iter().all(|column| column.len() == 3));
+
+        let mut arrays = columns.iter_mut().map(|column| column.finish_any());
+        assert_eq!(
+            *arrays
+                .next()
+                .and_then(|array| array.downcast::<Int32Array<true>>().ok())
+                .expect("primitive array"),
+            [Some(0), None, Some(2)]
+        );
+        assert_eq!(
+            *arrays
+                .next()
+                .and_then(|array| array.downcast::<BooleanArray<true>>().ok())
+                .expect("boolean array"),
+            [Some(true), None, Some(false)]
+        );
+        assert_eq!(
+            arrays
+                .next()
+                .and_then(|array| array.downcast::<NullArray>().ok())
+                .expect("null array")
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn chunking_rows() {
+        let mut builder = ChunkingBuilder::<Int32Array>::new(ChunkPolicy::max_rows(4));
+        builder.extend(0..10);
+        assert_eq!(builder.num_chunks(), 2);
+        assert_eq!(builder.current().len(), 2);
+        assert_eq!(
+            builder.pop_chunk(),
+            Some([0, 1, 2, 3].into_iter().collect())
+        );
+        builder.append(10);
+        let chunks = builder.finish();
+        assert_eq!(chunks.iter().map(Length::len).collect::<Vec<_>>(), [4, 3]);
+        assert!(chunks.iter().flatten().copied().eq(4..11));
+    }
+
+    #[test]
+    fn chunking_exact_multiple() {
+        let mut builder = ChunkingBuilder::<Int32Array>::new(ChunkPolicy::max_rows(3));
+        builder.extend(0..9);
+        assert!(builder.current().is_empty());
+        let chunks = builder.finish();
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|chunk| chunk.len() == 3));
+        assert!(chunks.iter().flatten().copied().eq(0..9));
+    }
+
+    #[test]
+    fn chunking_bytes() {
+        let items = ["a", "bcd", "", "efghij", "k", "lm", "nopqrstu", "v"];
+        let policy = ChunkPolicy {
+            max_rows: Some(4),
+            max_bytes: Some(24),
+        };
+        let mut builder = ChunkingBuilder::<StringArray>::new(policy);
+        builder.extend(items);
+        let chunks = builder.finish();
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.len() <= 4);
+            // The limit is reached by at most the last row.
+            let without_last = chunk.iter().take(chunk.len() - 1).collect::<StringArray>();
+            assert!(without_last.buffer_bytes() < 24);
+        }
+        assert_eq!(chunks.iter().flatten().collect::<Vec<_>>(), items);
+        assert!(ChunkingBuilder::<StringArray>::new(policy)
+            .finish()
+            .is_empty());
+    }
+
+    #[test]
+    fn maybe_nullable_valid() {
+        let mut builder = MaybeNullable::<u32>::new();
+        builder.extend([1, 2]);
+        builder.extend([Some(3), Some(4)]);
+        assert!(!builder.is_nullable());
+        assert_eq!(builder.len(), 4);
+        match builder.finish() {
+            MaybeNullableArray::NonNullable(array) => assert_eq!(array, [1, 2, 3, 4]),
+            MaybeNullableArray::Nullable(_) => panic!("expected a non-nullable array"),
+        }
+    }
+
+    #[test]
+    fn maybe_nullable_upgrade() {
+        let mut builder = MaybeNullable::<u32>::default();
+        builder.extend(0..10);
+        builder.extend([Some(10), None, Some(12)]);
+        assert!(builder.is_nullable());
+        builder.extend([13]);
+        builder.extend([None]);
+        let array = builder.finish();
+        assert!(array.is_nullable());
+        assert_eq!(array.len(), 15);
+        let nullable = array.into_nullable();
+        assert_eq!(nullable.null_count(), 2);
+        assert!(nullable
+            .iter_with_validity()
+            .take(11)
+            .map(|(value, _)| value)
+            .eq(0..11));
+        assert_eq!(
+            nullable.into_iter().skip(10).collect::<Vec<_>>(),
+            [Some(10), None, Some(12), Some(13), None]
+        );
+    }
+
+    #[test]
+    fn maybe_nullable_bulk_validity() {
+        let mut builder = MaybeNullable::<u8>::new();
+        builder.extend([Some(1), Some(2), Some(3), None]);
+        let MaybeNullableArray::Nullable(array) = builder.finish() else {
+            panic!("expected a nullable array");
+        };
+        // The first three bits are set by the upgrade, the null is appended.
+        assert_eq!(array.0.validity.buffer, [0b0111]);
+        let values: FixedSizePrimitiveArray<u8> = [1, 2].into_iter().collect();
+        assert_eq!(
+            MaybeNullableArray::NonNullable(values)
+                .into_nullable()
+                .0
+                .validity,
+            Bitmap::<VecBuffer>::new_repeated(true, 2)
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Byte views of arrays with [bytemuck](https://docs.rs/bytemuck).
+//!
+//! With this feature enabled, all [`FixedSize`] types are [`Pod`], so the
+//! values of non-nullable [`FixedSizePrimitiveArray`]s can be viewed as bytes
+//! and constructed from bytes without unsafe code.
+
+use crate::{array::FixedSizePrimitiveArray, buffer::BufferType, FixedSize};
+use bytemuck::{Pod, PodCastError};
+
+impl<T: FixedSize + Pod, Buffer: BufferType> FixedSizePrimitiveArray<T, false, Buffer> {
+    /// Returns the values of this array as a byte slice.
+    #[must_use]
+    pub fn as_byte_slice(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Returns an array with the values stored in `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PodCastError`] if `bytes` is not aligned for `T`, or if
+    /// the length of `bytes` is not a multiple of the size of `T`.
+    pub fn try_from_byte_slice<'a>(bytes: &'a [u8]) -> Result<Self, PodCastError>
+    where
+        <Buffer as BufferType>::Buffer<T>: From<&'a [T]>,
+    {
+        bytemuck::try_cast_slice(bytes).map(|values| Self(From::from(values)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{Float64Array, Uint16Array},
+        buffer::SliceBuffer,
+    };
+
+    #[test]
+    #[cfg(not(feature = "arrow-rs"))]
+    fn roundtrip_array() {
+        let array = [[1_u8, 2], [3, 4]]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<[u8; 2]>>();
+        assert_eq!(array.as_byte_slice(), [1, 2, 3, 4]);
+        assert_eq!(
+            FixedSizePrimitiveArray::<[u8; 2]>::try_from_byte_slice(array.as_byte_slice()),
+            Ok(array)
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let floats = [1.5, -0.0, f64::MAX].into_iter().collect::<Float64Array>();
+        let bytes = floats.as_byte_slice();
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(Float64Array::try_from_byte_slice(bytes), Ok(floats.clone()));
+        let view =
+            FixedSizePrimitiveArray::<f64, false, SliceBuffer<'_>>::try_from_byte_slice(bytes)
+                .expect("aligned");
+        assert_eq!(view.as_slice(), floats.as_slice());
+    }
+
+    #[test]
+    fn errors() {
+        let values = [0_u16; 4].into_iter().collect::<Uint16Array>();
+        let bytes = values.as_byte_slice();
+        assert_eq!(
+            <Uint16Array>::try_from_byte_slice(&bytes[1..5]),
+            Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+        );
+        assert_eq!(
+            <Uint16Array>::try_from_byte_slice(&bytes[..3]),
+            Err(PodCastError::OutputSliceWouldHaveSlop)
+        );
+    }
+}
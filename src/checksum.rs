@@ -0,0 +1,353 @@
+//! Buffer checksums for integrity verification.
+//!
+//! Checksums are CRC32C (Castagnoli) checksums of the bytes of the buffers of
+//! an array, computed directly over the buffers without copies.
+
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray, StringArray, VariableSizeBinaryArray},
+    bitmap::Bitmap,
+    buffer::{Buffer, BufferType},
+    offset::OffsetElement,
+    FixedSize,
+};
+use core::fmt::{self, Display, Formatter};
+
+/// The reflected CRC32C (Castagnoli) polynomial.
+const POLYNOMIAL: u32 = 0x82f6_3b78;
+
+/// The lookup table of the CRC32C computation, one entry per byte value.
+const TABLE: [u32; 256] = table();
+
+/// Returns the lookup table of the CRC32C computation.
+const fn table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+    let mut value: u32 = 0;
+    while index < table.len() {
+        let mut crc = value;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+        value += 1;
+    }
+    table
+}
+
+/// A streaming CRC32C checksum computation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crc32c(u32);
+
+impl Crc32c {
+    /// Returns a new CRC32C computation without bytes.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(u32::MAX)
+    }
+
+    /// Updates the checksum with the given bytes.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |crc, &byte| {
+            TABLE[usize::from(crc.to_le_bytes()[0] ^ byte)] ^ (crc >> 8)
+        });
+    }
+
+    /// Returns the checksum of the bytes.
+    #[must_use]
+    pub const fn finish(&self) -> u32 {
+        !self.0
+    }
+}
+
+impl Default for Crc32c {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the CRC32C checksum of the given bytes.
+#[must_use]
+pub fn crc32c(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32c::new();
+    crc.update(bytes);
+    crc.finish()
+}
+
+/// Returns the CRC32C checksum of the bytes of a buffer.
+fn buffer_checksum<T: FixedSize>(buffer: &impl Buffer<T>) -> u32 {
+    crc32c(buffer.as_bytes())
+}
+
+/// The buffers of an array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChecksumBuffer {
+    /// The validity bitmap.
+    Validity,
+    /// The offsets buffer.
+    Offsets,
+    /// The data buffer.
+    Data,
+}
+
+impl Display for ChecksumBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Validity => write!(f, "validity"),
+            Self::Offsets => write!(f, "offsets"),
+            Self::Data => write!(f, "data"),
+        }
+    }
+}
+
+/// The checksums of the buffers of an array.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Checksums {
+    /// The checksum of the validity bitmap, if the array is nullable.
+    pub validity: Option<u32>,
+    /// The checksum of the offsets buffer, if the array has one.
+    pub offsets: Option<u32>,
+    /// The checksum of the data buffer.
+    pub data: u32,
+}
+
+impl Checksums {
+    /// Checks that `actual` matches these (expected) checksums.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChecksumMismatch`] for the first buffer, in the order
+    /// validity, offsets and data, with a different checksum.
+    pub fn verify(&self, actual: &Self) -> Result<(), ChecksumMismatch> {
+        [
+            (ChecksumBuffer::Validity, self.validity, actual.validity),
+            (ChecksumBuffer::Offsets, self.offsets, actual.offsets),
+            (ChecksumBuffer::Data, Some(self.data), Some(actual.data)),
+        ]
+        .into_iter()
+        .try_for_each(|(buffer, expected, found)| match (expected, found) {
+            (Some(expected_checksum), Some(actual_checksum))
+                if expected_checksum != actual_checksum =>
+            {
+                Err(ChecksumMismatch {
+                    buffer,
+                    expected: expected_checksum,
+                    actual: actual_checksum,
+                })
+            }
+            _ => Ok(()),
+        })
+    }
+}
+
+/// The error returned when the checksum of a buffer does not match the
+/// expected checksum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChecksumMismatch {
+    /// The buffer with the mismatch.
+    pub buffer: ChecksumBuffer,
+    /// The expected checksum.
+    pub expected: u32,
+    /// The actual checksum.
+    pub actual: u32,
+}
+
+impl Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "checksum mismatch in {} buffer: expected {:#010x}, got {:#010x}",
+            self.buffer, self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChecksumMismatch {}
+
+/// Checksums of the buffers of an array.
+pub trait Checksum {
+    /// Returns the checksums of the buffers of this array.
+    fn checksums(&self) -> Checksums;
+
+    /// Checks the buffers of this array against the expected checksums.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChecksumMismatch`] for the first buffer with a different
+    /// checksum, see [`Checksums::verify`].
+    fn verify_checksums(&self, expected: &Checksums) -> Result<(), ChecksumMismatch> {
+        expected.verify(&self.checksums())
+    }
+}
+
+impl<Buffer: BufferType> Bitmap<Buffer> {
+    /// Returns the CRC32C checksum of the buffer of this bitmap.
+    ///
+    /// This includes the bits before the offset and the padding bits.
+    #[must_use]
+    pub fn checksum(&self) -> u32 {
+        buffer_checksum(&self.buffer)
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Checksum for FixedSizePrimitiveArray<T, false, Buffer> {
+    fn checksums(&self) -> Checksums {
+        Checksums {
+            validity: None,
+            offsets: None,
+            data: buffer_checksum(&self.0),
+        }
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> Checksum for FixedSizePrimitiveArray<T, true, Buffer> {
+    fn checksums(&self) -> Checksums {
+        Checksums {
+            validity: Some(self.0.validity.checksum()),
+            offsets: None,
+            data: buffer_checksum(&self.0.data),
+        }
+    }
+}
+
+impl<Buffer: BufferType> Checksum for BooleanArray<false, Buffer> {
+    fn checksums(&self) -> Checksums {
+        Checksums {
+            validity: None,
+            offsets: None,
+            data: self.0.checksum(),
+        }
+    }
+}
+
+impl<Buffer: BufferType> Checksum for BooleanArray<true, Buffer> {
+    fn checksums(&self) -> Checksums {
+        Checksums {
+            validity: Some(self.0.validity.checksum()),
+            offsets: None,
+            data: self.0.data.checksum(),
+        }
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Checksum
+    for VariableSizeBinaryArray<false, OffsetItem, Buffer>
+{
+    fn checksums(&self) -> Checksums {
+        Checksums {
+            validity: None,
+            offsets: Some(buffer_checksum(&self.0.offsets.0)),
+            data: buffer_checksum(&self.0.data.0),
+        }
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Checksum
+    for VariableSizeBinaryArray<true, OffsetItem, Buffer>
+{
+    fn checksums(&self) -> Checksums {
+        Checksums {
+            validity: Some(self.0.offsets.validity.checksum()),
+            offsets: Some(buffer_checksum(&self.0.offsets.data.0)),
+            data: buffer_checksum(&self.0.data.0),
+        }
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Checksum
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    crate::offset::Offsets<OffsetItem, Buffer>: crate::validity::Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: Checksum,
+{
+    fn checksums(&self) -> Checksums {
+        self.0.checksums()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{Int64Array, Uint16Array},
+        buffer::BufferMut,
+    };
+
+    #[test]
+    fn crc32c_vectors() {
+        assert_eq!(crc32c(b""), 0);
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+        assert_eq!(crc32c(&[0; 32]), 0x8a91_36aa);
+        let mut crc = Crc32c::new();
+        crc.update(b"1234");
+        crc.update(b"56789");
+        assert_eq!(crc.finish(), crc32c(b"123456789"));
+    }
+
+    #[test]
+    fn corrupt_data() {
+        let mut array = [1_i64, 2, 3].into_iter().collect::<Int64Array>();
+        let checksums = array.checksums();
+        assert_eq!(checksums.validity, None);
+        assert_eq!(array.verify_checksums(&checksums), Ok(()));
+        array.0.as_mut_bytes()[9] ^= 0x40;
+        let mismatch = array.verify_checksums(&checksums).expect_err("corrupted");
+        assert_eq!(mismatch.buffer, ChecksumBuffer::Data);
+        assert_eq!(mismatch.expected, checksums.data);
+        assert_eq!(mismatch.actual, array.checksums().data);
+    }
+
+    #[test]
+    fn corrupt_validity() {
+        let mut array = [Some(1_u16), None, Some(3)]
+            .into_iter()
+            .collect::<Uint16Array<true>>();
+        let checksums = array.checksums();
+        array.0.validity.buffer[0] ^= 0b10;
+        assert_eq!(
+            array.verify_checksums(&checksums).map_err(|err| err.buffer),
+            Err(ChecksumBuffer::Validity)
+        );
+
+        let mut booleans = [Some(true), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let boolean_checksums = booleans.checksums();
+        booleans.0.data.buffer[0] ^= 0b1;
+        assert_eq!(
+            booleans
+                .verify_checksums(&boolean_checksums)
+                .map_err(|err| err.buffer),
+            Err(ChecksumBuffer::Data)
+        );
+    }
+
+    #[test]
+    fn corrupt_offsets() {
+        let mut array = ["a", "bc", "def"].into_iter().collect::<StringArray>();
+        let checksums = array.checksums();
+        assert!(checksums.offsets.is_some());
+        array.0 .0.offsets.0[1] += 1;
+        let mismatch = array.verify_checksums(&checksums).expect_err("corrupted");
+        assert_eq!(mismatch.buffer, ChecksumBuffer::Offsets);
+        assert_eq!(
+            mismatch.to_string(),
+            format!(
+                "checksum mismatch in offsets buffer: expected {:#010x}, got {:#010x}",
+                mismatch.expected, mismatch.actual
+            )
+        );
+
+        let nullable = [Some("a"), None].into_iter().collect::<StringArray<true>>();
+        let nullable_checksums = nullable.checksums();
+        assert!(nullable_checksums.validity.is_some());
+        assert_eq!(nullable.verify_checksums(&nullable_checksums), Ok(()));
+    }
+}
@@ -0,0 +1,548 @@
+//! Aggregation kernels.
+
+use super::{Float, Numeric, ValidSlices, ValidValues, ValuesRef};
+use crate::{array::FixedSizePrimitiveArray, buffer::BufferType, validity::Validity};
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// The error returned when an arithmetic operation overflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("arithmetic overflow")
+    }
+}
+
+impl Error for Overflow {}
+
+/// Returns the valid value that is preferred by `replace`, which returns
+/// `true` when the candidate (first argument) should replace the current
+/// value (second argument).
+fn select<T: Copy>(values: impl Iterator<Item = T>, replace: impl Fn(T, T) -> bool) -> Option<T> {
+    values.fold(None, |acc, value| match acc {
+        Some(current) if !replace(value, current) => Some(current),
+        _ => Some(value),
+    })
+}
+
+/// Returns the index of the valid value that is preferred by `replace`, see
+/// [`select`]. NaN values are skipped.
+fn arg_select<T: Numeric>(
+    slices: ValidSlices<'_, T>,
+    replace: impl Fn(T, T) -> bool,
+) -> Option<usize> {
+    slices
+        .flat_map(|(start, values)| {
+            values
+                .iter()
+                .enumerate()
+                .map(move |(index, &value)| (start + index, value))
+        })
+        .filter(|&(_, value)| !value.is_nan())
+        .fold(None, |acc, (index, value)| match acc {
+            Some((_, current)) if !replace(value, current) => acc,
+            _ => Some((index, value)),
+        })
+        .map(|(index, _)| index)
+}
+
+/// Returns the valid values that are not NaN.
+fn non_nan<T: Numeric>(values: ValidValues<'_, T>) -> impl Iterator<Item = T> + '_ {
+    values.filter(|value| !value.is_nan())
+}
+
+/// A compensated sum using the Kahan–Babuška (Neumaier) algorithm.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct KahanSum {
+    /// The running sum.
+    sum: f64,
+    /// The running compensation for lost low-order bits.
+    compensation: f64,
+}
+
+impl KahanSum {
+    /// Adds `value` to the sum.
+    pub(crate) fn add(&mut self, value: f64) {
+        let sum = self.sum + value;
+        self.compensation += if self.sum.abs() >= value.abs() {
+            (self.sum - sum) + value
+        } else {
+            (value - sum) + self.sum
+        };
+        self.sum = sum;
+    }
+
+    /// Returns the compensated sum.
+    pub(crate) fn value(self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+/// Running count, mean and sum of squared differences from the mean, using
+/// Welford's online algorithm.
+#[derive(Clone, Copy, Debug, Default)]
+struct Welford {
+    /// The number of values (as [`f64`]).
+    count: f64,
+    /// The running mean.
+    mean: f64,
+    /// The running sum of squared differences from the mean.
+    m2: f64,
+}
+
+impl Welford {
+    /// Adds `value` to the state.
+    fn add(mut self, value: f64) -> Self {
+        self.count += 1.;
+        let delta = value - self.mean;
+        self.mean += delta / self.count;
+        self.m2 += delta * (value - self.mean);
+        self
+    }
+
+    /// Returns the variance with `ddof` delta degrees of freedom, or `None`
+    /// if the count is not larger than `ddof`.
+    fn variance(self, ddof: u8) -> Option<f64> {
+        let degrees = f64::from(ddof);
+        (self.count > degrees).then(|| self.m2 / (self.count - degrees))
+    }
+}
+
+impl<T: Numeric, const NULLABLE: bool, Buffer: BufferType>
+    FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: ValuesRef<Item = T>,
+{
+    /// Returns the minimum value in this array.
+    ///
+    /// Nulls and NaN values are ignored. Returns `None` when there are no
+    /// such values, i.e. when the array is empty or all values are null or
+    /// NaN. Use [`Self::min_total`] to include NaN values.
+    #[must_use]
+    pub fn min(&self) -> Option<T> {
+        select(non_nan(self.valid_values()), |value, current| {
+            value < current
+        })
+    }
+
+    /// Returns the maximum value in this array.
+    ///
+    /// Nulls and NaN values are ignored. Returns `None` when there are no
+    /// such values, i.e. when the array is empty or all values are null or
+    /// NaN. Use [`Self::max_total`] to include NaN values.
+    #[must_use]
+    pub fn max(&self) -> Option<T> {
+        select(non_nan(self.valid_values()), |value, current| {
+            value > current
+        })
+    }
+
+    /// Returns the index of the first occurrence of the minimum value in
+    /// this array.
+    ///
+    /// Nulls and NaN values are ignored, consistent with [`Self::min`].
+    /// Returns `None` when the array is empty or all values are null or NaN.
+    #[must_use]
+    pub fn arg_min(&self) -> Option<usize> {
+        arg_select(self.valid_slices(), |value, current| value < current)
+    }
+
+    /// Returns the index of the first occurrence of the maximum value in
+    /// this array.
+    ///
+    /// Nulls and NaN values are ignored, consistent with [`Self::max`].
+    /// Returns `None` when the array is empty or all values are null or NaN.
+    #[must_use]
+    pub fn arg_max(&self) -> Option<usize> {
+        arg_select(self.valid_slices(), |value, current| value > current)
+    }
+
+    /// Returns the sum of the values in this array, wrapping around on
+    /// overflow.
+    ///
+    /// Nulls are skipped. Returns `None` when the array is empty or all
+    /// values are null.
+    #[must_use]
+    pub fn sum(&self) -> Option<T> {
+        let mut values = self.valid_values();
+        values
+            .next()
+            .map(|first| values.fold(first, T::wrapping_add))
+    }
+
+    /// Returns the sum of the values in this array, or [`Overflow`] when the
+    /// sum overflows.
+    ///
+    /// Nulls are skipped. Returns `Ok(None)` when the array is empty or all
+    /// values are null.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Overflow`] when the sum overflows `T`.
+    pub fn checked_sum(&self) -> Result<Option<T>, Overflow> {
+        let mut values = self.valid_values();
+        values.next().map_or(Ok(None), |first| {
+            values
+                .try_fold(first, T::checked_add)
+                .map(Some)
+                .ok_or(Overflow)
+        })
+    }
+
+    /// Returns the sum of the values in this array, converted to `W` before
+    /// summation, wrapping around on overflow of `W`.
+    ///
+    /// This can be used to sum into a wider type, e.g. to sum `i32` values
+    /// as `i64` without overflowing.
+    ///
+    /// Nulls are skipped. Returns `None` when the array is empty or all
+    /// values are null.
+    #[must_use]
+    pub fn sum_as<W: Numeric + From<T>>(&self) -> Option<W> {
+        let mut values = self.valid_values();
+        values.next().map(|first| {
+            values.fold(W::from(first), |acc, value| {
+                acc.wrapping_add(W::from(value))
+            })
+        })
+    }
+
+    /// Returns the variance of the values in this array, with `ddof` delta
+    /// degrees of freedom (the divisor is `N - ddof`, where `N` is the
+    /// number of valid values).
+    ///
+    /// This uses Welford's single pass algorithm. Nulls are skipped. Returns
+    /// `None` when the number of valid values is not larger than `ddof`.
+    #[must_use]
+    pub fn var(&self, ddof: u8) -> Option<f64> {
+        self.valid_values()
+            .map(T::to_f64)
+            .fold(Welford::default(), Welford::add)
+            .variance(ddof)
+    }
+
+    /// Returns the standard deviation of the values in this array, with
+    /// `ddof` delta degrees of freedom. This is the square root of
+    /// [`Self::var`].
+    ///
+    /// Nulls are skipped. Returns `None` when the number of valid values is
+    /// not larger than `ddof`.
+    #[must_use]
+    pub fn std(&self, ddof: u8) -> Option<f64> {
+        self.var(ddof).map(f64::sqrt)
+    }
+}
+
+impl<T: Float, const NULLABLE: bool, Buffer: BufferType>
+    FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: ValuesRef<Item = T>,
+{
+    /// Returns the minimum value in this array, ordered by
+    /// [`Float::total_cmp`].
+    ///
+    /// Nulls are ignored, NaN values are not: a negative NaN is smaller than
+    /// all other values. Returns `None` when the array is empty or all
+    /// values are null.
+    #[must_use]
+    pub fn min_total(&self) -> Option<T> {
+        select(self.valid_values(), |value, current| {
+            value.total_cmp(&current) == Ordering::Less
+        })
+    }
+
+    /// Returns the maximum value in this array, ordered by
+    /// [`Float::total_cmp`].
+    ///
+    /// Nulls are ignored, NaN values are not: a positive NaN is larger than
+    /// all other values. Returns `None` when the array is empty or all
+    /// values are null.
+    #[must_use]
+    pub fn max_total(&self) -> Option<T> {
+        select(self.valid_values(), |value, current| {
+            value.total_cmp(&current) == Ordering::Greater
+        })
+    }
+
+    /// Returns the sum of the values in this array, computed in [`f64`]
+    /// using Kahan–Babuška compensated summation.
+    ///
+    /// Nulls are skipped. NaN values propagate: the sum is NaN when any
+    /// valid value is NaN. Returns `None` when the array is empty or all
+    /// values are null.
+    #[must_use]
+    pub fn sum_kahan(&self) -> Option<f64> {
+        self.kahan().map(|(sum, _)| sum.value())
+    }
+
+    /// Returns the arithmetic mean of the values in this array, using
+    /// [`Self::sum_kahan`].
+    ///
+    /// Nulls are skipped. NaN values propagate: the mean is NaN when any
+    /// valid value is NaN. Returns `None` when the array is empty or all
+    /// values are null.
+    #[must_use]
+    pub fn mean(&self) -> Option<f64> {
+        self.kahan().map(|(sum, count)| sum.value() / count)
+    }
+
+    /// Returns the compensated sum and the number of valid values (as
+    /// [`f64`]), or `None` if there are no valid values.
+    fn kahan(&self) -> Option<(KahanSum, f64)> {
+        let (sum, count) =
+            self.valid_values()
+                .fold((KahanSum::default(), 0.), |(mut sum, count), value| {
+                    sum.add(value.to_f64());
+                    (sum, count + 1.)
+                });
+        (count > 0.).then_some((sum, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float32Array, Float64Array, Int32Array, Uint8Array};
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    #[test]
+    fn min_max() {
+        let empty = Int32Array::<false>::default();
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+
+        let array = [3, -1, 4, 1, -5, 9].into_iter().collect::<Int32Array>();
+        assert_eq!(array.min(), Some(-5));
+        assert_eq!(array.max(), Some(9));
+    }
+
+    #[test]
+    fn min_max_nullable() {
+        let all_null = [None::<u8>, None, None]
+            .into_iter()
+            .collect::<Uint8Array<true>>();
+        assert_eq!(all_null.min(), None);
+        assert_eq!(all_null.max(), None);
+
+        let mixed = [None, Some(3), Some(7), None, Some(1), None]
+            .into_iter()
+            .collect::<Uint8Array<true>>();
+        assert_eq!(mixed.min(), Some(1));
+        assert_eq!(mixed.max(), Some(7));
+
+        // Long runs, with values in null slots that must be ignored.
+        let long = (0..200_u8)
+            .map(|x| (x % 50 != 0).then_some(x))
+            .collect::<Uint8Array<true>>();
+        assert_eq!(long.min(), Some(1));
+        assert_eq!(long.max(), Some(199));
+    }
+
+    #[test]
+    fn min_max_nan() {
+        let array = [f64::NAN, 2.0, -1.0, f64::NAN]
+            .into_iter()
+            .collect::<Float64Array>();
+        assert_eq!(array.min(), Some(-1.0));
+        assert_eq!(array.max(), Some(2.0));
+        assert_eq!(array.min_total(), Some(-1.0));
+        assert!(array.max_total().is_some_and(f64::is_nan));
+
+        let negative_nan = [1.0, -f64::NAN].into_iter().collect::<Float64Array>();
+        assert!(negative_nan.min_total().is_some_and(f64::is_nan));
+        assert_eq!(negative_nan.max_total(), Some(1.0));
+
+        let only_nan = [Some(f64::NAN), None]
+            .into_iter()
+            .collect::<Float64Array<true>>();
+        assert_eq!(only_nan.min(), None);
+        assert_eq!(only_nan.max(), None);
+        assert!(only_nan.min_total().is_some_and(f64::is_nan));
+
+        let zeros = [0.0, -0.0].into_iter().collect::<Float64Array>();
+        assert!(zeros.min_total().is_some_and(f64::is_sign_negative));
+        assert!(zeros.max_total().is_some_and(f64::is_sign_positive));
+    }
+
+    #[test]
+    fn arg_min_max() {
+        let empty = Int32Array::<false>::default();
+        assert_eq!(empty.arg_min(), None);
+        assert_eq!(empty.arg_max(), None);
+
+        let ties = [3, 1, 4, 1, 5, 9, 2, 6, 9]
+            .into_iter()
+            .collect::<Int32Array>();
+        assert_eq!(ties.arg_min(), Some(1));
+        assert_eq!(ties.arg_max(), Some(5));
+
+        let all_null = [None::<i32>, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(all_null.arg_min(), None);
+        assert_eq!(all_null.arg_max(), None);
+
+        let nullable = [None, Some(2), None, Some(1), Some(3), None, Some(1)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(nullable.arg_min(), Some(3));
+        assert_eq!(nullable.arg_max(), Some(4));
+
+        let floats = [f64::NAN, 2.0, -1.0, f64::NAN, 2.0]
+            .into_iter()
+            .collect::<Float64Array>();
+        assert_eq!(floats.arg_min(), Some(2));
+        assert_eq!(floats.arg_max(), Some(1));
+
+        let only_nan = [f64::NAN, f64::NAN].into_iter().collect::<Float64Array>();
+        assert_eq!(only_nan.arg_min(), None);
+        assert_eq!(only_nan.arg_max(), None);
+    }
+
+    #[test]
+    fn sum() {
+        let empty = Int32Array::<false>::default();
+        assert_eq!(empty.sum(), None);
+        assert_eq!(empty.checked_sum(), Ok(None));
+        assert_eq!(empty.sum_as::<i64>(), None);
+
+        let all_null = [None::<i32>, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(all_null.sum(), None);
+        assert_eq!(all_null.checked_sum(), Ok(None));
+        assert_eq!(all_null.sum_as::<i64>(), None);
+
+        let mixed = [Some(1), None, Some(2), Some(3), None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(mixed.sum(), Some(6));
+        assert_eq!(mixed.checked_sum(), Ok(Some(6)));
+
+        let floats = [0.5, 1.5, -1.0].into_iter().collect::<Float64Array>();
+        assert_eq!(floats.sum(), Some(1.0));
+        assert_eq!(floats.checked_sum(), Ok(Some(1.0)));
+    }
+
+    #[test]
+    fn sum_overflow() {
+        let array = [i32::MAX, 1, i32::MAX].into_iter().collect::<Int32Array>();
+        assert_eq!(
+            array.sum(),
+            Some(i32::MAX.wrapping_add(1).wrapping_add(i32::MAX))
+        );
+        assert_eq!(array.checked_sum(), Err(Overflow));
+        assert_eq!(array.sum_as::<i64>(), Some(2 * i64::from(i32::MAX) + 1));
+
+        // Overflowing values in null slots are ignored.
+        let nullable = [Some(i32::MAX), None, Some(-1)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(nullable.checked_sum(), Ok(Some(i32::MAX - 1)));
+
+        let unsigned = [u8::MAX, 1].into_iter().collect::<Uint8Array>();
+        assert_eq!(unsigned.sum(), Some(0));
+        assert_eq!(unsigned.checked_sum(), Err(Overflow));
+        assert_eq!(unsigned.sum_as::<u16>(), Some(256));
+        assert_eq!(unsigned.sum_as::<f64>(), Some(256.0));
+    }
+
+    #[test]
+    fn sum_kahan() {
+        let empty = Float64Array::<false>::default();
+        assert_eq!(empty.sum_kahan(), None);
+        assert_eq!(empty.mean(), None);
+
+        let all_null = [None::<f32>, None]
+            .into_iter()
+            .collect::<Float32Array<true>>();
+        assert_eq!(all_null.sum_kahan(), None);
+        assert_eq!(all_null.mean(), None);
+
+        // Naive summation loses the small values completely.
+        let adversarial = [1.0, 1e100, 1.0, -1e100]
+            .into_iter()
+            .collect::<Float64Array>();
+        assert_eq!(adversarial.sum(), Some(0.0));
+        assert_eq!(adversarial.sum_kahan(), Some(2.0));
+        assert_eq!(adversarial.mean(), Some(0.5));
+
+        // The correctly rounded sum of 10,000 times 0.1 (as f64) is 1000.
+        let tenths = std::iter::repeat(0.1)
+            .take(10_000)
+            .collect::<Float64Array>();
+        assert_ne!(tenths.sum(), Some(1000.0));
+        assert_eq!(tenths.sum_kahan(), Some(1000.0));
+        assert_eq!(tenths.mean(), Some(0.1));
+
+        let nullable = [Some(1.5_f32), None, Some(2.5), None]
+            .into_iter()
+            .collect::<Float32Array<true>>();
+        assert_eq!(nullable.sum_kahan(), Some(4.0));
+        assert_eq!(nullable.mean(), Some(2.0));
+
+        let nan = [1.0, f64::NAN].into_iter().collect::<Float64Array>();
+        assert!(nan.sum_kahan().is_some_and(f64::is_nan));
+        assert!(nan.mean().is_some_and(f64::is_nan));
+    }
+
+    #[test]
+    fn var_std() {
+        /// Returns the variance computed with the two-pass algorithm.
+        #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+        fn two_pass(values: &[f64], ddof: u8) -> f64 {
+            let count = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / count;
+            values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (count - f64::from(ddof))
+        }
+
+        let mut rng = SmallRng::seed_from_u64(1234);
+        let values = (0..1000)
+            .map(|_| rng.gen_range(-1e3..1e3) + 1e6)
+            .collect::<Vec<f64>>();
+        let array = values.iter().copied().collect::<Float64Array>();
+        for ddof in 0..=1 {
+            let expected = two_pass(&values, ddof);
+            let variance = array.var(ddof).expect("variance");
+            assert!((variance - expected).abs() / expected < 1e-9);
+            let std = array.std(ddof).expect("std");
+            assert!((std - expected.sqrt()).abs() / expected.sqrt() < 1e-9);
+        }
+
+        let ints = [
+            Some(2),
+            None,
+            Some(4),
+            Some(4),
+            Some(4),
+            Some(5),
+            Some(5),
+            Some(7),
+            Some(9),
+        ]
+        .into_iter()
+        .collect::<Int32Array<true>>();
+        assert_eq!(ints.var(0), Some(4.0));
+        assert_eq!(ints.std(0), Some(2.0));
+        assert_eq!(ints.var(1), Some(32.0 / 7.0));
+
+        // The count after skipping nulls must be larger than ddof.
+        let single = [None, Some(3_u8)].into_iter().collect::<Uint8Array<true>>();
+        assert_eq!(single.var(0), Some(0.0));
+        assert_eq!(single.var(1), None);
+        assert_eq!(single.std(1), None);
+
+        let all_null = [None::<f32>, None]
+            .into_iter()
+            .collect::<Float32Array<true>>();
+        assert_eq!(all_null.var(0), None);
+        assert_eq!(all_null.std(0), None);
+    }
+}
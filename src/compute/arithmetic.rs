@@ -0,0 +1,132 @@
+//! Arithmetic kernels.
+
+use super::{
+    datum::{binary, primitive},
+    Datum, Numeric,
+};
+use crate::array::FixedSizePrimitiveArray;
+
+/// Returns the (wrapping) sums of the elements of `left` and `right`.
+///
+/// Either operand can be an array or a [`Scalar`](super::Scalar), see
+/// [`Datum`]. Nulls propagate, and a null scalar results in all nulls.
+///
+/// # Panics
+///
+/// Panics if both operands are arrays with different lengths.
+pub fn add<L: Datum, R: Datum<Item = L::Item>>(
+    left: &L,
+    right: &R,
+) -> FixedSizePrimitiveArray<L::Item, true>
+where
+    L::Item: Numeric,
+{
+    primitive(binary(left, right, Numeric::wrapping_add))
+}
+
+/// Returns the (wrapping) differences of the elements of `left` and `right`.
+///
+/// Either operand can be an array or a [`Scalar`](super::Scalar), see
+/// [`Datum`]. Nulls propagate, and a null scalar results in all nulls.
+///
+/// # Panics
+///
+/// Panics if both operands are arrays with different lengths.
+pub fn sub<L: Datum, R: Datum<Item = L::Item>>(
+    left: &L,
+    right: &R,
+) -> FixedSizePrimitiveArray<L::Item, true>
+where
+    L::Item: Numeric,
+{
+    primitive(binary(left, right, Numeric::wrapping_sub))
+}
+
+/// Returns the (wrapping) products of the elements of `left` and `right`.
+///
+/// Either operand can be an array or a [`Scalar`](super::Scalar), see
+/// [`Datum`]. Nulls propagate, and a null scalar results in all nulls.
+///
+/// # Panics
+///
+/// Panics if both operands are arrays with different lengths.
+pub fn mul<L: Datum, R: Datum<Item = L::Item>>(
+    left: &L,
+    right: &R,
+) -> FixedSizePrimitiveArray<L::Item, true>
+where
+    L::Item: Numeric,
+{
+    primitive(binary(left, right, Numeric::wrapping_mul))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{Float64Array, Int32Array, Uint8Array},
+        bitmap::ValidityBitmap,
+        compute::Scalar,
+        Length,
+    };
+
+    #[test]
+    fn arrays() {
+        let left = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let right = [10, 20, 30].into_iter().collect::<Int32Array>();
+        assert_eq!(add(&left, &right), [Some(11), None, Some(33)]);
+        assert_eq!(sub(&right, &left), [Some(9), None, Some(27)]);
+        assert_eq!(mul(&right, &right), [Some(100), Some(400), Some(900)]);
+        let wrapped = [200_u8].into_iter().collect::<Uint8Array>();
+        assert_eq!(add(&wrapped, &wrapped), [Some(144)]);
+    }
+
+    #[test]
+    fn scalar_right() {
+        let array = [1.5, 2.5].into_iter().collect::<Float64Array>();
+        assert_eq!(add(&array, &Scalar::new(1.0)), [Some(2.5), Some(3.5)]);
+        assert_eq!(mul(&array, &Scalar::new(2.0)), [Some(3.0), Some(5.0)]);
+    }
+
+    #[test]
+    fn scalar_left() {
+        let array = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(sub(&Scalar::new(10), &array), [Some(9), None, Some(7)]);
+        assert_eq!(sub(&array, &Scalar::new(10)), [Some(-9), None, Some(-7)]);
+    }
+
+    #[test]
+    fn null_scalar() {
+        let array = [1, 2, 3].into_iter().collect::<Int32Array>();
+        let result = add(&array, &Scalar::null());
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.null_count(), 3);
+        assert_eq!(sub(&Scalar::null(), &array), [None, None, None]);
+        assert_eq!(add(&Scalar::new(1), &Scalar::<i32>::null()), [None]);
+        assert_eq!(add(&Scalar::new(1), &Scalar::new(2)), [Some(3)]);
+    }
+
+    #[test]
+    fn materialized() {
+        let array = [Some(4), None, Some(-2), Some(7)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        for scalar in [Scalar::new(3), Scalar::null()] {
+            let constant = scalar.to_array(array.len());
+            assert_eq!(add(&array, &scalar), add(&array, &constant));
+            assert_eq!(sub(&scalar, &array), sub(&constant, &array));
+            assert_eq!(mul(&array, &scalar), mul(&array, &constant));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch")]
+    fn length_mismatch() {
+        let array = [1, 2].into_iter().collect::<Int32Array>();
+        let _ = add(&array, &[1].into_iter().collect::<Int32Array>());
+    }
+}
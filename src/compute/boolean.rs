@@ -0,0 +1,240 @@
+//! Boolean kernels.
+
+use crate::{
+    array::BooleanArray,
+    bitmap::{mask, popcount, Bitmap},
+    buffer::BufferType,
+};
+
+impl<Buffer: BufferType> BooleanArray<false, Buffer> {
+    /// Returns `true` if any value in this array is `true`.
+    ///
+    /// Returns `false` when the array is empty.
+    #[must_use]
+    pub fn any(&self) -> bool {
+        self.0.words().any(|(word, _)| word != 0)
+    }
+
+    /// Returns `true` if all values in this array are `true`.
+    ///
+    /// Returns `true` when the array is empty.
+    #[must_use]
+    pub fn all(&self) -> bool {
+        self.0.words().all(|(word, bits)| word == mask(bits))
+    }
+
+    /// Returns the number of `true` values in this array.
+    #[must_use]
+    pub fn true_count(&self) -> usize {
+        self.0.count_ones()
+    }
+
+    /// Returns the number of `false` values in this array.
+    #[must_use]
+    pub fn false_count(&self) -> usize {
+        self.0.count_zeros()
+    }
+}
+
+/// Returns an iterator over the words of the values and the validity bitmaps,
+/// yielding the values word, the validity word and the number of bits.
+fn zip_words<'a, Buffer: BufferType>(
+    values: &'a Bitmap<Buffer>,
+    validity: &'a Bitmap<Buffer>,
+) -> impl Iterator<Item = (u64, u64, usize)> + 'a {
+    values
+        .words()
+        .zip(validity.words())
+        .map(|((value_word, _), (validity_word, bits))| (value_word, validity_word, bits))
+}
+
+impl<Buffer: BufferType> BooleanArray<true, Buffer> {
+    /// Returns whether any value in this array is `true`, using three-valued
+    /// (Kleene) logic.
+    ///
+    /// Returns `Some(true)` if any valid value is `true`, `None` if there are
+    /// no valid `true` values but there are nulls, and `Some(false)`
+    /// otherwise (including when the array is empty).
+    #[must_use]
+    pub fn any(&self) -> Option<bool> {
+        let mut nulls = false;
+        for (values, validity, bits) in zip_words(&self.0.data, &self.0.validity) {
+            if values & validity != 0 {
+                return Some(true);
+            }
+            nulls |= validity != mask(bits);
+        }
+        (!nulls).then_some(false)
+    }
+
+    /// Returns whether all values in this array are `true`, using
+    /// three-valued (Kleene) logic.
+    ///
+    /// Returns `Some(false)` if any valid value is `false`, `None` if there
+    /// are no valid `false` values but there are nulls, and `Some(true)`
+    /// otherwise (including when the array is empty).
+    #[must_use]
+    pub fn all(&self) -> Option<bool> {
+        let mut nulls = false;
+        for (values, validity, bits) in zip_words(&self.0.data, &self.0.validity) {
+            if !values & validity != 0 {
+                return Some(false);
+            }
+            nulls |= validity != mask(bits);
+        }
+        (!nulls).then_some(true)
+    }
+
+    /// Returns the number of valid `true` values in this array.
+    #[must_use]
+    pub fn true_count(&self) -> usize {
+        zip_words(&self.0.data, &self.0.validity)
+            .map(|(values, validity, _)| popcount(values & validity))
+            .sum()
+    }
+
+    /// Returns the number of valid `false` values in this array.
+    #[must_use]
+    pub fn false_count(&self) -> usize {
+        zip_words(&self.0.data, &self.0.validity)
+            .map(|(values, validity, _)| popcount(!values & validity))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::VecBuffer;
+    use crate::proptest::{bitmap, boolean_array};
+    use proptest::{prop_assert_eq, proptest, strategy::Strategy};
+    use std::iter;
+
+    #[test]
+    fn any_all() {
+        let empty = BooleanArray::<false>::default();
+        assert!(!empty.any());
+        assert!(empty.all());
+
+        let mixed = [true, false, true].into_iter().collect::<BooleanArray>();
+        assert!(mixed.any());
+        assert!(!mixed.all());
+
+        // Determined by the first word.
+        let early = iter::once(false)
+            .chain(iter::repeat(true).take(200))
+            .collect::<BooleanArray>();
+        assert!(early.any());
+        assert!(!early.all());
+
+        // Determined by the last word.
+        let late = iter::repeat(true)
+            .take(200)
+            .chain(iter::once(false))
+            .collect::<BooleanArray>();
+        assert!(late.any());
+        assert!(!late.all());
+        let late_true = iter::repeat(false)
+            .take(200)
+            .chain(iter::once(true))
+            .collect::<BooleanArray>();
+        assert!(late_true.any());
+        assert!(!late_true.all());
+    }
+
+    #[test]
+    fn any_all_padding() {
+        // The padding bits of the last byte are set, and must be ignored.
+        // Safety:
+        // - The buffer contains 9 bits.
+        let falses = unsafe { Bitmap::<VecBuffer>::from_raw_parts(vec![0, 0xfe], 9, 0) };
+        assert!(!BooleanArray::<false>(falses).any());
+
+        // Safety:
+        // - The buffer contains 9 bits.
+        let trues = unsafe { Bitmap::<VecBuffer>::from_raw_parts(vec![0xff, 0x81], 9, 0) };
+        assert!(BooleanArray::<false>(trues).all());
+
+        // Safety:
+        // - The buffer contains 1 + 8 bits.
+        let offset = unsafe { Bitmap::<VecBuffer>::from_raw_parts(vec![0xfe, 0x01], 8, 1) };
+        assert!(BooleanArray::<false>(offset).all());
+    }
+
+    #[test]
+    fn kleene() {
+        let empty = BooleanArray::<true>::default();
+        assert_eq!(empty.any(), Some(false));
+        assert_eq!(empty.all(), Some(true));
+
+        let all_null = [None::<bool>, None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(all_null.any(), None);
+        assert_eq!(all_null.all(), None);
+
+        let true_null = [Some(true), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(true_null.any(), Some(true));
+        assert_eq!(true_null.all(), None);
+
+        let false_null = [None, Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(false_null.any(), None);
+        assert_eq!(false_null.all(), Some(false));
+
+        let valid = [Some(true), Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(valid.any(), Some(true));
+        assert_eq!(valid.all(), Some(false));
+
+        let trues = iter::repeat(Some(true))
+            .take(100)
+            .collect::<BooleanArray<true>>();
+        assert_eq!(trues.any(), Some(true));
+        assert_eq!(trues.all(), Some(true));
+
+        let falses = iter::repeat(Some(false))
+            .take(100)
+            .collect::<BooleanArray<true>>();
+        assert_eq!(falses.any(), Some(false));
+        assert_eq!(falses.all(), Some(false));
+    }
+
+    #[test]
+    fn true_false_count_empty() {
+        let empty = BooleanArray::<false>::default();
+        assert_eq!(empty.true_count(), 0);
+        assert_eq!(empty.false_count(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn true_false_count(
+            (array, validity) in (0..1000_usize)
+                .prop_flat_map(|len| (boolean_array(len, 0.5), bitmap(len, 0.8))),
+        ) {
+            let values = array
+                .iter()
+                .zip(&validity)
+                .map(|(value, valid)| valid.then_some(value))
+                .collect::<Vec<_>>();
+            let nullable = values.iter().copied().collect::<BooleanArray<true>>();
+            prop_assert_eq!(
+                nullable.true_count(),
+                values.iter().filter(|&&x| x == Some(true)).count()
+            );
+            prop_assert_eq!(
+                nullable.false_count(),
+                values.iter().filter(|&&x| x == Some(false)).count()
+            );
+
+            let trues = array.iter().filter(|&value| value).count();
+            prop_assert_eq!(array.true_count(), trues);
+            prop_assert_eq!(array.false_count(), values.len() - trues);
+        }
+    }
+}
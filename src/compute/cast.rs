@@ -0,0 +1,523 @@
+//! Cast kernels.
+
+use super::{validity_bits, ValuesRef};
+use crate::{
+    array::FixedSizePrimitiveArray, bitmap::Bitmap, buffer::BufferType, nullable::Nullable,
+    validity::Validity, FixedSize,
+};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// The error returned when a value can't be represented in the target type
+/// of a cast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CastError {
+    /// The index of the first value that can't be represented.
+    pub index: usize,
+}
+
+impl Display for CastError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value at index {} is not representable in the target type",
+            self.index
+        )
+    }
+}
+
+impl Error for CastError {}
+
+/// Conversion from `T` with the semantics of an `as` cast.
+///
+/// This is implemented for all pairs of numeric types, which makes the
+/// supported casts explicit. Conversions follow the semantics of the `as`
+/// operator:
+///
+/// - Casting between integers of the same size is a no-op (two's complement).
+/// - Casting from a larger integer to a smaller integer truncates.
+/// - Casting from a smaller integer to a larger integer sign-extends signed
+///   integers and zero-extends unsigned integers.
+/// - Casting from a float to an integer rounds towards zero and saturates
+///   on overflow. NaN is converted to `0`.
+/// - Casting from an integer to a float rounds to the nearest float.
+/// - Casting from [`f64`] to [`f32`] rounds to the nearest [`f32`], which may
+///   lose precision and overflow to infinity.
+pub trait CastFrom<T>: FixedSize {
+    /// Converts `value` to `Self` with the semantics of an `as` cast.
+    fn cast_from(value: T) -> Self;
+}
+
+/// Implements [`CastFrom`] for all pairs of the given types.
+macro_rules! impl_cast_from {
+    ([$($from:ty),+] => $to:tt) => {
+        $(
+            impl_cast_from!(@from $from => $to);
+        )+
+    };
+    (@from $from:ty => [$($to:ty),+]) => {
+        $(
+            impl CastFrom<$from> for $to {
+                #[inline]
+                #[allow(
+                    clippy::as_conversions,
+                    clippy::cast_lossless,
+                    clippy::cast_possible_truncation,
+                    clippy::cast_possible_wrap,
+                    clippy::cast_precision_loss,
+                    clippy::cast_sign_loss,
+                    trivial_numeric_casts
+                )]
+                fn cast_from(value: $from) -> Self {
+                    value as $to
+                }
+            }
+        )+
+    };
+}
+
+impl_cast_from!(
+    [i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64]
+        => [i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64]
+);
+#[cfg(not(feature = "arrow-rs"))]
+impl_cast_from!(
+    [u128, isize, usize]
+        => [i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, isize, usize, f32, f64]
+);
+#[cfg(not(feature = "arrow-rs"))]
+impl_cast_from!(
+    [i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64] => [u128, isize, usize]
+);
+
+/// Checked conversion from `T`.
+///
+/// This is implemented for all pairs of numeric types. Conversions fail
+/// when the value is not representable in `Self`:
+///
+/// - Integer conversions fail when the value is out of range.
+/// - Float to integer conversions truncate towards zero, and fail when the
+///   value is NaN, infinite or out of range after truncation.
+/// - Integer to float conversions round to the nearest float and never fail.
+/// - Conversions from [`f64`] to [`f32`] fail when a finite value overflows
+///   to infinity.
+pub trait TryCastFrom<T>: CastFrom<T> {
+    /// Converts `value` to `Self`, returning `None` if the value is not
+    /// representable in `Self`.
+    fn try_cast_from(value: T) -> Option<Self>;
+}
+
+/// Implements [`TryCastFrom`] for all pairs of the given integer types.
+macro_rules! impl_try_cast_from_int {
+    ([$($from:ty),+] => $to:tt) => {
+        $(
+            impl_try_cast_from_int!(@from $from => $to);
+        )+
+    };
+    (@from $from:ty => [$($to:ty),+]) => {
+        $(
+            impl TryCastFrom<$from> for $to {
+                #[inline]
+                fn try_cast_from(value: $from) -> Option<Self> {
+                    <$to>::try_from(value).ok()
+                }
+            }
+        )+
+    };
+}
+
+/// Implements [`TryCastFrom`] from the given float types to the given
+/// integer types.
+macro_rules! impl_try_cast_from_float {
+    ([$($from:ty),+] => $to:tt) => {
+        $(
+            impl_try_cast_from_float!(@from $from => $to);
+        )+
+    };
+    (@from $from:ty => [$($to:ty),+]) => {
+        $(
+            impl TryCastFrom<$from> for $to {
+                #[inline]
+                #[allow(
+                    clippy::as_conversions,
+                    clippy::cast_possible_truncation,
+                    clippy::cast_possible_wrap,
+                    clippy::cast_sign_loss
+                )]
+                fn try_cast_from(value: $from) -> Option<Self> {
+                    // The bounds are powers of two, which are exactly
+                    // representable as floats.
+                    let bits = <$to>::BITS as i32;
+                    let (min, max) = if <$to>::MIN == 0 {
+                        (0.0, <$from>::powi(2.0, bits))
+                    } else {
+                        (-<$from>::powi(2.0, bits - 1), <$from>::powi(2.0, bits - 1))
+                    };
+                    let truncated = value.trunc();
+                    (truncated >= min && truncated < max).then_some(truncated as $to)
+                }
+            }
+        )+
+    };
+}
+
+/// Implements [`TryCastFrom`] from the given integer types to the given
+/// float types.
+macro_rules! impl_try_cast_to_float {
+    ([$($from:ty),+] => $to:tt) => {
+        $(
+            impl_try_cast_to_float!(@from $from => $to);
+        )+
+    };
+    (@from $from:ty => [$($to:ty),+]) => {
+        $(
+            impl TryCastFrom<$from> for $to {
+                #[inline]
+                fn try_cast_from(value: $from) -> Option<Self> {
+                    Some(<$to>::cast_from(value))
+                }
+            }
+        )+
+    };
+}
+
+impl_try_cast_from_int!(
+    [i8, i16, i32, i64, i128, u8, u16, u32, u64] => [i8, i16, i32, i64, i128, u8, u16, u32, u64]
+);
+impl_try_cast_from_float!([f32, f64] => [i8, i16, i32, i64, i128, u8, u16, u32, u64]);
+impl_try_cast_to_float!([i8, i16, i32, i64, i128, u8, u16, u32, u64, f32] => [f32, f64]);
+#[cfg(not(feature = "arrow-rs"))]
+impl_try_cast_from_int!(
+    [u128, isize, usize] => [i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, isize, usize]
+);
+#[cfg(not(feature = "arrow-rs"))]
+impl_try_cast_from_int!([i8, i16, i32, i64, i128, u8, u16, u32, u64] => [u128, isize, usize]);
+#[cfg(not(feature = "arrow-rs"))]
+impl_try_cast_from_float!([f32, f64] => [u128, isize, usize]);
+#[cfg(not(feature = "arrow-rs"))]
+impl_try_cast_to_float!([u128, isize, usize] => [f32, f64]);
+
+impl TryCastFrom<f64> for f32 {
+    #[inline]
+    fn try_cast_from(value: f64) -> Option<Self> {
+        let cast = f32::cast_from(value);
+        (cast.is_finite() || !value.is_finite()).then_some(cast)
+    }
+}
+
+impl TryCastFrom<f64> for f64 {
+    #[inline]
+    fn try_cast_from(value: f64) -> Option<Self> {
+        Some(value)
+    }
+}
+
+/// Returns the `values` cast to `U`, or a [`CastError`] with the index of the
+/// first valid value that is not representable in `U`.
+fn try_cast_values<T: FixedSize, U: TryCastFrom<T>, Buffer: BufferType, Output>(
+    values: &[T],
+    validity: Option<&Bitmap<Buffer>>,
+) -> Result<Output, CastError>
+where
+    Output: FromIterator<U>,
+{
+    values
+        .iter()
+        .zip(validity_bits(validity, values.len()))
+        .enumerate()
+        .map(|(index, (&value, valid))| {
+            if valid {
+                U::try_cast_from(value).ok_or(CastError { index })
+            } else {
+                Ok(U::cast_from(value))
+            }
+        })
+        .collect()
+}
+
+impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType>
+    FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: ValuesRef<Item = T, Buffer = Buffer>,
+{
+    /// Returns a nullable array with the values of this array cast to `U`,
+    /// where values that are not representable in `U` (see
+    /// [`TryCastFrom`]) are null. Existing nulls are preserved.
+    #[must_use]
+    pub fn cast_or_null<U: TryCastFrom<T>>(&self) -> FixedSizePrimitiveArray<U, true, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<U>: FromIterator<U>,
+        Bitmap<Buffer>: FromIterator<bool>,
+    {
+        let values = self.values_ref();
+        let (data, validity): (Vec<_>, Vec<_>) = values
+            .iter()
+            .zip(validity_bits(self.validity_ref(), values.len()))
+            .map(
+                |(&value, valid)| match U::try_cast_from(value).filter(|_| valid) {
+                    Some(cast) => (cast, true),
+                    None => (U::cast_from(value), false),
+                },
+            )
+            .unzip();
+        FixedSizePrimitiveArray(Nullable {
+            data: data.into_iter().collect(),
+            validity: validity.into_iter().collect(),
+        })
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> FixedSizePrimitiveArray<T, false, Buffer> {
+    /// Returns an array with the values of this array cast to `U`, with the
+    /// semantics of an `as` cast, see [`CastFrom`].
+    #[must_use]
+    pub fn cast<U: CastFrom<T>>(&self) -> FixedSizePrimitiveArray<U, false, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<U>: FromIterator<U>,
+    {
+        FixedSizePrimitiveArray(
+            self.values_ref()
+                .iter()
+                .map(|&value| U::cast_from(value))
+                .collect(),
+        )
+    }
+
+    /// Returns an array with the values of this array cast to `U`, or a
+    /// [`CastError`] with the index of the first value that is not
+    /// representable in `U`, see [`TryCastFrom`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CastError`] when a value is not representable in `U`.
+    pub fn try_cast<U: TryCastFrom<T>>(
+        &self,
+    ) -> Result<FixedSizePrimitiveArray<U, false, Buffer>, CastError>
+    where
+        <Buffer as BufferType>::Buffer<U>: FromIterator<U>,
+    {
+        try_cast_values::<_, _, Buffer, _>(self.values_ref(), None).map(FixedSizePrimitiveArray)
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> FixedSizePrimitiveArray<T, true, Buffer> {
+    /// Returns an array with the values of this array cast to `U`, with the
+    /// semantics of an `as` cast, see [`CastFrom`]. The validity bitmap is
+    /// preserved.
+    #[must_use]
+    pub fn cast<U: CastFrom<T>>(&self) -> FixedSizePrimitiveArray<U, true, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<U>: FromIterator<U>,
+        Bitmap<Buffer>: Clone,
+    {
+        FixedSizePrimitiveArray(Nullable {
+            data: self
+                .values_ref()
+                .iter()
+                .map(|&value| U::cast_from(value))
+                .collect(),
+            validity: self.0.validity.clone(),
+        })
+    }
+
+    /// Returns an array with the values of this array cast to `U`, or a
+    /// [`CastError`] with the index of the first valid value that is not
+    /// representable in `U`, see [`TryCastFrom`]. Values in null slots are
+    /// ignored. The validity bitmap is preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CastError`] when a valid value is not representable in
+    /// `U`.
+    pub fn try_cast<U: TryCastFrom<T>>(
+        &self,
+    ) -> Result<FixedSizePrimitiveArray<U, true, Buffer>, CastError>
+    where
+        <Buffer as BufferType>::Buffer<U>: FromIterator<U>,
+        Bitmap<Buffer>: Clone,
+    {
+        try_cast_values(self.values_ref(), Some(&self.0.validity)).map(|data| {
+            FixedSizePrimitiveArray(Nullable {
+                data,
+                validity: self.0.validity.clone(),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CastError;
+    use crate::{
+        array::{Float32Array, Float64Array, Int32Array, Int64Array, Uint8Array},
+        bitmap::{BitmapRef, ValidityBitmap},
+    };
+
+    #[test]
+    fn narrowing() {
+        let array = [i64::MAX, -1, 1 << 32, (1 << 32) + 7]
+            .into_iter()
+            .collect::<Int64Array>();
+        assert_eq!(array.cast::<i32>().as_slice(), [-1, -1, 0, 7]);
+        assert_eq!(array.cast::<u8>().as_slice(), [255, 255, 0, 7]);
+
+        let floats = [f64::NAN, -1.5, 1e10, 2.9]
+            .into_iter()
+            .collect::<Float64Array>();
+        assert_eq!(floats.cast::<i32>().as_slice(), [0, -1, i32::MAX, 2]);
+        assert_eq!(floats.cast::<u8>().as_slice(), [0, 0, u8::MAX, 2]);
+    }
+
+    #[test]
+    fn widening() {
+        let array = (0..=u8::MAX).collect::<Uint8Array>();
+        let floats = array.cast::<f32>();
+        assert!(floats
+            .as_slice()
+            .iter()
+            .zip(0..=u8::MAX)
+            .all(|(&float, int)| float.to_bits() == f32::from(int).to_bits()));
+        assert_eq!(
+            [-1_i32]
+                .into_iter()
+                .collect::<Int32Array>()
+                .cast::<i64>()
+                .as_slice(),
+            [-1]
+        );
+    }
+
+    #[test]
+    fn precision() {
+        // 0.1 is not exactly representable, casting to f32 rounds.
+        let array = [0.1, 16_777_217.0].into_iter().collect::<Float64Array>();
+        let floats: Float32Array = array.cast::<f32>();
+        assert_eq!(floats.as_slice()[0].to_bits(), 0.1_f32.to_bits());
+        assert_eq!(floats.as_slice()[1].to_bits(), 16_777_216.0_f32.to_bits());
+    }
+
+    #[test]
+    fn validity() {
+        let array = [Some(1), None, Some(-1), None, Some(300)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let cast = array.cast::<u8>();
+        assert_eq!(cast.bitmap_ref(), array.bitmap_ref());
+        assert_eq!(cast.null_count(), 2);
+        assert_eq!(
+            cast.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(255), None, Some(44)]
+        );
+    }
+
+    #[test]
+    fn try_cast() {
+        let array = [0, i64::MAX, -1].into_iter().collect::<Int64Array>();
+        assert_eq!(
+            array.try_cast::<i32>().map(Vec::from),
+            Err(CastError { index: 1 })
+        );
+        assert_eq!(
+            array.try_cast::<u64>().map(Vec::from),
+            Err(CastError { index: 2 })
+        );
+        assert_eq!(
+            array.try_cast::<i128>().map(Vec::from),
+            Ok(vec![0, i128::from(i64::MAX), -1])
+        );
+        assert_eq!(
+            array.cast_or_null::<i32>().into_iter().collect::<Vec<_>>(),
+            [Some(0), None, Some(-1)]
+        );
+        assert_eq!(
+            array.cast_or_null::<u8>().into_iter().collect::<Vec<_>>(),
+            [Some(0), None, None]
+        );
+
+        // Values in null slots are ignored.
+        let nullable = [Some(1), None, Some(i64::MAX)]
+            .into_iter()
+            .collect::<Int64Array<true>>();
+        assert_eq!(
+            nullable
+                .try_cast::<i32>()
+                .map(|cast| cast.into_iter().collect::<Vec<_>>()),
+            Err(CastError { index: 2 })
+        );
+        assert_eq!(
+            nullable
+                .cast_or_null::<i32>()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(1), None, None]
+        );
+        assert_eq!(
+            nullable
+                .cast_or_null::<i128>()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(1), None, Some(i128::from(i64::MAX))]
+        );
+    }
+
+    #[test]
+    fn try_cast_float() {
+        let floats = [
+            1.9,
+            -1.9,
+            f64::NAN,
+            f64::INFINITY,
+            9.3e18,
+            -9.3e18,
+            255.5,
+            256.0,
+        ]
+        .into_iter()
+        .collect::<Float64Array>();
+        assert_eq!(
+            floats.cast_or_null::<i64>().into_iter().collect::<Vec<_>>(),
+            [
+                Some(1),
+                Some(-1),
+                None,
+                None,
+                None,
+                None,
+                Some(255),
+                Some(256)
+            ]
+        );
+        assert_eq!(
+            floats.cast_or_null::<u8>().into_iter().collect::<Vec<_>>(),
+            [Some(1), None, None, None, None, None, Some(255), None]
+        );
+        assert_eq!(
+            floats.try_cast::<i64>().map(Vec::from),
+            Err(CastError { index: 2 })
+        );
+
+        // The bounds are exact.
+        let bounds = [-9_223_372_036_854_775_808.0, 9_223_372_036_854_775_808.0]
+            .into_iter()
+            .collect::<Float64Array>();
+        assert_eq!(
+            bounds.cast_or_null::<i64>().into_iter().collect::<Vec<_>>(),
+            [Some(i64::MIN), None]
+        );
+
+        let large = [f64::MAX, f64::INFINITY, 0.1]
+            .into_iter()
+            .collect::<Float64Array>();
+        assert_eq!(
+            large
+                .cast_or_null::<f32>()
+                .into_iter()
+                .map(|value| value.map(f32::to_bits))
+                .collect::<Vec<_>>(),
+            [None, Some(f32::INFINITY.to_bits()), Some(0.1_f32.to_bits())]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Comparison kernels.
+
+use super::{datum::binary, Datum};
+use crate::{array::BooleanArray, nullable::Nullable};
+
+/// Returns the results of `op` applied to the elements of `left` and
+/// `right`.
+fn compare<L: Datum, R: Datum<Item = L::Item>>(
+    left: &L,
+    right: &R,
+    op: impl Fn(L::Item, L::Item) -> bool,
+) -> BooleanArray<true> {
+    let (data, validity) = binary(left, right, op);
+    BooleanArray(Nullable { data, validity })
+}
+
+/// Implements a comparison kernel.
+macro_rules! impl_comparison {
+    ($(($name:ident, $op:tt, $doc:literal)),+) => {
+        $(
+            #[doc = concat!("Returns whether the elements of `left` are ", $doc, " the elements of `right`.")]
+            ///
+            /// Either operand can be an array or a [`Scalar`](super::Scalar),
+            /// see [`Datum`]. Nulls propagate, and a null scalar results in
+            /// all nulls.
+            ///
+            /// # Panics
+            ///
+            /// Panics if both operands are arrays with different lengths.
+            pub fn $name<L: Datum, R: Datum<Item = L::Item>>(left: &L, right: &R) -> BooleanArray<true>
+            where
+                L::Item: PartialOrd,
+            {
+                compare(left, right, |lhs, rhs| lhs $op rhs)
+            }
+        )+
+    };
+}
+
+impl_comparison!(
+    (eq, ==, "equal to"),
+    (neq, !=, "not equal to"),
+    (lt, <, "less than"),
+    (lt_eq, <=, "less than or equal to"),
+    (gt, >, "greater than"),
+    (gt_eq, >=, "greater than or equal to")
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{Extended, ExtensionType, Float64Array, Int32Array},
+        compute::Scalar,
+        Length,
+    };
+
+    #[test]
+    fn arrays() {
+        let left = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let right = [1, 2, 4].into_iter().collect::<Int32Array>();
+        assert_eq!(eq(&left, &right), [Some(true), None, Some(false)]);
+        assert_eq!(neq(&left, &right), [Some(false), None, Some(true)]);
+        assert_eq!(lt(&left, &right), [Some(false), None, Some(true)]);
+        assert_eq!(gt_eq(&left, &right), [Some(true), None, Some(false)]);
+        let nan = [f64::NAN].into_iter().collect::<Float64Array>();
+        assert_eq!(eq(&nan, &nan), [Some(false)]);
+    }
+
+    #[test]
+    fn scalar_right() {
+        let array = [1, 5, 9].into_iter().collect::<Int32Array>();
+        assert_eq!(
+            gt(&array, &Scalar::new(4)),
+            [Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(
+            eq(&array, &Scalar::new(5)),
+            [Some(false), Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn scalar_left() {
+        let array = [1, 5, 9].into_iter().collect::<Int32Array>();
+        assert_eq!(
+            gt(&Scalar::new(4), &array),
+            [Some(true), Some(false), Some(false)]
+        );
+        assert_eq!(
+            lt_eq(&Scalar::new(5), &array),
+            [Some(false), Some(true), Some(true)]
+        );
+    }
+
+    #[test]
+    fn null_scalar() {
+        let array = [1, 5].into_iter().collect::<Int32Array>();
+        assert_eq!(eq(&array, &Scalar::null()), [None, None]);
+        assert_eq!(lt(&Scalar::null(), &array), [None, None]);
+    }
+
+    #[test]
+    fn materialized() {
+        let array = [Some(4), None, Some(-2), Some(7)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        for scalar in [Scalar::new(4), Scalar::null()] {
+            let constant = scalar.to_array(array.len());
+            assert_eq!(eq(&array, &scalar), eq(&array, &constant));
+            assert_eq!(lt(&scalar, &array), lt(&constant, &array));
+            assert_eq!(gt_eq(&array, &scalar), gt_eq(&array, &constant));
+        }
+    }
+
+    #[test]
+    fn extended() {
+        let array = Extended::new(
+            [Some(4), None, Some(-2)]
+                .into_iter()
+                .collect::<Int32Array<true>>(),
+            ExtensionType::new("celsius"),
+        );
+        assert_eq!(
+            gt(&array, &Scalar::new(0)),
+            gt(array.inner(), &Scalar::new(0))
+        );
+        assert_eq!(eq(&array, &array), [Some(true), None, Some(true)]);
+    }
+}
@@ -0,0 +1,275 @@
+//! Conditional kernels.
+
+use super::{validity_words, BooleanValuesRef, ValuesRef};
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray},
+    bitmap::Bitmap,
+    buffer::BufferType,
+    nullable::Nullable,
+    FixedSize, Length,
+};
+
+/// Selection of elements from two arrays according to a boolean mask.
+pub trait IfElse<Mask>: Sized {
+    /// Returns an array with the elements of `if_true` where `mask` is
+    /// `true`, and the elements of `if_false` where `mask` is `false`.
+    ///
+    /// For nullable arrays, a null in the mask results in a null, and nulls
+    /// in the selected array propagate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of `mask`, `if_true` and `if_false` are not
+    /// equal.
+    fn if_else(mask: &Mask, if_true: &Self, if_false: &Self) -> Self;
+}
+
+/// Returns an array with the elements of `if_true` where `mask` is `true`,
+/// and the elements of `if_false` where `mask` is `false`.
+///
+/// For nullable arrays, a null in the mask results in a null, and nulls in
+/// the selected array propagate. The mask of non-nullable arrays must be
+/// non-nullable.
+///
+/// # Panics
+///
+/// Panics if the lengths of `mask`, `if_true` and `if_false` are not equal.
+pub fn if_else<Mask, A: IfElse<Mask>>(mask: &Mask, if_true: &A, if_false: &A) -> A {
+    A::if_else(mask, if_true, if_false)
+}
+
+/// Asserts that the lengths of the mask and both inputs are equal.
+fn assert_lengths(mask: usize, if_true: usize, if_false: usize) {
+    assert!(
+        mask == if_true && mask == if_false,
+        "length mismatch: mask has {mask} elements, inputs have {if_true} and {if_false} elements"
+    );
+}
+
+/// Returns the values selected by the `mask` from `if_true` and `if_false`,
+/// copying runs of equal mask values in bulk.
+fn select_values<T: Copy, Buffer: BufferType>(
+    mask: &Bitmap<Buffer>,
+    if_true: &[T],
+    if_false: &[T],
+    output: &mut impl Extend<T>,
+) {
+    for (range, value) in mask.runs() {
+        let values = if value { if_true } else { if_false };
+        output.extend(values[range].iter().copied());
+    }
+}
+
+/// Returns the validity of the selected elements.
+///
+/// The validity is computed a word at a time, in a single pass over the
+/// mask, its validity and the validity of both inputs.
+fn select_validity<Buffer: BufferType, Output: BufferType>(
+    mask: &Bitmap<Buffer>,
+    mask_validity: Option<&Bitmap<Buffer>>,
+    if_true: Option<&Bitmap<impl BufferType>>,
+    if_false: Option<&Bitmap<impl BufferType>>,
+) -> Bitmap<Output>
+where
+    <Output as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    let len = mask.len();
+    Bitmap::from_words(
+        mask.words()
+            .zip(validity_words(mask_validity, len))
+            .zip(validity_words(if_true, len).zip(validity_words(if_false, len)))
+            .map(|(((value, bits), valid), (true_valid, false_valid))| {
+                (
+                    valid & ((value & true_valid) | (!value & false_valid)),
+                    bits,
+                )
+            }),
+    )
+}
+
+impl<T: FixedSize, Buffer: BufferType, MaskBuffer: BufferType>
+    IfElse<BooleanArray<false, MaskBuffer>> for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Default + Extend<T>,
+{
+    fn if_else(mask: &BooleanArray<false, MaskBuffer>, if_true: &Self, if_false: &Self) -> Self {
+        let (true_values, false_values) = (if_true.values_ref(), if_false.values_ref());
+        assert_lengths(mask.len(), true_values.len(), false_values.len());
+        let mut data = <Buffer as BufferType>::Buffer::<T>::default();
+        select_values(mask.bits_ref(), true_values, false_values, &mut data);
+        FixedSizePrimitiveArray(data)
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType, Mask: BooleanValuesRef> IfElse<Mask>
+    for FixedSizePrimitiveArray<T, true, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Default + Extend<T>,
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    fn if_else(mask: &Mask, if_true: &Self, if_false: &Self) -> Self {
+        let (true_values, false_values) = (if_true.values_ref(), if_false.values_ref());
+        let bits = mask.bits_ref();
+        assert_lengths(bits.len(), true_values.len(), false_values.len());
+        let mut data = <Buffer as BufferType>::Buffer::<T>::default();
+        select_values(bits, true_values, false_values, &mut data);
+        let validity = select_validity(
+            bits,
+            mask.validity_ref(),
+            if_true.validity_ref(),
+            if_false.validity_ref(),
+        );
+        FixedSizePrimitiveArray(Nullable { data, validity })
+    }
+}
+
+impl<Buffer: BufferType, MaskBuffer: BufferType> IfElse<BooleanArray<false, MaskBuffer>>
+    for BooleanArray<false, Buffer>
+where
+    Bitmap<Buffer>: FromIterator<bool>,
+{
+    fn if_else(mask: &BooleanArray<false, MaskBuffer>, if_true: &Self, if_false: &Self) -> Self {
+        assert_lengths(mask.len(), if_true.len(), if_false.len());
+        BooleanArray(
+            mask.0
+                .iter()
+                .zip(if_true.0.iter().zip(&if_false.0))
+                .map(|(value, (a, b))| if value { a } else { b })
+                .collect(),
+        )
+    }
+}
+
+impl<Buffer: BufferType, Mask: BooleanValuesRef> IfElse<Mask> for BooleanArray<true, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    fn if_else(mask: &Mask, if_true: &Self, if_false: &Self) -> Self {
+        let bits = mask.bits_ref();
+        assert_lengths(bits.len(), if_true.len(), if_false.len());
+        let data = bits
+            .iter()
+            .zip(if_true.0.data.iter().zip(&if_false.0.data))
+            .map(|(value, (a, b))| if value { a } else { b })
+            .collect();
+        let validity = select_validity(
+            bits,
+            mask.validity_ref(),
+            Some(&if_true.0.validity),
+            Some(&if_false.0.validity),
+        );
+        BooleanArray(Nullable { data, validity })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+    use std::iter;
+
+    #[test]
+    fn alternating() {
+        let mask = [true, false, true, false]
+            .into_iter()
+            .collect::<BooleanArray>();
+        let a = [1, 2, 3, 4].into_iter().collect::<Int32Array>();
+        let b = [10, 20, 30, 40].into_iter().collect::<Int32Array>();
+        assert_eq!(if_else(&mask, &a, &b).as_slice(), [1, 20, 3, 40]);
+
+        let x = [true, true, true, true]
+            .into_iter()
+            .collect::<BooleanArray>();
+        let y = [false, false, false, false]
+            .into_iter()
+            .collect::<BooleanArray>();
+        assert_eq!(
+            if_else(&mask, &x, &y).into_iter().collect::<Vec<_>>(),
+            [true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn bulk() {
+        let mask = iter::repeat(true)
+            .take(100)
+            .chain(iter::repeat(false).take(100))
+            .collect::<BooleanArray>();
+        let a = (0..200).collect::<Int32Array>();
+        let b = (0..200).map(|x| -x).collect::<Int32Array>();
+        let result = if_else(&mask, &a, &b);
+        assert_eq!(result.as_slice()[..100], a.as_slice()[..100]);
+        assert_eq!(result.as_slice()[100..], b.as_slice()[100..]);
+    }
+
+    #[test]
+    fn nullable() {
+        let mask = [Some(true), None, Some(false), Some(true), Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let a = [Some(1), Some(2), Some(3), None, Some(5)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let b = [Some(10), Some(20), None, Some(40), Some(50)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            if_else(&mask, &a, &b).into_iter().collect::<Vec<_>>(),
+            [Some(1), None, None, None, Some(50)]
+        );
+
+        let non_nullable_mask = [false, true, false, true, false]
+            .into_iter()
+            .collect::<BooleanArray>();
+        assert_eq!(
+            if_else(&non_nullable_mask, &a, &b)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(10), Some(2), None, None, Some(50)]
+        );
+
+        let x = [Some(true), Some(true), Some(true), None, Some(true)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let y = [Some(false), Some(false), None, Some(false), Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(
+            if_else(&mask, &x, &y).into_iter().collect::<Vec<_>>(),
+            [Some(true), None, None, None, Some(false)]
+        );
+
+        // The validity is computed a word at a time, including the last
+        // partial word.
+        let long_mask = (0..150)
+            .map(|index| (index % 7 != 0).then_some(index % 2 == 0))
+            .collect::<BooleanArray<true>>();
+        let long_a = (0..150)
+            .map(|index| (index % 3 != 0).then_some(index))
+            .collect::<Int32Array<true>>();
+        let long_b = (0..150)
+            .map(|index| Some(-index))
+            .collect::<Int32Array<true>>();
+        let expected = (0..150)
+            .map(|index| match (index % 7 != 0).then_some(index % 2 == 0) {
+                Some(true) => (index % 3 != 0).then_some(index),
+                Some(false) => Some(-index),
+                None => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            if_else(&long_mask, &long_a, &long_b)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch")]
+    fn length_mismatch() {
+        let mask = [true].into_iter().collect::<BooleanArray>();
+        let a = [1, 2].into_iter().collect::<Int32Array>();
+        let _ = if_else(&mask, &a, &a);
+    }
+}
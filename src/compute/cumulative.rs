@@ -0,0 +1,308 @@
+//! Cumulative (prefix scan) kernels.
+
+use super::{distinct::items, Numeric, Overflow, ValuesRef};
+use crate::{array::FixedSizePrimitiveArray, buffer::BufferType};
+use std::convert::Infallible;
+
+/// How nulls are treated by the cumulative sum kernels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NullTreatment {
+    /// Nulls result in nulls, and do not change the running total.
+    #[default]
+    Skip,
+    /// Nulls are treated as zero, and result in the running total.
+    Zero,
+}
+
+/// Returns the running values of `step` over the items. Nulls are replaced by
+/// `null`, or result in nulls when `null` is `None`. Stops at the first error
+/// returned by `step`.
+fn scan<T: Copy, E>(
+    items: impl Iterator<Item = Option<T>>,
+    null: Option<T>,
+    step: impl Fn(T, T) -> Result<T, E>,
+) -> Result<Vec<Option<T>>, E> {
+    let mut running = None;
+    items
+        .map(|item| {
+            item.or(null)
+                .map(|value| {
+                    let next = match running {
+                        Some(current) => step(current, value)?,
+                        None => value,
+                    };
+                    running = Some(next);
+                    Ok(next)
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+/// Returns the value of a result that can't be an error.
+fn infallible<T>(result: Result<T, Infallible>) -> T {
+    result.unwrap_or_else(|never| match never {})
+}
+
+/// Returns the null replacement value for the cumulative sum kernels.
+fn null_value<T: Numeric>(nulls: NullTreatment) -> Option<T> {
+    match nulls {
+        NullTreatment::Skip => None,
+        NullTreatment::Zero => Some(T::ZERO),
+    }
+}
+
+/// Returns the running sums of the items, wrapping around on overflow.
+fn wrapping_cumsum<T: Numeric>(
+    items: impl Iterator<Item = Option<T>>,
+    nulls: NullTreatment,
+) -> Vec<Option<T>> {
+    infallible(scan(items, null_value(nulls), |current, value| {
+        Ok(current.wrapping_add(value))
+    }))
+}
+
+/// Returns the running sums of the items, or [`Overflow`] when a sum
+/// overflows.
+fn checked_cumsum<T: Numeric>(
+    items: impl Iterator<Item = Option<T>>,
+    nulls: NullTreatment,
+) -> Result<Vec<Option<T>>, Overflow> {
+    scan(items, null_value(nulls), |current, value| {
+        current.checked_add(value).ok_or(Overflow)
+    })
+}
+
+/// Returns the running extremes of the items, where `replace` returns `true`
+/// when the candidate (first argument) should replace the current extreme
+/// (second argument). NaN values are ignored unless no other value was seen.
+fn cumulative_select<T: Numeric>(
+    items: impl Iterator<Item = Option<T>>,
+    replace: impl Fn(T, T) -> bool,
+) -> Vec<Option<T>> {
+    infallible(scan(items, None, |current, value| {
+        Ok(if current.is_nan() || replace(value, current) {
+            value
+        } else {
+            current
+        })
+    }))
+}
+
+impl<T: Numeric, Buffer: BufferType> FixedSizePrimitiveArray<T, false, Buffer>
+where
+    Self: FromIterator<T>,
+{
+    /// Returns the running sums of the values in this array, wrapping around
+    /// on overflow.
+    #[must_use]
+    pub fn cumsum(&self) -> Self {
+        wrapping_cumsum(
+            self.values_ref().iter().copied().map(Some),
+            NullTreatment::Skip,
+        )
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Returns the running sums of the values in this array, or [`Overflow`]
+    /// when a sum overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Overflow`] when a sum overflows `T`.
+    pub fn checked_cumsum(&self) -> Result<Self, Overflow> {
+        checked_cumsum(
+            self.values_ref().iter().copied().map(Some),
+            NullTreatment::Skip,
+        )
+        .map(|sums| sums.into_iter().flatten().collect())
+    }
+
+    /// Returns the running maximums of the values in this array. NaN values
+    /// are ignored unless no other value was seen.
+    #[must_use]
+    pub fn cummax(&self) -> Self {
+        cumulative_select(
+            self.values_ref().iter().copied().map(Some),
+            |value, current| value > current,
+        )
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Returns the running minimums of the values in this array. NaN values
+    /// are ignored unless no other value was seen.
+    #[must_use]
+    pub fn cummin(&self) -> Self {
+        cumulative_select(
+            self.values_ref().iter().copied().map(Some),
+            |value, current| value < current,
+        )
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl<T: Numeric, Buffer: BufferType> FixedSizePrimitiveArray<T, true, Buffer>
+where
+    Self: FromIterator<Option<T>>,
+{
+    /// Returns the running sums of the values in this array, wrapping around
+    /// on overflow. Nulls result in nulls, and do not change the running
+    /// total.
+    #[must_use]
+    pub fn cumsum(&self) -> Self {
+        self.cumsum_with(NullTreatment::Skip)
+    }
+
+    /// Returns the running sums of the values in this array, wrapping around
+    /// on overflow. Nulls are treated according to `nulls`.
+    #[must_use]
+    pub fn cumsum_with(&self, nulls: NullTreatment) -> Self {
+        wrapping_cumsum(items(self.values_ref(), self.validity_ref()), nulls)
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the running sums of the values in this array, or [`Overflow`]
+    /// when a sum overflows. Nulls result in nulls, and do not change the
+    /// running total.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Overflow`] when a sum overflows `T`.
+    pub fn checked_cumsum(&self) -> Result<Self, Overflow> {
+        self.checked_cumsum_with(NullTreatment::Skip)
+    }
+
+    /// Returns the running sums of the values in this array, or [`Overflow`]
+    /// when a sum overflows. Nulls are treated according to `nulls`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Overflow`] when a sum overflows `T`.
+    pub fn checked_cumsum_with(&self, nulls: NullTreatment) -> Result<Self, Overflow> {
+        checked_cumsum(items(self.values_ref(), self.validity_ref()), nulls)
+            .map(|sums| sums.into_iter().collect())
+    }
+
+    /// Returns the running maximums of the values in this array. Nulls result
+    /// in nulls. NaN values are ignored unless no other value was seen.
+    #[must_use]
+    pub fn cummax(&self) -> Self {
+        cumulative_select(
+            items(self.values_ref(), self.validity_ref()),
+            |value, current| value > current,
+        )
+        .into_iter()
+        .collect()
+    }
+
+    /// Returns the running minimums of the values in this array. Nulls result
+    /// in nulls. NaN values are ignored unless no other value was seen.
+    #[must_use]
+    pub fn cummin(&self) -> Self {
+        cumulative_select(
+            items(self.values_ref(), self.validity_ref()),
+            |value, current| value < current,
+        )
+        .into_iter()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float64Array, Int32Array, Uint8Array};
+
+    #[test]
+    fn cumsum() {
+        let array = [1, 2, 3, 4].into_iter().collect::<Int32Array>();
+        assert_eq!(array.cumsum().as_slice(), [1, 3, 6, 10]);
+        assert_eq!(array.checked_cumsum().map(Vec::from), Ok(vec![1, 3, 6, 10]));
+
+        let empty = Int32Array::<false>::default();
+        assert!(empty.cumsum().as_slice().is_empty());
+        assert!(empty
+            .checked_cumsum()
+            .is_ok_and(|sums| sums.as_slice().is_empty()));
+        assert!(Int32Array::<true>::default().cumsum().values().is_empty());
+    }
+
+    #[test]
+    fn overflow() {
+        let array = [200, 50, 10].into_iter().collect::<Uint8Array>();
+        assert_eq!(array.cumsum().as_slice(), [200, 250, 4]);
+        assert_eq!(array.checked_cumsum().map(Vec::from), Err(Overflow));
+
+        let nullable = [Some(i32::MAX), None, Some(1)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            nullable.cumsum().into_iter().collect::<Vec<_>>(),
+            [Some(i32::MAX), None, Some(i32::MIN)]
+        );
+        assert!(nullable.checked_cumsum().is_err());
+    }
+
+    #[test]
+    fn nulls() {
+        let array = [None, Some(1), None, Some(2), Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            array.cumsum().into_iter().collect::<Vec<_>>(),
+            [None, Some(1), None, Some(3), Some(6)]
+        );
+        assert_eq!(
+            array
+                .cumsum_with(NullTreatment::Zero)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(0), Some(1), Some(1), Some(3), Some(6)]
+        );
+        assert_eq!(
+            array
+                .checked_cumsum_with(NullTreatment::Zero)
+                .map(|sums| sums.into_iter().collect::<Vec<_>>()),
+            Ok(vec![Some(0), Some(1), Some(1), Some(3), Some(6)])
+        );
+    }
+
+    #[test]
+    fn cummax_cummin() {
+        let array = [3, 1, 4, 1, 5, 9, 2].into_iter().collect::<Int32Array>();
+        assert_eq!(array.cummax().as_slice(), [3, 3, 4, 4, 5, 9, 9]);
+        assert_eq!(array.cummin().as_slice(), [3, 1, 1, 1, 1, 1, 1]);
+
+        let nullable = [None, Some(2), None, Some(1), Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            nullable.cummax().into_iter().collect::<Vec<_>>(),
+            [None, Some(2), None, Some(2), Some(3)]
+        );
+        assert_eq!(
+            nullable.cummin().into_iter().collect::<Vec<_>>(),
+            [None, Some(2), None, Some(1), Some(1)]
+        );
+
+        let floats = [f64::NAN, 1.0, f64::NAN, 0.5]
+            .into_iter()
+            .collect::<Float64Array>();
+        let maximums = floats.cummax();
+        assert!(maximums.as_slice()[0].is_nan());
+        assert_eq!(
+            maximums.as_slice()[1..]
+                .iter()
+                .map(|value| value.to_bits())
+                .collect::<Vec<_>>(),
+            [1.0_f64.to_bits(); 3]
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Array and scalar operands of binary kernels.
+
+use super::ValuesRef;
+use crate::{
+    array::FixedSizePrimitiveArray,
+    bitmap::{combine_validity, Bitmap},
+    buffer::{BufferType, VecBuffer},
+    nullable::Nullable,
+    FixedSize,
+};
+use std::iter;
+
+/// A single, possibly null, value.
+///
+/// Binary kernels accept a scalar on either side via [`Datum`], which applies
+/// the scalar to every element of the other operand without materializing a
+/// constant array. A null scalar results in all nulls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Scalar<T>(Option<T>);
+
+impl<T> Scalar<T> {
+    /// Returns a new valid [`Scalar`] with the given value.
+    pub const fn new(value: T) -> Self {
+        Self(Some(value))
+    }
+
+    /// Returns a new null [`Scalar`].
+    #[must_use]
+    pub const fn null() -> Self {
+        Self(None)
+    }
+
+    /// Returns the value of this scalar, or `None` if it is null.
+    pub fn value(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+
+    /// Returns `true` if this scalar is null.
+    pub fn is_null(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns an array with `len` copies of this scalar.
+    #[must_use]
+    pub fn to_array(&self, len: usize) -> FixedSizePrimitiveArray<T, true>
+    where
+        T: FixedSize + Default,
+    {
+        self.0.map_or_else(
+            || FixedSizePrimitiveArray::new_null(len),
+            |value| iter::repeat(Some(value)).take(len).collect(),
+        )
+    }
+}
+
+impl<T> From<T> for Scalar<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<Option<T>> for Scalar<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+/// The values of an operand of a binary kernel, see [`Datum`].
+#[derive(Debug)]
+pub enum ArrayOrScalar<'a, T, Buffer: BufferType> {
+    /// The values and validity of an array.
+    Array(&'a [T], Option<&'a Bitmap<Buffer>>),
+    /// A scalar that applies to every element of the other operand.
+    Scalar(Scalar<T>),
+}
+
+/// Operands of binary kernels: arrays or scalars.
+pub trait Datum {
+    /// The type of the values.
+    type Item: FixedSize;
+
+    /// The buffer type of the validity bitmap.
+    type Buffer: BufferType;
+
+    /// Returns the values of this operand.
+    fn as_datum(&self) -> ArrayOrScalar<'_, Self::Item, Self::Buffer>;
+}
+
+impl<A: ValuesRef> Datum for A {
+    type Item = <A as ValuesRef>::Item;
+    type Buffer = <A as ValuesRef>::Buffer;
+
+    fn as_datum(&self) -> ArrayOrScalar<'_, Self::Item, Self::Buffer> {
+        ArrayOrScalar::Array(self.values_ref(), self.validity_ref())
+    }
+}
+
+impl<T: FixedSize> Datum for Scalar<T> {
+    type Item = T;
+    type Buffer = VecBuffer;
+
+    fn as_datum(&self) -> ArrayOrScalar<'_, T, VecBuffer> {
+        ArrayOrScalar::Scalar(*self)
+    }
+}
+
+/// Returns the values and validity of `op` applied to the elements of
+/// `left` and `right`.
+///
+/// A scalar applies to every element of the other operand, and two scalars
+/// result in a single element. Null elements have default values, and the
+/// operation is not applied when a scalar is null.
+///
+/// # Panics
+///
+/// Panics if both operands are arrays with different lengths.
+pub(crate) fn binary<L: Datum, R: Datum<Item = L::Item>, U: Default, Values: FromIterator<U>>(
+    left: &L,
+    right: &R,
+    op: impl Fn(L::Item, L::Item) -> U,
+) -> (Values, Bitmap) {
+    match (left.as_datum(), right.as_datum()) {
+        (
+            ArrayOrScalar::Array(left_values, left_validity),
+            ArrayOrScalar::Array(right_values, right_validity),
+        ) => {
+            assert_eq!(
+                left_values.len(),
+                right_values.len(),
+                "length mismatch: left has {} elements, right has {} elements",
+                left_values.len(),
+                right_values.len()
+            );
+            (
+                left_values
+                    .iter()
+                    .zip(right_values)
+                    .map(|(&lhs, &rhs)| op(lhs, rhs))
+                    .collect(),
+                combine_validity(left_validity, right_validity)
+                    .unwrap_or_else(|| Bitmap::new_valid(left_values.len())),
+            )
+        }
+        (ArrayOrScalar::Array(values, validity), ArrayOrScalar::Scalar(Scalar(Some(rhs)))) => (
+            values.iter().map(|&lhs| op(lhs, rhs)).collect(),
+            copy_validity(validity, values.len()),
+        ),
+        (ArrayOrScalar::Scalar(Scalar(Some(lhs))), ArrayOrScalar::Array(values, validity)) => (
+            values.iter().map(|&rhs| op(lhs, rhs)).collect(),
+            copy_validity(validity, values.len()),
+        ),
+        (ArrayOrScalar::Array(values, _), ArrayOrScalar::Scalar(Scalar(None)))
+        | (ArrayOrScalar::Scalar(Scalar(None)), ArrayOrScalar::Array(values, _)) => (
+            iter::repeat_with(U::default).take(values.len()).collect(),
+            Bitmap::new_null(values.len()),
+        ),
+        (ArrayOrScalar::Scalar(Scalar(lhs)), ArrayOrScalar::Scalar(Scalar(rhs))) => {
+            let value = lhs
+                .zip(rhs)
+                .map(|(lhs_value, rhs_value)| op(lhs_value, rhs_value));
+            let validity = iter::once(value.is_some()).collect();
+            (iter::once(value.unwrap_or_default()).collect(), validity)
+        }
+    }
+}
+
+/// Returns a copy of an optional validity bitmap, or a bitmap with `len`
+/// valid elements if there is no bitmap.
+fn copy_validity<Buffer: BufferType>(validity: Option<&Bitmap<Buffer>>, len: usize) -> Bitmap {
+    combine_validity(validity, None::<&Bitmap>).unwrap_or_else(|| Bitmap::new_valid(len))
+}
+
+/// Returns a nullable primitive array with the values and validity returned
+/// by [`binary`].
+pub(crate) fn primitive<T: FixedSize>(
+    (values, validity): (Vec<T>, Bitmap),
+) -> FixedSizePrimitiveArray<T, true> {
+    FixedSizePrimitiveArray(Nullable {
+        data: values,
+        validity,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Length;
+
+    #[test]
+    fn scalar() {
+        let valid = Scalar::new(3_u8);
+        assert_eq!(valid.value(), Some(&3));
+        assert!(!valid.is_null());
+        assert_eq!(valid.to_array(2), [Some(3), Some(3)]);
+
+        let null = Scalar::<u8>::null();
+        assert!(null.is_null());
+        assert_eq!(null.to_array(2), [None, None]);
+        assert_eq!(Scalar::from(None::<u8>), null);
+        assert!(valid.to_array(0).is_empty());
+    }
+}
@@ -0,0 +1,784 @@
+//! Dictionary encoding kernels.
+
+use super::{
+    distinct::items,
+    string::{nullable_strs, strs},
+    Numeric, ValuesRef,
+};
+use crate::{
+    array::{DictionaryArray, FixedSizePrimitiveArray, StringArray, Uint32Array},
+    buffer::BufferType,
+    offset::OffsetElement,
+    FixedSize, Index,
+};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    error::Error,
+    fmt::{self, Display, Formatter},
+    hash::Hash,
+};
+
+/// The error returned when the number of values in a dictionary does not fit
+/// in the key type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DictionaryKeyOverflow;
+
+impl Display for DictionaryKeyOverflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("dictionary key overflow")
+    }
+}
+
+impl Error for DictionaryKeyOverflow {}
+
+/// Returns the keys and the distinct values (in first-occurrence order) of
+/// the items. Items are compared by their `key`, nulls get null keys.
+///
+/// # Panics
+///
+/// Panics if the number of distinct values does not fit in a [`u32`].
+fn encode_by<T, K: Eq + Hash>(
+    items: impl Iterator<Item = Option<T>>,
+    key: impl Fn(&T) -> K,
+) -> (Vec<Option<u32>>, Vec<T>) {
+    let mut positions = HashMap::<K, u32>::new();
+    let mut values = Vec::new();
+    let keys = items
+        .map(|item| {
+            item.map(|value| match positions.entry(key(&value)) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    let position = u32::try_from(values.len()).expect("key fits in u32");
+                    values.push(value);
+                    *entry.insert(position)
+                }
+            })
+        })
+        .collect();
+    (keys, values)
+}
+
+/// Returns the dictionary keys and values of a primitive array.
+fn primitive_encode<A: ValuesRef>(array: &A) -> (Vec<Option<u32>>, Vec<A::Item>)
+where
+    A::Item: Numeric,
+{
+    encode_by(
+        items(array.values_ref(), array.validity_ref()),
+        |value: &A::Item| value.hash_bits(),
+    )
+}
+
+/// Converts a dictionary key to an index in the dictionary.
+fn key_index(key: u32) -> usize {
+    usize::try_from(key).expect("key fits in usize")
+}
+
+/// Converts a dictionary key of type `K` to an index in the dictionary.
+fn index_of<K: Into<u64>>(key: K) -> usize {
+    usize::try_from(key.into()).expect("key fits in usize")
+}
+
+/// Converts an index in the dictionary to a key of type `K`.
+fn key_of<K: TryFrom<usize>>(index: usize) -> Result<K, DictionaryKeyOverflow> {
+    K::try_from(index).map_err(|_| DictionaryKeyOverflow)
+}
+
+/// Returns the indices of the non-nullable `keys`.
+fn indices<K: FixedSize + Into<u64>>(keys: &FixedSizePrimitiveArray<K>) -> Vec<Option<usize>> {
+    keys.values_ref()
+        .iter()
+        .map(|&key| Some(index_of(key)))
+        .collect()
+}
+
+/// Returns the indices of the nullable `keys`.
+fn nullable_indices<K: FixedSize + Into<u64>>(
+    keys: &FixedSizePrimitiveArray<K, true>,
+) -> Vec<Option<usize>> {
+    items(keys.values_ref(), keys.validity_ref())
+        .map(|key| key.map(index_of))
+        .collect()
+}
+
+/// Returns the indices of the values in a dictionary with `len` values that
+/// are referenced by `keys`, in dictionary order, and the keys remapped to
+/// positions in these indices.
+///
+/// # Panics
+///
+/// Panics if a key is out of bounds of the dictionary.
+fn compact_keys(keys: &[Option<usize>], len: usize) -> (Vec<usize>, Vec<Option<usize>>) {
+    let mut positions = vec![None; len];
+    keys.iter()
+        .flatten()
+        .for_each(|&index| positions[index] = Some(0));
+    let mut referenced = Vec::new();
+    for (index, position) in positions.iter_mut().enumerate() {
+        if position.is_some() {
+            *position = Some(referenced.len());
+            referenced.push(index);
+        }
+    }
+    let remapped = keys
+        .iter()
+        .map(|key| key.and_then(|index| positions[index]))
+        .collect();
+    (referenced, remapped)
+}
+
+/// Returns the distinct values of all `dictionaries` (in first-occurrence
+/// order) and the keys of every dictionary remapped to these values. Values
+/// are compared by their `hash`.
+///
+/// # Errors
+///
+/// Returns a [`DictionaryKeyOverflow`] error if the number of distinct values
+/// does not fit in the key type `K`.
+///
+/// # Panics
+///
+/// Panics if a key is out of bounds of its dictionary.
+#[allow(clippy::type_complexity)]
+fn unify_by<K: Copy + TryFrom<usize>, V: Copy, H: Eq + Hash>(
+    dictionaries: &[(Vec<Option<usize>>, Vec<V>)],
+    hash: impl Fn(&V) -> H,
+) -> Result<(Vec<Vec<Option<K>>>, Vec<V>), DictionaryKeyOverflow> {
+    let mut positions = HashMap::<H, K>::new();
+    let mut values = Vec::new();
+    let mut keys = Vec::with_capacity(dictionaries.len());
+    for dictionary in dictionaries {
+        let remap = dictionary
+            .1
+            .iter()
+            .map(|value| match positions.entry(hash(value)) {
+                Entry::Occupied(entry) => Ok(*entry.get()),
+                Entry::Vacant(entry) => {
+                    let key = key_of(values.len())?;
+                    values.push(*value);
+                    Ok(*entry.insert(key))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        keys.push(
+            dictionary
+                .0
+                .iter()
+                .map(|index| index.map(|position| remap[position]))
+                .collect(),
+        );
+    }
+    Ok((keys, values))
+}
+
+/// Returns the compacted keys with type `K`.
+///
+/// # Panics
+///
+/// Panics if a compacted key does not fit in `K`. This can't happen for keys
+/// that were compacted from keys with type `K`.
+fn compacted<K: TryFrom<usize>>(indices: Vec<Option<usize>>) -> impl Iterator<Item = Option<K>> {
+    indices.into_iter().map(|index| {
+        index.map(|position| key_of(position).expect("compacted key fits in the key type"))
+    })
+}
+
+impl<T: Numeric, Buffer: BufferType> FixedSizePrimitiveArray<T, false, Buffer>
+where
+    Self: FromIterator<T>,
+{
+    /// Returns the dictionary-encoded equivalent of this array. The values in
+    /// the dictionary are in first-occurrence order. Floating point values
+    /// are compared by their bit pattern.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of distinct values does not fit in a [`u32`].
+    #[must_use]
+    pub fn dictionary_encode(&self) -> DictionaryArray<Uint32Array, Self> {
+        let (keys, values) = primitive_encode(self);
+        DictionaryArray::new(
+            keys.into_iter().flatten().collect(),
+            values.into_iter().collect(),
+        )
+    }
+}
+
+impl<T: Numeric, Buffer: BufferType> FixedSizePrimitiveArray<T, true, Buffer>
+where
+    Self: FromIterator<Option<T>>,
+{
+    /// Returns the dictionary-encoded equivalent of this array. The values in
+    /// the dictionary are in first-occurrence order. Floating point values
+    /// are compared by their bit pattern. Nulls are encoded as null keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of distinct values does not fit in a [`u32`].
+    #[must_use]
+    pub fn dictionary_encode(&self) -> DictionaryArray<Uint32Array<true>, Self> {
+        let (keys, values) = primitive_encode(self);
+        DictionaryArray::new(
+            keys.into_iter().collect(),
+            values.into_iter().map(Some).collect(),
+        )
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<false, OffsetItem, Buffer>
+where
+    Self: for<'a> FromIterator<&'a str>,
+{
+    /// Returns the dictionary-encoded equivalent of this array. The values in
+    /// the dictionary are in first-occurrence order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of distinct values does not fit in a [`u32`].
+    #[must_use]
+    pub fn dictionary_encode(&self) -> DictionaryArray<Uint32Array, Self> {
+        let (keys, values) = encode_by(strs(self).map(Some), |&value| value);
+        DictionaryArray::new(
+            keys.into_iter().flatten().collect(),
+            values.into_iter().collect(),
+        )
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<true, OffsetItem, Buffer>
+where
+    Self: for<'a> FromIterator<Option<&'a str>>,
+{
+    /// Returns the dictionary-encoded equivalent of this array. The values in
+    /// the dictionary are in first-occurrence order. Nulls are encoded as null
+    /// keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of distinct values does not fit in a [`u32`].
+    #[must_use]
+    pub fn dictionary_encode(&self) -> DictionaryArray<Uint32Array<true>, Self> {
+        let (keys, values) = encode_by(nullable_strs(self), |&value| value);
+        DictionaryArray::new(
+            keys.into_iter().collect(),
+            values.into_iter().map(Some).collect(),
+        )
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType>
+    DictionaryArray<Uint32Array, FixedSizePrimitiveArray<T, false, Buffer>>
+where
+    FixedSizePrimitiveArray<T, false, Buffer>: FromIterator<T>,
+{
+    /// Returns the decoded (materialized) values of this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of the dictionary.
+    #[must_use]
+    pub fn decode(&self) -> FixedSizePrimitiveArray<T, false, Buffer> {
+        let values = self.values.values_ref();
+        self.keys
+            .values_ref()
+            .iter()
+            .map(|&key| values[key_index(key)])
+            .collect()
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType>
+    DictionaryArray<Uint32Array<true>, FixedSizePrimitiveArray<T, true, Buffer>>
+where
+    FixedSizePrimitiveArray<T, true, Buffer>: FromIterator<Option<T>>,
+{
+    /// Returns the decoded (materialized) values of this array. Null keys and
+    /// keys of null values decode to nulls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of the dictionary.
+    #[must_use]
+    pub fn decode(&self) -> FixedSizePrimitiveArray<T, true, Buffer> {
+        let values = self.values.values_ref();
+        let validity = &self.values.0.validity;
+        items(self.keys.values_ref(), self.keys.validity_ref())
+            .map(|key| {
+                key.map(key_index).and_then(|index| {
+                    let value = values[index];
+                    validity.get(index).unwrap_or_default().then_some(value)
+                })
+            })
+            .collect()
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType>
+    DictionaryArray<Uint32Array, StringArray<false, OffsetItem, Buffer>>
+where
+    StringArray<false, OffsetItem, Buffer>: for<'a> FromIterator<&'a str>,
+{
+    /// Returns the decoded (materialized) values of this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of the dictionary.
+    #[must_use]
+    pub fn decode(&self) -> StringArray<false, OffsetItem, Buffer> {
+        self.keys
+            .values_ref()
+            .iter()
+            .map(|&key| self.values.index_checked(key_index(key)))
+            .collect()
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType>
+    DictionaryArray<Uint32Array<true>, StringArray<true, OffsetItem, Buffer>>
+where
+    StringArray<true, OffsetItem, Buffer>: for<'a> FromIterator<Option<&'a str>>,
+{
+    /// Returns the decoded (materialized) values of this array. Null keys and
+    /// keys of null values decode to nulls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of the dictionary.
+    #[must_use]
+    pub fn decode(&self) -> StringArray<true, OffsetItem, Buffer> {
+        items(self.keys.values_ref(), self.keys.validity_ref())
+            .map(|key| key.and_then(|index| self.values.index_checked(key_index(index))))
+            .collect()
+    }
+}
+
+impl<K, T: Numeric, Buffer: BufferType>
+    DictionaryArray<FixedSizePrimitiveArray<K>, FixedSizePrimitiveArray<T, false, Buffer>>
+where
+    K: FixedSize + TryFrom<usize> + Into<u64>,
+    FixedSizePrimitiveArray<T, false, Buffer>: FromIterator<T>,
+{
+    /// Returns this array without the values that are not referenced by a
+    /// key, with the keys remapped to the remaining values. The items of the
+    /// array are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of the dictionary.
+    #[must_use]
+    pub fn compact(&self) -> Self {
+        let values = self.values.values_ref();
+        let (referenced, keys) = compact_keys(&indices(&self.keys), values.len());
+        Self::new(
+            compacted::<K>(keys).flatten().collect(),
+            referenced.into_iter().map(|index| values[index]).collect(),
+        )
+    }
+
+    /// Returns the `arrays` with one shared dictionary with the distinct
+    /// values of their dictionaries, in first-occurrence order. The items of
+    /// the arrays are unchanged. Floating point values are compared by their
+    /// bit pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryKeyOverflow`] error if the number of distinct
+    /// values does not fit in the key type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of its dictionary.
+    pub fn unify(arrays: &[&Self]) -> Result<Vec<Self>, DictionaryKeyOverflow> {
+        let dictionaries = arrays
+            .iter()
+            .map(|array| (indices(&array.keys), array.values.values_ref().to_vec()))
+            .collect::<Vec<_>>();
+        let (unified_keys, values) =
+            unify_by::<K, _, _>(&dictionaries, |value: &T| value.hash_bits())?;
+        Ok(unified_keys
+            .into_iter()
+            .map(|keys| {
+                Self::new(
+                    keys.into_iter().flatten().collect(),
+                    values.iter().copied().collect(),
+                )
+            })
+            .collect())
+    }
+}
+
+impl<K, T: Numeric, Buffer: BufferType>
+    DictionaryArray<FixedSizePrimitiveArray<K, true>, FixedSizePrimitiveArray<T, true, Buffer>>
+where
+    K: FixedSize + Default + TryFrom<usize> + Into<u64>,
+    FixedSizePrimitiveArray<T, true, Buffer>: FromIterator<Option<T>>,
+{
+    /// Returns this array without the values that are not referenced by a
+    /// key, with the keys remapped to the remaining values. The items of the
+    /// array are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of the dictionary.
+    #[must_use]
+    pub fn compact(&self) -> Self {
+        let values =
+            items(self.values.values_ref(), self.values.validity_ref()).collect::<Vec<_>>();
+        let (referenced, keys) = compact_keys(&nullable_indices(&self.keys), values.len());
+        Self::new(
+            compacted::<K>(keys).collect(),
+            referenced.into_iter().map(|index| values[index]).collect(),
+        )
+    }
+
+    /// Returns the `arrays` with one shared dictionary with the distinct
+    /// values of their dictionaries, in first-occurrence order. The items of
+    /// the arrays are unchanged. Floating point values are compared by their
+    /// bit pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryKeyOverflow`] error if the number of distinct
+    /// values does not fit in the key type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of its dictionary.
+    pub fn unify(arrays: &[&Self]) -> Result<Vec<Self>, DictionaryKeyOverflow> {
+        let dictionaries = arrays
+            .iter()
+            .map(|array| {
+                (
+                    nullable_indices(&array.keys),
+                    items(array.values.values_ref(), array.values.validity_ref()).collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let (unified_keys, values) = unify_by::<K, _, _>(&dictionaries, |value: &Option<T>| {
+            value.map(Numeric::hash_bits)
+        })?;
+        Ok(unified_keys
+            .into_iter()
+            .map(|keys| Self::new(keys.into_iter().collect(), values.iter().copied().collect()))
+            .collect())
+    }
+}
+
+impl<K, OffsetItem: OffsetElement, Buffer: BufferType>
+    DictionaryArray<FixedSizePrimitiveArray<K>, StringArray<false, OffsetItem, Buffer>>
+where
+    K: FixedSize + TryFrom<usize> + Into<u64>,
+    StringArray<false, OffsetItem, Buffer>: for<'a> FromIterator<&'a str>,
+{
+    /// Returns this array without the values that are not referenced by a
+    /// key, with the keys remapped to the remaining values. The items of the
+    /// array are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of the dictionary.
+    #[must_use]
+    pub fn compact(&self) -> Self {
+        let values = strs(&self.values).collect::<Vec<_>>();
+        let (referenced, keys) = compact_keys(&indices(&self.keys), values.len());
+        Self::new(
+            compacted::<K>(keys).flatten().collect(),
+            referenced.into_iter().map(|index| values[index]).collect(),
+        )
+    }
+
+    /// Returns the `arrays` with one shared dictionary with the distinct
+    /// values of their dictionaries, in first-occurrence order. The items of
+    /// the arrays are unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryKeyOverflow`] error if the number of distinct
+    /// values does not fit in the key type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of its dictionary.
+    pub fn unify(arrays: &[&Self]) -> Result<Vec<Self>, DictionaryKeyOverflow> {
+        let dictionaries = arrays
+            .iter()
+            .map(|array| (indices(&array.keys), strs(&array.values).collect()))
+            .collect::<Vec<_>>();
+        let (unified_keys, values) = unify_by::<K, _, _>(&dictionaries, |&value: &&str| value)?;
+        Ok(unified_keys
+            .into_iter()
+            .map(|keys| {
+                Self::new(
+                    keys.into_iter().flatten().collect(),
+                    values.iter().copied().collect(),
+                )
+            })
+            .collect())
+    }
+}
+
+impl<K, OffsetItem: OffsetElement, Buffer: BufferType>
+    DictionaryArray<FixedSizePrimitiveArray<K, true>, StringArray<true, OffsetItem, Buffer>>
+where
+    K: FixedSize + Default + TryFrom<usize> + Into<u64>,
+    StringArray<true, OffsetItem, Buffer>: for<'a> FromIterator<Option<&'a str>>,
+{
+    /// Returns this array without the values that are not referenced by a
+    /// key, with the keys remapped to the remaining values. The items of the
+    /// array are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of the dictionary.
+    #[must_use]
+    pub fn compact(&self) -> Self {
+        let values = nullable_strs(&self.values).collect::<Vec<_>>();
+        let (referenced, keys) = compact_keys(&nullable_indices(&self.keys), values.len());
+        Self::new(
+            compacted::<K>(keys).collect(),
+            referenced.into_iter().map(|index| values[index]).collect(),
+        )
+    }
+
+    /// Returns the `arrays` with one shared dictionary with the distinct
+    /// values of their dictionaries, in first-occurrence order. The items of
+    /// the arrays are unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryKeyOverflow`] error if the number of distinct
+    /// values does not fit in the key type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is out of bounds of its dictionary.
+    pub fn unify(arrays: &[&Self]) -> Result<Vec<Self>, DictionaryKeyOverflow> {
+        let dictionaries = arrays
+            .iter()
+            .map(|array| {
+                (
+                    nullable_indices(&array.keys),
+                    nullable_strs(&array.values).collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let (unified_keys, values) = unify_by::<K, _, _>(&dictionaries, |value| *value)?;
+        Ok(unified_keys
+            .into_iter()
+            .map(|keys| Self::new(keys.into_iter().collect(), values.iter().copied().collect()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, Uint32Array};
+
+    #[test]
+    fn cardinality_one() {
+        let array = [7; 5].into_iter().collect::<Int32Array>();
+        let dictionary = array.dictionary_encode();
+        assert_eq!(dictionary.keys().as_slice(), [0; 5]);
+        assert_eq!(dictionary.values().as_slice(), [7]);
+        assert_eq!(dictionary.decode().as_slice(), [7; 5]);
+    }
+
+    #[test]
+    fn high_cardinality() {
+        let array = (0..1000).rev().collect::<Int32Array>();
+        let dictionary = array.dictionary_encode();
+        assert_eq!(dictionary.keys().as_slice(), (0..1000).collect::<Vec<_>>());
+        assert_eq!(dictionary.values().as_slice(), array.as_slice());
+        assert_eq!(dictionary.decode().as_slice(), array.as_slice());
+    }
+
+    #[test]
+    fn nulls() {
+        let array = [None, Some(2), Some(1), None, Some(2)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let dictionary = array.dictionary_encode();
+        assert_eq!(
+            dictionary.keys().clone().into_iter().collect::<Vec<_>>(),
+            [None, Some(0), Some(1), None, Some(0)]
+        );
+        assert_eq!(
+            dictionary.values().clone().into_iter().collect::<Vec<_>>(),
+            [Some(2), Some(1)]
+        );
+        assert_eq!(
+            dictionary.decode().into_iter().collect::<Vec<_>>(),
+            array.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn strings() {
+        let array = ["b", "a", "b", "\u{1f980}", "a"]
+            .into_iter()
+            .collect::<StringArray>();
+        let dictionary = array.dictionary_encode();
+        assert_eq!(dictionary.keys().as_slice(), [0, 1, 0, 2, 1]);
+        assert_eq!(
+            dictionary.values().into_iter().collect::<Vec<_>>(),
+            ["b", "a", "\u{1f980}"]
+        );
+        assert_eq!(
+            dictionary.decode().into_iter().collect::<Vec<_>>(),
+            array.into_iter().collect::<Vec<_>>()
+        );
+
+        let nullable = [Some("x"), None, Some("x"), Some("")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let encoded = nullable.dictionary_encode();
+        assert_eq!(
+            encoded.keys().clone().into_iter().collect::<Vec<_>>(),
+            [Some(0), None, Some(0), Some(1)]
+        );
+        assert_eq!(
+            encoded.decode().into_iter().collect::<Vec<_>>(),
+            nullable.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn compact() {
+        let array = [3, 1, 4, 1, 5, 9, 2, 6].into_iter().collect::<Int32Array>();
+        let dictionary = array.dictionary_encode();
+        // Keep the items that are at least 5, as a filter would.
+        let filtered = DictionaryArray::new(
+            dictionary
+                .keys()
+                .as_slice()
+                .iter()
+                .copied()
+                .filter(|&key| {
+                    dictionary.values().as_slice()[usize::try_from(key).expect("fits")] >= 5
+                })
+                .collect::<Uint32Array>(),
+            dictionary.values().clone(),
+        );
+        assert_eq!(filtered.values().as_slice().len(), 7);
+        let compacted = filtered.compact();
+        assert_eq!(compacted.values().as_slice(), [5, 9, 6]);
+        assert_eq!(compacted.keys().as_slice(), [0, 1, 2]);
+        assert_eq!(compacted.decode().as_slice(), filtered.decode().as_slice());
+
+        let nullable = DictionaryArray::new(
+            [None, Some(2_u8), Some(2)]
+                .into_iter()
+                .collect::<FixedSizePrimitiveArray<u8, true>>(),
+            [Some(1), None, Some(3)]
+                .into_iter()
+                .collect::<Int32Array<true>>(),
+        );
+        let compacted_nullable = nullable.compact();
+        assert_eq!(
+            compacted_nullable
+                .keys()
+                .clone()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [None, Some(0), Some(0)]
+        );
+        assert_eq!(
+            compacted_nullable
+                .values()
+                .clone()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(3)]
+        );
+
+        let strings = DictionaryArray::new(
+            [2_u16, 0]
+                .into_iter()
+                .collect::<FixedSizePrimitiveArray<u16>>(),
+            ["a", "b", "c"].into_iter().collect::<StringArray>(),
+        );
+        let compacted_strings = strings.compact();
+        assert_eq!(compacted_strings.keys().as_slice(), [1, 0]);
+        assert_eq!(
+            compacted_strings.values().into_iter().collect::<Vec<_>>(),
+            ["a", "c"]
+        );
+    }
+
+    #[test]
+    fn unify() {
+        let left = [1, 2, 1]
+            .into_iter()
+            .collect::<Int32Array>()
+            .dictionary_encode();
+        let right = [3, 2, 4]
+            .into_iter()
+            .collect::<Int32Array>()
+            .dictionary_encode();
+        let unified =
+            DictionaryArray::<Uint32Array, Int32Array>::unify(&[&left, &right]).expect("fits");
+        assert_eq!(unified.len(), 2);
+        assert_eq!(unified[0].values().as_slice(), [1, 2, 3, 4]);
+        assert_eq!(unified[1].values().as_slice(), [1, 2, 3, 4]);
+        assert_eq!(unified[0].keys().as_slice(), [0, 1, 0]);
+        assert_eq!(unified[1].keys().as_slice(), [2, 1, 3]);
+        assert_eq!(unified[0].decode().as_slice(), left.decode().as_slice());
+        assert_eq!(unified[1].decode().as_slice(), right.decode().as_slice());
+
+        let left_strings = [Some("x"), None, Some("y")]
+            .into_iter()
+            .collect::<StringArray<true>>()
+            .dictionary_encode();
+        let right_strings = [Some("y"), Some("z")]
+            .into_iter()
+            .collect::<StringArray<true>>()
+            .dictionary_encode();
+        let unified_strings = DictionaryArray::<Uint32Array<true>, StringArray<true>>::unify(&[
+            &left_strings,
+            &right_strings,
+        ])
+        .expect("fits");
+        assert_eq!(
+            unified_strings[1].values().iter().collect::<Vec<_>>(),
+            [Some("x"), Some("y"), Some("z")]
+        );
+        assert_eq!(
+            unified_strings[1]
+                .keys()
+                .clone()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(1), Some(2)]
+        );
+        assert_eq!(
+            unified_strings[0].decode().iter().collect::<Vec<_>>(),
+            [Some("x"), None, Some("y")]
+        );
+    }
+
+    #[test]
+    fn unify_overflow() {
+        let dictionary = |values: core::ops::Range<i32>| {
+            DictionaryArray::new(
+                (0..200_u8).collect::<FixedSizePrimitiveArray<u8>>(),
+                values.collect::<Int32Array>(),
+            )
+        };
+        let left = dictionary(0..200);
+        let right = dictionary(100..300);
+        assert_eq!(
+            DictionaryArray::<FixedSizePrimitiveArray<u8>, Int32Array>::unify(&[&left, &right])
+                .map(|arrays| arrays.len()),
+            Err(DictionaryKeyOverflow)
+        );
+        let overlapping = dictionary(50..250);
+        let unified = DictionaryArray::<FixedSizePrimitiveArray<u8>, Int32Array>::unify(&[
+            &left,
+            &overlapping,
+        ])
+        .expect("fits");
+        assert_eq!(unified[0].values().as_slice().len(), 250);
+        assert_eq!(unified[1].keys().as_slice()[0], 50);
+    }
+}
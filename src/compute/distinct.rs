@@ -0,0 +1,445 @@
+//! Distinct value kernels.
+
+use super::{validity_bits, Numeric, ValuesRef};
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray, Uint32Array, Uint64Array},
+    bitmap::Bitmap,
+    buffer::BufferType,
+};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// Returns the distinct items in first-occurrence order, with their
+/// occurrence counts. Items are compared by their `key`.
+fn value_counts_by<T, K: Eq + Hash>(
+    items: impl Iterator<Item = Option<T>>,
+    key: impl Fn(&T) -> K,
+) -> (Vec<Option<T>>, Vec<u64>) {
+    let mut positions = HashMap::<Option<K>, usize>::new();
+    let mut values = Vec::new();
+    let mut counts = Vec::new();
+    for item in items {
+        match positions.entry(item.as_ref().map(&key)) {
+            Entry::Occupied(entry) => counts[*entry.get()] += 1,
+            Entry::Vacant(entry) => {
+                entry.insert(values.len());
+                values.push(item);
+                counts.push(1);
+            }
+        }
+    }
+    (values, counts)
+}
+
+/// Returns the items that are not equal to their predecessor, with their
+/// indices. Items are compared by their `key`, nulls are equal to each other.
+///
+/// # Panics
+///
+/// Panics if an index does not fit in a [`u32`].
+fn dedup_by<T, K: PartialEq>(
+    items: impl Iterator<Item = Option<T>>,
+    key: impl Fn(&T) -> K,
+) -> (Vec<Option<T>>, Vec<u32>) {
+    let mut previous = None;
+    let mut values = Vec::new();
+    let mut indices = Vec::new();
+    for (index, item) in items.enumerate() {
+        let current = item.as_ref().map(&key);
+        if index == 0 || previous.as_ref() != Some(&current) {
+            values.push(item);
+            indices.push(u32::try_from(index).expect("index fits in u32"));
+        }
+        previous = Some(current);
+    }
+    (values, indices)
+}
+
+/// Returns an iterator over the items of an array, given its `values` and
+/// optional `validity` bitmap.
+pub(super) fn items<'a, T: Copy, Buffer: BufferType>(
+    values: &'a [T],
+    validity: Option<&'a Bitmap<Buffer>>,
+) -> impl Iterator<Item = Option<T>> + 'a {
+    values
+        .iter()
+        .zip(validity_bits(validity, values.len()))
+        .map(|(&value, valid)| valid.then_some(value))
+}
+
+/// Returns the deduplicated values of a primitive array with their indices.
+fn primitive_dedup<A: ValuesRef>(array: &A) -> (Vec<Option<A::Item>>, Vec<u32>)
+where
+    A::Item: Numeric,
+{
+    dedup_by(
+        items(array.values_ref(), array.validity_ref()),
+        |value: &A::Item| value.hash_bits(),
+    )
+}
+
+/// Returns the distinct values of a primitive array with their counts.
+fn primitive_value_counts<A: ValuesRef>(array: &A) -> (Vec<Option<A::Item>>, Vec<u64>)
+where
+    A::Item: Numeric,
+{
+    value_counts_by(
+        items(array.values_ref(), array.validity_ref()),
+        |value: &A::Item| value.hash_bits(),
+    )
+}
+
+impl<T: Numeric, Buffer: BufferType> FixedSizePrimitiveArray<T, false, Buffer>
+where
+    Self: FromIterator<T>,
+{
+    /// Returns the distinct values in this array, in order of first
+    /// occurrence.
+    ///
+    /// Values are compared by their bit pattern. For floating point values
+    /// this means that NaN values with the same bit pattern are equal, and
+    /// that `0.0` and `-0.0` are not equal.
+    #[must_use]
+    pub fn unique(&self) -> Self {
+        self.value_counts().0
+    }
+
+    /// Returns the distinct values in this array, in order of first
+    /// occurrence, with the number of occurrences of each value.
+    ///
+    /// Values are compared by their bit pattern, see [`Self::unique`].
+    #[must_use]
+    pub fn value_counts(&self) -> (Self, Uint64Array) {
+        let (values, counts) = primitive_value_counts(self);
+        (
+            values.into_iter().flatten().collect(),
+            FixedSizePrimitiveArray(counts),
+        )
+    }
+
+    /// Returns this array without consecutive duplicate values.
+    ///
+    /// Values are compared by their bit pattern, see [`Self::unique`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of this array exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn dedup(&self) -> Self {
+        self.dedup_with_indices().0
+    }
+
+    /// Returns this array without consecutive duplicate values, with the
+    /// indices of the values that are kept.
+    ///
+    /// Values are compared by their bit pattern, see [`Self::unique`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of this array exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn dedup_with_indices(&self) -> (Self, Uint32Array) {
+        let (values, indices) = primitive_dedup(self);
+        (
+            values.into_iter().flatten().collect(),
+            FixedSizePrimitiveArray(indices),
+        )
+    }
+}
+
+impl<T: Numeric, Buffer: BufferType> FixedSizePrimitiveArray<T, true, Buffer>
+where
+    Self: FromIterator<Option<T>>,
+{
+    /// Returns the distinct values in this array, in order of first
+    /// occurrence. Nulls are equal to each other, so there is at most one
+    /// null in the output.
+    ///
+    /// Values are compared by their bit pattern. For floating point values
+    /// this means that NaN values with the same bit pattern are equal, and
+    /// that `0.0` and `-0.0` are not equal.
+    #[must_use]
+    pub fn unique(&self) -> Self {
+        self.value_counts().0
+    }
+
+    /// Returns the distinct values in this array, in order of first
+    /// occurrence, with the number of occurrences of each value. Nulls are
+    /// counted as a single distinct value.
+    ///
+    /// Values are compared by their bit pattern, see [`Self::unique`].
+    #[must_use]
+    pub fn value_counts(&self) -> (Self, Uint64Array) {
+        let (values, counts) = primitive_value_counts(self);
+        (
+            values.into_iter().collect(),
+            FixedSizePrimitiveArray(counts),
+        )
+    }
+
+    /// Returns this array without consecutive duplicate values. Nulls are
+    /// equal to each other, and not equal to any value.
+    ///
+    /// Values are compared by their bit pattern, see [`Self::unique`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of this array exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn dedup(&self) -> Self {
+        self.dedup_with_indices().0
+    }
+
+    /// Returns this array without consecutive duplicate values, with the
+    /// indices of the values that are kept. Nulls are equal to each other,
+    /// and not equal to any value.
+    ///
+    /// Values are compared by their bit pattern, see [`Self::unique`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of this array exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn dedup_with_indices(&self) -> (Self, Uint32Array) {
+        let (values, indices) = primitive_dedup(self);
+        (
+            values.into_iter().collect(),
+            FixedSizePrimitiveArray(indices),
+        )
+    }
+}
+
+impl<Buffer: BufferType> BooleanArray<false, Buffer>
+where
+    Self: FromIterator<bool>,
+{
+    /// Returns the distinct values in this array, in order of first
+    /// occurrence.
+    #[must_use]
+    pub fn unique(&self) -> Self {
+        self.value_counts().0
+    }
+
+    /// Returns the distinct values in this array, in order of first
+    /// occurrence, with the number of occurrences of each value.
+    #[must_use]
+    pub fn value_counts(&self) -> (Self, Uint64Array) {
+        let (values, counts) = value_counts_by(self.0.iter().map(Some), |&value| value);
+        (
+            values.into_iter().flatten().collect(),
+            FixedSizePrimitiveArray(counts),
+        )
+    }
+
+    /// Returns this array without consecutive duplicate values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of this array exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn dedup(&self) -> Self {
+        self.dedup_with_indices().0
+    }
+
+    /// Returns this array without consecutive duplicate values, with the
+    /// indices of the values that are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of this array exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn dedup_with_indices(&self) -> (Self, Uint32Array) {
+        let (values, indices) = dedup_by(self.0.iter().map(Some), |&value| value);
+        (
+            values.into_iter().flatten().collect(),
+            FixedSizePrimitiveArray(indices),
+        )
+    }
+}
+
+impl<Buffer: BufferType> BooleanArray<true, Buffer>
+where
+    Self: FromIterator<Option<bool>>,
+{
+    /// Returns the distinct values in this array, in order of first
+    /// occurrence. Nulls are equal to each other, so there is at most one
+    /// null in the output.
+    #[must_use]
+    pub fn unique(&self) -> Self {
+        self.value_counts().0
+    }
+
+    /// Returns the distinct values in this array, in order of first
+    /// occurrence, with the number of occurrences of each value. Nulls are
+    /// counted as a single distinct value.
+    #[must_use]
+    pub fn value_counts(&self) -> (Self, Uint64Array) {
+        let (values, counts) = value_counts_by(self.items(), |&value| value);
+        (
+            values.into_iter().collect(),
+            FixedSizePrimitiveArray(counts),
+        )
+    }
+
+    /// Returns this array without consecutive duplicate values. Nulls are
+    /// equal to each other, and not equal to any value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of this array exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn dedup(&self) -> Self {
+        self.dedup_with_indices().0
+    }
+
+    /// Returns this array without consecutive duplicate values, with the
+    /// indices of the values that are kept. Nulls are equal to each other,
+    /// and not equal to any value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of this array exceeds [`u32::MAX`].
+    #[must_use]
+    pub fn dedup_with_indices(&self) -> (Self, Uint32Array) {
+        let (values, indices) = dedup_by(self.items(), |&value| value);
+        (
+            values.into_iter().collect(),
+            FixedSizePrimitiveArray(indices),
+        )
+    }
+
+    /// Returns an iterator over the items in this array.
+    pub(super) fn items(&self) -> impl Iterator<Item = Option<bool>> + '_ {
+        self.0
+            .data
+            .iter()
+            .zip(&self.0.validity)
+            .map(|(value, valid)| valid.then_some(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{BooleanArray, Float64Array, Int32Array};
+
+    #[test]
+    fn unique() {
+        let empty = Int32Array::<false>::default();
+        assert!(empty.unique().as_slice().is_empty());
+        let (empty_values, empty_counts) = empty.value_counts();
+        assert!(empty_values.as_slice().is_empty());
+        assert!(empty_counts.as_slice().is_empty());
+
+        let array = [3, 1, 3, 2, 1, 3].into_iter().collect::<Int32Array>();
+        assert_eq!(array.unique().as_slice(), [3, 1, 2]);
+        let (values, counts) = array.value_counts();
+        assert_eq!(values.as_slice(), [3, 1, 2]);
+        assert_eq!(counts.as_slice(), [3, 2, 1]);
+    }
+
+    #[test]
+    fn unique_nullable() {
+        let array = [Some(1), None, Some(1), None, Some(2), None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            array.unique().into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(2)]
+        );
+        let (values, counts) = array.value_counts();
+        assert_eq!(
+            values.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(2)]
+        );
+        assert_eq!(counts.as_slice(), [2, 3, 1]);
+
+        // Values in null slots are ignored.
+        let all_null = [None::<i32>, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(all_null.unique().into_iter().collect::<Vec<_>>(), [None]);
+    }
+
+    #[test]
+    fn unique_float() {
+        let array = [0.0, -0.0, f64::NAN, 1.0, f64::NAN, 0.0]
+            .into_iter()
+            .collect::<Float64Array>();
+        let (values, counts) = array.value_counts();
+        assert_eq!(values.as_slice().len(), 4);
+        assert!(values.as_slice()[0].is_sign_positive());
+        assert!(values.as_slice()[1].is_sign_negative());
+        assert!(values.as_slice()[2].is_nan());
+        assert_eq!(values.as_slice()[3].to_bits(), 1.0_f64.to_bits());
+        assert_eq!(counts.as_slice(), [2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn unique_boolean() {
+        let array = [false, false, true, false]
+            .into_iter()
+            .collect::<BooleanArray>();
+        assert_eq!(
+            array.unique().into_iter().collect::<Vec<_>>(),
+            [false, true]
+        );
+        assert_eq!(array.value_counts().1.as_slice(), [3, 1]);
+
+        let nullable = [None, Some(true), None, Some(true)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let (values, counts) = nullable.value_counts();
+        assert_eq!(values.into_iter().collect::<Vec<_>>(), [None, Some(true)]);
+        assert_eq!(counts.as_slice(), [2, 2]);
+    }
+
+    #[test]
+    fn dedup() {
+        let empty = Int32Array::<false>::default();
+        assert!(empty.dedup().as_slice().is_empty());
+
+        let array = [1, 1, 2, 3, 3, 3, 1, 4, 4]
+            .into_iter()
+            .collect::<Int32Array>();
+        let (values, indices) = array.dedup_with_indices();
+        assert_eq!(values.as_slice(), [1, 2, 3, 1, 4]);
+        assert_eq!(indices.as_slice(), [0, 2, 3, 6, 7]);
+        assert_eq!(array.dedup().as_slice(), [1, 2, 3, 1, 4]);
+
+        let alternating = [1, 2, 1, 2].into_iter().collect::<Int32Array>();
+        assert_eq!(alternating.dedup().as_slice(), [1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn dedup_nullable() {
+        let array = [None, None, Some(1), None, Some(1), Some(1), None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let (values, indices) = array.dedup_with_indices();
+        assert_eq!(
+            values.into_iter().collect::<Vec<_>>(),
+            [None, Some(1), None, Some(1), None]
+        );
+        assert_eq!(indices.as_slice(), [0, 2, 3, 4, 6]);
+
+        let booleans = [Some(true), Some(true), None, None, Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let (boolean_values, boolean_indices) = booleans.dedup_with_indices();
+        assert_eq!(
+            boolean_values.into_iter().collect::<Vec<_>>(),
+            [Some(true), None, Some(false)]
+        );
+        assert_eq!(boolean_indices.as_slice(), [0, 2, 4]);
+
+        let boolean = [false, false, true, true]
+            .into_iter()
+            .collect::<BooleanArray>();
+        assert_eq!(
+            boolean.dedup().into_iter().collect::<Vec<_>>(),
+            [false, true]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Byte order conversion kernels.
+
+use super::Numeric;
+use crate::{
+    array::FixedSizePrimitiveArray,
+    buffer::{BufferMut, BufferType},
+};
+
+/// Reverses the byte order of all `values`.
+fn swap_bytes<T: Numeric>(values: &mut [T]) {
+    for value in values {
+        *value = value.swap_bytes();
+    }
+}
+
+/// Reverses the byte order of all `values` if the target is little endian.
+fn swap_bytes_if_little_endian<T: Numeric>(values: &mut [T]) {
+    if cfg!(target_endian = "little") {
+        swap_bytes(values);
+    }
+}
+
+/// Reverses the byte order of all `values` if the target is big endian.
+fn swap_bytes_if_big_endian<T: Numeric>(values: &mut [T]) {
+    if cfg!(target_endian = "big") {
+        swap_bytes(values);
+    }
+}
+
+impl<T: Numeric, Buffer: BufferType> FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: BufferMut<T>,
+{
+    /// Returns a new array from a buffer with values in big-endian byte order,
+    /// converting them to the native byte order of the target.
+    #[must_use]
+    pub fn from_be_buffer(mut buffer: <Buffer as BufferType>::Buffer<T>) -> Self {
+        swap_bytes_if_little_endian(buffer.as_mut_slice());
+        Self(buffer)
+    }
+
+    /// Returns a new array from a buffer with values in little-endian byte
+    /// order, converting them to the native byte order of the target.
+    #[must_use]
+    pub fn from_le_buffer(mut buffer: <Buffer as BufferType>::Buffer<T>) -> Self {
+        swap_bytes_if_big_endian(buffer.as_mut_slice());
+        Self(buffer)
+    }
+
+    /// Reverses the byte order of the values in this array.
+    pub fn swap_bytes_mut(&mut self) {
+        swap_bytes(self.0.as_mut_slice());
+    }
+
+    /// Returns a copy of this array with the byte order of the values
+    /// reversed.
+    #[must_use]
+    pub fn swap_bytes(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut array = self.clone();
+        array.swap_bytes_mut();
+        array
+    }
+
+    /// Returns a copy of this array with the values converted from the native
+    /// byte order of the target to big endian.
+    #[must_use]
+    pub fn to_be(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut array = self.clone();
+        swap_bytes_if_little_endian(array.0.as_mut_slice());
+        array
+    }
+
+    /// Returns a copy of this array with the values converted from the native
+    /// byte order of the target to little endian.
+    #[must_use]
+    pub fn to_le(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut array = self.clone();
+        swap_bytes_if_big_endian(array.0.as_mut_slice());
+        array
+    }
+}
+
+impl<T: Numeric, Buffer: BufferType> FixedSizePrimitiveArray<T, true, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: BufferMut<T>,
+{
+    /// Reverses the byte order of the values in this array. The validity is
+    /// unchanged.
+    pub fn swap_bytes_mut(&mut self) {
+        swap_bytes(self.0.data.as_mut_slice());
+    }
+
+    /// Returns a copy of this array with the byte order of the values
+    /// reversed. The validity is unchanged.
+    #[must_use]
+    pub fn swap_bytes(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut array = self.clone();
+        array.swap_bytes_mut();
+        array
+    }
+
+    /// Returns a copy of this array with the values converted from the native
+    /// byte order of the target to big endian. The validity is unchanged.
+    #[must_use]
+    pub fn to_be(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut array = self.clone();
+        swap_bytes_if_little_endian(array.0.data.as_mut_slice());
+        array
+    }
+
+    /// Returns a copy of this array with the values converted from the native
+    /// byte order of the target to little endian. The validity is unchanged.
+    #[must_use]
+    pub fn to_le(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut array = self.clone();
+        swap_bytes_if_big_endian(array.0.data.as_mut_slice());
+        array
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::{Float32Array, Int16Array, Int64Array, Uint32Array, Uint8Array};
+
+    #[test]
+    fn byte_patterns() {
+        let int16 = [0x0102_i16].into_iter().collect::<Int16Array>();
+        assert_eq!(int16.swap_bytes().as_slice(), [0x0201]);
+
+        let uint32 = [0x0102_0304_u32].into_iter().collect::<Uint32Array>();
+        assert_eq!(uint32.swap_bytes().as_slice(), [0x0403_0201]);
+
+        let int64 = [0x0102_0304_0506_0708_i64]
+            .into_iter()
+            .collect::<Int64Array>();
+        assert_eq!(int64.swap_bytes().as_slice(), [0x0807_0605_0403_0201]);
+
+        let float32 = [f32::from_bits(0x3f80_0000)]
+            .into_iter()
+            .collect::<Float32Array>();
+        assert_eq!(float32.swap_bytes().as_slice()[0].to_bits(), 0x0000_803f);
+    }
+
+    #[test]
+    fn single_byte() {
+        let array = [1_u8, 2, 255].into_iter().collect::<Uint8Array>();
+        assert_eq!(array.swap_bytes().as_slice(), [1, 2, 255]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut array = [Some(1_i64), None, Some(-42)]
+            .into_iter()
+            .collect::<Int64Array<true>>();
+        let swapped = array.swap_bytes();
+        assert_eq!(
+            swapped.clone().into_iter().collect::<Vec<_>>(),
+            [
+                Some(0x0100_0000_0000_0000),
+                None,
+                Some(-0x2900_0000_0000_0001)
+            ]
+        );
+        assert_eq!(
+            swapped.swap_bytes().into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(-42)]
+        );
+        array.swap_bytes_mut();
+        array.swap_bytes_mut();
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(-42)]
+        );
+    }
+
+    #[test]
+    fn from_be_buffer() {
+        let bytes = [0x01_u8, 0x02, 0x03, 0x04];
+        let raw = vec![u32::from_ne_bytes(bytes)];
+        assert_eq!(
+            Uint32Array::<false>::from_be_buffer(raw.clone()).as_slice(),
+            [0x0102_0304]
+        );
+        assert_eq!(
+            Uint32Array::<false>::from_le_buffer(raw).as_slice(),
+            [0x0403_0201]
+        );
+
+        let array = [0x0102_0304_u32].into_iter().collect::<Uint32Array>();
+        assert_eq!(array.to_be().as_slice()[0].to_ne_bytes(), bytes);
+        assert_eq!(
+            array.to_le().as_slice()[0].to_ne_bytes(),
+            [0x04, 0x03, 0x02, 0x01]
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Hash-based grouping kernels.
+
+use super::{
+    distinct::items,
+    string::{nullable_strs, strs},
+    Numeric, ValuesRef,
+};
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray, StringArray, Uint32Array},
+    bitmap::Bitmap,
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// Assigns group ids to rows based on their keys.
+///
+/// Groups are numbered in order of first occurrence, and all null keys belong
+/// to the same group. Keys of multiple columns can be grouped by inserting
+/// tuples of their keys.
+#[derive(Clone, Debug)]
+pub struct GroupHasher<K> {
+    /// The group ids of the keys.
+    groups: HashMap<Option<K>, u32>,
+    /// The group id of every inserted row.
+    rows: Vec<u32>,
+}
+
+impl<K> Default for GroupHasher<K> {
+    fn default() -> Self {
+        Self {
+            groups: HashMap::default(),
+            rows: Vec::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> GroupHasher<K> {
+    /// Returns a new [`GroupHasher`] without groups.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a row with the given `key`, and returns the id of its group.
+    /// The id of a new group is equal to the number of groups before
+    /// inserting the row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of groups does not fit in a [`u32`].
+    pub fn insert(&mut self, key: Option<K>) -> u32 {
+        let next = u32::try_from(self.groups.len()).expect("group id fits in u32");
+        let group = *self.groups.entry(key).or_insert(next);
+        self.rows.push(group);
+        group
+    }
+
+    /// Returns the number of groups.
+    #[must_use]
+    pub fn group_count(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns the offsets and indices of the groups of the inserted rows.
+    ///
+    /// The indices of the rows in group `i` are stored in order at positions
+    /// `offsets[i]..offsets[i + 1]` of the indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows does not fit in a [`u32`].
+    #[must_use]
+    pub fn finish(self) -> (Vec<u32>, Uint32Array) {
+        let mut offsets = vec![0_u32; self.groups.len() + 1];
+        for &group in &self.rows {
+            offsets[group_index(group) + 1] += 1;
+        }
+        for index in 1..offsets.len() {
+            offsets[index] += offsets[index - 1];
+        }
+        let mut positions = offsets.clone();
+        let mut indices = vec![0; self.rows.len()];
+        for (row, &group) in self.rows.iter().enumerate() {
+            let position = &mut positions[group_index(group)];
+            indices[group_index(*position)] = u32::try_from(row).expect("index fits in u32");
+            *position += 1;
+        }
+        (offsets, FixedSizePrimitiveArray(indices))
+    }
+}
+
+/// Converts a group id or offset to an index.
+fn group_index(group: u32) -> usize {
+    usize::try_from(group).expect("group id fits in usize")
+}
+
+/// Groups the items by their `key`, and returns the distinct items in order
+/// of first occurrence with the offsets and indices of their groups.
+fn group_by<T, K: Eq + Hash>(
+    items: impl Iterator<Item = Option<T>>,
+    key: impl Fn(&T) -> K,
+) -> (Vec<Option<T>>, Vec<u32>, Uint32Array) {
+    let mut hasher = GroupHasher::new();
+    let mut values = Vec::new();
+    for item in items {
+        if group_index(hasher.insert(item.as_ref().map(&key))) == values.len() {
+            values.push(item);
+        }
+    }
+    let (offsets, indices) = hasher.finish();
+    (values, offsets, indices)
+}
+
+/// Arrays that can be used as group keys.
+pub trait GroupKeys: Sized {
+    /// Groups the rows of this array by their value, and returns the distinct
+    /// keys in order of first occurrence, together with the offsets and
+    /// indices of the groups. All nulls belong to the same group.
+    ///
+    /// See [`GroupHasher::finish`] for the layout of the offsets and indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows does not fit in a [`u32`].
+    #[must_use]
+    fn group_indices(&self) -> (Self, Vec<u32>, Uint32Array);
+}
+
+/// Groups the rows of `keys` by their value, and returns the distinct keys in
+/// order of first occurrence, together with the offsets and indices of the
+/// groups. All nulls belong to the same group.
+///
+/// See [`GroupHasher::finish`] for the layout of the offsets and indices.
+///
+/// # Panics
+///
+/// Panics if the number of rows does not fit in a [`u32`].
+pub fn group_indices<A: GroupKeys>(keys: &A) -> (A, Vec<u32>, Uint32Array) {
+    keys.group_indices()
+}
+
+impl<T: Numeric, Buffer: BufferType> GroupKeys for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    Self: FromIterator<T>,
+{
+    fn group_indices(&self) -> (Self, Vec<u32>, Uint32Array) {
+        let (values, offsets, indices) = group_by(
+            items(self.values_ref(), self.validity_ref()),
+            |value: &T| value.hash_bits(),
+        );
+        (values.into_iter().flatten().collect(), offsets, indices)
+    }
+}
+
+impl<T: Numeric, Buffer: BufferType> GroupKeys for FixedSizePrimitiveArray<T, true, Buffer>
+where
+    Self: FromIterator<Option<T>>,
+{
+    fn group_indices(&self) -> (Self, Vec<u32>, Uint32Array) {
+        let (values, offsets, indices) = group_by(
+            items(self.values_ref(), self.validity_ref()),
+            |value: &T| value.hash_bits(),
+        );
+        (values.into_iter().collect(), offsets, indices)
+    }
+}
+
+impl<Buffer: BufferType> GroupKeys for BooleanArray<false, Buffer>
+where
+    Bitmap<Buffer>: FromIterator<bool>,
+{
+    fn group_indices(&self) -> (Self, Vec<u32>, Uint32Array) {
+        let (values, offsets, indices) = group_by(self.0.iter().map(Some), |&value| value);
+        (
+            BooleanArray(values.into_iter().flatten().collect()),
+            offsets,
+            indices,
+        )
+    }
+}
+
+impl<Buffer: BufferType> GroupKeys for BooleanArray<true, Buffer>
+where
+    Self: FromIterator<Option<bool>>,
+{
+    fn group_indices(&self) -> (Self, Vec<u32>, Uint32Array) {
+        let (values, offsets, indices) = group_by(self.items(), |&value| value);
+        (values.into_iter().collect(), offsets, indices)
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> GroupKeys
+    for StringArray<false, OffsetItem, Buffer>
+where
+    Self: for<'a> FromIterator<&'a str>,
+{
+    fn group_indices(&self) -> (Self, Vec<u32>, Uint32Array) {
+        let (values, offsets, indices) = group_by(strs(self).map(Some), |&value| value);
+        (values.into_iter().flatten().collect(), offsets, indices)
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> GroupKeys
+    for StringArray<true, OffsetItem, Buffer>
+where
+    Self: for<'a> FromIterator<Option<&'a str>>,
+{
+    fn group_indices(&self) -> (Self, Vec<u32>, Uint32Array) {
+        let (values, offsets, indices) = group_by(nullable_strs(self), |&value| value);
+        (values.into_iter().collect(), offsets, indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+
+    /// Returns the indices of every group.
+    fn groups(offsets: &[u32], indices: &Uint32Array) -> Vec<Vec<u32>> {
+        offsets
+            .windows(2)
+            .map(|window| {
+                indices.as_slice()[group_index(window[0])..group_index(window[1])].to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn duplicates() {
+        let keys = [3, 1, 3, 3, 2, 1, 3].into_iter().collect::<Int32Array>();
+        let (unique, offsets, indices) = group_indices(&keys);
+        assert_eq!(unique.as_slice(), [3, 1, 2]);
+        assert_eq!(offsets, [0, 4, 6, 7]);
+        assert_eq!(
+            groups(&offsets, &indices),
+            [vec![0, 2, 3, 6], vec![1, 5], vec![4]]
+        );
+    }
+
+    #[test]
+    fn null_group() {
+        let keys = [None, Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        let (unique, offsets, indices) = group_indices(&keys);
+        assert_eq!(
+            unique.into_iter().collect::<Vec<_>>(),
+            [None, Some("a".to_owned()), Some("b".to_owned())]
+        );
+        assert_eq!(
+            groups(&offsets, &indices),
+            [vec![0, 2], vec![1, 4], vec![3]]
+        );
+
+        let booleans = [Some(true), None, Some(false), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let (unique_booleans, boolean_offsets, boolean_indices) = group_indices(&booleans);
+        assert_eq!(
+            unique_booleans.into_iter().collect::<Vec<_>>(),
+            [Some(true), None, Some(false)]
+        );
+        assert_eq!(
+            groups(&boolean_offsets, &boolean_indices),
+            [vec![0], vec![1, 3], vec![2]]
+        );
+    }
+
+    #[test]
+    fn offsets_and_indices() {
+        let keys = (0..1000).map(|value| value % 7).collect::<Int32Array>();
+        let (unique, offsets, indices) = group_indices(&keys);
+        assert_eq!(unique.as_slice(), (0..7).collect::<Vec<_>>());
+        assert_eq!(offsets.len(), 8);
+        assert_eq!(offsets.last().copied(), Some(1000));
+        for (key, group) in groups(&offsets, &indices).into_iter().enumerate() {
+            assert!(group.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(group
+                .iter()
+                .all(|&index| keys.as_slice()[group_index(index)]
+                    == i32::try_from(key).expect("fits")));
+        }
+    }
+
+    #[test]
+    fn multi_column() {
+        let mut hasher = GroupHasher::new();
+        for key in [(1, "a"), (1, "b"), (1, "a"), (2, "a")] {
+            hasher.insert(Some(key));
+        }
+        assert_eq!(hasher.group_count(), 3);
+        let (offsets, indices) = hasher.finish();
+        assert_eq!(groups(&offsets, &indices), [vec![0, 2], vec![1], vec![3]]);
+    }
+}
@@ -0,0 +1,361 @@
+//! Hash kernels.
+
+use super::{BinaryValuesRef, Numeric, ValuesRef};
+use crate::{
+    array::{
+        BooleanArray, FixedSizePrimitiveArray, StringArray, Uint64Array, VariableSizeBinaryArray,
+    },
+    bitmap::Bitmap,
+    buffer::BufferType,
+    offset::{OffsetElement, Offsets},
+    validity::Validity,
+    Length,
+};
+
+/// The seed used by [`HashArray::hash_values`].
+pub const DEFAULT_SEED: u64 = 0;
+
+/// The hash of null slots when hashed with [`DEFAULT_SEED`].
+pub const NULL_HASH: u64 = hash_null(DEFAULT_SEED);
+
+/// Multiplier used to spread the state before mixing in a word.
+const MULTIPLIER: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// The word that is mixed into the state for null slots.
+const NULL_WORD: u64 = u64::from_le_bytes(*b"null\0\0\0\0");
+
+/// The finalizer of `SplitMix64`.
+const fn mix(word: u64) -> u64 {
+    let mut x = word;
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Mixes `word` into the hash `state`.
+const fn hash_word(state: u64, word: u64) -> u64 {
+    mix(state.wrapping_add(MULTIPLIER).wrapping_mul(MULTIPLIER) ^ word)
+}
+
+/// Returns the hash of a null slot, given the hash `state`.
+const fn hash_null(state: u64) -> u64 {
+    hash_word(state, NULL_WORD)
+}
+
+/// Mixes the `value` into the hash `state`.
+fn hash_numeric<T: Numeric>(state: u64, value: T) -> u64 {
+    let bits = value.hash_bits();
+    let low = u64::try_from(bits & u128::from(u64::MAX)).expect("fits in u64");
+    if T::SIZE > 8 {
+        let high = u64::try_from(bits >> 64).expect("fits in u64");
+        hash_word(hash_word(state, low), high)
+    } else {
+        hash_word(state, low)
+    }
+}
+
+/// Mixes the `bytes` into the hash `state`, 8 bytes (little-endian) at a
+/// time, prefixed by the number of bytes.
+fn hash_bytes(state: u64, bytes: &[u8]) -> u64 {
+    let len = u64::try_from(bytes.len()).expect("length fits in u64");
+    let chunks = bytes.chunks_exact(8);
+    let remainder = chunks.remainder();
+    let hash = chunks.fold(hash_word(state, len), |hash, chunk| {
+        hash_word(
+            hash,
+            u64::from_le_bytes(chunk.try_into().expect("chunk of 8 bytes")),
+        )
+    });
+    if remainder.is_empty() {
+        hash
+    } else {
+        let mut word = [0; 8];
+        word[..remainder.len()].copy_from_slice(remainder);
+        hash_word(hash, u64::from_le_bytes(word))
+    }
+}
+
+/// Updates the `hashes` of null slots according to the `validity` bitmap, and
+/// calls `valid` with the ranges of valid slots and their hashes.
+fn for_each_valid<Buffer: BufferType>(
+    hashes: &mut [u64],
+    validity: Option<&Bitmap<Buffer>>,
+    mut valid: impl FnMut(usize, &mut [u64]),
+) {
+    if let Some(bitmap) = validity {
+        for (range, is_valid) in bitmap.runs() {
+            if is_valid {
+                valid(range.start, &mut hashes[range]);
+            } else {
+                hashes[range]
+                    .iter_mut()
+                    .for_each(|hash| *hash = hash_null(*hash));
+            }
+        }
+    } else {
+        valid(0, hashes);
+    }
+}
+
+/// Hashing of the elements of arrays.
+///
+/// The hashes are stable: they are identical across platforms (including
+/// endianness) and releases. Null slots hash to [`NULL_HASH`] with the
+/// default seed. Floating point values are hashed by their bit pattern,
+/// so `0.0` and `-0.0` have different hashes.
+///
+/// This is not a cryptographic hash function.
+pub trait HashArray: Length {
+    /// Mixes the hashes of the elements of this array into `hashes`, using
+    /// the existing hashes as seeds. This is order-sensitive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `hashes` is not equal to the length of this
+    /// array.
+    fn hash_into(&self, hashes: &mut [u64]);
+
+    /// Returns the hashes of the elements of this array, using `seed`.
+    fn hash_with_seed(&self, seed: u64) -> Uint64Array {
+        let mut hashes = vec![seed; self.len()];
+        self.hash_into(&mut hashes);
+        FixedSizePrimitiveArray(hashes)
+    }
+
+    /// Returns the hashes of the elements of this array, using
+    /// [`DEFAULT_SEED`].
+    fn hash_values(&self) -> Uint64Array {
+        self.hash_with_seed(DEFAULT_SEED)
+    }
+}
+
+/// Mixes the hashes of the elements of `array` into the hashes in `acc`.
+///
+/// This can be used to compute hashes of rows of multiple columns, e.g. to
+/// group by multiple keys. The result depends on the order in which columns
+/// are combined.
+///
+/// # Panics
+///
+/// Panics if the length of `acc` is not equal to the length of `array`.
+pub fn hash_combine<A: HashArray + ?Sized>(acc: &mut Uint64Array, array: &A) {
+    array.hash_into(acc.0.as_mut_slice());
+}
+
+impl<T: Numeric, const NULLABLE: bool, Buffer: BufferType> HashArray
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: ValuesRef<Item = T> + Length,
+{
+    fn hash_into(&self, hashes: &mut [u64]) {
+        assert_eq!(hashes.len(), self.len(), "length mismatch");
+        let values = self.values_ref();
+        for_each_valid(hashes, self.validity_ref(), |start, valid| {
+            valid
+                .iter_mut()
+                .zip(&values[start..])
+                .for_each(|(hash, &value)| *hash = hash_numeric(*hash, value));
+        });
+    }
+}
+
+impl<Buffer: BufferType> HashArray for BooleanArray<false, Buffer> {
+    fn hash_into(&self, hashes: &mut [u64]) {
+        assert_eq!(hashes.len(), self.len(), "length mismatch");
+        hashes
+            .iter_mut()
+            .zip(&self.0)
+            .for_each(|(hash, value)| *hash = hash_word(*hash, u64::from(value)));
+    }
+}
+
+impl<Buffer: BufferType> HashArray for BooleanArray<true, Buffer> {
+    fn hash_into(&self, hashes: &mut [u64]) {
+        assert_eq!(hashes.len(), self.len(), "length mismatch");
+        hashes
+            .iter_mut()
+            .zip(self.0.data.iter().zip(&self.0.validity))
+            .for_each(|(hash, (value, valid))| {
+                *hash = if valid {
+                    hash_word(*hash, u64::from(value))
+                } else {
+                    hash_null(*hash)
+                };
+            });
+    }
+}
+
+/// Mixes the hashes of the elements of a variable-size binary `array` into
+/// `hashes`.
+fn hash_binary(array: &impl BinaryValuesRef, hashes: &mut [u64]) {
+    assert_eq!(hashes.len(), array.byte_slices().len(), "length mismatch");
+    let values = array.byte_slices();
+    for_each_valid(hashes, array.validity_ref(), |start, valid| {
+        valid
+            .iter_mut()
+            .zip(values.clone().skip(start))
+            .for_each(|(hash, bytes)| *hash = hash_bytes(*hash, bytes));
+    });
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> HashArray
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Self: BinaryValuesRef + Length,
+{
+    fn hash_into(&self, hashes: &mut [u64]) {
+        hash_binary(self, hashes);
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> HashArray
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    Self: BinaryValuesRef + Length,
+{
+    fn hash_into(&self, hashes: &mut [u64]) {
+        hash_binary(self, hashes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float64Array, Int32Array, Uint8Array};
+
+    #[test]
+    fn stable() {
+        // Snapshots, these must not change.
+        assert_eq!(NULL_HASH, 0x6f34_6e55_e03f_576c);
+        assert_eq!(
+            [0, 1, -1]
+                .into_iter()
+                .collect::<Int32Array>()
+                .hash_values()
+                .as_slice(),
+            [
+                0x5196_65bf_9141_6c4d,
+                0xaa9b_9651_dbea_bacd,
+                0xfc24_7091_c0c1_4493
+            ]
+        );
+        assert_eq!(
+            [i128::MIN]
+                .into_iter()
+                .collect::<FixedSizePrimitiveArray<i128>>()
+                .hash_values()
+                .as_slice(),
+            [0xfe31_c3cd_e06e_30c4]
+        );
+        assert_eq!(
+            [0.0, -0.0]
+                .into_iter()
+                .collect::<Float64Array>()
+                .hash_values()
+                .as_slice(),
+            [0x5196_65bf_9141_6c4d, 0x4ca6_ee96_38f6_ecde]
+        );
+        assert_eq!(
+            [true, false]
+                .into_iter()
+                .collect::<BooleanArray>()
+                .hash_values()
+                .as_slice(),
+            [0xaa9b_9651_dbea_bacd, 0x5196_65bf_9141_6c4d]
+        );
+        assert_eq!(
+            ["", "narrow", "0123456789"]
+                .into_iter()
+                .collect::<StringArray>()
+                .hash_values()
+                .as_slice(),
+            [
+                0x5196_65bf_9141_6c4d,
+                0x9640_43ab_2cec_9a23,
+                0xf098_9e07_2584_9883
+            ]
+        );
+    }
+
+    #[test]
+    fn seed() {
+        let array = [1, 2, 3].into_iter().collect::<Uint8Array>();
+        assert_eq!(
+            array.hash_with_seed(DEFAULT_SEED).as_slice(),
+            array.hash_values().as_slice()
+        );
+        assert_ne!(
+            array.hash_with_seed(1).as_slice(),
+            array.hash_values().as_slice()
+        );
+    }
+
+    #[test]
+    fn nulls() {
+        let array = [1, 2, 3].into_iter().collect::<Int32Array>().hash_values();
+        let nullable = [Some(1), None, Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>()
+            .hash_values();
+        assert_eq!(
+            nullable.as_slice(),
+            [array.as_slice()[0], NULL_HASH, array.as_slice()[2]]
+        );
+
+        let booleans = [None, Some(true)]
+            .into_iter()
+            .collect::<BooleanArray<true>>()
+            .hash_values();
+        assert_eq!(booleans.as_slice(), [NULL_HASH, array.as_slice()[0]]);
+
+        let strings = [Some("a"), None]
+            .into_iter()
+            .collect::<StringArray<true>>()
+            .hash_values();
+        assert_eq!(
+            strings.as_slice(),
+            [
+                ["a"]
+                    .into_iter()
+                    .collect::<StringArray>()
+                    .hash_values()
+                    .as_slice()[0],
+                NULL_HASH
+            ]
+        );
+    }
+
+    #[test]
+    fn combine() {
+        let a = [1, 2, 1].into_iter().collect::<Int32Array>();
+        let b = ["x", "y", "y"].into_iter().collect::<StringArray>();
+
+        let mut ab = a.hash_values();
+        hash_combine(&mut ab, &b);
+        let mut ba = b.hash_values();
+        hash_combine(&mut ba, &a);
+        assert_ne!(ab.as_slice(), ba.as_slice());
+        assert_ne!(ab.as_slice(), a.hash_values().as_slice());
+
+        // Equal rows have equal hashes.
+        let mut rows = [1, 2, 1].into_iter().collect::<Int32Array>().hash_values();
+        hash_combine(
+            &mut rows,
+            &["y", "x", "y"].into_iter().collect::<StringArray>(),
+        );
+        assert_ne!(rows.as_slice()[0], ab.as_slice()[0]);
+        assert_eq!(rows.as_slice()[2], ab.as_slice()[2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch")]
+    fn combine_length_mismatch() {
+        let mut hashes = [1, 2].into_iter().collect::<Int32Array>().hash_values();
+        hash_combine(&mut hashes, &[1].into_iter().collect::<Int32Array>());
+    }
+}
@@ -0,0 +1,59 @@
+//! Compute kernels.
+
+mod aggregate;
+pub use self::aggregate::*;
+
+mod arithmetic;
+pub use self::arithmetic::*;
+
+mod boolean;
+
+mod cast;
+pub use self::cast::*;
+
+mod comparison;
+pub use self::comparison::*;
+
+mod conditional;
+pub use self::conditional::*;
+
+mod cumulative;
+pub use self::cumulative::*;
+
+mod datum;
+pub use self::datum::{ArrayOrScalar, Datum, Scalar};
+
+mod dictionary;
+pub use self::dictionary::*;
+
+mod distinct;
+
+mod endian;
+
+mod group;
+pub use self::group::*;
+
+mod hash;
+pub use self::hash::*;
+
+mod nulls;
+pub use self::nulls::*;
+
+mod numeric;
+pub use self::numeric::*;
+
+#[cfg(feature = "regex")]
+mod regex;
+
+mod reinterpret;
+pub use self::reinterpret::*;
+
+mod rolling;
+
+mod statistics;
+pub use self::statistics::*;
+
+mod string;
+
+mod values;
+pub use self::values::*;
@@ -0,0 +1,415 @@
+//! Kernels for handling nulls.
+
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray, Uint32Array},
+    bitmap::{Bitmap, BitmapRuns},
+    buffer::{Buffer, BufferType},
+    nullable::Nullable,
+    FixedSize, Length,
+};
+use std::{iter, ops::Range};
+
+/// Returns a [`BooleanArray`] that is `true` for all elements of `array`.
+///
+/// This is the [`ValidityBitmap::is_valid_mask`] of non-nullable arrays, which
+/// allows generic code to build validity masks for arrays regardless of their
+/// nullability.
+///
+/// [`ValidityBitmap::is_valid_mask`]: crate::bitmap::ValidityBitmap::is_valid_mask
+#[must_use]
+pub fn all_valid_mask(array: &impl Length) -> BooleanArray<false> {
+    BooleanArray(iter::repeat(true).take(array.len()).collect())
+}
+
+/// Returns the ranges of valid items of the given runs of validity bits.
+fn valid_ranges(runs: BitmapRuns<'_>) -> impl Iterator<Item = Range<usize>> + '_ {
+    runs.filter_map(|(range, valid)| valid.then_some(range))
+}
+
+/// Returns the indices in the given ranges.
+fn range_indices(ranges: impl Iterator<Item = Range<usize>>) -> Uint32Array {
+    ranges
+        .flatten()
+        .map(|index| u32::try_from(index).expect("index fits in u32"))
+        .collect()
+}
+
+impl<T: FixedSize, Buffer: BufferType> FixedSizePrimitiveArray<T, true, Buffer> {
+    /// Returns a non-nullable array where the nulls of this array are
+    /// replaced by `value`.
+    #[must_use]
+    pub fn fill_null(&self, value: T) -> FixedSizePrimitiveArray<T, false, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<T>: Default + Extend<T>,
+    {
+        let values = self.0.data.as_slice();
+        let mut data = <Buffer as BufferType>::Buffer::<T>::default();
+        for (range, valid) in self.0.validity.runs() {
+            if valid {
+                data.extend(values[range].iter().copied());
+            } else {
+                data.extend(iter::repeat(value).take(range.len()));
+            }
+        }
+        FixedSizePrimitiveArray(data)
+    }
+
+    /// Returns an array where the nulls of this array are replaced by the
+    /// items at the same positions in `other` (coalesce). The result has
+    /// nulls where both arrays have nulls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `other` is not equal to the length of this
+    /// array.
+    #[must_use]
+    pub fn fill_null_with(&self, other: &Self) -> Self
+    where
+        <Buffer as BufferType>::Buffer<T>: Default + Extend<T>,
+        Bitmap<Buffer>: FromIterator<bool>,
+    {
+        assert_eq!(self.len(), other.len(), "length mismatch");
+        let values = self.0.data.as_slice();
+        let replacements = other.0.data.as_slice();
+        let mut data = <Buffer as BufferType>::Buffer::<T>::default();
+        for (range, valid) in self.0.validity.runs() {
+            let source = if valid { values } else { replacements };
+            data.extend(source[range].iter().copied());
+        }
+        let validity = self
+            .0
+            .validity
+            .iter()
+            .zip(&other.0.validity)
+            .map(|(valid, replacement_valid)| valid || replacement_valid)
+            .collect();
+        FixedSizePrimitiveArray(Nullable { data, validity })
+    }
+
+    /// Returns an array where the nulls of this array are replaced by the
+    /// last preceding valid value (forward fill). Leading nulls remain null.
+    #[must_use]
+    pub fn fill_null_forward(&self) -> Self
+    where
+        <Buffer as BufferType>::Buffer<T>: Default + Extend<T>,
+        Bitmap<Buffer>: FromIterator<bool>,
+    {
+        let values = self.0.data.as_slice();
+        let mut data = <Buffer as BufferType>::Buffer::<T>::default();
+        let mut last = None;
+        let mut leading = 0;
+        for (range, valid) in self.0.validity.runs() {
+            if valid {
+                last = range.end.checked_sub(1).map(|index| values[index]);
+                data.extend(values[range].iter().copied());
+            } else if let Some(value) = last {
+                data.extend(iter::repeat(value).take(range.len()));
+            } else {
+                leading = range.len();
+                data.extend(values[range].iter().copied());
+            }
+        }
+        let validity = iter::repeat(false)
+            .take(leading)
+            .chain(iter::repeat(true).take(values.len() - leading))
+            .collect();
+        FixedSizePrimitiveArray(Nullable { data, validity })
+    }
+
+    /// Returns a non-nullable array with the valid items of this array.
+    ///
+    /// Valid runs are copied in bulk.
+    #[must_use]
+    pub fn drop_nulls(&self) -> FixedSizePrimitiveArray<T, false, Buffer>
+    where
+        <Buffer as BufferType>::Buffer<T>: Default + Extend<T>,
+    {
+        let values = self.0.data.as_slice();
+        let mut data = <Buffer as BufferType>::Buffer::<T>::default();
+        for range in valid_ranges(self.0.validity.runs()) {
+            data.extend(values[range].iter().copied());
+        }
+        FixedSizePrimitiveArray(data)
+    }
+
+    /// Returns a non-nullable array with the valid items of this array, and
+    /// the indices of these items in this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index does not fit in a [`u32`].
+    #[must_use]
+    pub fn drop_nulls_with_indices(
+        &self,
+    ) -> (FixedSizePrimitiveArray<T, false, Buffer>, Uint32Array)
+    where
+        <Buffer as BufferType>::Buffer<T>: Default + Extend<T>,
+    {
+        (
+            self.drop_nulls(),
+            range_indices(valid_ranges(self.0.validity.runs())),
+        )
+    }
+}
+
+impl<Buffer: BufferType> BooleanArray<true, Buffer> {
+    /// Returns a non-nullable array where the nulls of this array are
+    /// replaced by `value`.
+    #[must_use]
+    pub fn fill_null(&self, value: bool) -> BooleanArray<false, Buffer>
+    where
+        Bitmap<Buffer>: FromIterator<bool>,
+    {
+        BooleanArray(
+            self.0
+                .data
+                .iter()
+                .zip(&self.0.validity)
+                .map(|(item, valid)| if valid { item } else { value })
+                .collect(),
+        )
+    }
+
+    /// Returns an array where the nulls of this array are replaced by the
+    /// items at the same positions in `other` (coalesce). The result has
+    /// nulls where both arrays have nulls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `other` is not equal to the length of this
+    /// array.
+    #[must_use]
+    pub fn fill_null_with(&self, other: &Self) -> Self
+    where
+        Bitmap<Buffer>: Default + Extend<bool>,
+    {
+        assert_eq!(self.len(), other.len(), "length mismatch");
+        let (data, validity) = self
+            .0
+            .data
+            .iter()
+            .zip(&self.0.validity)
+            .zip(other.0.data.iter().zip(&other.0.validity))
+            .map(|((item, valid), (replacement, replacement_valid))| {
+                if valid {
+                    (item, true)
+                } else {
+                    (replacement, replacement_valid)
+                }
+            })
+            .unzip();
+        BooleanArray(Nullable { data, validity })
+    }
+
+    /// Returns an array where the nulls of this array are replaced by the
+    /// last preceding valid value (forward fill). Leading nulls remain null.
+    #[must_use]
+    pub fn fill_null_forward(&self) -> Self
+    where
+        Bitmap<Buffer>: Default + Extend<bool>,
+    {
+        let mut last = None;
+        let (data, validity) = self
+            .0
+            .data
+            .iter()
+            .zip(&self.0.validity)
+            .map(|(item, valid)| {
+                if valid {
+                    last = Some(item);
+                }
+                last.map_or((item, false), |value| (value, true))
+            })
+            .unzip();
+        BooleanArray(Nullable { data, validity })
+    }
+
+    /// Returns a non-nullable array with the valid items of this array.
+    #[must_use]
+    pub fn drop_nulls(&self) -> BooleanArray<false, Buffer>
+    where
+        Bitmap<Buffer>: FromIterator<bool>,
+    {
+        BooleanArray(
+            self.0
+                .data
+                .iter()
+                .zip(&self.0.validity)
+                .filter_map(|(item, valid)| valid.then_some(item))
+                .collect(),
+        )
+    }
+
+    /// Returns a non-nullable array with the valid items of this array, and
+    /// the indices of these items in this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index does not fit in a [`u32`].
+    #[must_use]
+    pub fn drop_nulls_with_indices(&self) -> (BooleanArray<false, Buffer>, Uint32Array)
+    where
+        Bitmap<Buffer>: FromIterator<bool>,
+    {
+        (
+            self.drop_nulls(),
+            range_indices(valid_ranges(self.0.validity.runs())),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{array::Int32Array, bitmap::ValidityBitmap, buffer::VecBuffer, compute::if_else};
+
+    #[test]
+    fn fill_null() {
+        let array = [None, Some(1), None, None, Some(4), None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(array.fill_null(0).as_slice(), [0, 1, 0, 0, 4, 0]);
+
+        let all_null = [None::<i32>, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(all_null.fill_null(7).as_slice(), [7, 7]);
+        assert_eq!(
+            all_null.fill_null_forward().into_iter().collect::<Vec<_>>(),
+            [None, None]
+        );
+
+        let booleans = [Some(true), None, Some(false)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(
+            booleans.fill_null(true).into_iter().collect::<Vec<_>>(),
+            [true, true, false]
+        );
+    }
+
+    #[test]
+    fn fill_null_with() {
+        let array = [None, Some(1), None, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let other = [Some(10), Some(20), None, Some(40)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            array.fill_null_with(&other).into_iter().collect::<Vec<_>>(),
+            [Some(10), Some(1), None, Some(40)]
+        );
+
+        let booleans = [None, Some(true), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let replacements = [Some(false), Some(false), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(
+            booleans
+                .fill_null_with(&replacements)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(false), Some(true), None]
+        );
+    }
+
+    #[test]
+    fn fill_null_forward() {
+        let array = [None, None, Some(1), None, Some(3), None, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            array.fill_null_forward().into_iter().collect::<Vec<_>>(),
+            [None, None, Some(1), Some(1), Some(3), Some(3), Some(3)]
+        );
+
+        let booleans = [None, Some(true), None, Some(false), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(
+            booleans.fill_null_forward().into_iter().collect::<Vec<_>>(),
+            [None, Some(true), Some(true), Some(false), Some(false)]
+        );
+    }
+
+    #[test]
+    fn drop_nulls() {
+        let no_nulls = [Some(1), Some(2), Some(3)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let (values, indices) = no_nulls.drop_nulls_with_indices();
+        assert_eq!(values.as_slice(), [1, 2, 3]);
+        assert_eq!(indices.as_slice(), [0, 1, 2]);
+
+        let all_null = [None::<i32>, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let (empty, empty_indices) = all_null.drop_nulls_with_indices();
+        assert!(empty.is_empty());
+        assert!(empty_indices.is_empty());
+
+        let interleaved = [None, Some(1), Some(2), None, Some(4), None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let (sparse, sparse_indices) = interleaved.drop_nulls_with_indices();
+        assert_eq!(sparse.as_slice(), [1, 2, 4]);
+        assert_eq!(sparse_indices.as_slice(), [1, 2, 4]);
+
+        let booleans = [Some(true), None, Some(false), None]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        let (compacted, booleans_indices) = booleans.drop_nulls_with_indices();
+        assert_eq!(compacted.into_iter().collect::<Vec<_>>(), [true, false]);
+        assert_eq!(booleans_indices.as_slice(), [0, 2]);
+    }
+
+    #[test]
+    fn masks() {
+        let array = [
+            Some(1),
+            None,
+            Some(3),
+            None,
+            None,
+            Some(6),
+            Some(7),
+            None,
+            Some(9),
+        ]
+        .into_iter()
+        .collect::<Int32Array<true>>();
+        let is_valid = array.is_valid_mask();
+        let is_null = array.is_null_mask();
+        assert_eq!(is_valid.values().buffer, [0b0110_0101, 0b1]);
+        assert_eq!(is_null.values().buffer, [0b1001_1010, 0b0]);
+        assert_eq!(is_null.values().count_ones(), array.null_count());
+
+        // Safety:
+        // - The buffer has enough bytes for 6 bits at offset 3.
+        let offset = unsafe { Bitmap::<VecBuffer>::from_raw_parts(vec![0b0011_1000, 0], 6, 3) };
+        assert_eq!(offset.is_null_mask().values().buffer, [0b0011_1000]);
+        assert_eq!(offset.is_valid_mask().values().buffer, [0b0000_0111]);
+
+        let values = Int32Array::<false>::from_iter([1, 2, 3]);
+        assert_eq!(
+            all_valid_mask(&values).into_iter().collect::<Vec<_>>(),
+            [true; 3]
+        );
+    }
+
+    #[test]
+    fn masks_select() {
+        let array = [Some(1), None, Some(3), None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        let fallback = Int32Array::<false>::from_iter([10, 20, 30, 40]);
+        let values = array.fill_null(0);
+        assert_eq!(
+            if_else(&array.is_valid_mask(), &values, &fallback).as_slice(),
+            [1, 20, 3, 40]
+        );
+    }
+}
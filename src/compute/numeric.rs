@@ -0,0 +1,187 @@
+//! Numeric types supported by the compute kernels.
+
+use crate::FixedSize;
+use std::cmp::Ordering;
+
+/// Numeric types that are supported by the compute kernels.
+///
+/// This trait is sealed to prevent downstream implementations.
+pub trait Numeric: FixedSize + Default + PartialOrd + sealed::Sealed {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// Wrapping addition. For floating point types this is regular addition.
+    #[must_use]
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Wrapping subtraction. For floating point types this is regular
+    /// subtraction.
+    #[must_use]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Wrapping multiplication. For floating point types this is regular
+    /// multiplication.
+    #[must_use]
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Checked addition, returns `None` on overflow. For floating point types
+    /// this never returns `None`.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Converts this value to the nearest [`f64`].
+    fn to_f64(self) -> f64;
+
+    /// Returns the bits of this value, used for hashing and bit-pattern
+    /// equality. Integers are sign or zero extended to 128 bits, floating
+    /// point values are represented by their bit pattern.
+    fn hash_bits(self) -> u128;
+
+    /// Returns `true` if this value is NaN. This is always `false` for
+    /// integer types.
+    fn is_nan(self) -> bool;
+
+    /// Reverses the byte order of this value.
+    #[must_use]
+    fn swap_bytes(self) -> Self;
+}
+
+/// Floating point types that are supported by the compute kernels.
+///
+/// This trait is sealed to prevent downstream implementations.
+pub trait Float: Numeric {
+    /// Returns the ordering between `self` and `other` according to the
+    /// `totalOrder` predicate as defined in IEEE 754.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// Private module for [`sealed::Sealed`] trait.
+mod sealed {
+    /// Used to seal [`super::Numeric`].
+    pub trait Sealed {}
+
+    // Prevent downstream implementation of [super::Numeric].
+    impl<T> Sealed for T where T: super::Numeric {}
+}
+
+/// Implements [`Numeric`] for integer types.
+macro_rules! impl_numeric_int {
+    ($($ty:ty),+) => {
+        $(
+            impl Numeric for $ty {
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_add(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_sub(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_mul(self, rhs)
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, rhs)
+                }
+
+                #[inline]
+                #[allow(clippy::as_conversions, clippy::cast_precision_loss, clippy::cast_lossless)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                #[allow(
+                    clippy::as_conversions,
+                    clippy::cast_lossless,
+                    clippy::cast_sign_loss,
+                    clippy::cast_possible_wrap
+                )]
+                fn hash_bits(self) -> u128 {
+                    self as u128
+                }
+
+                #[inline]
+                fn is_nan(self) -> bool {
+                    false
+                }
+
+                #[inline]
+                fn swap_bytes(self) -> Self {
+                    <$ty>::swap_bytes(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_numeric_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+#[cfg(not(feature = "arrow-rs"))]
+impl_numeric_int!(u128, isize, usize);
+
+/// Implements [`Numeric`] and [`Float`] for floating point types.
+macro_rules! impl_numeric_float {
+    ($($ty:ty),+) => {
+        $(
+            impl Numeric for $ty {
+                const ZERO: Self = 0.;
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    self + rhs
+                }
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    self - rhs
+                }
+
+                #[inline]
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    self * rhs
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs)
+                }
+
+                #[inline]
+                #[allow(clippy::as_conversions, clippy::cast_precision_loss, clippy::cast_lossless)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn hash_bits(self) -> u128 {
+                    u128::from(self.to_bits())
+                }
+
+                #[inline]
+                fn is_nan(self) -> bool {
+                    <$ty>::is_nan(self)
+                }
+
+                #[inline]
+                fn swap_bytes(self) -> Self {
+                    <$ty>::from_bits(self.to_bits().swap_bytes())
+                }
+            }
+
+            impl Float for $ty {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$ty>::total_cmp(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_numeric_float!(f32, f64);
@@ -0,0 +1,175 @@
+//! Regular expression kernels for string arrays.
+
+use super::string::{nullable_strs, strs};
+use crate::{
+    array::{BooleanArray, StringArray},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+use regex::{Error, Regex};
+
+/// Returns the capture group `group` of the first match of `regex` in `value`.
+fn extract<'a>(regex: &Regex, value: &'a str, group: usize) -> Option<&'a str> {
+    regex
+        .captures(value)
+        .and_then(|captures| captures.get(group))
+        .map(|capture| capture.as_str())
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<false, OffsetItem, Buffer> {
+    /// Returns whether the strings match the regular expression `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression.
+    pub fn regex_is_match(&self, pattern: &str) -> Result<BooleanArray, Error> {
+        Regex::new(pattern).map(|regex| self.regex_is_match_with(&regex))
+    }
+
+    /// Returns whether the strings match `regex`.
+    #[must_use]
+    pub fn regex_is_match_with(&self, regex: &Regex) -> BooleanArray {
+        strs(self).map(|value| regex.is_match(value)).collect()
+    }
+
+    /// Returns the capture group `group` of the first match of the regular
+    /// expression `pattern` in the strings, or null when there is no match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression.
+    pub fn regex_extract(
+        &self,
+        pattern: &str,
+        group: usize,
+    ) -> Result<StringArray<true, OffsetItem, Buffer>, Error>
+    where
+        StringArray<true, OffsetItem, Buffer>: for<'a> FromIterator<Option<&'a str>>,
+    {
+        Regex::new(pattern).map(|regex| self.regex_extract_with(&regex, group))
+    }
+
+    /// Returns the capture group `group` of the first match of `regex` in the
+    /// strings, or null when there is no match.
+    #[must_use]
+    pub fn regex_extract_with(
+        &self,
+        regex: &Regex,
+        group: usize,
+    ) -> StringArray<true, OffsetItem, Buffer>
+    where
+        StringArray<true, OffsetItem, Buffer>: for<'a> FromIterator<Option<&'a str>>,
+    {
+        strs(self)
+            .map(|value| extract(regex, value, group))
+            .collect()
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<true, OffsetItem, Buffer> {
+    /// Returns whether the strings match the regular expression `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression.
+    pub fn regex_is_match(&self, pattern: &str) -> Result<BooleanArray<true>, Error> {
+        Regex::new(pattern).map(|regex| self.regex_is_match_with(&regex))
+    }
+
+    /// Returns whether the strings match `regex`.
+    #[must_use]
+    pub fn regex_is_match_with(&self, regex: &Regex) -> BooleanArray<true> {
+        nullable_strs(self)
+            .map(|value| value.map(|item| regex.is_match(item)))
+            .collect()
+    }
+
+    /// Returns the capture group `group` of the first match of the regular
+    /// expression `pattern` in the strings, or null when there is no match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` is not a valid regular expression.
+    pub fn regex_extract(&self, pattern: &str, group: usize) -> Result<Self, Error>
+    where
+        Self: for<'a> FromIterator<Option<&'a str>>,
+    {
+        Regex::new(pattern).map(|regex| self.regex_extract_with(&regex, group))
+    }
+
+    /// Returns the capture group `group` of the first match of `regex` in the
+    /// strings, or null when there is no match.
+    #[must_use]
+    pub fn regex_extract_with(&self, regex: &Regex, group: usize) -> Self
+    where
+        Self: for<'a> FromIterator<Option<&'a str>>,
+    {
+        nullable_strs(self)
+            .map(|value| value.and_then(|item| extract(regex, item, group)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_pattern() {
+        let array = ["a"].into_iter().collect::<StringArray>();
+        assert!(array.regex_is_match("(").is_err());
+        assert!(array.regex_extract("[a-", 0).is_err());
+    }
+
+    #[test]
+    fn is_match() {
+        let array = [Some("abc123"), None, Some("abc")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        assert_eq!(
+            array
+                .regex_is_match(r"\d+")
+                .expect("valid pattern")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(true), None, Some(false)]
+        );
+
+        let regex = Regex::new("^a").expect("valid pattern");
+        let values = ["abc", "bca"].into_iter().collect::<StringArray>();
+        assert_eq!(
+            values
+                .regex_is_match_with(&regex)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [true, false]
+        );
+    }
+
+    #[test]
+    fn extract() {
+        let array = ["key=value", "no match", "x=\u{1f980}"]
+            .into_iter()
+            .collect::<StringArray>();
+        assert_eq!(
+            array
+                .regex_extract(r"(\w+)=(.+)", 2)
+                .expect("valid pattern")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some("value".to_owned()), None, Some("\u{1f980}".to_owned())]
+        );
+
+        let nullable = [None, Some("a1"), Some("b")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        assert_eq!(
+            nullable
+                .regex_extract(r"[a-z](\d)?", 1)
+                .expect("valid pattern")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [None, Some("1".to_owned()), None]
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! Bit-level reinterpretation of primitive arrays.
+
+use crate::{
+    array::FixedSizePrimitiveArray,
+    buffer::{ArcBuffer, ArrayBuffer, BoxBuffer, BufferType, RcBuffer, SliceBuffer, VecBuffer},
+    nullable::Nullable,
+    FixedSize,
+};
+use std::{
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    rc::Rc,
+    slice,
+    sync::Arc,
+};
+
+/// Compile-time check that `T` and `U` have the same size.
+struct SameSize<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> SameSize<T, U> {
+    /// Fails to evaluate if the sizes of `T` and `U` are not equal.
+    const ASSERT: () = assert!(
+        mem::size_of::<T>() == mem::size_of::<U>(),
+        "reinterpret requires types of equal size"
+    );
+}
+
+/// Returns the bits of `value` reinterpreted as a `U`.
+fn reinterpret_value<T: FixedSize, U: FixedSize>(value: &T) -> U {
+    let () = SameSize::<T, U>::ASSERT;
+    // Safety:
+    // - The sizes of `T` and `U` are equal.
+    // - All bit patterns are valid for fixed-size types.
+    unsafe { mem::transmute_copy(value) }
+}
+
+/// [`BufferType`]s with buffers that can be reinterpreted as buffers of
+/// another [`FixedSize`] type of the same size.
+pub trait Reinterpret: BufferType {
+    /// Returns the buffer with its items reinterpreted as items of type `U`.
+    ///
+    /// This does not copy the items when the buffer type allows it.
+    fn reinterpret<T: FixedSize, U: FixedSize>(buffer: Self::Buffer<T>) -> Self::Buffer<U>;
+}
+
+impl Reinterpret for VecBuffer {
+    /// Moves the allocation when `T` and `U` have the same alignment, and
+    /// copies the items otherwise.
+    fn reinterpret<T: FixedSize, U: FixedSize>(buffer: Vec<T>) -> Vec<U> {
+        let () = SameSize::<T, U>::ASSERT;
+        if mem::align_of::<T>() == mem::align_of::<U>() {
+            let mut values = ManuallyDrop::new(buffer);
+            // Safety:
+            // - The allocation was made by a `Vec`, for items with the same size
+            //   and alignment as `U`.
+            // - The length and capacity are unchanged because the sizes of `T`
+            //   and `U` are equal.
+            // - All bit patterns are valid for fixed-size types.
+            unsafe {
+                Vec::from_raw_parts(values.as_mut_ptr().cast(), values.len(), values.capacity())
+            }
+        } else {
+            buffer.iter().map(reinterpret_value).collect()
+        }
+    }
+}
+
+impl Reinterpret for BoxBuffer {
+    /// Moves the allocation when `T` and `U` have the same alignment, and
+    /// copies the items otherwise.
+    fn reinterpret<T: FixedSize, U: FixedSize>(buffer: Box<[T]>) -> Box<[U]> {
+        VecBuffer::reinterpret(buffer.into_vec()).into_boxed_slice()
+    }
+}
+
+impl<'a> Reinterpret for SliceBuffer<'a> {
+    /// Never copies the items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is not aligned for `U`.
+    fn reinterpret<T: FixedSize, U: FixedSize>(buffer: &'a [T]) -> &'a [U] {
+        let () = SameSize::<T, U>::ASSERT;
+        assert!(
+            buffer
+                .as_ptr()
+                .cast::<U>()
+                .align_offset(mem::align_of::<U>())
+                == 0,
+            "slice is not aligned for the target type"
+        );
+        // Safety:
+        // - The pointer is aligned for `U`.
+        // - The slice has `len` items of the same size as `U`.
+        // - All bit patterns are valid for fixed-size types.
+        unsafe { slice::from_raw_parts(buffer.as_ptr().cast(), buffer.len()) }
+    }
+}
+
+impl Reinterpret for ArcBuffer {
+    /// Copies the items.
+    fn reinterpret<T: FixedSize, U: FixedSize>(buffer: Arc<[T]>) -> Arc<[U]> {
+        buffer.iter().map(reinterpret_value).collect()
+    }
+}
+
+impl Reinterpret for RcBuffer {
+    /// Copies the items.
+    fn reinterpret<T: FixedSize, U: FixedSize>(buffer: Rc<[T]>) -> Rc<[U]> {
+        buffer.iter().map(reinterpret_value).collect()
+    }
+}
+
+impl<const N: usize> Reinterpret for ArrayBuffer<N> {
+    /// Copies the items.
+    fn reinterpret<T: FixedSize, U: FixedSize>(buffer: [T; N]) -> [U; N] {
+        buffer.map(|value| reinterpret_value(&value))
+    }
+}
+
+impl<T: FixedSize, Buffer: Reinterpret> FixedSizePrimitiveArray<T, false, Buffer> {
+    /// Returns this array with its values reinterpreted as values of type `U`,
+    /// which must have the same size as `T`.
+    ///
+    /// See [`Reinterpret`] for when the values are copied.
+    ///
+    /// Types of different sizes are rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use narrow::array::Uint32Array;
+    ///
+    /// let array = [1_u32].into_iter().collect::<Uint32Array>();
+    /// let _ = array.reinterpret::<u64>();
+    /// ```
+    #[must_use]
+    pub fn reinterpret<U: FixedSize>(self) -> FixedSizePrimitiveArray<U, false, Buffer> {
+        FixedSizePrimitiveArray(Buffer::reinterpret(self.0))
+    }
+}
+
+impl<T: FixedSize, Buffer: Reinterpret> FixedSizePrimitiveArray<T, true, Buffer> {
+    /// Returns this array with its values reinterpreted as values of type `U`,
+    /// which must have the same size as `T`. The validity is unchanged.
+    ///
+    /// See [`Reinterpret`] for when the values are copied.
+    #[must_use]
+    pub fn reinterpret<U: FixedSize>(self) -> FixedSizePrimitiveArray<U, true, Buffer> {
+        FixedSizePrimitiveArray(Nullable {
+            data: Buffer::reinterpret(self.0.data),
+            validity: self.0.validity,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float32Array, Uint32Array};
+
+    #[test]
+    fn bit_patterns() {
+        let array = [0x3f80_0000_u32, 0xc000_0000, 0x7fc0_0000]
+            .into_iter()
+            .collect::<Uint32Array>();
+        let floats = array.reinterpret::<f32>();
+        assert_eq!(floats.as_slice()[0].to_bits(), 1.0_f32.to_bits());
+        assert_eq!(floats.as_slice()[1].to_bits(), (-2.0_f32).to_bits());
+        assert!(floats.as_slice()[2].is_nan());
+        assert_eq!(
+            floats.reinterpret::<u32>().as_slice(),
+            [0x3f80_0000, 0xc000_0000, 0x7fc0_0000]
+        );
+
+        let signed = [-1_i64]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<i64>>();
+        assert_eq!(signed.reinterpret::<u64>().as_slice(), [u64::MAX]);
+    }
+
+    #[test]
+    fn nullable() {
+        let array = [Some(1.5_f32), None]
+            .into_iter()
+            .collect::<Float32Array<true>>();
+        assert_eq!(
+            array.reinterpret::<u32>().into_iter().collect::<Vec<_>>(),
+            [Some(1.5_f32.to_bits()), None]
+        );
+    }
+
+    #[test]
+    fn zero_copy() {
+        let values = [1_u32, 2, 3];
+        let array = FixedSizePrimitiveArray::<u32, false, SliceBuffer<'_>>(&values);
+        let reinterpreted = array.reinterpret::<i32>();
+        assert_eq!(
+            reinterpreted.as_slice().as_ptr().cast::<u32>(),
+            values.as_ptr()
+        );
+        assert_eq!(reinterpreted.as_slice(), [1, 2, 3]);
+
+        let vec = vec![1_u32, 2, 3];
+        let ptr = vec.as_ptr();
+        let moved = FixedSizePrimitiveArray::<u32>(vec).reinterpret::<f32>();
+        assert_eq!(moved.as_slice().as_ptr().cast::<u32>(), ptr);
+    }
+
+    #[test]
+    #[cfg(not(feature = "arrow-rs"))]
+    fn copy() {
+        let array = FixedSizePrimitiveArray::<u16>(vec![0x0102, 0x0304]);
+        let bytes = array.reinterpret::<[u8; 2]>();
+        assert_eq!(
+            bytes.as_slice(),
+            [0x0102_u16.to_ne_bytes(), 0x0304_u16.to_ne_bytes()]
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! Rolling window kernels.
+
+use super::{validity_bits, Numeric, ValuesRef};
+use crate::{
+    array::{FixedSizePrimitiveArray, Float64Array},
+    buffer::BufferType,
+    validity::Validity,
+};
+use std::collections::VecDeque;
+
+/// The state of a rolling window aggregate.
+trait Window<T> {
+    /// The type of the aggregate.
+    type Output;
+
+    /// Adds the valid value at `index` to the window.
+    fn push(&mut self, index: usize, value: T);
+
+    /// Removes the valid value at `index` from the window. Values are removed
+    /// in the order they were added.
+    fn pop(&mut self, index: usize, value: T);
+
+    /// Returns the aggregate of the `count` valid values in the window.
+    fn output(&self, count: usize) -> Option<Self::Output>;
+}
+
+/// Sliding sum, wrapping around on overflow.
+struct Sum<T>(T);
+
+impl<T: Numeric> Window<T> for Sum<T> {
+    type Output = T;
+
+    fn push(&mut self, _: usize, value: T) {
+        self.0 = self.0.wrapping_add(value);
+    }
+
+    fn pop(&mut self, _: usize, value: T) {
+        self.0 = self.0.wrapping_sub(value);
+    }
+
+    fn output(&self, _: usize) -> Option<T> {
+        Some(self.0)
+    }
+}
+
+/// Sliding mean.
+struct Mean(f64);
+
+impl<T: Numeric> Window<T> for Mean {
+    type Output = f64;
+
+    fn push(&mut self, _: usize, value: T) {
+        self.0 += value.to_f64();
+    }
+
+    fn pop(&mut self, _: usize, value: T) {
+        self.0 -= value.to_f64();
+    }
+
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    fn output(&self, count: usize) -> Option<f64> {
+        (count > 0).then(|| self.0 / count as f64)
+    }
+}
+
+/// Sliding extreme using a monotonic deque of candidates. NaN values are
+/// ignored.
+struct Extreme<T, F> {
+    /// The candidates, with the current extreme at the front.
+    candidates: VecDeque<(usize, T)>,
+    /// Returns `true` when the candidate (first argument) should replace the
+    /// current value (second argument).
+    replace: F,
+}
+
+impl<T: Numeric, F: Fn(T, T) -> bool> Window<T> for Extreme<T, F> {
+    type Output = T;
+
+    fn push(&mut self, index: usize, value: T) {
+        if !value.is_nan() {
+            while self
+                .candidates
+                .back()
+                .is_some_and(|&(_, candidate)| !(self.replace)(candidate, value))
+            {
+                self.candidates.pop_back();
+            }
+            self.candidates.push_back((index, value));
+        }
+    }
+
+    fn pop(&mut self, index: usize, _: T) {
+        if self
+            .candidates
+            .front()
+            .is_some_and(|&(candidate, _)| candidate == index)
+        {
+            self.candidates.pop_front();
+        }
+    }
+
+    fn output(&self, _: usize) -> Option<T> {
+        self.candidates.front().map(|&(_, value)| value)
+    }
+}
+
+/// Returns the aggregates of `state` over the windows ending at every value.
+/// Windows with less than `min_periods` valid values result in nulls.
+///
+/// # Panics
+///
+/// Panics if `window` is zero.
+fn rolling<T: Copy, W: Window<T>>(
+    values: &[T],
+    validity: impl Iterator<Item = bool>,
+    window: usize,
+    min_periods: usize,
+    mut state: W,
+) -> Vec<Option<W::Output>> {
+    assert!(window > 0, "window must be positive");
+    let valid = validity.collect::<Vec<_>>();
+    let mut count = 0;
+    (0..values.len())
+        .map(|index| {
+            if valid[index] {
+                state.push(index, values[index]);
+                count += 1;
+            }
+            if let Some(expired) = index.checked_sub(window) {
+                if valid[expired] {
+                    state.pop(expired, values[expired]);
+                    count -= 1;
+                }
+            }
+            if count >= min_periods {
+                state.output(count)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+impl<T: Numeric, const NULLABLE: bool, Buffer: BufferType>
+    FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: ValuesRef<Item = T>,
+{
+    /// Returns the aggregates of `state` over the windows of this array.
+    fn rolling<W: Window<T>>(
+        &self,
+        window: usize,
+        min_periods: usize,
+        state: W,
+    ) -> Vec<Option<W::Output>> {
+        let values = self.values_ref();
+        rolling(
+            values,
+            validity_bits(self.validity_ref(), values.len()),
+            window,
+            min_periods,
+            state,
+        )
+    }
+
+    /// Returns the sums of the values in the windows of `window` values
+    /// ending at every value, wrapping around on overflow. The first
+    /// `window - 1` sums and the sums of windows with nulls are null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn rolling_sum(&self, window: usize) -> FixedSizePrimitiveArray<T, true> {
+        self.rolling_sum_with(window, window)
+    }
+
+    /// Returns the sums of the valid values in the windows of `window` values
+    /// ending at every value, wrapping around on overflow. Windows with less
+    /// than `min_periods` valid values result in nulls.
+    ///
+    /// The sum is updated incrementally, so for floating point values
+    /// non-finite values affect the sums of all later windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn rolling_sum_with(
+        &self,
+        window: usize,
+        min_periods: usize,
+    ) -> FixedSizePrimitiveArray<T, true> {
+        self.rolling(window, min_periods, Sum(T::ZERO))
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the means of the values in the windows of `window` values
+    /// ending at every value. The first `window - 1` means and the means of
+    /// windows with nulls are null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn rolling_mean(&self, window: usize) -> Float64Array<true> {
+        self.rolling_mean_with(window, window)
+    }
+
+    /// Returns the means of the valid values in the windows of `window`
+    /// values ending at every value. Windows with less than `min_periods`
+    /// valid values, or without valid values, result in nulls.
+    ///
+    /// The sum is updated incrementally, so for floating point values
+    /// non-finite values affect the means of all later windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn rolling_mean_with(&self, window: usize, min_periods: usize) -> Float64Array<true> {
+        self.rolling(window, min_periods, Mean(0.))
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the minimums of the values in the windows of `window` values
+    /// ending at every value. The first `window - 1` minimums and the
+    /// minimums of windows with nulls are null. NaN values are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn rolling_min(&self, window: usize) -> FixedSizePrimitiveArray<T, true> {
+        self.rolling_min_with(window, window)
+    }
+
+    /// Returns the minimums of the valid values in the windows of `window`
+    /// values ending at every value. Windows with less than `min_periods`
+    /// valid values, or without valid values, result in nulls. NaN values are
+    /// ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn rolling_min_with(
+        &self,
+        window: usize,
+        min_periods: usize,
+    ) -> FixedSizePrimitiveArray<T, true> {
+        let state = Extreme {
+            candidates: VecDeque::new(),
+            replace: |value: T, current: T| value < current,
+        };
+        self.rolling(window, min_periods, state)
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the maximums of the values in the windows of `window` values
+    /// ending at every value. The first `window - 1` maximums and the
+    /// maximums of windows with nulls are null. NaN values are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn rolling_max(&self, window: usize) -> FixedSizePrimitiveArray<T, true> {
+        self.rolling_max_with(window, window)
+    }
+
+    /// Returns the maximums of the valid values in the windows of `window`
+    /// values ending at every value. Windows with less than `min_periods`
+    /// valid values, or without valid values, result in nulls. NaN values are
+    /// ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[must_use]
+    pub fn rolling_max_with(
+        &self,
+        window: usize,
+        min_periods: usize,
+    ) -> FixedSizePrimitiveArray<T, true> {
+        let state = Extreme {
+            candidates: VecDeque::new(),
+            replace: |value: T, current: T| value > current,
+        };
+        self.rolling(window, min_periods, state)
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::Int64Array;
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    /// Returns the valid values in the window of `window` items ending at
+    /// `index`, or `None` if it has less than `min_periods` valid values.
+    fn naive_window(
+        items: &[Option<i64>],
+        index: usize,
+        window: usize,
+        min_periods: usize,
+    ) -> Option<Vec<i64>> {
+        let start = (index + 1).saturating_sub(window);
+        let values = items[start..=index]
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        (values.len() >= min_periods).then_some(values)
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        let items = (0..500)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(-100..100)))
+            .collect::<Vec<_>>();
+        let array = items.iter().copied().collect::<Int64Array<true>>();
+        for window in [1, 2, 3, 7, 64, 500, 501] {
+            for min_periods in [1, window / 2, window] {
+                let naive = |index| naive_window(&items, index, window, min_periods);
+                let sums = array
+                    .rolling_sum_with(window, min_periods)
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                let means = array
+                    .rolling_mean_with(window, min_periods)
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                let minimums = array
+                    .rolling_min_with(window, min_periods)
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                let maximums = array
+                    .rolling_max_with(window, min_periods)
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                for index in 0..items.len() {
+                    let values = naive(index);
+                    assert_eq!(sums[index], values.as_ref().map(|v| v.iter().sum()));
+                    assert_eq!(
+                        minimums[index],
+                        values.as_ref().and_then(|v| v.iter().min().copied())
+                    );
+                    assert_eq!(
+                        maximums[index],
+                        values.as_ref().and_then(|v| v.iter().max().copied())
+                    );
+                    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+                    let mean = values
+                        .filter(|v| !v.is_empty())
+                        .map(|v| v.iter().sum::<i64>() as f64 / v.len() as f64);
+                    assert_eq!(means[index].map(f64::to_bits), mean.map(f64::to_bits));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn window_one() {
+        let array = [Some(3_i64), None, Some(-1)]
+            .into_iter()
+            .collect::<Int64Array<true>>();
+        assert_eq!(
+            array.rolling_sum(1).into_iter().collect::<Vec<_>>(),
+            [Some(3), None, Some(-1)]
+        );
+        assert_eq!(
+            array.rolling_max(1).into_iter().collect::<Vec<_>>(),
+            [Some(3), None, Some(-1)]
+        );
+    }
+
+    #[test]
+    fn window_larger_than_len() {
+        let array = [1_i64, 2, 3].into_iter().collect::<Int64Array>();
+        assert_eq!(
+            array.rolling_sum(4).into_iter().collect::<Vec<_>>(),
+            [None, None, None]
+        );
+        assert_eq!(
+            array.rolling_min_with(4, 1).into_iter().collect::<Vec<_>>(),
+            [Some(1), Some(1), Some(1)]
+        );
+        assert_eq!(
+            array
+                .rolling_mean_with(4, 2)
+                .into_iter()
+                .map(|mean| mean.map(f64::to_bits))
+                .collect::<Vec<_>>(),
+            [None, Some(1.5_f64.to_bits()), Some(2.0_f64.to_bits())]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "window must be positive")]
+    fn zero_window() {
+        let _ = [1_i64].into_iter().collect::<Int64Array>().rolling_sum(0);
+    }
+}
@@ -0,0 +1,274 @@
+//! Array statistics.
+
+use super::{BooleanValuesRef, Numeric, ValuesRef};
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray},
+    bitmap::Bitmap,
+    buffer::BufferType,
+    validity::Validity,
+    Length,
+};
+use std::{collections::HashSet, sync::OnceLock};
+
+/// Statistics of the values in an array.
+///
+/// These can be used to skip work, e.g. a filter with a predicate that is
+/// outside the range of `min` and `max` does not have to look at the values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Statistics<T> {
+    /// The minimum valid value, or `None` if there are no valid values.
+    pub min: Option<T>,
+    /// The maximum valid value, or `None` if there are no valid values.
+    pub max: Option<T>,
+    /// The number of null values.
+    pub null_count: usize,
+    /// The number of distinct valid values, if known.
+    pub distinct_count: Option<usize>,
+}
+
+/// Computation of [`Statistics`].
+pub trait ComputeStatistics {
+    /// The type of the values.
+    type Item;
+
+    /// Returns the statistics of the values in this array.
+    fn compute_statistics(&self) -> Statistics<Self::Item>;
+}
+
+/// Returns the number of nulls given an optional validity bitmap.
+fn null_count<Buffer: BufferType>(validity: Option<&Bitmap<Buffer>>) -> usize {
+    validity.map_or(0, Bitmap::count_zeros)
+}
+
+impl<T: Numeric, const NULLABLE: bool, Buffer: BufferType> ComputeStatistics
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    Self: ValuesRef<Item = T>,
+{
+    type Item = T;
+
+    /// Returns the statistics of the values in this array.
+    ///
+    /// The minimum and maximum ignore NaN values, see [`Self::min`] and
+    /// [`Self::max`]. Distinct values are compared by their bit pattern,
+    /// consistent with [`Self::unique`].
+    fn compute_statistics(&self) -> Statistics<T> {
+        let distinct = self
+            .valid_values()
+            .map(Numeric::hash_bits)
+            .collect::<HashSet<_>>();
+        Statistics {
+            min: self.min(),
+            max: self.max(),
+            null_count: null_count(self.validity_ref()),
+            distinct_count: Some(distinct.len()),
+        }
+    }
+}
+
+/// Returns the statistics of a boolean array given the number of valid `true`
+/// and `false` values.
+fn boolean_statistics(true_count: usize, false_count: usize, nulls: usize) -> Statistics<bool> {
+    Statistics {
+        min: (false_count != 0)
+            .then_some(false)
+            .or((true_count != 0).then_some(true)),
+        max: (true_count != 0)
+            .then_some(true)
+            .or((false_count != 0).then_some(false)),
+        null_count: nulls,
+        distinct_count: Some(usize::from(true_count != 0) + usize::from(false_count != 0)),
+    }
+}
+
+impl<Buffer: BufferType> ComputeStatistics for BooleanArray<false, Buffer> {
+    type Item = bool;
+
+    fn compute_statistics(&self) -> Statistics<bool> {
+        boolean_statistics(self.true_count(), self.false_count(), 0)
+    }
+}
+
+impl<Buffer: BufferType> ComputeStatistics for BooleanArray<true, Buffer> {
+    type Item = bool;
+
+    fn compute_statistics(&self) -> Statistics<bool> {
+        boolean_statistics(
+            self.true_count(),
+            self.false_count(),
+            null_count(self.validity_ref()),
+        )
+    }
+}
+
+/// An array that caches its [`Statistics`].
+///
+/// The statistics are computed on first access and cleared when the array is
+/// mutated, e.g. by extending it or via [`StatisticsArray::array_mut`].
+#[derive(Clone, Debug, Default)]
+pub struct StatisticsArray<A: ComputeStatistics> {
+    /// The wrapped array.
+    array: A,
+    /// The cached statistics of the array.
+    statistics: OnceLock<Statistics<A::Item>>,
+}
+
+impl<A: ComputeStatistics> StatisticsArray<A> {
+    /// Returns a new wrapper around `array`, without computed statistics.
+    pub fn new(array: A) -> Self {
+        Self {
+            array,
+            statistics: OnceLock::new(),
+        }
+    }
+
+    /// Returns the statistics of the array, computing them if they are not
+    /// cached.
+    pub fn statistics(&self) -> &Statistics<A::Item> {
+        self.statistics
+            .get_or_init(|| self.array.compute_statistics())
+    }
+
+    /// Returns the cached statistics of the array, without computing them.
+    pub fn cached_statistics(&self) -> Option<&Statistics<A::Item>> {
+        self.statistics.get()
+    }
+
+    /// Returns a reference to the wrapped array.
+    pub fn array(&self) -> &A {
+        &self.array
+    }
+
+    /// Returns a mutable reference to the wrapped array.
+    ///
+    /// This clears the cached statistics.
+    pub fn array_mut(&mut self) -> &mut A {
+        self.statistics.take();
+        &mut self.array
+    }
+
+    /// Returns the wrapped array.
+    pub fn into_inner(self) -> A {
+        self.array
+    }
+}
+
+impl<A: ComputeStatistics> From<A> for StatisticsArray<A> {
+    fn from(array: A) -> Self {
+        Self::new(array)
+    }
+}
+
+impl<A: ComputeStatistics> AsRef<A> for StatisticsArray<A> {
+    fn as_ref(&self) -> &A {
+        &self.array
+    }
+}
+
+impl<A: ComputeStatistics + Length> Length for StatisticsArray<A> {
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+}
+
+impl<A: ComputeStatistics + Extend<U>, U> Extend<U> for StatisticsArray<A> {
+    fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        self.array_mut().extend(iter);
+    }
+}
+
+impl<A: ComputeStatistics + FromIterator<U>, U> FromIterator<U> for StatisticsArray<A> {
+    fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float64Array, Int32Array};
+
+    #[test]
+    fn primitive() {
+        let array = [Some(3), None, Some(-1), Some(3), None, Some(7)]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            array.compute_statistics(),
+            Statistics {
+                min: Some(-1),
+                max: Some(7),
+                null_count: 2,
+                distinct_count: Some(3),
+            }
+        );
+
+        let floats = [1.5, f64::NAN, -0.5].into_iter().collect::<Float64Array>();
+        let statistics = floats.compute_statistics();
+        assert_eq!(statistics.min, Some(-0.5));
+        assert_eq!(statistics.max, Some(1.5));
+        assert_eq!(statistics.distinct_count, Some(3));
+    }
+
+    #[test]
+    fn boolean() {
+        let array = [Some(true), None, Some(true)]
+            .into_iter()
+            .collect::<BooleanArray<true>>();
+        assert_eq!(
+            array.compute_statistics(),
+            Statistics {
+                min: Some(true),
+                max: Some(true),
+                null_count: 1,
+                distinct_count: Some(1),
+            }
+        );
+        let values = [false, true].into_iter().collect::<BooleanArray>();
+        let statistics = values.compute_statistics();
+        assert_eq!((statistics.min, statistics.max), (Some(false), Some(true)));
+    }
+
+    #[test]
+    fn all_null() {
+        let array = [None::<i32>, None]
+            .into_iter()
+            .collect::<Int32Array<true>>();
+        assert_eq!(
+            array.compute_statistics(),
+            Statistics {
+                min: None,
+                max: None,
+                null_count: 2,
+                distinct_count: Some(0),
+            }
+        );
+        let booleans = [None::<bool>].into_iter().collect::<BooleanArray<true>>();
+        assert_eq!(booleans.compute_statistics().min, None);
+        assert_eq!(booleans.compute_statistics().max, None);
+    }
+
+    #[test]
+    fn invalidation() {
+        let mut array = [1, 2, 3]
+            .into_iter()
+            .collect::<StatisticsArray<Int32Array>>();
+        assert!(array.cached_statistics().is_none());
+        assert_eq!(array.statistics().max, Some(3));
+        assert!(array.cached_statistics().is_some());
+
+        array.extend([10]);
+        assert!(array.cached_statistics().is_none());
+        assert_eq!(array.statistics().max, Some(10));
+        assert_eq!(array.len(), 4);
+
+        let mut nullable = StatisticsArray::new(Int32Array::<true>::default());
+        assert_eq!(nullable.statistics().null_count, 0);
+        nullable.extend([None, Some(-4)]);
+        assert_eq!(nullable.statistics().null_count, 1);
+        assert_eq!(nullable.statistics().min, Some(-4));
+        nullable.array_mut();
+        assert!(nullable.cached_statistics().is_none());
+    }
+}
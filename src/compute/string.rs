@@ -0,0 +1,305 @@
+//! Kernels for string arrays.
+//!
+//! Characters are Unicode scalar values, i.e. a combining character counts as
+//! a separate character.
+
+use super::{validity_bits, BinaryValuesRef};
+use crate::{
+    array::{BooleanArray, Int32Array, StringArray},
+    buffer::BufferType,
+    offset::{OffsetElement, Offsets},
+    validity::Validity,
+    Length,
+};
+use std::str;
+
+/// Returns an iterator over the strings of a string array. This includes the
+/// (usually empty) strings stored in null slots.
+pub(super) fn strs<OffsetItem: OffsetElement, Buffer: BufferType, const NULLABLE: bool>(
+    array: &StringArray<NULLABLE, OffsetItem, Buffer>,
+) -> impl Iterator<Item = &str>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    StringArray<NULLABLE, OffsetItem, Buffer>: BinaryValuesRef<OffsetItem = OffsetItem>,
+{
+    array.byte_slices().map(|bytes| {
+        // Safety:
+        // - The values of a string array are valid UTF-8.
+        unsafe { str::from_utf8_unchecked(bytes) }
+    })
+}
+
+/// Returns the strings of a nullable string array, with `None` for nulls.
+pub(super) fn nullable_strs<OffsetItem: OffsetElement, Buffer: BufferType>(
+    array: &StringArray<true, OffsetItem, Buffer>,
+) -> impl Iterator<Item = Option<&str>> {
+    strs(array)
+        .zip(validity_bits(array.validity_ref(), array.len()))
+        .map(|(value, valid)| valid.then_some(value))
+}
+
+/// Returns the length of `value` in bytes.
+fn byte_length(value: &str) -> i32 {
+    i32::try_from(value.len()).expect("length fits in i32")
+}
+
+/// Returns the length of `value` in characters.
+fn char_length(value: &str) -> i32 {
+    i32::try_from(value.chars().count()).expect("length fits in i32")
+}
+
+/// Returns the substring of `value` with at most `len` characters, starting at
+/// character `start`.
+fn substring(value: &str, start: usize, len: usize) -> &str {
+    let mut boundaries = value
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain([value.len()])
+        .skip(start);
+    let begin = boundaries.next().unwrap_or(value.len());
+    let end = if len == 0 {
+        begin
+    } else {
+        boundaries.nth(len - 1).unwrap_or(value.len())
+    };
+    &value[begin..end]
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<false, OffsetItem, Buffer> {
+    /// Returns the lengths of the strings in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a length does not fit in an [`i32`].
+    #[must_use]
+    pub fn length(&self) -> Int32Array {
+        strs(self).map(byte_length).collect()
+    }
+
+    /// Returns the lengths of the strings in characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a length does not fit in an [`i32`].
+    #[must_use]
+    pub fn char_length(&self) -> Int32Array {
+        strs(self).map(char_length).collect()
+    }
+
+    /// Returns whether the strings start with `prefix`.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> BooleanArray {
+        strs(self).map(|value| value.starts_with(prefix)).collect()
+    }
+
+    /// Returns whether the strings end with `suffix`.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> BooleanArray {
+        strs(self).map(|value| value.ends_with(suffix)).collect()
+    }
+
+    /// Returns whether the strings contain `pattern`.
+    #[must_use]
+    pub fn contains(&self, pattern: &str) -> BooleanArray {
+        strs(self).map(|value| value.contains(pattern)).collect()
+    }
+
+    /// Returns the substrings with at most `len` characters, starting at
+    /// character `start`. Strings with fewer than `start` characters result in
+    /// empty strings.
+    #[must_use]
+    pub fn substring(&self, start: usize, len: usize) -> Self
+    where
+        Self: for<'a> FromIterator<&'a str>,
+    {
+        strs(self)
+            .map(|value| substring(value, start, len))
+            .collect()
+    }
+
+    /// Returns the lowercase equivalent of the strings.
+    #[must_use]
+    pub fn to_lowercase(&self) -> Self
+    where
+        Self: FromIterator<String>,
+    {
+        strs(self).map(str::to_lowercase).collect()
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> StringArray<true, OffsetItem, Buffer> {
+    /// Returns the lengths of the strings in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a length does not fit in an [`i32`].
+    #[must_use]
+    pub fn length(&self) -> Int32Array<true> {
+        nullable_strs(self)
+            .map(|value| value.map(byte_length))
+            .collect()
+    }
+
+    /// Returns the lengths of the strings in characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a length does not fit in an [`i32`].
+    #[must_use]
+    pub fn char_length(&self) -> Int32Array<true> {
+        nullable_strs(self)
+            .map(|value| value.map(char_length))
+            .collect()
+    }
+
+    /// Returns whether the strings start with `prefix`.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> BooleanArray<true> {
+        nullable_strs(self)
+            .map(|value| value.map(|item| item.starts_with(prefix)))
+            .collect()
+    }
+
+    /// Returns whether the strings end with `suffix`.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> BooleanArray<true> {
+        nullable_strs(self)
+            .map(|value| value.map(|item| item.ends_with(suffix)))
+            .collect()
+    }
+
+    /// Returns whether the strings contain `pattern`.
+    #[must_use]
+    pub fn contains(&self, pattern: &str) -> BooleanArray<true> {
+        nullable_strs(self)
+            .map(|value| value.map(|item| item.contains(pattern)))
+            .collect()
+    }
+
+    /// Returns the substrings with at most `len` characters, starting at
+    /// character `start`. Strings with fewer than `start` characters result in
+    /// empty strings.
+    #[must_use]
+    pub fn substring(&self, start: usize, len: usize) -> Self
+    where
+        Self: for<'a> FromIterator<Option<&'a str>>,
+    {
+        nullable_strs(self)
+            .map(|value| value.map(|item| substring(item, start, len)))
+            .collect()
+    }
+
+    /// Returns the lowercase equivalent of the strings.
+    #[must_use]
+    pub fn to_lowercase(&self) -> Self
+    where
+        Self: FromIterator<Option<String>>,
+    {
+        nullable_strs(self)
+            .map(|value| value.map(str::to_lowercase))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lengths() {
+        let array = ["", "abc", "h\u{e9}", "e\u{301}", "\u{1f980}"]
+            .into_iter()
+            .collect::<StringArray>();
+        assert_eq!(array.length().as_slice(), [0, 3, 3, 3, 4]);
+        assert_eq!(array.char_length().as_slice(), [0, 3, 2, 2, 1]);
+
+        let nullable = [Some("\u{1f980}\u{1f980}"), None, Some("")]
+            .into_iter()
+            .collect::<StringArray<true>>();
+        assert_eq!(
+            nullable.length().into_iter().collect::<Vec<_>>(),
+            [Some(8), None, Some(0)]
+        );
+        assert_eq!(
+            nullable.char_length().into_iter().collect::<Vec<_>>(),
+            [Some(2), None, Some(0)]
+        );
+    }
+
+    #[test]
+    fn predicates() {
+        let array = [
+            Some("\u{1f980} crab"),
+            None,
+            Some(""),
+            Some("crab \u{1f980}"),
+        ]
+        .into_iter()
+        .collect::<StringArray<true>>();
+        assert_eq!(
+            array
+                .starts_with("\u{1f980}")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(true), None, Some(false), Some(false)]
+        );
+        assert_eq!(
+            array.ends_with("\u{1f980}").into_iter().collect::<Vec<_>>(),
+            [Some(false), None, Some(false), Some(true)]
+        );
+        assert_eq!(
+            array.contains("").into_iter().collect::<Vec<_>>(),
+            [Some(true), None, Some(true), Some(true)]
+        );
+
+        let values = ["abc", "", "bcd"].into_iter().collect::<StringArray>();
+        assert_eq!(
+            values.contains("bc").into_iter().collect::<Vec<_>>(),
+            [true, false, true]
+        );
+    }
+
+    #[test]
+    fn substrings() {
+        assert_eq!(substring("abc", 0, 0), "");
+        assert_eq!(substring("abc", 1, 5), "bc");
+        assert_eq!(substring("abc", 3, 1), "");
+        assert_eq!(substring("abc", 4, 1), "");
+        assert_eq!(substring("\u{1f980}e\u{301}x", 1, 2), "e\u{301}");
+
+        let array = [
+            Some("h\u{e9}llo"),
+            None,
+            Some(""),
+            Some("\u{1f980}\u{1f99e}"),
+        ]
+        .into_iter()
+        .collect::<StringArray<true>>();
+        assert_eq!(
+            array.substring(1, 2).into_iter().collect::<Vec<_>>(),
+            [
+                Some("\u{e9}l".to_owned()),
+                None,
+                Some(String::new()),
+                Some("\u{1f99e}".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn to_lowercase() {
+        let array = ["ABC", "\u{c9}t\u{c9}", "", "\u{1f980}"]
+            .into_iter()
+            .collect::<StringArray>();
+        assert_eq!(
+            array.to_lowercase().into_iter().collect::<Vec<_>>(),
+            ["abc", "\u{e9}t\u{e9}", "", "\u{1f980}"]
+        );
+
+        let nullable = [None, Some("A")].into_iter().collect::<StringArray<true>>();
+        assert_eq!(
+            nullable.to_lowercase().into_iter().collect::<Vec<_>>(),
+            [None, Some("a".to_owned())]
+        );
+    }
+}
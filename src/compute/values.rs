@@ -0,0 +1,599 @@
+//! Access to the values and validity of arrays.
+
+use crate::{
+    array::{
+        BooleanArray, Extended, FixedSizePrimitiveArray, StringArray, VariableSizeBinaryArray,
+    },
+    bitmap::{mask, Bitmap, BitmapRuns, BitmapWords},
+    buffer::{Buffer, BufferType},
+    offset::{OffsetElement, Offsets},
+    validity::Validity,
+    FixedSize,
+};
+use std::{mem, slice};
+
+/// Immutable access to the values and validity of an array.
+///
+/// This is implemented for both the nullable and the non-nullable variant of
+/// [`FixedSizePrimitiveArray`], which allows kernels to be written once for
+/// both variants.
+pub trait ValuesRef {
+    /// The type of the values.
+    type Item: FixedSize;
+
+    /// The buffer type of the validity bitmap.
+    type Buffer: BufferType;
+
+    /// Returns a slice with all the values. This includes the (unspecified)
+    /// values stored in null slots.
+    fn values_ref(&self) -> &[Self::Item];
+
+    /// Returns a reference to the validity bitmap, or `None` when there is no
+    /// validity bitmap because all values are valid.
+    fn validity_ref(&self) -> Option<&Bitmap<Self::Buffer>>;
+
+    /// Returns an iterator over the values in batches, walking the validity
+    /// bitmap a word (64 values) at a time.
+    ///
+    /// Consecutive words without nulls are merged into a single
+    /// [`ValidityBatch::Valid`] batch, so the values of mostly-valid arrays can
+    /// be read directly from the values slice.
+    fn iter_batched(&self) -> Batches<'_, Self::Item> {
+        Batches::new(self.values_ref(), self.validity_ref().map(Bitmap::words))
+    }
+
+    /// Returns an iterator over the valid values, skipping null slots.
+    fn valid_values(&self) -> ValidValues<'_, Self::Item> {
+        ValidValues::new(self.iter_batched())
+    }
+
+    /// Returns an iterator over the maximal slices of valid values, together
+    /// with the index of the first value of each slice.
+    fn valid_slices(&self) -> ValidSlices<'_, Self::Item> {
+        ValidSlices::new(self.values_ref(), self.validity_ref().map(Bitmap::runs))
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> ValuesRef for FixedSizePrimitiveArray<T, false, Buffer> {
+    type Item = T;
+    type Buffer = Buffer;
+
+    fn values_ref(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    fn validity_ref(&self) -> Option<&Bitmap<Buffer>> {
+        None
+    }
+}
+
+impl<T: FixedSize, Buffer: BufferType> ValuesRef for FixedSizePrimitiveArray<T, true, Buffer> {
+    type Item = T;
+    type Buffer = Buffer;
+
+    fn values_ref(&self) -> &[T] {
+        self.0.data.as_slice()
+    }
+
+    fn validity_ref(&self) -> Option<&Bitmap<Buffer>> {
+        Some(&self.0.validity)
+    }
+}
+
+impl<A: ValuesRef> ValuesRef for Extended<A> {
+    type Item = <A as ValuesRef>::Item;
+    type Buffer = <A as ValuesRef>::Buffer;
+
+    fn values_ref(&self) -> &[Self::Item] {
+        self.inner().values_ref()
+    }
+
+    fn validity_ref(&self) -> Option<&Bitmap<Self::Buffer>> {
+        self.inner().validity_ref()
+    }
+}
+
+/// Immutable access to the bits and validity of a boolean array.
+///
+/// This is implemented for both the nullable and the non-nullable variant of
+/// [`BooleanArray`].
+pub trait BooleanValuesRef {
+    /// The buffer type of the bitmaps.
+    type Buffer: BufferType;
+
+    /// Returns a reference to the bitmap with the values. This includes the
+    /// (unspecified) values stored in null slots.
+    fn bits_ref(&self) -> &Bitmap<Self::Buffer>;
+
+    /// Returns a reference to the validity bitmap, or `None` when there is no
+    /// validity bitmap because all values are valid.
+    fn validity_ref(&self) -> Option<&Bitmap<Self::Buffer>>;
+}
+
+impl<Buffer: BufferType> BooleanValuesRef for BooleanArray<false, Buffer> {
+    type Buffer = Buffer;
+
+    fn bits_ref(&self) -> &Bitmap<Buffer> {
+        &self.0
+    }
+
+    fn validity_ref(&self) -> Option<&Bitmap<Buffer>> {
+        None
+    }
+}
+
+impl<Buffer: BufferType> BooleanValuesRef for BooleanArray<true, Buffer> {
+    type Buffer = Buffer;
+
+    fn bits_ref(&self) -> &Bitmap<Buffer> {
+        &self.0.data
+    }
+
+    fn validity_ref(&self) -> Option<&Bitmap<Buffer>> {
+        Some(&self.0.validity)
+    }
+}
+
+/// Immutable access to the bytes, offsets and validity of a variable-size
+/// binary array.
+///
+/// This is implemented for both the nullable and the non-nullable variant of
+/// [`VariableSizeBinaryArray`] and [`StringArray`].
+pub trait BinaryValuesRef {
+    /// The type of the offset values.
+    type OffsetItem: OffsetElement;
+
+    /// The buffer type of the validity bitmap.
+    type Buffer: BufferType;
+
+    /// Returns a slice with the concatenated bytes of all values.
+    fn bytes_ref(&self) -> &[u8];
+
+    /// Returns a slice with the offsets. This has one more element than the
+    /// number of values.
+    fn offsets_ref(&self) -> &[Self::OffsetItem];
+
+    /// Returns a reference to the validity bitmap, or `None` when there is no
+    /// validity bitmap because all values are valid.
+    fn validity_ref(&self) -> Option<&Bitmap<Self::Buffer>>;
+
+    /// Returns an iterator over the bytes of all values. This includes the
+    /// (usually empty) values stored in null slots.
+    fn byte_slices(&self) -> ByteSlices<'_, Self::OffsetItem> {
+        ByteSlices {
+            bytes: self.bytes_ref(),
+            offsets: self.offsets_ref().windows(2),
+        }
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> BinaryValuesRef
+    for VariableSizeBinaryArray<false, OffsetItem, Buffer>
+{
+    type OffsetItem = OffsetItem;
+    type Buffer = Buffer;
+
+    fn bytes_ref(&self) -> &[u8] {
+        self.0.data.0.as_slice()
+    }
+
+    fn offsets_ref(&self) -> &[OffsetItem] {
+        self.0.offsets.as_slice()
+    }
+
+    fn validity_ref(&self) -> Option<&Bitmap<Buffer>> {
+        None
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> BinaryValuesRef
+    for VariableSizeBinaryArray<true, OffsetItem, Buffer>
+{
+    type OffsetItem = OffsetItem;
+    type Buffer = Buffer;
+
+    fn bytes_ref(&self) -> &[u8] {
+        self.0.data.0.as_slice()
+    }
+
+    fn offsets_ref(&self) -> &[OffsetItem] {
+        self.0.offsets.data.as_slice()
+    }
+
+    fn validity_ref(&self) -> Option<&Bitmap<Buffer>> {
+        Some(&self.0.offsets.validity)
+    }
+}
+
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> BinaryValuesRef
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>:
+        BinaryValuesRef<OffsetItem = OffsetItem, Buffer = Buffer>,
+{
+    type OffsetItem = OffsetItem;
+    type Buffer = Buffer;
+
+    fn bytes_ref(&self) -> &[u8] {
+        self.0.bytes_ref()
+    }
+
+    fn offsets_ref(&self) -> &[OffsetItem] {
+        self.0.offsets_ref()
+    }
+
+    fn validity_ref(&self) -> Option<&Bitmap<Buffer>> {
+        self.0.validity_ref()
+    }
+}
+
+/// An iterator over the bytes of the values of a variable-size binary array.
+#[derive(Clone, Debug)]
+pub struct ByteSlices<'a, OffsetItem> {
+    /// The concatenated bytes of all values.
+    bytes: &'a [u8],
+    /// The windows over the offsets.
+    offsets: slice::Windows<'a, OffsetItem>,
+}
+
+impl<'a, OffsetItem: OffsetElement> Iterator for ByteSlices<'a, OffsetItem> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.offsets.nth(n).map(|window| {
+            let start = window[0].try_into().expect("offset value out of range");
+            let end = window[1].try_into().expect("offset value out of range");
+            &self.bytes[start..end]
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<OffsetItem: OffsetElement> ExactSizeIterator for ByteSlices<'_, OffsetItem> {}
+
+/// Returns an iterator over the first `len` bits of an optional validity
+/// bitmap, yielding `true` for all elements if there is no bitmap.
+pub(crate) fn validity_bits<Buffer: BufferType>(
+    validity: Option<&Bitmap<Buffer>>,
+    len: usize,
+) -> impl Iterator<Item = bool> + '_ {
+    let mut bits = validity.map(Bitmap::iter);
+    (0..len).map(move |_| {
+        bits.as_mut()
+            .map_or(true, |iter| iter.next().unwrap_or_default())
+    })
+}
+
+/// Returns an iterator over the words of the first `len` bits of an optional
+/// validity bitmap, see [`Bitmap::words`]. Yields words with all bits set if
+/// there is no bitmap.
+pub(crate) fn validity_words<Buffer: BufferType>(
+    validity: Option<&Bitmap<Buffer>>,
+    len: usize,
+) -> impl Iterator<Item = u64> + '_ {
+    let mut words = validity.map(Bitmap::words);
+    (0..len / 64 + usize::from(len % 64 != 0)).map(move |_| {
+        words
+            .as_mut()
+            .map_or(u64::MAX, |iter| iter.next().map_or(0, |(word, _)| word))
+    })
+}
+
+/// A batch of values yielded by [`ValuesRef::iter_batched`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidityBatch<'a, T> {
+    /// Values that are all valid.
+    Valid(&'a [T]),
+    /// At most 64 values with their validity bits, where bit `i` of the word
+    /// (LSB numbering) is the validity of value `i` of the slice.
+    Mixed(u64, &'a [T]),
+}
+
+/// An iterator over the values of an array in [`ValidityBatch`]es.
+#[derive(Clone, Debug)]
+pub struct Batches<'a, T> {
+    /// All the values. Set to an empty slice after returning all values as a
+    /// single batch, when there is no validity bitmap.
+    values: &'a [T],
+    /// The words of the validity bitmap, `None` if all values are valid.
+    words: Option<BitmapWords<'a>>,
+    /// The index of the first value of the next batch.
+    position: usize,
+    /// A word with nulls that ended the previous run of valid words.
+    pending: Option<(u64, usize)>,
+}
+
+impl<'a, T> Batches<'a, T> {
+    /// Returns an iterator over the `values` in batches, using the `words` of
+    /// a validity bitmap (`None` if all values are valid).
+    pub(crate) fn new(values: &'a [T], words: Option<BitmapWords<'a>>) -> Self {
+        Self {
+            values,
+            words,
+            position: 0,
+            pending: None,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Batches<'a, T> {
+    type Item = ValidityBatch<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(words) = self.words.as_mut() else {
+            let values = mem::take(&mut self.values);
+            return (!values.is_empty()).then_some(ValidityBatch::Valid(values));
+        };
+        let start = self.position;
+        let (word, bits) = self.pending.take().or_else(|| words.next())?;
+        self.position += bits;
+        if word != mask(bits) {
+            return Some(ValidityBatch::Mixed(
+                word,
+                &self.values[start..self.position],
+            ));
+        }
+        // Merge the following words without nulls into this batch.
+        for (next_word, next_bits) in words.by_ref() {
+            if next_word == mask(next_bits) {
+                self.position += next_bits;
+            } else {
+                self.pending = Some((next_word, next_bits));
+                break;
+            }
+        }
+        Some(ValidityBatch::Valid(&self.values[start..self.position]))
+    }
+}
+
+/// An iterator over the valid values of an array.
+///
+/// The values are read in [`ValidityBatch`]es: the values of valid batches
+/// are read directly from the values slice, and the values of mixed batches
+/// are selected using the set bits of their validity word.
+#[derive(Clone, Debug)]
+pub struct ValidValues<'a, T> {
+    /// The batches of values.
+    batches: Batches<'a, T>,
+    /// The values of the current valid batch.
+    current: slice::Iter<'a, T>,
+    /// The remaining validity bits and the values of the current mixed batch.
+    mixed: (u64, &'a [T]),
+}
+
+impl<'a, T> ValidValues<'a, T> {
+    /// Returns an iterator over the valid values in the given `batches`.
+    pub(crate) fn new(batches: Batches<'a, T>) -> Self {
+        Self {
+            batches,
+            current: [].iter(),
+            mixed: (0, &[]),
+        }
+    }
+}
+
+/// Returns the index of the lowest set bit of `word`, and clears it.
+fn pop_lowest_bit(word: &mut u64) -> usize {
+    let index = usize::try_from(word.trailing_zeros()).expect("bit index fits in usize");
+    *word &= *word - 1;
+    index
+}
+
+impl<T: Copy> Iterator for ValidValues<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.current.next() {
+                return Some(*value);
+            }
+            if self.mixed.0 != 0 {
+                return Some(self.mixed.1[pop_lowest_bit(&mut self.mixed.0)]);
+            }
+            match self.batches.next()? {
+                ValidityBatch::Valid(values) => self.current = values.iter(),
+                ValidityBatch::Mixed(word, values) => self.mixed = (word, values),
+            }
+        }
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let Self {
+            batches,
+            current,
+            mixed: (mut word, values),
+        } = self;
+        let mut acc = current.copied().fold(init, &mut f);
+        while word != 0 {
+            acc = f(acc, values[pop_lowest_bit(&mut word)]);
+        }
+        batches.fold(acc, |batch_acc, batch| match batch {
+            ValidityBatch::Valid(valid) => valid.iter().copied().fold(batch_acc, &mut f),
+            ValidityBatch::Mixed(mut bits, mixed) => {
+                let mut mixed_acc = batch_acc;
+                while bits != 0 {
+                    mixed_acc = f(mixed_acc, mixed[pop_lowest_bit(&mut bits)]);
+                }
+                mixed_acc
+            }
+        })
+    }
+}
+
+/// An iterator over the maximal slices of valid values of an array.
+///
+/// Yields the index of the first value of each slice, and the slice.
+#[derive(Clone, Debug)]
+pub struct ValidSlices<'a, T> {
+    /// All the values. Set to `None` after returning all values as a single
+    /// slice, when there is no validity bitmap.
+    values: Option<&'a [T]>,
+    /// The runs in the validity bitmap, `None` if all values are valid.
+    runs: Option<BitmapRuns<'a>>,
+}
+
+impl<'a, T> ValidSlices<'a, T> {
+    /// Returns an iterator over the slices of valid `values`, using the `runs`
+    /// of a validity bitmap (`None` if all values are valid).
+    pub(crate) fn new(values: &'a [T], runs: Option<BitmapRuns<'a>>) -> Self {
+        Self {
+            values: Some(values),
+            runs,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ValidSlices<'a, T> {
+    type Item = (usize, &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let values = self.values?;
+        if let Some(runs) = self.runs.as_mut() {
+            runs.find(|&(_, valid)| valid)
+                .map(|(range, _)| (range.start, &values[range]))
+        } else {
+            self.values = None;
+            (!values.is_empty()).then_some((0, values))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::BinaryArray;
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    #[test]
+    fn valid_values() {
+        let array = [1, 2, 3, 4]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u8>>();
+        assert_eq!(array.valid_values().collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+        let nullable = [None, Some(1), Some(2), None, Some(3)]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u8, true>>();
+        assert_eq!(nullable.valid_values().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(nullable.valid_values().sum::<u8>(), 6);
+
+        let all_null = [None::<u8>, None]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u8, true>>();
+        assert_eq!(all_null.valid_values().next(), None);
+    }
+
+    #[test]
+    fn iter_batched() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..500);
+            let probability = [0.5, 0.99, 1.][rng.gen_range(0..3)];
+            let input = (0..len)
+                .map(|_| rng.gen_bool(probability).then(|| rng.gen::<u32>()))
+                .collect::<Vec<_>>();
+            let nullable = input.iter().collect::<FixedSizePrimitiveArray<u32, true>>();
+
+            let mut reconstructed = Vec::with_capacity(len);
+            for batch in nullable.iter_batched() {
+                match batch {
+                    ValidityBatch::Valid(values) => {
+                        reconstructed.extend(values.iter().copied().map(Some));
+                    }
+                    ValidityBatch::Mixed(word, values) => {
+                        assert!(values.len() <= 64);
+                        assert_ne!(word, mask(values.len()));
+                        reconstructed.extend(
+                            values
+                                .iter()
+                                .enumerate()
+                                .map(|(index, value)| (word & (1 << index) != 0).then_some(*value)),
+                        );
+                    }
+                }
+            }
+            assert_eq!(
+                reconstructed,
+                nullable.clone().into_iter().collect::<Vec<_>>()
+            );
+
+            let valid = input.iter().flatten().copied().collect::<Vec<_>>();
+            assert_eq!(nullable.valid_values().collect::<Vec<_>>(), valid);
+            assert_eq!(
+                nullable.valid_values().fold(Vec::new(), |mut acc, value| {
+                    acc.push(value);
+                    acc
+                }),
+                valid
+            );
+        }
+
+        let array = (0..200).collect::<FixedSizePrimitiveArray<u8>>();
+        assert_eq!(
+            array.iter_batched().collect::<Vec<_>>(),
+            [ValidityBatch::Valid(array.values_ref())]
+        );
+        let all_valid = array
+            .iter()
+            .copied()
+            .map(Some)
+            .collect::<FixedSizePrimitiveArray<u8, true>>();
+        assert_eq!(
+            all_valid.iter_batched().collect::<Vec<_>>(),
+            [ValidityBatch::Valid(all_valid.values_ref())]
+        );
+    }
+
+    #[test]
+    fn valid_slices() {
+        let empty = FixedSizePrimitiveArray::<u8>::default();
+        assert_eq!(empty.valid_slices().next(), None);
+
+        let array = [1, 2, 3]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u8>>();
+        assert_eq!(
+            array.valid_slices().collect::<Vec<_>>(),
+            [(0, [1, 2, 3].as_slice())]
+        );
+
+        let nullable = [None, Some(1), Some(2), None, Some(3)]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u8, true>>();
+        assert_eq!(
+            nullable.valid_slices().collect::<Vec<_>>(),
+            [(1, [1, 2].as_slice()), (4, [3].as_slice())]
+        );
+    }
+
+    #[test]
+    fn byte_slices() {
+        let strings = ["a", "", "bc"].into_iter().collect::<StringArray>();
+        assert_eq!(
+            strings.byte_slices().collect::<Vec<_>>(),
+            [b"a".as_slice(), b"", b"bc"]
+        );
+        assert_eq!(strings.validity_ref(), None);
+
+        let binary = [Some(vec![1_u8, 2]), None, Some(vec![3])]
+            .into_iter()
+            .collect::<BinaryArray<true>>();
+        assert_eq!(binary.byte_slices().len(), 3);
+        assert_eq!(
+            binary.byte_slices().collect::<Vec<_>>(),
+            [[1_u8, 2].as_slice(), &[], &[3]]
+        );
+        assert!(binary.validity_ref().is_some());
+    }
+}
@@ -1,7 +1,7 @@
 //! Subtrait for fixed-size types.
 
 use crate::array::ArrayType;
-use std::{fmt::Debug, mem};
+use core::{fmt::Debug, mem};
 
 #[cfg(feature = "arrow-rs")]
 /// Module that re-exports the [`arrow_buffer::ArrowNativeType`] trait.
@@ -18,15 +18,41 @@ mod arrow_rs {
 }
 use arrow_rs::_arrow_rs_trait;
 
+#[cfg(feature = "bytemuck")]
+/// Module that re-exports the [`bytemuck::Pod`] trait.
+mod bytemuck_pod {
+    pub use bytemuck::Pod as _bytemuck_trait;
+}
+#[cfg(not(feature = "bytemuck"))]
+/// Module with empty trait to work around [RFC-3399](https://rust-lang.github.io/rfcs/3399-cfg-attribute-in-where.html).
+mod bytemuck_pod {
+    /// Empty trait.
+    pub trait Type {}
+    impl<T> Type for T {}
+    pub use Type as _bytemuck_trait;
+}
+use bytemuck_pod::_bytemuck_trait;
+
 /// Subtrait for fixed-size types.
 ///
 /// This exists to be used as trait bound where one or more of the supertraits
 /// of this trait are required, and to restrict certain implementations to
 /// fixed-size types.
 ///
+/// With the `bytemuck` feature enabled, all fixed-size types are
+/// [`bytemuck::Pod`].
+///
 /// This trait is sealed to prevent downstream implementations.
 pub trait FixedSize:
-    ArrayType<Self> + Copy + Debug + PartialEq + Sized + sealed::Sealed + 'static + _arrow_rs_trait
+    ArrayType<Self>
+    + Copy
+    + Debug
+    + PartialEq
+    + Sized
+    + sealed::Sealed
+    + 'static
+    + _arrow_rs_trait
+    + _bytemuck_trait
 {
     /// The fixed-size of this type in bytes.
     const SIZE: usize = mem::size_of::<Self>();
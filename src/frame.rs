@@ -0,0 +1,648 @@
+//! Contiguous single-buffer encoding of arrays.
+//!
+//! A frame is an array encoded in a single byte buffer, e.g. to ship it
+//! through shared memory or in a single network message. It has the following
+//! layout:
+//!
+//! | Bytes      | Content                                                   |
+//! |------------|-----------------------------------------------------------|
+//! | `0`        | The type tag of the values, see [`FrameType::TAG`].       |
+//! | `1`        | `1` for nullable arrays, `0` otherwise.                   |
+//! | `2..8`     | Reserved, zero.                                           |
+//! | `8..16`    | The number of items, as a `u64`.                          |
+//! | validity   | Nullable arrays only: the validity bits, zero-padded to a multiple of 8 bytes. |
+//! | data       | The values, bit-packed for boolean arrays.                |
+//!
+//! All integers and values are stored in little-endian byte order, regardless
+//! of the byte order of the target. Bits are stored LSB-first.
+//!
+//! Decoding into arrays with a [`VecBuffer`] copies the buffers. Decoding
+//! into arrays with a [`SliceBuffer`] views the buffers of the frame without
+//! copies, which requires a little-endian target and a data buffer that is
+//! aligned for the values.
+
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray},
+    bitmap::{bytes_for_bits, Bitmap},
+    buffer::{Buffer, BufferMut, BufferType, SliceBuffer, VecBuffer},
+    nullable::Nullable,
+    FixedSize, Length,
+};
+use alloc::{vec, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    mem, slice,
+};
+
+/// The number of bytes of the header of a frame.
+pub const HEADER_LEN: usize = 16;
+
+/// The type tag of boolean arrays.
+const BOOL_TAG: u8 = 1;
+
+/// Types of the values of arrays that can be encoded in a frame.
+pub trait FrameType: FixedSize + Default {
+    /// The type tag of arrays with values of this type.
+    const TAG: u8;
+
+    /// Converts a value from the native byte order to little endian, and
+    /// vice versa.
+    #[must_use]
+    fn to_le(self) -> Self;
+}
+
+/// Implements [`FrameType`] for integer types.
+macro_rules! impl_frame_type_int {
+    ($($ty:ty = $tag:literal),+) => {
+        $(
+            impl FrameType for $ty {
+                const TAG: u8 = $tag;
+
+                fn to_le(self) -> Self {
+                    <$ty>::to_le(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_frame_type_int!(
+    u8 = 2,
+    u16 = 3,
+    u32 = 4,
+    u64 = 5,
+    i8 = 7,
+    i16 = 8,
+    i32 = 9,
+    i64 = 10,
+    i128 = 11
+);
+
+#[cfg(not(feature = "arrow-rs"))]
+impl_frame_type_int!(u128 = 6);
+
+/// Implements [`FrameType`] for floating point types.
+macro_rules! impl_frame_type_float {
+    ($($ty:ty = $tag:literal),+) => {
+        $(
+            impl FrameType for $ty {
+                const TAG: u8 = $tag;
+
+                fn to_le(self) -> Self {
+                    <$ty>::from_bits(FrameType::to_le(self.to_bits()))
+                }
+            }
+        )+
+    };
+}
+
+impl_frame_type_float!(f32 = 12, f64 = 13);
+
+/// The error returned when a frame can't be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The frame has fewer bytes than required by its header.
+    Truncated {
+        /// The number of bytes required.
+        required: usize,
+        /// The number of bytes in the frame.
+        actual: usize,
+    },
+    /// The frame has more bytes than required by its header.
+    TrailingBytes {
+        /// The number of bytes required.
+        required: usize,
+        /// The number of bytes in the frame.
+        actual: usize,
+    },
+    /// The type tag of the frame does not match the type of the array.
+    TypeTag {
+        /// The type tag of the array.
+        expected: u8,
+        /// The type tag of the frame.
+        actual: u8,
+    },
+    /// The nullability of the frame does not match the nullability of the
+    /// array.
+    Nullability {
+        /// The nullability of the array.
+        expected: bool,
+    },
+    /// The nullability flag or the reserved bytes of the header are invalid.
+    Header,
+    /// The number of items of the frame does not fit in a `usize`.
+    Overflow,
+    /// The data buffer of the frame can't be viewed: it is not aligned for
+    /// the values, or the target is not little endian.
+    Unaligned,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Truncated { required, actual } => write!(
+                f,
+                "truncated frame: required {required} bytes, got {actual}"
+            ),
+            Self::TrailingBytes { required, actual } => write!(
+                f,
+                "trailing bytes in frame: required {required} bytes, got {actual}"
+            ),
+            Self::TypeTag { expected, actual } => {
+                write!(f, "type tag mismatch: expected {expected}, got {actual}")
+            }
+            Self::Nullability { expected } => {
+                write!(f, "nullability mismatch: expected nullable {expected}")
+            }
+            Self::Header => write!(f, "invalid frame header"),
+            Self::Overflow => write!(f, "frame length overflows usize"),
+            Self::Unaligned => write!(f, "frame data can't be viewed without copies"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Encoding of arrays in a frame.
+pub trait EncodeFrame {
+    /// Returns a frame with the items of this array.
+    ///
+    /// See the [module documentation](self) for the layout.
+    fn to_frame(&self) -> Vec<u8>;
+}
+
+/// Decoding of arrays from a frame.
+pub trait DecodeFrame<'a>: Sized {
+    /// Returns the array encoded in the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DecodeError`] when the header of the frame does not match
+    /// this array type, or when the number of bytes of the frame does not
+    /// match its header.
+    fn from_frame(bytes: &'a [u8]) -> Result<Self, DecodeError>;
+}
+
+/// Returns the number of bytes of the validity section of a frame with `len`
+/// items, or `None` if it overflows.
+fn validity_len(len: usize) -> Option<usize> {
+    bytes_for_bits(len)
+        .checked_add(7)
+        .map(|bytes| bytes / 8 * 8)
+}
+
+/// Appends the bits of a bitmap to `bytes`, starting at the first bit of a
+/// new byte.
+fn write_bitmap<Buffer: BufferType>(bytes: &mut Vec<u8>, bitmap: &Bitmap<Buffer>) {
+    for (word, bits) in bitmap.words() {
+        bytes.extend_from_slice(&word.to_le_bytes()[..bytes_for_bits(bits)]);
+    }
+}
+
+/// Returns a frame with the header, the validity bitmap of nullable arrays,
+/// and the data written by `data`.
+fn encode<Buffer: BufferType>(
+    tag: u8,
+    len: usize,
+    validity: Option<&Bitmap<Buffer>>,
+    data_len: usize,
+    data: impl FnOnce(&mut Vec<u8>),
+) -> Vec<u8> {
+    let validity_bytes = validity.map_or(0, |_| {
+        validity_len(len).expect("validity length of an array should not overflow")
+    });
+    let mut bytes = Vec::with_capacity(HEADER_LEN + validity_bytes + data_len);
+    bytes.push(tag);
+    bytes.push(u8::from(validity.is_some()));
+    bytes.resize(8, 0);
+    bytes.extend_from_slice(
+        &u64::try_from(len)
+            .expect("length should fit in a u64")
+            .to_le_bytes(),
+    );
+    if let Some(bitmap) = validity {
+        write_bitmap(&mut bytes, bitmap);
+        bytes.resize(HEADER_LEN + validity_bytes, 0);
+    }
+    data(&mut bytes);
+    bytes
+}
+
+/// Appends the values to `bytes` in little-endian byte order.
+fn write_values<T: FrameType>(bytes: &mut Vec<u8>, values: &[T]) {
+    if cfg!(target_endian = "little") {
+        bytes.extend_from_slice(values.as_bytes());
+    } else {
+        for &value in values {
+            bytes.extend_from_slice([value.to_le()].as_bytes());
+        }
+    }
+}
+
+/// The sections of a frame.
+struct Sections<'a> {
+    /// The number of items.
+    len: usize,
+    /// The validity bits of nullable arrays.
+    validity: Option<&'a [u8]>,
+    /// The data.
+    data: &'a [u8],
+}
+
+/// Returns the sections of a frame, checking the header and the number of
+/// bytes.
+///
+/// `data_len` returns the number of bytes of the data of `len` items.
+fn decode(
+    bytes: &[u8],
+    tag: u8,
+    nullable: bool,
+    data_len: impl FnOnce(usize) -> Option<usize>,
+) -> Result<Sections<'_>, DecodeError> {
+    let header = bytes.get(..HEADER_LEN).ok_or(DecodeError::Truncated {
+        required: HEADER_LEN,
+        actual: bytes.len(),
+    })?;
+    if header[0] != tag {
+        return Err(DecodeError::TypeTag {
+            expected: tag,
+            actual: header[0],
+        });
+    }
+    if header[1] > 1 || header[2..8].iter().any(|&byte| byte != 0) {
+        return Err(DecodeError::Header);
+    }
+    if (header[1] == 1) != nullable {
+        return Err(DecodeError::Nullability { expected: nullable });
+    }
+    let mut len_bytes = [0; 8];
+    len_bytes.copy_from_slice(&header[8..]);
+    let len = usize::try_from(u64::from_le_bytes(len_bytes)).map_err(|_| DecodeError::Overflow)?;
+    let validity_bytes = if nullable {
+        validity_len(len).ok_or(DecodeError::Overflow)?
+    } else {
+        0
+    };
+    let data_bytes = data_len(len).ok_or(DecodeError::Overflow)?;
+    let required = HEADER_LEN
+        .checked_add(validity_bytes)
+        .and_then(|header_bytes| header_bytes.checked_add(data_bytes))
+        .ok_or(DecodeError::Overflow)?;
+    match bytes.len() {
+        actual if actual < required => Err(DecodeError::Truncated { required, actual }),
+        actual if actual > required => Err(DecodeError::TrailingBytes { required, actual }),
+        _ => Ok(Sections {
+            len,
+            validity: nullable.then(|| &bytes[HEADER_LEN..HEADER_LEN + bytes_for_bits(len)]),
+            data: &bytes[HEADER_LEN + validity_bytes..],
+        }),
+    }
+}
+
+/// Returns the number of bytes of `len` values of type `T`.
+fn values_len<T: FixedSize>(len: usize) -> Option<usize> {
+    len.checked_mul(T::SIZE)
+}
+
+/// Returns the values stored in `bytes` in little-endian byte order.
+fn read_values<T: FrameType>(bytes: &[u8]) -> Vec<T> {
+    let mut values = vec![T::default(); bytes.len() / T::SIZE];
+    values.as_mut_bytes().copy_from_slice(bytes);
+    if cfg!(target_endian = "big") {
+        for value in &mut values {
+            *value = value.to_le();
+        }
+    }
+    values
+}
+
+/// Returns a view of the values stored in `bytes`, or `None` if `bytes` is
+/// not aligned for `T` or the target is not little endian.
+fn view_values<T: FrameType>(bytes: &[u8]) -> Option<&[T]> {
+    (cfg!(target_endian = "little") && bytes.as_ptr().align_offset(mem::align_of::<T>()) == 0).then(
+        ||
+            // Safety:
+            // - The pointer is aligned for `T`, and points to `len * size_of::<T>`
+            //   initialized bytes that are borrowed for the lifetime of the result.
+            // - All bit patterns are valid values of the primitive frame types.
+            unsafe { slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / T::SIZE) },
+    )
+}
+
+/// Returns a bitmap with `len` bits stored in `bytes`.
+fn read_bitmap<Buffer: BufferType>(
+    bytes: <Buffer as BufferType>::Buffer<u8>,
+    len: usize,
+) -> Bitmap<Buffer> {
+    Bitmap::from_bytes(bytes, len).expect("frame bitmap length is checked")
+}
+
+impl<T: FrameType, Buffer: BufferType> EncodeFrame for FixedSizePrimitiveArray<T, false, Buffer> {
+    fn to_frame(&self) -> Vec<u8> {
+        encode::<Buffer>(T::TAG, self.len(), None, self.0.as_bytes().len(), |bytes| {
+            write_values(bytes, self.as_slice());
+        })
+    }
+}
+
+impl<T: FrameType, Buffer: BufferType> EncodeFrame for FixedSizePrimitiveArray<T, true, Buffer> {
+    fn to_frame(&self) -> Vec<u8> {
+        encode(
+            T::TAG,
+            self.len(),
+            Some(&self.0.validity),
+            self.0.data.as_bytes().len(),
+            |bytes| write_values(bytes, self.values()),
+        )
+    }
+}
+
+impl<Buffer: BufferType> EncodeFrame for BooleanArray<false, Buffer> {
+    fn to_frame(&self) -> Vec<u8> {
+        encode::<Buffer>(
+            BOOL_TAG,
+            self.len(),
+            None,
+            bytes_for_bits(self.len()),
+            |bytes| {
+                write_bitmap(bytes, &self.0);
+            },
+        )
+    }
+}
+
+impl<Buffer: BufferType> EncodeFrame for BooleanArray<true, Buffer> {
+    fn to_frame(&self) -> Vec<u8> {
+        encode(
+            BOOL_TAG,
+            self.len(),
+            Some(&self.0.validity),
+            bytes_for_bits(self.len()),
+            |bytes| write_bitmap(bytes, &self.0.data),
+        )
+    }
+}
+
+impl<T: FrameType> DecodeFrame<'_> for FixedSizePrimitiveArray<T, false, VecBuffer> {
+    fn from_frame(bytes: &[u8]) -> Result<Self, DecodeError> {
+        decode(bytes, T::TAG, false, values_len::<T>)
+            .map(|sections| Self(read_values(sections.data)))
+    }
+}
+
+impl<T: FrameType> DecodeFrame<'_> for FixedSizePrimitiveArray<T, true, VecBuffer> {
+    fn from_frame(bytes: &[u8]) -> Result<Self, DecodeError> {
+        decode(bytes, T::TAG, true, values_len::<T>).map(|sections| {
+            Self(Nullable {
+                data: read_values(sections.data),
+                validity: read_bitmap(sections.validity.unwrap_or_default().to_vec(), sections.len),
+            })
+        })
+    }
+}
+
+impl<'a, T: FrameType> DecodeFrame<'a> for FixedSizePrimitiveArray<T, false, SliceBuffer<'a>> {
+    fn from_frame(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        let sections = decode(bytes, T::TAG, false, values_len::<T>)?;
+        view_values(sections.data)
+            .map(Self)
+            .ok_or(DecodeError::Unaligned)
+    }
+}
+
+impl<'a, T: FrameType> DecodeFrame<'a> for FixedSizePrimitiveArray<T, true, SliceBuffer<'a>> {
+    fn from_frame(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        let sections = decode(bytes, T::TAG, true, values_len::<T>)?;
+        view_values(sections.data)
+            .map(|data| {
+                Self(Nullable {
+                    data,
+                    validity: read_bitmap(sections.validity.unwrap_or_default(), sections.len),
+                })
+            })
+            .ok_or(DecodeError::Unaligned)
+    }
+}
+
+impl DecodeFrame<'_> for BooleanArray<false, VecBuffer> {
+    fn from_frame(bytes: &[u8]) -> Result<Self, DecodeError> {
+        decode(bytes, BOOL_TAG, false, |len| Some(bytes_for_bits(len)))
+            .map(|sections| Self(read_bitmap(sections.data.to_vec(), sections.len)))
+    }
+}
+
+impl DecodeFrame<'_> for BooleanArray<true, VecBuffer> {
+    fn from_frame(bytes: &[u8]) -> Result<Self, DecodeError> {
+        decode(bytes, BOOL_TAG, true, |len| Some(bytes_for_bits(len))).map(|sections| {
+            Self(Nullable {
+                data: read_bitmap(sections.data.to_vec(), sections.len),
+                validity: read_bitmap(sections.validity.unwrap_or_default().to_vec(), sections.len),
+            })
+        })
+    }
+}
+
+impl<'a> DecodeFrame<'a> for BooleanArray<false, SliceBuffer<'a>> {
+    fn from_frame(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        decode(bytes, BOOL_TAG, false, |len| Some(bytes_for_bits(len)))
+            .map(|sections| Self(read_bitmap(sections.data, sections.len)))
+    }
+}
+
+impl<'a> DecodeFrame<'a> for BooleanArray<true, SliceBuffer<'a>> {
+    fn from_frame(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        decode(bytes, BOOL_TAG, true, |len| Some(bytes_for_bits(len))).map(|sections| {
+            Self(Nullable {
+                data: read_bitmap(sections.data, sections.len),
+                validity: read_bitmap(sections.validity.unwrap_or_default(), sections.len),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, Uint32Array, Uint64Array};
+    use core::fmt::Debug;
+
+    /// Checks that encoding and decoding the items returns the same items.
+    fn roundtrip<T: FrameType>(items: &[T]) {
+        let array = items
+            .iter()
+            .copied()
+            .collect::<FixedSizePrimitiveArray<T>>();
+        let bytes = array.to_frame();
+        assert_eq!(bytes.len(), HEADER_LEN + items.len() * T::SIZE);
+        assert_eq!(bytes[0], T::TAG);
+        assert_eq!(<FixedSizePrimitiveArray<T>>::from_frame(&bytes), Ok(array));
+
+        let nullable = items
+            .iter()
+            .enumerate()
+            .map(|(index, &item)| (index % 3 != 1).then_some(item))
+            .collect::<FixedSizePrimitiveArray<T, true>>();
+        assert_eq!(
+            <FixedSizePrimitiveArray<T, true>>::from_frame(&nullable.to_frame()),
+            Ok(nullable)
+        );
+    }
+
+    /// Checks the round-trip of the values `0..100` of a type.
+    fn roundtrip_range<T: FrameType + TryFrom<u8>>()
+    where
+        <T as TryFrom<u8>>::Error: Debug,
+    {
+        roundtrip(
+            &(0..100)
+                .map(|value| T::try_from(value).expect("fits"))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn primitives() {
+        roundtrip_range::<u8>();
+        roundtrip_range::<u16>();
+        roundtrip_range::<u32>();
+        roundtrip_range::<u64>();
+        #[cfg(not(feature = "arrow-rs"))]
+        roundtrip_range::<u128>();
+        roundtrip_range::<i16>();
+        roundtrip_range::<i32>();
+        roundtrip_range::<i64>();
+        roundtrip_range::<i128>();
+        roundtrip(&[i8::MIN, -1, 0, i8::MAX]);
+        roundtrip(&[f32::MIN, -0.5, 0.0, f32::MAX]);
+        roundtrip(&[f64::MIN, -0.5, f64::EPSILON, f64::MAX]);
+        roundtrip::<u32>(&[]);
+    }
+
+    #[test]
+    fn little_endian() {
+        let bytes = [0x0102_0304_u32]
+            .into_iter()
+            .collect::<Uint32Array>()
+            .to_frame();
+        assert_eq!(bytes[8..HEADER_LEN], [1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bytes[HEADER_LEN..], [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn booleans() {
+        let array = (0..20)
+            .map(|index| (index % 4 != 0).then_some(index % 3 == 0))
+            .collect::<BooleanArray<true>>();
+        let bytes = array.to_frame();
+        assert_eq!(bytes.len(), HEADER_LEN + 8 + 3);
+        assert_eq!(<BooleanArray<true>>::from_frame(&bytes), Ok(array.clone()));
+        let view = BooleanArray::<true, SliceBuffer<'_>>::from_frame(&bytes).expect("view");
+        assert!(view.into_iter().eq(array));
+
+        let values = [true, false, true].into_iter().collect::<BooleanArray>();
+        assert_eq!(<BooleanArray>::from_frame(&values.to_frame()), Ok(values));
+    }
+
+    #[test]
+    fn errors() {
+        let bytes = [1, 2, 3].into_iter().collect::<Int32Array>().to_frame();
+        assert_eq!(
+            <Int32Array>::from_frame(&bytes[..20]),
+            Err(DecodeError::Truncated {
+                required: 28,
+                actual: 20
+            })
+        );
+        assert_eq!(
+            <Int32Array>::from_frame(&bytes[..4]),
+            Err(DecodeError::Truncated {
+                required: HEADER_LEN,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            <Uint32Array>::from_frame(&bytes),
+            Err(DecodeError::TypeTag {
+                expected: u32::TAG,
+                actual: i32::TAG
+            })
+        );
+        assert_eq!(
+            <Int32Array<true>>::from_frame(&bytes),
+            Err(DecodeError::Nullability { expected: true })
+        );
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            <Int32Array>::from_frame(&trailing),
+            Err(DecodeError::TrailingBytes {
+                required: 28,
+                actual: 29
+            })
+        );
+        let mut header = bytes;
+        header[1] = 2;
+        assert_eq!(<Int32Array>::from_frame(&header), Err(DecodeError::Header));
+
+        // The sizes derived from a huge length in the header are checked. On
+        // targets where the length does not fit in a `usize`, decoding fails
+        // before the sizes are derived.
+        let mut huge = [BOOL_TAG, 1, 0, 0, 0, 0, 0, 0].to_vec();
+        huge.extend_from_slice(&u64::MAX.to_le_bytes());
+        let truncated = Err(if cfg!(target_pointer_width = "64") {
+            DecodeError::Truncated {
+                required: HEADER_LEN + 2 * (usize::MAX / 8 + 1),
+                actual: HEADER_LEN,
+            }
+        } else {
+            DecodeError::Overflow
+        });
+
+        assert_eq!(
+            <BooleanArray<true>>::from_frame(&huge).map(|_| ()),
+            truncated
+        );
+        assert_eq!(
+            BooleanArray::<true, SliceBuffer<'_>>::from_frame(&huge).map(|_| ()),
+            truncated
+        );
+        huge[0] = i32::TAG;
+        huge[1] = 0;
+        assert_eq!(<Int32Array>::from_frame(&huge), Err(DecodeError::Overflow));
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn view() {
+        let array = (0..10_u64)
+            .map(|value| (value % 2 == 0).then_some(value))
+            .collect::<Uint64Array<true>>();
+        let frame = array.to_frame();
+        // Store the frame in u64s so it is aligned for the values.
+        let mut words = vec![0_u64; (frame.len() + 8) / 8];
+        let aligned = &mut words.as_mut_bytes()[..=frame.len()];
+        aligned[..frame.len()].copy_from_slice(&frame);
+        let bytes = &aligned[..frame.len()];
+        let view = FixedSizePrimitiveArray::<u64, true, SliceBuffer<'_>>::from_frame(bytes)
+            .expect("aligned");
+        // The values are not copied.
+        assert_eq!(
+            view.values().as_ptr().cast::<u8>(),
+            bytes[HEADER_LEN + 8..].as_ptr()
+        );
+        assert!(view
+            .iter_with_validity()
+            .map(|(value, valid)| valid.then_some(value))
+            .eq(array.into_iter()));
+
+        // A frame that starts at an odd address can't be viewed.
+        aligned.copy_within(..frame.len(), 1);
+        assert_eq!(
+            FixedSizePrimitiveArray::<u64, true, SliceBuffer<'_>>::from_frame(&aligned[1..])
+                .map(|_| ()),
+            Err(DecodeError::Unaligned)
+        );
+    }
+}
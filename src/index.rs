@@ -1,7 +1,30 @@
 //! Indexing operations.
 
 use crate::Length;
-use std::{collections::VecDeque, rc::Rc, sync::Arc};
+use alloc::{boxed::Box, collections::VecDeque, rc::Rc, sync::Arc, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+/// The error returned when an index is out of bounds of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    /// The requested index.
+    pub index: usize,
+    /// The length of the collection.
+    pub len: usize,
+}
+
+impl Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index out of bounds: the len is {} but the index is {}",
+            self.len, self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOutOfBounds {}
 
 /// Index operation for shared access to values in a collection.
 pub trait Index: Length {
@@ -18,6 +41,19 @@ pub trait Index: Length {
             unsafe { self.index_unchecked(index)})
     }
 
+    /// Returns the value at given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexOutOfBounds`] error with the index and the length of
+    /// this collection if the index is out of bounds.
+    fn try_get(&self, index: usize) -> Result<Self::Item<'_>, IndexOutOfBounds> {
+        self.index(index).ok_or(IndexOutOfBounds {
+            index,
+            len: self.len(),
+        })
+    }
+
     /// Returns the value at given index. Panics if the index is out of bounds.
     ///
     /// # Panics
@@ -136,6 +172,48 @@ impl<T> Index for VecDeque<T> {
 
     unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
         // VecDeque has no unchecked get so this panics for out of bounds access
-        std::ops::Index::index(self, index)
+        core::ops::Index::index(self, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+
+    #[test]
+    fn try_get() {
+        let vec = vec![1, 2, 3];
+        assert_eq!(vec.try_get(2), Ok(&3));
+        assert_eq!(vec.try_get(3), Err(IndexOutOfBounds { index: 3, len: 3 }));
+
+        let array = [Some(1), None].into_iter().collect::<Int32Array<true>>();
+        assert_eq!(array.try_get(0), Ok(Some(&1)));
+        assert_eq!(array.try_get(1), Ok(None));
+        assert_eq!(array.try_get(5), Err(IndexOutOfBounds { index: 5, len: 2 }));
+    }
+
+    #[test]
+    fn index_out_of_bounds() {
+        let error = IndexOutOfBounds { index: 4, len: 2 };
+        assert_eq!(
+            error.to_string(),
+            "index out of bounds: the len is 2 but the index is 4"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn index_out_of_bounds_error() {
+        use std::error::Error;
+
+        let error = IndexOutOfBounds { index: 4, len: 2 };
+        assert!(error.source().is_none());
+
+        let boxed: Box<dyn Error> = Box::new(error);
+        assert_eq!(
+            boxed.downcast_ref::<IndexOutOfBounds>(),
+            Some(&IndexOutOfBounds { index: 4, len: 2 })
+        );
     }
 }
@@ -1,6 +1,15 @@
 //! The length (number of elements) of a collection.
 
-use std::{collections::VecDeque, rc::Rc, sync::Arc};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::VecDeque,
+    rc::Rc,
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt::{self, Display, Formatter};
 
 /// The length (or number of elements) of a collection.
 pub trait Length {
@@ -15,6 +24,29 @@ pub trait Length {
     }
 }
 
+/// The error returned when the lengths of two collections that are combined
+/// don't match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The expected length.
+    pub expected: usize,
+    /// The actual length.
+    pub actual: usize,
+}
+
+impl Display for LengthMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "length mismatch: expected {}, got {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthMismatch {}
+
 impl<const N: usize, T> Length for [T; N] {
     fn len(&self) -> usize {
         N
@@ -28,45 +60,52 @@ impl<T> Length for [T] {
     }
 }
 
-impl<T> Length for &[T] {
+impl<T> Length for &mut [T] {
     #[inline]
     fn len(&self) -> usize {
         <[T]>::len(self)
     }
 }
 
-impl<T> Length for &mut [T] {
+impl<T> Length for Vec<T> {
     #[inline]
     fn len(&self) -> usize {
-        <[T]>::len(self)
+        Vec::len(self)
     }
 }
 
-impl<T> Length for Vec<T> {
+impl<T: Length + ?Sized> Length for &T {
     #[inline]
     fn len(&self) -> usize {
-        Vec::len(self)
+        T::len(self)
     }
 }
 
-impl<T> Length for Box<[T]> {
+impl<T: Length + ?Sized> Length for Box<T> {
     #[inline]
     fn len(&self) -> usize {
-        <[T]>::len(self)
+        T::len(self)
     }
 }
 
-impl<T> Length for Rc<[T]> {
+impl<T: Length + ?Sized> Length for Rc<T> {
     #[inline]
     fn len(&self) -> usize {
-        <[T]>::len(self)
+        T::len(self)
     }
 }
 
-impl<T> Length for Arc<[T]> {
+impl<T: Length + ?Sized> Length for Arc<T> {
     #[inline]
     fn len(&self) -> usize {
-        <[T]>::len(self)
+        T::len(self)
+    }
+}
+
+impl<T: Length + ToOwned + ?Sized> Length for Cow<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        T::len(self)
     }
 }
 
@@ -95,13 +134,13 @@ impl<T: Length> Length for Option<T> {
     }
 }
 
-impl<T> Length for std::vec::IntoIter<T> {
+impl<T> Length for alloc::vec::IntoIter<T> {
     fn len(&self) -> usize {
         ExactSizeIterator::len(self)
     }
 }
 
-impl<T> Length for std::slice::Iter<'_, T> {
+impl<T> Length for core::slice::Iter<'_, T> {
     fn len(&self) -> usize {
         ExactSizeIterator::len(self)
     }
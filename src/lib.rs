@@ -5,6 +5,9 @@
     html_favicon_url = "https://raw.githubusercontent.com/mbrobbel/narrow/main/narrow.svg"
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg, doc_cfg))]
+// Unit tests use the standard library (and its prelude) regardless of the
+// `std` feature. The `no_std` integration test covers builds without it.
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 // The goal of the list of lints here is to help reduce complexity and improve consistency
 #![deny(
     // Rustc
@@ -42,7 +45,6 @@
     clippy::shadow_unrelated,
     clippy::str_to_string,
     clippy::string_add,
-    clippy::string_to_string,
     clippy::tests_outside_test_module,
     clippy::undocumented_unsafe_blocks,
     clippy::unnecessary_safety_comment,
@@ -56,14 +58,16 @@
 )]
 #![allow(clippy::module_name_repetitions, clippy::pub_use)]
 
+extern crate alloc;
+
 mod fixed_size;
 pub use self::fixed_size::FixedSize;
 
 mod length;
-pub use self::length::Length;
+pub use self::length::{Length, LengthMismatch};
 
 mod index;
-pub use self::index::Index;
+pub use self::index::{Index, IndexOutOfBounds};
 
 pub mod buffer;
 
@@ -74,13 +78,46 @@ pub(crate) mod nullable;
 pub mod offset;
 pub(crate) mod validity;
 
+mod validation;
+pub use self::validation::{Validate, ValidationError};
+
+mod memory;
+pub use self::memory::MemoryUsage;
+
 pub mod array;
 
+pub mod builder;
+
+pub mod checksum;
+
+pub mod frame;
+
 pub mod logical;
 
+#[cfg(feature = "std")]
+pub mod compute;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
 #[cfg(feature = "arrow-rs")]
 pub mod arrow;
 
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+#[cfg(any(test, feature = "proptest"))]
+pub mod proptest;
+
+#[cfg(feature = "rand")]
+pub mod rand;
+
+#[cfg(feature = "rayon")]
+pub mod rayon;
+
+#[cfg(feature = "stream")]
+pub mod stream;
+
 // Re-export `narrow_derive` macros when the `derive` feature is enabled.
 #[cfg(feature = "derive")]
 pub use narrow_derive::ArrayType;
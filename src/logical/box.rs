@@ -1,4 +1,5 @@
 use crate::array::ArrayType;
+use alloc::boxed::Box;
 
 use super::{LogicalArray, LogicalArrayType};
 
@@ -1,15 +1,18 @@
 #![allow(missing_docs)]
 
 use std::{
-    collections::HashMap,
+    borrow::Borrow,
+    collections::{BTreeMap, HashMap},
     hash::{BuildHasher, Hash},
+    ops::Range,
 };
 
 use crate::{
-    array::{self, UnionType},
+    array::{self, union, UnionType},
+    bitmap::ValidityBitmap,
     buffer::BufferType,
-    offset::OffsetElement,
-    ArrayType,
+    offset::{self, OffsetElement},
+    ArrayType, Index,
 };
 
 use super::{LogicalArray, LogicalArrayType};
@@ -57,6 +60,237 @@ impl<K: array::ArrayType<K> + Hash + Eq, V: array::ArrayType<V>, S: BuildHasher
     }
 }
 
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> array::ArrayType<BTreeMap<K, V>>
+    for BTreeMap<K, V>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> array::ArrayType<BTreeMap<K, V>>
+    for Option<BTreeMap<K, V>>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<BTreeMap<K, V>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> LogicalArrayType<BTreeMap<K, V>>
+    for BTreeMap<K, V>
+{
+    type ArrayType = Vec<KeyValue<K, V>>;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        item.into_iter()
+            .map(|KeyValue { key, value }| (key, value))
+            .collect()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_iter()
+            .map(|(key, value)| KeyValue { key, value })
+            .collect()
+    }
+}
+
+/// Map types that are stored in map arrays.
+pub trait MapType {
+    /// The type of the keys.
+    type Key;
+    /// The type of the values.
+    type Value;
+    /// Whether the keys of every map are stored in sorted order.
+    const KEYS_SORTED: bool;
+}
+
+impl<K, V, S> MapType for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+    const KEYS_SORTED: bool = false;
+}
+
+impl<K, V> MapType for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+    const KEYS_SORTED: bool = true;
+}
+
+/// The array of the keys or values of a map array.
+type EntryArray<T, Buffer> = <T as array::ArrayType<T>>::Array<Buffer, offset::NA, union::NA>;
+
+/// The item of the keys or values array of a map array.
+type EntryItem<'a, T, Buffer> = <EntryArray<T, Buffer> as Index>::Item<'a>;
+
+/// Returns the value for `key` in the entries in `range`, binary searching
+/// the keys if they are sorted.
+fn lookup<'a, K, V, Q: Ord + ?Sized, Buffer: BufferType>(
+    entries: &'a array::StructArray<KeyValue<K, V>, false, Buffer>,
+    mut range: Range<usize>,
+    key: &Q,
+    sorted: bool,
+) -> Option<EntryItem<'a, V, Buffer>>
+where
+    K: array::ArrayType<K> + 'a,
+    V: array::ArrayType<V> + 'a,
+    EntryArray<K, Buffer>: Index + 'a,
+    EntryArray<V, Buffer>: Index + 'a,
+    EntryItem<'a, K, Buffer>: Borrow<Q>,
+{
+    let keys = entries.0.key();
+    let position = if sorted {
+        let (mut low, mut high) = (range.start, range.end);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if keys.index_checked(mid).borrow() < key {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        (low < range.end && keys.index_checked(low).borrow() == key).then_some(low)
+    } else {
+        range.find(|&index| keys.index_checked(index).borrow() == key)
+    };
+    position.map(|index| entries.0.value().index_checked(index))
+}
+
+/// Returns the range of the entries of `row` given the `offsets`.
+fn entries_range<OffsetItem: OffsetElement>(offsets: &[OffsetItem], row: usize) -> Range<usize> {
+    let to_usize =
+        |offset: OffsetItem| -> usize { offset.try_into().expect("offset value out of range") };
+    to_usize(offsets[row])..to_usize(offsets[row + 1])
+}
+
+impl<M, K, V, Buffer: BufferType, OffsetItem: OffsetElement>
+    LogicalArray<M, false, Buffer, OffsetItem, union::NA>
+where
+    M: MapType<Key = K, Value = V> + LogicalArrayType<M, ArrayType = Vec<KeyValue<K, V>>>,
+    Option<M>: array::ArrayType<M>,
+    K: array::ArrayType<K>,
+    V: array::ArrayType<V>,
+{
+    /// Returns `true` if the keys of every map are stored in sorted order,
+    /// which is the case for [`BTreeMap`] items.
+    #[must_use]
+    pub fn keys_sorted(&self) -> bool {
+        M::KEYS_SORTED
+    }
+
+    /// Returns the value for `key` in the map at `row`.
+    ///
+    /// Returns `None` if the row is out of bounds, and `Some(None)` if the
+    /// map does not contain the key. This is a binary search if the keys are
+    /// sorted, and a scan otherwise.
+    pub fn get<'a, Q: Ord + ?Sized>(
+        &'a self,
+        row: usize,
+        key: &Q,
+    ) -> Option<Option<EntryItem<'a, V, Buffer>>>
+    where
+        K: 'a,
+        V: 'a,
+        EntryArray<K, Buffer>: Index + 'a,
+        EntryArray<V, Buffer>: Index + 'a,
+        EntryItem<'a, K, Buffer>: Borrow<Q>,
+    {
+        let offsets = self.0 .0.offsets.as_slice();
+        (row + 1 < offsets.len()).then(|| {
+            lookup(
+                &self.0 .0.data,
+                entries_range(offsets, row),
+                key,
+                M::KEYS_SORTED,
+            )
+        })
+    }
+
+    /// Returns an iterator over the entries of the maps, in the order in
+    /// which they are stored.
+    ///
+    /// For [`HashMap`] items this is the iteration order of the maps when
+    /// they were added to this array.
+    pub fn into_entries(self) -> impl Iterator<Item = Vec<(K, V)>>
+    where
+        <Vec<KeyValue<K, V>> as array::ArrayType<Vec<KeyValue<K, V>>>>::Array<
+            Buffer,
+            OffsetItem,
+            union::NA,
+        >: IntoIterator<Item = Vec<KeyValue<K, V>>>,
+    {
+        self.0.into_iter().map(|entries| {
+            entries
+                .into_iter()
+                .map(|KeyValue { key, value }| (key, value))
+                .collect()
+        })
+    }
+}
+
+impl<M, K, V, Buffer: BufferType, OffsetItem: OffsetElement>
+    LogicalArray<M, true, Buffer, OffsetItem, union::NA>
+where
+    M: MapType<Key = K, Value = V> + LogicalArrayType<M, ArrayType = Vec<KeyValue<K, V>>>,
+    Option<M>: array::ArrayType<M>,
+    K: array::ArrayType<K>,
+    V: array::ArrayType<V>,
+{
+    /// Returns `true` if the keys of every map are stored in sorted order,
+    /// which is the case for [`BTreeMap`] items.
+    #[must_use]
+    pub fn keys_sorted(&self) -> bool {
+        M::KEYS_SORTED
+    }
+
+    /// Returns the value for `key` in the map at `row`.
+    ///
+    /// Returns `None` if the row is out of bounds or null, and `Some(None)`
+    /// if the map does not contain the key. This is a binary search if the
+    /// keys are sorted, and a scan otherwise.
+    pub fn get<'a, Q: Ord + ?Sized>(
+        &'a self,
+        row: usize,
+        key: &Q,
+    ) -> Option<Option<EntryItem<'a, V, Buffer>>>
+    where
+        K: 'a,
+        V: 'a,
+        EntryArray<K, Buffer>: Index + 'a,
+        EntryArray<V, Buffer>: Index + 'a,
+        EntryItem<'a, K, Buffer>: Borrow<Q>,
+    {
+        self.0.is_valid(row).unwrap_or(false).then(|| {
+            lookup(
+                &self.0 .0.data,
+                entries_range(self.0 .0.offsets.data.as_slice(), row),
+                key,
+                M::KEYS_SORTED,
+            )
+        })
+    }
+
+    /// Returns an iterator over the entries of the maps, in the order in
+    /// which they are stored, or `None` for null maps.
+    ///
+    /// For [`HashMap`] items this is the iteration order of the maps when
+    /// they were added to this array.
+    pub fn into_entries(self) -> impl Iterator<Item = Option<Vec<(K, V)>>>
+    where
+        <Option<Vec<KeyValue<K, V>>> as array::ArrayType<Vec<KeyValue<K, V>>>>::Array<
+            Buffer,
+            OffsetItem,
+            union::NA,
+        >: IntoIterator<Item = Option<Vec<KeyValue<K, V>>>>,
+    {
+        self.0.into_iter().map(|map| {
+            map.map(|entries| {
+                entries
+                    .into_iter()
+                    .map(|KeyValue { key, value }| (key, value))
+                    .collect()
+            })
+        })
+    }
+}
+
 /// An array for [`HashMap`] items.
 #[allow(unused)]
 pub type HashMapArray<
@@ -67,6 +301,16 @@ pub type HashMapArray<
     OffsetItem = i32,
 > = LogicalArray<HashMap<K, V>, NULLABLE, Buffer, OffsetItem, crate::array::union::NA>;
 
+/// An array for [`BTreeMap`] items, with sorted keys.
+#[allow(unused)]
+pub type BTreeMapArray<
+    K,
+    V,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+    OffsetItem = i32,
+> = LogicalArray<BTreeMap<K, V>, NULLABLE, Buffer, OffsetItem, crate::array::union::NA>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,4 +367,70 @@ mod tests {
         let output_nullable = array_nullable.into_iter().collect::<Vec<_>>();
         assert_eq!(input_nullable, output_nullable.as_slice());
     }
+
+    #[test]
+    fn get_sorted() {
+        let array = [
+            BTreeMap::from_iter([(3, 30_u8), (1, 10), (2, 20)]),
+            BTreeMap::default(),
+            BTreeMap::from_iter([(5, 50)]),
+        ]
+        .into_iter()
+        .collect::<BTreeMapArray<u32, u8>>();
+        assert!(array.keys_sorted());
+        assert_eq!(array.get(0, &1), Some(Some(&10)));
+        assert_eq!(array.get(0, &2), Some(Some(&20)));
+        assert_eq!(array.get(0, &3), Some(Some(&30)));
+        assert_eq!(array.get(0, &4), Some(None));
+        assert_eq!(array.get(0, &5), Some(None));
+        assert_eq!(array.get(1, &1), Some(None));
+        assert_eq!(array.get(2, &5), Some(Some(&50)));
+        assert_eq!(array.get(3, &5), None);
+        assert_eq!(
+            array.into_entries().next(),
+            Some(vec![(1, 10), (2, 20), (3, 30)])
+        );
+    }
+
+    #[test]
+    fn get_unsorted() {
+        let map = HashMap::from_iter([
+            ("b".to_owned(), 2_u8),
+            ("a".to_owned(), 1),
+            ("c".to_owned(), 3),
+        ]);
+        let array = [map.clone()]
+            .into_iter()
+            .collect::<HashMapArray<String, u8>>();
+        assert!(!array.keys_sorted());
+        assert_eq!(array.get(0, "a"), Some(Some(&1)));
+        assert_eq!(array.get(0, "c"), Some(Some(&3)));
+        assert_eq!(array.get(0, "d"), Some(None));
+        assert_eq!(
+            array.into_entries().collect::<Vec<_>>(),
+            [map.into_iter().collect::<Vec<_>>()]
+        );
+    }
+
+    #[test]
+    fn get_nullable() {
+        let array = [Some(BTreeMap::from_iter([(1, 2_i64)])), None]
+            .into_iter()
+            .collect::<BTreeMapArray<u8, i64, true>>();
+        assert!(array.keys_sorted());
+        assert_eq!(array.get(0, &1), Some(Some(&2)));
+        assert_eq!(array.get(0, &2), Some(None));
+        assert_eq!(array.get(1, &1), None);
+        assert_eq!(array.get(2, &1), None);
+        assert_eq!(
+            array.into_entries().collect::<Vec<_>>(),
+            [Some(vec![(1, 2)]), None]
+        );
+
+        let hash_maps = [None, Some(HashMap::from_iter([(7_u16, 8_u16)]))]
+            .into_iter()
+            .collect::<HashMapArray<u16, u16, true>>();
+        assert_eq!(hash_maps.get(0, &7), None);
+        assert_eq!(hash_maps.get(1, &7), Some(Some(&8)));
+    }
 }
@@ -1,13 +1,16 @@
 //! Logical array support.
 
-use std::iter::Map;
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::Map,
+};
 
 use crate::{
     array::{Array, ArrayType, UnionType},
     buffer::BufferType,
     offset::OffsetElement,
     validity::Nullability,
-    Length,
+    Length, MemoryUsage,
 };
 
 /// Box support via logical arrays.
@@ -103,6 +106,27 @@ where
     }
 }
 
+impl<
+        T: LogicalArrayType<T>,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+        UnionLayout: UnionType,
+    > Debug for LogicalArray<T, NULLABLE, Buffer, OffsetItem, UnionLayout>
+where
+    Option<T>: ArrayType<T>,
+    <T as LogicalArrayType<T>>::ArrayType: Nullability<NULLABLE>,
+    <<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item:
+        ArrayType<<T as LogicalArrayType<T>>::ArrayType>,
+    <<<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item as ArrayType<
+        <T as LogicalArrayType<T>>::ArrayType,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LogicalArray").field(&self.0).finish()
+    }
+}
+
 impl<
         T: LogicalArrayType<T>,
         const NULLABLE: bool,
@@ -299,6 +323,31 @@ where
     }
 }
 
+impl<
+        T: LogicalArrayType<T>,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+        UnionLayout: UnionType,
+    > MemoryUsage for LogicalArray<T, NULLABLE, Buffer, OffsetItem, UnionLayout>
+where
+    Option<T>: ArrayType<T>,
+    <T as LogicalArrayType<T>>::ArrayType: Nullability<NULLABLE>,
+    <<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item:
+        ArrayType<<T as LogicalArrayType<T>>::ArrayType>,
+    <<<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item as ArrayType<
+        <T as LogicalArrayType<T>>::ArrayType,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{array::union, buffer::VecBuffer, offset};
@@ -341,7 +390,7 @@ mod tests {
         let input_nullable = [Some(Foo(1)), None, Some(Foo(3)), Some(Foo(4))];
         let array_nullable = input_nullable.into_iter().collect::<FooArray<true>>();
         assert_eq!(array_nullable.len(), 4);
-        assert_eq!(array_nullable.0 .0.data, [1, u8::default(), 3, 4]);
+        assert_eq!(array_nullable.0.values(), [1, u8::default(), 3, 4]);
         assert_eq!(array_nullable.0 .0.validity, [true, false, true, true]);
     }
 
@@ -0,0 +1,93 @@
+//! Memory usage of buffers and arrays.
+
+use crate::FixedSize;
+use alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec};
+use core::mem;
+
+/// Reports the memory used by the buffers of a value.
+pub trait MemoryUsage {
+    /// Returns the number of bytes in the buffers of this value, i.e. the sum
+    /// of the lengths (in bytes) of its data and validity buffers.
+    fn buffer_bytes(&self) -> usize;
+
+    /// Returns the number of bytes allocated for the buffers of this value.
+    ///
+    /// This includes unused capacity for buffers that expose it, and equals
+    /// [`MemoryUsage::buffer_bytes`] for buffers that don't.
+    fn allocated_bytes(&self) -> usize {
+        self.buffer_bytes()
+    }
+}
+
+impl<T: MemoryUsage> MemoryUsage for &T {
+    fn buffer_bytes(&self) -> usize {
+        T::buffer_bytes(self)
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        T::allocated_bytes(self)
+    }
+}
+
+impl<T: FixedSize, const N: usize> MemoryUsage for [T; N] {
+    fn buffer_bytes(&self) -> usize {
+        mem::size_of_val(self)
+    }
+}
+
+impl<T: FixedSize> MemoryUsage for &[T] {
+    fn buffer_bytes(&self) -> usize {
+        mem::size_of_val(*self)
+    }
+}
+
+impl<T: FixedSize> MemoryUsage for &mut [T] {
+    fn buffer_bytes(&self) -> usize {
+        mem::size_of_val(*self)
+    }
+}
+
+impl<T: FixedSize> MemoryUsage for Vec<T> {
+    fn buffer_bytes(&self) -> usize {
+        mem::size_of_val(self.as_slice())
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.capacity() * mem::size_of::<T>()
+    }
+}
+
+impl<T: FixedSize> MemoryUsage for Box<[T]> {
+    fn buffer_bytes(&self) -> usize {
+        mem::size_of_val(self.as_ref())
+    }
+}
+
+impl<T: FixedSize> MemoryUsage for Arc<[T]> {
+    fn buffer_bytes(&self) -> usize {
+        mem::size_of_val(self.as_ref())
+    }
+}
+
+impl<T: FixedSize> MemoryUsage for Rc<[T]> {
+    fn buffer_bytes(&self) -> usize {
+        mem::size_of_val(self.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffers() {
+        let mut vec = Vec::<u32>::with_capacity(8);
+        vec.extend([1, 2, 3]);
+        assert_eq!(vec.buffer_bytes(), 12);
+        assert_eq!(vec.allocated_bytes(), 32);
+        assert_eq!([1_u16; 6].buffer_bytes(), 12);
+        assert_eq!(vec.as_slice().buffer_bytes(), 12);
+        assert_eq!(Box::<[u64]>::from([1, 2]).allocated_bytes(), 16);
+        assert_eq!(Arc::<[u8]>::from([1, 2]).buffer_bytes(), 2);
+    }
+}
@@ -1,11 +1,12 @@
 //! Nullable data.
 
 use crate::{
-    bitmap::{Bitmap, BitmapIntoIter, BitmapIter, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{self, BufferMut, BufferRef, BufferRefMut, BufferType, VecBuffer},
-    FixedSize, Index, Length,
+    bitmap::{Bitmap, BitmapIntoIter, BitmapRef, BitmapRefMut, ValidityBitmap},
+    buffer::{self, Buffer as _, BufferMut, BufferRef, BufferRefMut, BufferType, VecBuffer},
+    FixedSize, Index, Length, LengthMismatch, MemoryUsage, Validate, ValidationError,
 };
-use std::{
+use alloc::vec::Vec;
+use core::{
     borrow::Borrow,
     fmt::{Debug, Formatter, Result},
     iter::{Map, Zip},
@@ -24,6 +25,61 @@ pub struct Nullable<T, Buffer: BufferType = VecBuffer> {
     pub(crate) validity: Bitmap<Buffer>,
 }
 
+impl<T, Buffer: BufferType> Nullable<T, Buffer> {
+    /// Returns a new [`Nullable`] from the given data and validity bitmap.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the length of `data` equals the length of
+    /// `validity`.
+    pub unsafe fn from_parts_unchecked(data: T, validity: Bitmap<Buffer>) -> Self {
+        Self { data, validity }
+    }
+
+    /// Returns the data and validity bitmap of this [`Nullable`].
+    pub fn into_parts(self) -> (T, Bitmap<Buffer>) {
+        (self.data, self.validity)
+    }
+
+    /// Returns an iterator over references to the items in this
+    /// [`Nullable`], yielding `None` for null elements.
+    pub fn iter<'a>(&'a self) -> NullableRefIter<'a, <&'a T as IntoIterator>::IntoIter>
+    where
+        &'a T: IntoIterator,
+    {
+        NullableRefIter {
+            validity: self.validity.buffer.as_slice(),
+            front: self.validity.offset,
+            back: self.validity.offset + self.validity.bits,
+            data: self.data.into_iter(),
+        }
+    }
+}
+
+impl<T: Length, Buffer: BufferType> Nullable<T, Buffer> {
+    /// Returns a new [`Nullable`] from the given data and validity bitmap.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatch`] with the length of `data` as expected length
+    /// when the length of `validity` is different.
+    pub fn try_new(
+        data: T,
+        validity: Bitmap<Buffer>,
+    ) -> core::result::Result<Self, LengthMismatch> {
+        if data.len() == validity.len() {
+            Ok(Self { data, validity })
+        } else {
+            Err(LengthMismatch {
+                expected: data.len(),
+                actual: validity.len(),
+            })
+        }
+    }
+}
+
+/// Wraps non-nullable data with an all-valid validity bitmap. The conversions
+/// from non-nullable to nullable arrays use this implementation.
 impl<T: Length, Buffer: BufferType> From<T> for Nullable<T, Buffer>
 where
     Bitmap<Buffer>: FromIterator<bool>,
@@ -117,15 +173,35 @@ where
     <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Extend<u8>,
 {
     fn extend<I: IntoIterator<Item = (V, U)>>(&mut self, iter: I) {
-        // https://github.com/rust-lang/rust-clippy/issues/9378
-        #[allow(clippy::pattern_type_mismatch)]
-        self.data.extend(
-            iter.into_iter()
-                .inspect(|(valid, _value)| {
-                    self.validity.extend(std::iter::once(valid.borrow()));
-                })
-                .map(|(_, item)| item),
-        );
+        let mut items = iter.into_iter();
+        // The items are processed in chunks of 64, so the validity bits are
+        // appended a word at a time. The items of a chunk are buffered before
+        // they are appended, so when the iterator panics the items of the
+        // current chunk are dropped from both the data and the validity, and
+        // their lengths stay equal.
+        let mut values = Vec::with_capacity(items.size_hint().0.min(64));
+        loop {
+            let mut word = 0_u64;
+            values.extend(
+                items
+                    .by_ref()
+                    .take(64)
+                    .enumerate()
+                    .map(|(index, (valid, value))| {
+                        word |= u64::from(*valid.borrow()) << index;
+                        value
+                    }),
+            );
+            let bits = values.len();
+            if bits == 0 {
+                break;
+            }
+            self.data.extend(values.drain(..));
+            self.validity.extend_word(word, bits);
+            if bits < 64 {
+                break;
+            }
+        }
     }
 }
 
@@ -141,18 +217,24 @@ where
     }
 }
 
+impl<'a, T: Extend<U>, U: Copy + Default + 'a, Buffer: BufferType> Extend<&'a Option<U>>
+    for Nullable<T, Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Extend<u8>,
+{
+    fn extend<I: IntoIterator<Item = &'a Option<U>>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
 impl<'a, T, U, Buffer: BufferType> FromIterator<&'a Option<U>> for Nullable<T, Buffer>
 where
     T: Default + Extend<U>,
-    U: Copy + Default,
+    U: Copy + Default + 'a,
     <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Default + Extend<u8>,
 {
     fn from_iter<I: IntoIterator<Item = &'a Option<U>>>(iter: I) -> Self {
-        let (validity, data) = iter
-            .into_iter()
-            .map(|opt| (opt.is_some(), opt.as_ref().copied().unwrap_or_default()))
-            .unzip();
-        Self { data, validity }
+        iter.into_iter().copied().collect()
     }
 }
 
@@ -163,11 +245,14 @@ where
     <Buffer as BufferType>::Buffer<u8>: BufferMut<u8> + Default + Extend<u8>,
 {
     fn from_iter<I: IntoIterator<Item = Option<U>>>(iter: I) -> Self {
-        let (validity, data) = iter
-            .into_iter()
-            .map(|opt| (opt.is_some(), opt.unwrap_or_default()))
-            .unzip();
-        Self { data, validity }
+        // The `Extend` implementation packs the validity bits a word at a
+        // time and appends them with `Bitmap::extend_word`.
+        let mut nullable = Self {
+            data: T::default(),
+            validity: Bitmap::default(),
+        };
+        nullable.extend(iter);
+        nullable
     }
 }
 
@@ -190,19 +275,78 @@ where
     &'a T: IntoIterator,
 {
     type Item = Option<<&'a T as IntoIterator>::Item>;
-    type IntoIter = Map<
-        Zip<BitmapIter<'a>, <&'a T as IntoIterator>::IntoIter>,
-        fn((bool, <&'a T as IntoIterator>::Item)) -> Self::Item,
-    >;
+    type IntoIter = NullableRefIter<'a, <&'a T as IntoIterator>::IntoIter>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.validity
-            .into_iter()
-            .zip(&self.data)
-            .map(|(validity, value)| validity.then_some(value))
+        self.iter()
+    }
+}
+
+/// An iterator over the items of a [`Nullable`], yielding `None` for null
+/// elements.
+///
+/// This iterator is returned by [`Nullable::iter`]. The items of the data are
+/// yielded as they are, so iterating over a reference to the data yields
+/// references without copying the values.
+#[derive(Clone, Debug)]
+pub struct NullableRefIter<'a, I> {
+    /// The bytes of the validity bitmap.
+    validity: &'a [u8],
+    /// The index of the validity bit of the next item from the front.
+    front: usize,
+    /// The index after the validity bit of the next item from the back.
+    back: usize,
+    /// The iterator over the data.
+    data: I,
+}
+
+impl<I> NullableRefIter<'_, I> {
+    /// Returns the validity bit at `index`.
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity[index / 8] & (1 << (index % 8)) != 0
+    }
+}
+
+impl<I: Iterator> Iterator for NullableRefIter<'_, I> {
+    type Item = Option<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let value = self.data.next()?;
+        let valid = self.is_valid(self.front);
+        self.front += 1;
+        Some(valid.then_some(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        let (lower, upper) = self.data.size_hint();
+        (
+            lower.min(len),
+            Some(upper.map_or(len, |bound| bound.min(len))),
+        )
     }
 }
 
+impl<I: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for NullableRefIter<'_, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Skip the data items without a validity bit, like `Zip` does.
+        while self.data.len() > self.back - self.front {
+            self.data.next_back();
+        }
+        if self.front == self.back {
+            return None;
+        }
+        let value = self.data.next_back()?;
+        self.back -= 1;
+        Some(self.is_valid(self.back).then_some(value))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for NullableRefIter<'_, I> {}
+
 impl<T, Buffer: BufferType> IntoIterator for Nullable<T, Buffer>
 where
     T: IntoIterator,
@@ -252,13 +396,43 @@ where
 
 impl<T, Buffer: BufferType> ValidityBitmap for Nullable<T, Buffer> {}
 
+impl<T: Length, Buffer: BufferType> Validate for Nullable<T, Buffer> {
+    fn validate(&self) -> core::result::Result<(), ValidationError> {
+        self.validity.validate()?;
+        if self.data.len() == self.validity.len() {
+            Ok(())
+        } else {
+            Err(ValidationError::ValidityLength(LengthMismatch {
+                expected: self.data.len(),
+                actual: self.validity.len(),
+            }))
+        }
+    }
+}
+
+impl<T: MemoryUsage, Buffer: BufferType> MemoryUsage for Nullable<T, Buffer>
+where
+    Bitmap<Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.data.buffer_bytes() + self.validity.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.data.allocated_bytes() + self.validity.allocated_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::array::{BooleanArray, NullArray, Uint8Array};
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
     use std::{
         iter::{self, Repeat, Take},
         mem,
+        panic::{self, AssertUnwindSafe},
     };
 
     #[test]
@@ -277,6 +451,138 @@ mod tests {
         assert_eq!(nullable.len(), 6);
     }
 
+    #[test]
+    fn from_iter_unzip() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..300);
+            let input = (0..len)
+                .map(|_| rng.gen_bool(0.5).then(|| rng.gen::<u16>()))
+                .collect::<Vec<_>>();
+            let (validity, data): (Bitmap, Vec<_>) = input
+                .iter()
+                .map(|opt| (opt.is_some(), opt.unwrap_or_default()))
+                .unzip();
+            let nullable = input.iter().collect::<Nullable<Vec<_>>>();
+            assert_eq!(nullable.data, data);
+            assert_eq!(nullable.validity, validity);
+            assert_eq!(nullable.validity.buffer, validity.buffer);
+
+            // Without an exact size hint.
+            let filtered = input
+                .iter()
+                .copied()
+                .filter(|_| true)
+                .collect::<Nullable<Vec<_>>>();
+            assert_eq!(filtered.validity.buffer, validity.buffer);
+        }
+    }
+
+    #[test]
+    fn iter() {
+        let input = [
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            None,
+            Some(6),
+            None,
+            Some(8),
+            Some(9),
+        ];
+        let nullable = input.into_iter().collect::<Nullable<Vec<u32>>>();
+        let iter = nullable.iter();
+        assert_eq!(iter.len(), 9);
+        assert_eq!(
+            iter.map(Option::<&_>::copied).collect::<Vec<_>>(),
+            nullable.clone().into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            nullable
+                .iter()
+                .rev()
+                .map(Option::<&_>::copied)
+                .collect::<Vec<_>>(),
+            input.into_iter().rev().collect::<Vec<_>>()
+        );
+
+        let mut both_ends = nullable.iter();
+        assert_eq!(both_ends.next(), Some(Some(&1)));
+        assert_eq!(both_ends.next_back(), Some(Some(&9)));
+        assert_eq!(both_ends.next_back(), Some(Some(&8)));
+        assert_eq!(both_ends.len(), 6);
+        assert_eq!(both_ends.next(), Some(None));
+        assert_eq!(both_ends.count(), 5);
+    }
+
+    #[test]
+    fn iter_borrowed() {
+        let nullable = [Some([1_u8; 32]), None, Some([3; 32])]
+            .into_iter()
+            .collect::<Nullable<Vec<_>>>();
+        for (item, value) in nullable.iter().zip(&nullable.data) {
+            if let Some(borrowed) = item {
+                assert!(std::ptr::eq(borrowed, value));
+            }
+        }
+        assert_eq!(nullable.iter().flatten().count(), 2);
+    }
+
+    #[test]
+    fn extend_chunks() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let initial = rng.gen_range(0..100);
+            let len = rng.gen_range(0..300);
+            let input = (0..initial + len)
+                .map(|_| rng.gen_bool(0.5).then(|| rng.gen::<u16>()))
+                .collect::<Vec<_>>();
+            let pairs = input
+                .iter()
+                .map(|opt| (opt.is_some(), opt.unwrap_or_default()));
+
+            let mut expected = Nullable::<Vec<u16>>::default();
+            expected.extend(pairs);
+            let mut nullable = input[..initial].iter().collect::<Nullable<Vec<_>>>();
+            nullable.extend(input[initial..].iter().copied());
+            assert_eq!(nullable.data, expected.data);
+            assert_eq!(nullable.validity, expected.validity);
+            assert_eq!(nullable.validity.buffer, expected.validity.buffer);
+        }
+    }
+
+    #[test]
+    fn extend_panic() {
+        for panic_at in [0, 5, 64, 70, 130] {
+            let items = (0..).map(|index: usize| {
+                assert!(index != panic_at, "iterator panic");
+                (index % 3 != 0).then_some(index)
+            });
+            let mut nullable = [Some(1), None, Some(3)]
+                .into_iter()
+                .collect::<Nullable<Vec<usize>>>();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| nullable.extend(items)));
+            assert!(result.is_err());
+            assert_eq!(nullable.data.len(), nullable.validity.len());
+            assert_eq!(nullable.validate(), Ok(()));
+            // Only the items of complete chunks are appended.
+            assert_eq!(nullable.len(), 3 + panic_at / 64 * 64);
+
+            let bools = (0..).map(|index: usize| {
+                assert!(index != panic_at, "iterator panic");
+                (index % 3 != 0).then_some(index % 2 == 0)
+            });
+            let mut array = [Some(true), None]
+                .into_iter()
+                .collect::<BooleanArray<true>>();
+            let panicked = panic::catch_unwind(AssertUnwindSafe(|| array.extend(bools)));
+            assert!(panicked.is_err());
+            assert_eq!(array.0.data.len(), array.0.validity.len());
+            assert_eq!(array.validate(), Ok(()));
+        }
+    }
+
     #[test]
     fn from_iter_array() {
         let input = [Some([1234, 1234]), None, Some([42, 42])];
@@ -371,6 +677,52 @@ mod tests {
         assert_eq!(nullable.into_iter().collect::<Vec<Option<()>>>(), input);
     }
 
+    #[test]
+    fn try_new() {
+        let nullable =
+            Nullable::<_>::try_new(vec![1, 2, 3], [true, false, true].into_iter().collect())
+                .expect("matching lengths");
+        assert_eq!(
+            nullable.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(3)]
+        );
+        assert_eq!(
+            Nullable::<_>::try_new(vec![1, 2, 3], [true, false].into_iter().collect()).err(),
+            Some(LengthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            Nullable::<_>::try_new(vec![1], [true, false].into_iter().collect()).err(),
+            Some(LengthMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+        let empty = Nullable::<Vec<u8>>::try_new(Vec::new(), Bitmap::new_valid(0))
+            .expect("matching lengths");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn from() {
+        let nullable = Nullable::<_>::from(vec![1, 2, 3]);
+        assert!(nullable.all_valid());
+        assert_eq!(nullable.len(), 3);
+
+        let primitive: Uint8Array<true> = Uint8Array::<false>::from(vec![1, 2]).into();
+        assert!(primitive.all_valid());
+        assert_eq!(primitive.len(), 2);
+        let boolean: BooleanArray<true> =
+            [true, false].into_iter().collect::<BooleanArray>().into();
+        assert!(boolean.all_valid());
+        assert_eq!(boolean.len(), 2);
+        let null: NullArray<(), true> = [(), (), ()].into_iter().collect::<NullArray>().into();
+        assert!(null.all_valid());
+        assert_eq!(null.len(), 3);
+    }
+
     #[test]
     fn size_of() {
         assert_eq!(mem::size_of::<Nullable<()>>(), mem::size_of::<Bitmap>());
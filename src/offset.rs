@@ -2,12 +2,14 @@
 
 use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
-    buffer::{Buffer, BufferType, VecBuffer},
+    buffer::{Buffer, BufferType, SliceBuffer, VecBuffer},
     nullable::Nullable,
     validity::Validity,
-    FixedSize, Index, Length,
+    FixedSize, Index, Length, LengthMismatch, MemoryUsage, Validate, ValidationError,
 };
-use std::{
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::{
+    fmt::{self, Debug, Display},
     iter::{self, Map, Peekable, Zip},
     num::TryFromIntError,
     ops::{AddAssign, Range, Sub},
@@ -24,6 +26,7 @@ pub trait OffsetElement:
     + Default
     + TryFrom<usize, Error = TryFromIntError>
     + TryInto<usize, Error = TryFromIntError>
+    + Into<i64>
     + Sub<Output = Self>
     + sealed::Sealed
     + 'static
@@ -74,6 +77,275 @@ impl OffsetElement for i64 {
     }
 }
 
+/// The error returned when an offset value overflows its [`OffsetElement`]
+/// type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetOverflow;
+
+impl Display for OffsetOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("offset value overflow")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OffsetOverflow {}
+
+/// Returns the offset after a slot with `len` items that starts at `offset`.
+pub(crate) fn next_offset<OffsetItem: OffsetElement>(
+    offset: OffsetItem,
+    len: usize,
+) -> Result<OffsetItem, OffsetOverflow> {
+    OffsetItem::Unsigned::try_from(len)
+        .ok()
+        .and_then(|unsigned| offset.checked_add_unsigned(unsigned))
+        .ok_or(OffsetOverflow)
+}
+
+/// Checks that the `offsets` are valid for data with `len` items.
+///
+/// The offsets must have at least one value, the values must be
+/// non-decreasing, and the first and last value must be in `0..=len`. The
+/// first value is not required to be zero, to support slices of offsets.
+fn validate_offsets<OffsetItem: OffsetElement>(
+    offsets: &[OffsetItem],
+    len: usize,
+) -> Result<(), ValidationError> {
+    let in_bounds = |index: usize, value: OffsetItem| {
+        usize::try_from(value.into())
+            .ok()
+            .filter(|&position| position <= len)
+            .map(|_| ())
+            .ok_or(ValidationError::OffsetOutOfBounds { index, len })
+    };
+    let (&first, _) = offsets.split_first().ok_or(ValidationError::OffsetsEmpty)?;
+    in_bounds(0, first)?;
+    if let Some(index) = offsets
+        .windows(2)
+        .position(|window| window[1].into() < window[0].into())
+    {
+        return Err(ValidationError::OffsetDecreasing { index: index + 1 });
+    }
+    in_bounds(offsets.len() - 1, offsets[offsets.len() - 1])
+}
+
+/// The offset values of a variable-size array.
+///
+/// The offsets start with a leading zero and every slot adds the offset after
+/// its items, so slot `i` spans the items `offsets[i]..offsets[i + 1]`. The
+/// number of slots is one less than the number of offset values.
+///
+/// Slices of offsets (see [`Offsets::slice`]) start at the offset of their
+/// first slot, which is not zero in general.
+///
+/// These are the offsets stored by [`Offset`].
+pub struct Offsets<OffsetItem: OffsetElement = i32, Buffer: BufferType = VecBuffer>(
+    pub <Buffer as BufferType>::Buffer<OffsetItem>,
+);
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Offsets<OffsetItem, Buffer> {
+    /// Returns offsets with the values in `buffer`, checking that they are
+    /// valid for data with `len` items.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] naming the index of the first invalid
+    /// offset value, see [`Offsets::validate_len`].
+    pub fn try_from_parts(
+        buffer: <Buffer as BufferType>::Buffer<OffsetItem>,
+        len: usize,
+    ) -> Result<Self, ValidationError> {
+        let offsets = Self(buffer);
+        offsets.validate_len(len).map(|()| offsets)
+    }
+
+    /// Checks that the offset values are valid for data with `len` items.
+    ///
+    /// The offsets must have at least one value, the values must be
+    /// non-decreasing, and the first and last value must be in `0..=len`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] naming the index of the first invalid
+    /// offset value.
+    pub fn validate_len(&self, len: usize) -> Result<(), ValidationError> {
+        validate_offsets(self.as_slice(), len)
+    }
+
+    /// Returns the offset values as a slice.
+    pub fn as_slice(&self) -> &[OffsetItem] {
+        self.0.as_slice()
+    }
+
+    /// Returns the last offset value, i.e. the end of the last slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no offset values.
+    pub fn last(&self) -> OffsetItem {
+        self.as_slice()
+            .last()
+            .copied()
+            .expect("at least one value in the offsets buffer")
+    }
+
+    /// Returns the offsets of the slots in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice(&self, range: Range<usize>) -> Offsets<OffsetItem, SliceBuffer<'_>> {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "slice range out of bounds"
+        );
+        Offsets(&self.as_slice()[range.start..=range.end])
+    }
+
+    /// Appends a slot with `len` items.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OffsetOverflow`] error, without appending a slot, if the
+    /// end of the slot does not fit in the offset type.
+    pub fn try_push(&mut self, len: usize) -> Result<(), OffsetOverflow>
+    where
+        <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+    {
+        let offset = next_offset(self.last(), len)?;
+        self.0.extend(iter::once(offset));
+        Ok(())
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Clone for Offsets<OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Debug for Offsets<OffsetItem, Buffer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Offsets").field(&self.as_slice()).finish()
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Default for Offsets<OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Default + Extend<OffsetItem>,
+{
+    fn default() -> Self {
+        let mut offsets = <Buffer as BufferType>::Buffer::<OffsetItem>::default();
+        offsets.extend(iter::once(OffsetItem::default()));
+        Self(offsets)
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Extend<usize> for Offsets<OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+{
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        let mut state = self.last();
+        self.0.extend(iter.into_iter().map(|len| {
+            state = next_offset(state, len).expect("offset value overflow");
+            state
+        }));
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> FromIterator<usize>
+    for Offsets<OffsetItem, Buffer>
+where
+    Self: Default + Extend<usize>,
+{
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut offsets = Self::default();
+        offsets.extend(iter);
+        offsets
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Index for Offsets<OffsetItem, Buffer> {
+    type Item<'a> = (usize, usize)
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        // Safety:
+        // - There is one more offset value than there are slots.
+        let window = unsafe { self.as_slice().get_unchecked(index..index + 2) };
+        let (start, end) = (window[0], window[1]);
+        (
+            start.try_into().expect("offset value out of range"),
+            end.try_into().expect("offset value out of range"),
+        )
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> Length for Offsets<OffsetItem, Buffer> {
+    fn len(&self) -> usize {
+        // The offsets buffer has an additional value
+        self.0.len().checked_sub(1).expect("offset len underflow")
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> MemoryUsage for Offsets<OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.0.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType, OtherBuffer: BufferType>
+    PartialEq<Offsets<OffsetItem, OtherBuffer>> for Offsets<OffsetItem, Buffer>
+{
+    fn eq(&self, other: &Offsets<OffsetItem, OtherBuffer>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> PartialEq<[OffsetItem]>
+    for Offsets<OffsetItem, Buffer>
+{
+    fn eq(&self, other: &[OffsetItem]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<OffsetItem: OffsetElement, Buffer: BufferType> PartialEq<&[OffsetItem]>
+    for Offsets<OffsetItem, Buffer>
+{
+    fn eq(&self, other: &&[OffsetItem]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<const N: usize, OffsetItem: OffsetElement, Buffer: BufferType> PartialEq<[OffsetItem; N]>
+    for Offsets<OffsetItem, Buffer>
+{
+    fn eq(&self, other: &[OffsetItem; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<const N: usize, OffsetItem: OffsetElement, Buffer: BufferType> PartialEq<&[OffsetItem; N]>
+    for Offsets<OffsetItem, Buffer>
+{
+    fn eq(&self, other: &&[OffsetItem; N]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
 /// A reference to a slot in an offset
 #[allow(unused)]
 pub struct OffsetSlot<'a, OffsetItem: OffsetElement, Buffer: BufferType> {
@@ -83,7 +355,7 @@ pub struct OffsetSlot<'a, OffsetItem: OffsetElement, Buffer: BufferType> {
     index: usize,
 }
 
-impl<'a, OffsetItem: OffsetElement, Buffer: BufferType> OffsetSlot<'a, OffsetItem, Buffer> {
+impl<OffsetItem: OffsetElement, Buffer: BufferType> OffsetSlot<'_, OffsetItem, Buffer> {
     /// Returns the position of this slot in the buffer i.e. the index.
     #[must_use]
     pub fn position(&self) -> usize {
@@ -183,19 +455,18 @@ pub struct Offset<
     OffsetItem: OffsetElement = i32,
     Buffer: BufferType = VecBuffer,
 > where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
 {
     /// The data
     pub data: T,
     /// The offsets
-    pub offsets:
-        <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<NULLABLE>>::Storage<Buffer>,
+    pub offsets: <Offsets<OffsetItem, Buffer> as Validity<NULLABLE>>::Storage<Buffer>,
 }
 
 impl<const NULLABLE: bool, T, OffsetItem: OffsetElement, Buffer: BufferType>
     Offset<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Index,
 {
     /// Returns an iteratover over the offset items in this [`Offset`].
@@ -204,35 +475,44 @@ where
     }
 }
 
-impl<T: Default, OffsetItem: OffsetElement, Buffer: BufferType> Default
-    for Offset<T, false, OffsetItem, Buffer>
+impl<T: Clone, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Clone
+    for Offset<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Default + Extend<OffsetItem>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    <Offsets<OffsetItem, Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Clone,
 {
-    fn default() -> Self {
-        let mut offsets = <Buffer as BufferType>::Buffer::<OffsetItem>::default();
-        offsets.extend(iter::once(OffsetItem::default()));
+    fn clone(&self) -> Self {
         Self {
-            data: T::default(),
-            offsets,
+            data: self.data.clone(),
+            offsets: self.offsets.clone(),
         }
     }
 }
 
-impl<T: Default, OffsetItem: OffsetElement, Buffer: BufferType> Default
-    for Offset<T, true, OffsetItem, Buffer>
+impl<T: Debug, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Debug
+    for Offset<T, NULLABLE, OffsetItem, Buffer>
 where
-    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<true>>::Storage<Buffer>: Default,
-    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    <Offsets<OffsetItem, Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Offset")
+            .field("data", &self.data)
+            .field("offsets", &self.offsets)
+            .finish()
+    }
+}
+
+impl<T: Default, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Default
+    for Offset<T, NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    <Offsets<OffsetItem, Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Default,
 {
     fn default() -> Self {
-        let mut offsets = <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<true>>::Storage::<
-            Buffer,
-        >::default();
-        offsets.data.extend(iter::once(OffsetItem::default()));
         Self {
             data: T::default(),
-            offsets,
+            offsets: Default::default(),
         }
     }
 }
@@ -241,25 +521,12 @@ impl<T, U: IntoIterator + Length, OffsetItem: OffsetElement, Buffer: BufferType>
     for Offset<T, false, OffsetItem, Buffer>
 where
     T: Extend<<U as IntoIterator>::Item>,
-    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+    Offsets<OffsetItem, Buffer>: Extend<usize>,
 {
     fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
-        let mut state = self
-            .offsets
-            .as_slice()
-            .last()
-            .copied()
-            .expect("at least one value in the offsets buffer");
         self.data.extend(
             iter.into_iter()
-                .inspect(|item| {
-                    state = state
-                        .checked_add_unsigned(
-                            OffsetItem::Unsigned::try_from(item.len()).expect("len overflow"),
-                        )
-                        .expect("offset value overflow");
-                    self.offsets.extend(iter::once(state));
-                })
+                .inspect(|item| self.offsets.extend(iter::once(item.len())))
                 .flatten(),
         );
     }
@@ -269,26 +536,13 @@ impl<T, U: IntoIterator + Length, OffsetItem: OffsetElement, Buffer: BufferType>
     for Offset<T, true, OffsetItem, Buffer>
 where
     T: Extend<<U as IntoIterator>::Item>,
-    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<true>>::Storage<Buffer>:
-        Extend<(bool, OffsetItem)>,
+    <Offsets<OffsetItem, Buffer> as Validity<true>>::Storage<Buffer>: Extend<(bool, usize)>,
 {
     fn extend<I: IntoIterator<Item = Option<U>>>(&mut self, iter: I) {
-        let mut state = self
-            .offsets
-            .as_ref()
-            .as_slice()
-            .last()
-            .copied()
-            .expect("at least one value in the offsets buffer");
         self.data.extend(
             iter.into_iter()
                 .inspect(|opt| {
-                    state = state
-                        .checked_add_unsigned(
-                            OffsetItem::Unsigned::try_from(opt.len()).expect("len overflow"),
-                        )
-                        .expect("offset value overflow");
-                    self.offsets.extend(iter::once((opt.is_some(), state)));
+                    self.offsets.extend(iter::once((opt.is_some(), opt.len())));
                 })
                 .flatten()
                 .flatten(),
@@ -321,7 +575,7 @@ impl<T, U: IntoIterator + Length, OffsetItem: OffsetElement, Buffer: BufferType>
 where
     Self: Default,
     T: Extend<<U as IntoIterator>::Item>,
-    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+    Offsets<OffsetItem, Buffer>: Extend<usize>,
 {
     fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
         let mut offset = Self::default();
@@ -335,8 +589,7 @@ impl<T, U: IntoIterator + Length, OffsetItem: OffsetElement, Buffer: BufferType>
 where
     Self: Default,
     T: Extend<<U as IntoIterator>::Item>,
-    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<true>>::Storage<Buffer>:
-        Extend<(bool, OffsetItem)>,
+    <Offsets<OffsetItem, Buffer> as Validity<true>>::Storage<Buffer>: Extend<(bool, usize)>,
 {
     fn from_iter<I: IntoIterator<Item = Option<U>>>(iter: I) -> Self {
         let mut offset = Self::default();
@@ -346,14 +599,13 @@ where
 }
 
 impl<T, U: IntoIterator + Length, OffsetItem: OffsetElement, Buffer: BufferType>
-    FromIterator<std::option::IntoIter<U>> for Offset<T, true, OffsetItem, Buffer>
+    FromIterator<core::option::IntoIter<U>> for Offset<T, true, OffsetItem, Buffer>
 where
     Self: Default,
     T: Extend<<U as IntoIterator>::Item>,
-    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<true>>::Storage<Buffer>:
-        Extend<(bool, OffsetItem)>,
+    <Offsets<OffsetItem, Buffer> as Validity<true>>::Storage<Buffer>: Extend<(bool, usize)>,
 {
-    fn from_iter<I: IntoIterator<Item = std::option::IntoIter<U>>>(iter: I) -> Self {
+    fn from_iter<I: IntoIterator<Item = core::option::IntoIter<U>>>(iter: I) -> Self {
         let mut offset = Self::default();
         offset.extend(iter.into_iter().map(|mut v| v.next()));
         offset
@@ -363,7 +615,7 @@ where
 /// An iterator over items in an offset.
 pub struct OffsetSlice<'a, T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
 {
     /// The offset storing the values and offsets
     offset: &'a Offset<T, NULLABLE, OffsetItem, Buffer>,
@@ -375,10 +627,10 @@ where
 
 // TODO(mbrobbel): this is the remaining items in the iterator, maybe we want
 // this to be the original slot length?
-impl<'a, T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
-    for OffsetSlice<'a, T, NULLABLE, OffsetItem, Buffer>
+impl<T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
+    for OffsetSlice<'_, T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
 {
     #[inline]
     fn len(&self) -> usize {
@@ -386,10 +638,25 @@ where
     }
 }
 
+impl<'a, T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Debug
+    for OffsetSlice<'a, T, NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    T: Index,
+    <T as Index>::Item<'a>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data: &'a T = &self.offset.data;
+        f.debug_list()
+            .entries((self.index..self.end).map(|index| data.index_checked(index)))
+            .finish()
+    }
+}
+
 impl<'a, T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Iterator
     for OffsetSlice<'a, T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     T: Index,
 {
     type Item = <T as Index>::Item<'a>;
@@ -462,7 +729,7 @@ impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Index
 /// An iterator over an offset.
 pub struct OffsetIter<'a, const NULLABLE: bool, T, OffsetItem: OffsetElement, Buffer: BufferType>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
 {
     /// The offset being iterated over
     offset: &'a Offset<T, NULLABLE, OffsetItem, Buffer>,
@@ -473,7 +740,7 @@ where
 impl<'a, const NULLABLE: bool, T, OffsetItem: OffsetElement, Buffer: BufferType> Iterator
     for OffsetIter<'a, NULLABLE, T, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Index,
 {
     type Item = <Offset<T, NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>;
@@ -492,7 +759,7 @@ where
 impl<'a, const NULLABLE: bool, T, OffsetItem: OffsetElement, Buffer: BufferType> IntoIterator
     for &'a Offset<T, NULLABLE, OffsetItem, Buffer>
 where
-    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Index,
 {
     type Item = <Offset<T, NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>;
@@ -564,7 +831,7 @@ where
     fn into_iter(self) -> Self::IntoIter {
         OffsetIntoIter {
             data: self.data.into_iter(),
-            offsets: self.offsets.into_iter().peekable(),
+            offsets: self.offsets.0.into_iter().peekable(),
         }
     }
 }
@@ -596,7 +863,7 @@ where
             // values as indicated by the validity bitmap.
             .zip(OffsetIntoIter {
                 data: self.data.into_iter(),
-                offsets: self.offsets.data.into_iter().peekable(),
+                offsets: self.offsets.data.0.into_iter().peekable(),
             })
             .map(|(validity, value): (bool, Vec<_>)| validity.then_some(value))
     }
@@ -606,11 +873,7 @@ impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Length
     for Offset<T, false, OffsetItem, Buffer>
 {
     fn len(&self) -> usize {
-        // The offsets buffer has an additional value
-        self.offsets
-            .len()
-            .checked_sub(1)
-            .expect("offset len underflow")
+        self.offsets.len()
     }
 }
 
@@ -646,6 +909,47 @@ impl<T, OffsetItem: OffsetElement, Buffer: BufferType> ValidityBitmap
 {
 }
 
+impl<T: MemoryUsage, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
+    MemoryUsage for Offset<T, NULLABLE, OffsetItem, Buffer>
+where
+    Offsets<OffsetItem, Buffer>: Validity<NULLABLE>,
+    <Offsets<OffsetItem, Buffer> as Validity<NULLABLE>>::Storage<Buffer>: MemoryUsage,
+{
+    fn buffer_bytes(&self) -> usize {
+        self.data.buffer_bytes() + self.offsets.buffer_bytes()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.data.allocated_bytes() + self.offsets.allocated_bytes()
+    }
+}
+
+impl<T: Length + Validate, OffsetItem: OffsetElement, Buffer: BufferType> Validate
+    for Offset<T, false, OffsetItem, Buffer>
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_offsets(self.offsets.as_slice(), self.data.len())?;
+        self.data.validate()
+    }
+}
+
+impl<T: Length + Validate, OffsetItem: OffsetElement, Buffer: BufferType> Validate
+    for Offset<T, true, OffsetItem, Buffer>
+{
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_offsets(self.offsets.data.as_slice(), self.data.len())?;
+        self.offsets.validity.validate()?;
+        let expected = self.offsets.data.len();
+        if self.offsets.validity.len() != expected {
+            return Err(ValidationError::ValidityLength(LengthMismatch {
+                expected,
+                actual: self.offsets.validity.len(),
+            }));
+        }
+        self.data.validate()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -808,4 +1112,119 @@ mod tests {
         assert_eq!(offset_nullable.len(), 3);
         assert!(offset_nullable.all_valid());
     }
+
+    #[test]
+    fn offsets() {
+        let mut offsets = [3, 0, 2].into_iter().collect::<Offsets>();
+        assert_eq!(offsets.as_slice(), [0, 3, 3, 5]);
+        assert_eq!(offsets.len(), 3);
+        assert_eq!(offsets.index(0), Some((0, 3)));
+        // Empty slots start and end at the same offset.
+        assert_eq!(offsets.index(1), Some((3, 3)));
+        assert_eq!(offsets.index(3), None);
+
+        offsets.extend([0, 4]);
+        assert_eq!(offsets.last(), 9);
+        assert_eq!(Offsets::<i64>::default().as_slice(), [0]);
+        assert_eq!(Offsets::<i64>::default().len(), 0);
+    }
+
+    #[test]
+    fn offsets_overflow() {
+        let max = usize::try_from(i32::MAX).expect("fits");
+        let mut offsets = Offsets::<i32>::default();
+        assert_eq!(offsets.try_push(max - 1), Ok(()));
+        assert_eq!(offsets.try_push(1), Ok(()));
+        assert_eq!(offsets.try_push(1), Err(OffsetOverflow));
+        assert_eq!(offsets.try_push(0), Ok(()));
+        assert_eq!(offsets.as_slice(), [0, i32::MAX - 1, i32::MAX, i32::MAX]);
+
+        assert_eq!(
+            Offsets::<i32>::default().try_push(usize::MAX),
+            Err(OffsetOverflow)
+        );
+        let mut large = Offsets::<i64>::default();
+        assert_eq!(large.try_push(max), Ok(()));
+        assert_eq!(large.try_push(1), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "offset value overflow")]
+    fn offsets_extend_overflow() {
+        let max = usize::try_from(i32::MAX).expect("fits");
+        let _: Offsets = [max, 1].into_iter().collect();
+    }
+
+    #[test]
+    fn offsets_slice() {
+        let offsets = [1, 2, 0, 3].into_iter().collect::<Offsets<i64>>();
+        let slice = offsets.slice(1..3);
+        assert_eq!(slice.as_slice(), [1, 3, 3]);
+        assert_eq!(slice.len(), 2);
+        assert_eq!(slice.index(0), Some((1, 3)));
+        assert_eq!(slice.index(1), Some((3, 3)));
+        assert_eq!(offsets.slice(4..4).len(), 0);
+        assert_eq!(offsets.slice(0..4), offsets);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice range out of bounds")]
+    fn offsets_slice_out_of_bounds() {
+        let offsets = [1, 2].into_iter().collect::<Offsets>();
+        let _ = offsets.slice(1..3);
+    }
+
+    #[test]
+    fn offsets_validation() {
+        assert_eq!(
+            Offsets::<i32>::try_from_parts(vec![0, 2, 2, 5], 5),
+            Ok(Offsets(vec![0, 2, 2, 5]))
+        );
+        // Slices start at a non-zero offset.
+        assert!(Offsets::<i32>::try_from_parts(vec![2, 5], 5).is_ok());
+        assert_eq!(
+            Offsets::<i32>::try_from_parts(vec![], 0),
+            Err(ValidationError::OffsetsEmpty)
+        );
+        assert_eq!(
+            Offsets::<i64>::try_from_parts(vec![-1, 2], 2),
+            Err(ValidationError::OffsetOutOfBounds { index: 0, len: 2 })
+        );
+        assert_eq!(
+            Offsets::<i32>::try_from_parts(vec![0, 3, 2, 4], 4),
+            Err(ValidationError::OffsetDecreasing { index: 2 })
+        );
+        assert_eq!(
+            Offsets::<i32>::try_from_parts(vec![0, 1, 6], 5),
+            Err(ValidationError::OffsetOutOfBounds { index: 2, len: 5 })
+        );
+    }
+
+    #[test]
+    fn offset_validate() {
+        use crate::array::FixedSizePrimitiveArray;
+
+        let mut offset = [vec![1_u8, 2], vec![3]]
+            .into_iter()
+            .collect::<Offset<FixedSizePrimitiveArray<u8>>>();
+        assert_eq!(offset.validate(), Ok(()));
+        offset.offsets = Offsets(vec![0, 2, 4]);
+        assert_eq!(
+            offset.validate(),
+            Err(ValidationError::OffsetOutOfBounds { index: 2, len: 3 })
+        );
+
+        let mut nullable = [Some(vec![1_u8]), None]
+            .into_iter()
+            .collect::<Offset<FixedSizePrimitiveArray<u8>, true>>();
+        assert_eq!(nullable.validate(), Ok(()));
+        nullable.offsets.data.0.push(1);
+        assert_eq!(
+            nullable.validate(),
+            Err(ValidationError::ValidityLength(LengthMismatch {
+                expected: 3,
+                actual: 2
+            }))
+        );
+    }
 }
@@ -0,0 +1,146 @@
+//! [proptest](https://docs.rs/proptest) strategies for generating arrays.
+//!
+//! The strategies favour lengths around byte boundaries and generate runs of
+//! equal bits (e.g. runs of nulls), which exercise the word-based code paths
+//! of bitmaps.
+//!
+//! ```
+//! use narrow::{bitmap::ValidityBitmap, proptest::nullable_primitive_array, Length};
+//! use proptest::{prop_assert_eq, proptest};
+//!
+//! proptest!(|(array in nullable_primitive_array::<u32>(0..100, 0.2))| {
+//!     prop_assert_eq!(array.null_count() + array.valid_count(), array.len());
+//! });
+//! ```
+
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray},
+    bitmap::Bitmap,
+    FixedSize,
+};
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    bool::weighted,
+    collection::{vec, SizeRange},
+    sample::Index,
+    strategy::Strategy,
+};
+
+/// The maximum length of the runs of equal bits.
+const MAX_RUN: usize = 130;
+
+/// Returns a strategy for lengths in `len` that favours multiples of 8 and
+/// their neighbours.
+fn length(len: impl Into<SizeRange>) -> impl Strategy<Value = usize> {
+    let size: SizeRange = len.into();
+    let (start, end) = (size.start(), size.end_incl());
+    (start..=end, any::<bool>(), -1_isize..=1).prop_map(move |(random, aligned, delta)| {
+        if aligned {
+            (random / 8 * 8)
+                .saturating_add_signed(delta)
+                .clamp(start, end)
+        } else {
+            random
+        }
+    })
+}
+
+/// Returns a strategy for `len` bits that are set with the given probability,
+/// with up to four runs of equal bits.
+fn bits(len: impl Into<SizeRange>, probability: f64) -> impl Strategy<Value = Vec<bool>> {
+    length(len).prop_flat_map(move |bit_len| {
+        (
+            vec(weighted(probability), bit_len),
+            vec((any::<Index>(), 1..=MAX_RUN, weighted(probability)), 0..=4),
+        )
+            .prop_map(|(mut bits, runs)| {
+                if !bits.is_empty() {
+                    for (start, run, value) in runs {
+                        let from = start.index(bits.len());
+                        let to = bits.len().min(from + run);
+                        bits[from..to].fill(value);
+                    }
+                }
+                bits
+            })
+    })
+}
+
+/// Returns a strategy for [`FixedSizePrimitiveArray`]s with a length in `len`.
+pub fn primitive_array<T: FixedSize + Arbitrary>(
+    len: impl Into<SizeRange>,
+) -> impl Strategy<Value = FixedSizePrimitiveArray<T>> {
+    length(len)
+        .prop_flat_map(|array_len| vec(any::<T>(), array_len).prop_map(FromIterator::from_iter))
+}
+
+/// Returns a strategy for nullable [`FixedSizePrimitiveArray`]s with a length
+/// in `len`, where values are null with probability `null_probability`.
+///
+/// # Panics
+///
+/// Panics if `null_probability` is not in the range `0.0..=1.0`.
+pub fn nullable_primitive_array<T: FixedSize + Arbitrary>(
+    len: impl Into<SizeRange>,
+    null_probability: f64,
+) -> impl Strategy<Value = FixedSizePrimitiveArray<T, true>> {
+    bits(len, 1. - null_probability).prop_flat_map(|validity| {
+        vec(any::<T>(), validity.len()).prop_map(move |values| {
+            FixedSizePrimitiveArray::<T, true>::try_from_parts(values, validity.iter().collect())
+                .expect("lengths match")
+        })
+    })
+}
+
+/// Returns a strategy for [`BooleanArray`]s with a length in `len`, where
+/// values are `true` with probability `true_probability`.
+///
+/// # Panics
+///
+/// Panics if `true_probability` is not in the range `0.0..=1.0`.
+pub fn boolean_array(
+    len: impl Into<SizeRange>,
+    true_probability: f64,
+) -> impl Strategy<Value = BooleanArray> {
+    bits(len, true_probability).prop_map(FromIterator::from_iter)
+}
+
+/// Returns a strategy for [`Bitmap`]s with a length in `len`, where bits are
+/// set with probability `probability`.
+///
+/// # Panics
+///
+/// Panics if `probability` is not in the range `0.0..=1.0`.
+pub fn bitmap(len: impl Into<SizeRange>, probability: f64) -> impl Strategy<Value = Bitmap> {
+    bits(len, probability).prop_map(FromIterator::from_iter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bitmap::ValidityBitmap, Length};
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn lengths(len in length(5..=20)) {
+            prop_assert!((5..=20).contains(&len));
+        }
+
+        #[test]
+        fn nullable(array in nullable_primitive_array::<u16>(0..300, 0.3)) {
+            prop_assert_eq!(array.null_count() + array.valid_count(), array.len());
+            prop_assert!(array.len() < 300);
+        }
+
+        #[test]
+        fn bitmaps(bitmap in bitmap(0..300, 1.)) {
+            prop_assert_eq!(bitmap.count_ones(), bitmap.len());
+        }
+
+        #[test]
+        fn primitive(array in primitive_array::<i64>(3)) {
+            prop_assert_eq!(array.len(), 3);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Random array generation with [rand](https://docs.rs/rand).
+//!
+//! The values are sampled into a [`Vec`] that becomes the data buffer of the
+//! array, and validity bitmaps are generated byte by byte. The output only
+//! depends on the state of the given random number generator, so a seeded
+//! generator produces the same arrays.
+//!
+//! ```
+//! use narrow::{bitmap::ValidityBitmap, rand::random_nullable, Length};
+//! use rand::{rngs::SmallRng, SeedableRng};
+//!
+//! let mut rng = SmallRng::seed_from_u64(1234);
+//! let array = random_nullable::<u32, _>(&mut rng, 1000, 0.1);
+//! assert_eq!(array.len(), 1000);
+//! assert!(array.null_count() < 200);
+//! ```
+
+use crate::{
+    array::{BooleanArray, FixedSizePrimitiveArray},
+    bitmap::{bytes_for_bits, Bitmap},
+    FixedSize,
+};
+use alloc::{vec, vec::Vec};
+use rand::{
+    distributions::{Bernoulli, Distribution, Standard},
+    Rng,
+};
+
+/// Returns a bitmap with `len` bits that are set with the given probability.
+///
+/// # Panics
+///
+/// Panics if the probability is not in the range `0.0..=1.0`.
+#[allow(clippy::float_cmp)]
+fn random_bitmap<R: Rng + ?Sized>(rng: &mut R, len: usize, probability: f64) -> Bitmap {
+    let mut bytes = vec![0; bytes_for_bits(len)];
+    if probability == 0.5 {
+        // All bit patterns are equally likely.
+        rng.fill_bytes(&mut bytes);
+    } else {
+        let bernoulli = Bernoulli::new(probability).expect("probability should be in 0..=1");
+        for byte in &mut bytes {
+            *byte = (0..8).fold(0, |acc, bit| acc | u8::from(bernoulli.sample(rng)) << bit);
+        }
+    }
+    let mut bitmap = Bitmap::from_bytes(bytes, len).expect("enough bytes");
+    bitmap.normalize();
+    bitmap
+}
+
+/// Returns a [`FixedSizePrimitiveArray`] with `len` values sampled from the
+/// given distribution.
+pub fn random_primitive_array<T: FixedSize, R: Rng + ?Sized, D: Distribution<T>>(
+    rng: &mut R,
+    len: usize,
+    distribution: D,
+) -> FixedSizePrimitiveArray<T> {
+    let values = distribution.sample_iter(rng).take(len).collect::<Vec<_>>();
+    FixedSizePrimitiveArray::from(values)
+}
+
+/// Returns a nullable [`FixedSizePrimitiveArray`] with `len` values sampled
+/// from the [`Standard`] distribution, where values are null with probability
+/// `null_fraction`.
+///
+/// Null values are sampled as well, so the values do not depend on the
+/// validity.
+///
+/// # Panics
+///
+/// Panics if `null_fraction` is not in the range `0.0..=1.0`.
+pub fn random_nullable<T: FixedSize, R: Rng + ?Sized>(
+    rng: &mut R,
+    len: usize,
+    null_fraction: f64,
+) -> FixedSizePrimitiveArray<T, true>
+where
+    Standard: Distribution<T>,
+{
+    let values = (&mut *rng)
+        .sample_iter(Standard)
+        .take(len)
+        .collect::<Vec<_>>();
+    let validity = random_bitmap(rng, len, 1. - null_fraction);
+    FixedSizePrimitiveArray::try_from_parts(values, validity).expect("lengths match")
+}
+
+/// Returns a [`BooleanArray`] with `len` values that are `true` with
+/// probability `true_fraction`.
+///
+/// # Panics
+///
+/// Panics if `true_fraction` is not in the range `0.0..=1.0`.
+pub fn random_boolean_array<R: Rng + ?Sized>(
+    rng: &mut R,
+    len: usize,
+    true_fraction: f64,
+) -> BooleanArray {
+    BooleanArray(random_bitmap(rng, len, true_fraction))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bitmap::ValidityBitmap, Length};
+    use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn null_fraction() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        for null_fraction in [0., 0.1, 0.5, 0.9, 1.] {
+            let array = random_nullable::<u64, _>(&mut rng, 10_000, null_fraction);
+            assert_eq!(array.len(), 10_000);
+            let expected = 10_000. * null_fraction;
+            let nulls = f64::from(u32::try_from(array.null_count()).expect("fits"));
+            assert!((nulls - expected).abs() < 300., "{nulls} vs {expected}");
+            assert_eq!(array.0.validity.buffer.len(), 1250);
+        }
+
+        let booleans = random_boolean_array(&mut rng, 1003, 0.25);
+        assert_eq!(booleans.len(), 1003);
+        let trues = f64::from(u32::try_from(booleans.true_count()).expect("fits"));
+        assert!((trues - 250.).abs() < 60.);
+    }
+
+    #[test]
+    fn seeded() {
+        let generate = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (
+                random_primitive_array(&mut rng, 100, Uniform::new(0, 10)),
+                random_nullable::<i16, _>(&mut rng, 101, 0.3),
+                random_boolean_array(&mut rng, 102, 0.5),
+            )
+        };
+        let (values, nullable, booleans) = generate(42);
+        assert!(values.iter().all(|&value: &i32| (0..10).contains(&value)));
+        assert_eq!(generate(42), (values.clone(), nullable.clone(), booleans));
+        assert_ne!(generate(43).0, values);
+        assert_ne!(generate(43).1, nullable);
+    }
+}
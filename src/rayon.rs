@@ -0,0 +1,394 @@
+//! Parallel iteration and collection of arrays with rayon.
+//!
+//! References to primitive arrays implement [`IntoParallelIterator`], which
+//! makes `par_iter` of rayon's [`IntoParallelRefIterator`] available.
+//!
+//! [`IntoParallelRefIterator`]: rayon::iter::IntoParallelRefIterator
+
+use crate::{
+    array::FixedSizePrimitiveArray,
+    bitmap::{bytes_for_bits, Bitmap},
+    buffer::{Buffer as _, BufferType, GrowableBuffer, VecBuffer},
+    nullable::Nullable,
+    FixedSize, Length,
+};
+use rayon::{
+    iter::{
+        plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer},
+        FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, ParallelExtend,
+        ParallelIterator,
+    },
+    slice,
+};
+
+/// Appends the bits of `other` to `bitmap`, shifting the bytes of `other` when
+/// `bitmap` does not end at a byte boundary.
+///
+/// Both bitmaps must have no offset and unset padding bits, which holds for
+/// bitmaps that are collected from iterators.
+fn append_bitmap(bitmap: &mut Bitmap<VecBuffer>, other: Bitmap<VecBuffer>) {
+    let shift = bitmap.bits % 8;
+    let bits = bitmap
+        .bits
+        .checked_add(other.bits)
+        .expect("bitmap length overflow");
+    if shift == 0 {
+        bitmap.buffer.extend(other.buffer);
+    } else {
+        for byte in other.buffer {
+            if let Some(last) = bitmap.buffer.last_mut() {
+                *last |= byte << shift;
+            }
+            bitmap.buffer.push(byte >> (8 - shift));
+        }
+        bitmap.buffer.truncate(bytes_for_bits(bits));
+    }
+    bitmap.bits = bits;
+}
+
+impl<T: FixedSize + Send, Buffer: BufferType> FromParallelIterator<T>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+{
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        // Rayon collects the values of each thread in a `Vec` and concatenates
+        // them in order.
+        let values = par_iter.into_par_iter().collect::<Vec<_>>();
+        Self(values.into_iter().collect())
+    }
+}
+
+impl<T: FixedSize + Send, Buffer: GrowableBuffer> ParallelExtend<T>
+    for FixedSizePrimitiveArray<T, false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Extend<T>,
+{
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        let values = par_iter.into_par_iter().collect::<Vec<_>>();
+        self.0.extend(values);
+    }
+}
+
+impl<T: FixedSize + Default + Send, Buffer: BufferType> FromParallelIterator<Option<T>>
+    for FixedSizePrimitiveArray<T, true, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: FromIterator<T>,
+    <Buffer as BufferType>::Buffer<u8>: FromIterator<u8>,
+{
+    fn from_par_iter<I: IntoParallelIterator<Item = Option<T>>>(par_iter: I) -> Self {
+        // Each thread collects its items in a nullable chunk, and the chunks
+        // are merged in order.
+        let chunks = par_iter
+            .into_par_iter()
+            .fold(Vec::new, |mut items, item| {
+                items.push(item);
+                items
+            })
+            .map(|items| items.into_iter().collect::<Nullable<Vec<T>>>())
+            .collect::<Vec<_>>();
+
+        let mut data = Vec::with_capacity(chunks.iter().map(Length::len).sum());
+        let mut validity = Bitmap::<VecBuffer>::default();
+        for chunk in chunks {
+            let (chunk_data, chunk_validity) = chunk.into_parts();
+            data.extend(chunk_data);
+            append_bitmap(&mut validity, chunk_validity);
+        }
+
+        // Safety:
+        // - Every chunk has equal data and validity lengths.
+        Self(unsafe {
+            Nullable::from_parts_unchecked(
+                data.into_iter().collect(),
+                Bitmap {
+                    buffer: validity.buffer.into_iter().collect(),
+                    bits: validity.bits,
+                    offset: 0,
+                },
+            )
+        })
+    }
+}
+
+impl<'a, T: FixedSize + Sync, Buffer: BufferType> IntoParallelIterator
+    for &'a FixedSizePrimitiveArray<T, false, Buffer>
+{
+    type Iter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.0.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T: FixedSize + Send + Sync, Buffer: BufferType> IntoParallelIterator
+    for &'a FixedSizePrimitiveArray<T, true, Buffer>
+{
+    type Iter = NullableParIter<'a, T>;
+    type Item = Option<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        NullableParIter {
+            values: self.0.data.as_slice(),
+            validity: self.0.validity.buffer.as_slice(),
+            offset: self.0.validity.offset,
+        }
+    }
+}
+
+/// A parallel iterator over the items of a nullable primitive array.
+///
+/// This is also the producer that rayon splits, at arbitrary (bit) positions,
+/// to distribute the items over threads.
+#[derive(Clone, Copy, Debug)]
+pub struct NullableParIter<'a, T> {
+    /// The values.
+    values: &'a [T],
+    /// The bytes of the validity bitmap.
+    validity: &'a [u8],
+    /// The bit offset of the first value in the validity bytes.
+    offset: usize,
+}
+
+impl<T: FixedSize + Send + Sync> ParallelIterator for NullableParIter<'_, T> {
+    type Item = Option<T>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+impl<T: FixedSize + Send + Sync> IndexedParallelIterator for NullableParIter<'_, T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(self)
+    }
+}
+
+impl<'a, T: FixedSize + Send + Sync> Producer for NullableParIter<'a, T> {
+    type Item = Option<T>;
+    type IntoIter = NullableIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        NullableIter {
+            values: self.values,
+            validity: self.validity,
+            offset: self.offset,
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.values.split_at(index);
+        (
+            Self {
+                values: left,
+                validity: self.validity,
+                offset: self.offset,
+            },
+            Self {
+                values: right,
+                validity: self.validity,
+                offset: self.offset + index,
+            },
+        )
+    }
+}
+
+/// A sequential iterator over a part of the items of a nullable primitive
+/// array, used by [`NullableParIter`].
+#[derive(Clone, Debug)]
+pub struct NullableIter<'a, T> {
+    /// The remaining values.
+    values: &'a [T],
+    /// The bytes of the validity bitmap.
+    validity: &'a [u8],
+    /// The bit offset of the first remaining value in the validity bytes.
+    offset: usize,
+}
+
+impl<T: Copy> NullableIter<'_, T> {
+    /// Returns the item at `index` of the remaining values.
+    fn item(&self, index: usize) -> Option<T> {
+        let bit = self.offset + index;
+        (self.validity[bit / 8] & (1 << (bit % 8)) != 0).then(|| self.values[index])
+    }
+}
+
+impl<T: Copy> Iterator for NullableIter<'_, T> {
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (!self.values.is_empty()).then(|| {
+            let item = self.item(0);
+            self.values = &self.values[1..];
+            self.offset += 1;
+            item
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.values.len(), Some(self.values.len()))
+    }
+}
+
+impl<T: Copy> DoubleEndedIterator for NullableIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.values.len().checked_sub(1).map(|last| {
+            let item = self.item(last);
+            self.values = &self.values[..last];
+            item
+        })
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for NullableIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Float64Array, Uint32Array};
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+    use rayon::iter::IntoParallelRefIterator;
+
+    #[test]
+    fn from_par_iter() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..2000);
+            let chunk_len = rng.gen_range(1..20);
+            let input = (0..len).map(|_| rng.gen::<u32>()).collect::<Vec<_>>();
+            let parallel = input
+                .par_iter()
+                .with_max_len(chunk_len)
+                .copied()
+                .collect::<Uint32Array>();
+            assert_eq!(parallel, input.iter().copied().collect::<Uint32Array>());
+
+            let mut extended = parallel.clone();
+            extended.par_extend(input.par_iter().with_max_len(chunk_len).copied());
+            assert_eq!(extended.len(), 2 * len);
+            assert_eq!(&extended.as_slice()[len..], input.as_slice());
+        }
+    }
+
+    #[test]
+    fn from_par_iter_nullable() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..2000);
+            // Chunks with lengths that are not multiples of 8 result in
+            // chunk validity bitmaps that don't end at byte boundaries.
+            let chunk_len = rng.gen_range(1..20);
+            let input = (0..len)
+                .map(|_| rng.gen_bool(0.7).then(|| rng.gen::<f64>()))
+                .collect::<Vec<_>>();
+            let parallel = input
+                .par_iter()
+                .with_max_len(chunk_len)
+                .copied()
+                .collect::<Float64Array<true>>();
+            let sequential = input.iter().copied().collect::<Float64Array<true>>();
+            assert_eq!(parallel, sequential);
+            assert_eq!(parallel.0.validity.buffer, sequential.0.validity.buffer);
+        }
+    }
+
+    #[test]
+    fn append_bitmap() {
+        let mut bitmap = [true, false, true].into_iter().collect::<Bitmap>();
+        super::append_bitmap(&mut bitmap, [false; 9].into_iter().collect());
+        super::append_bitmap(&mut bitmap, [true; 4].into_iter().collect());
+        assert_eq!(
+            bitmap.iter().collect::<Vec<_>>(),
+            [
+                true, false, true, false, false, false, false, false, false, false, false, false,
+                true, true, true, true
+            ]
+        );
+        assert_eq!(bitmap.buffer, [0b0000_0101, 0b1111_0000]);
+    }
+
+    #[test]
+    fn par_iter() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..2000);
+            let array = (0..len)
+                .map(|_| rng.gen_range(0..1000))
+                .collect::<Uint32Array>();
+            assert_eq!(
+                array.par_iter().with_max_len(7).copied().sum::<u32>(),
+                array.iter().sum::<u32>()
+            );
+            assert_eq!(
+                array.par_iter().map(|value| value % 3).collect::<Vec<_>>(),
+                array.iter().map(|value| value % 3).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn par_iter_nullable() {
+        let mut rng = SmallRng::seed_from_u64(1234);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..2000);
+            let array = (0..len)
+                .map(|_| rng.gen_bool(0.7).then(|| rng.gen_range(0..1000)))
+                .collect::<Uint32Array<true>>();
+            let sequential = array.iter().map(Option::<&_>::copied).collect::<Vec<_>>();
+            assert_eq!(
+                array
+                    .par_iter()
+                    .with_max_len(rng.gen_range(1..20))
+                    .flatten()
+                    .sum::<u32>(),
+                sequential.iter().flatten().sum::<u32>()
+            );
+            assert_eq!(array.par_iter().collect::<Vec<_>>(), sequential);
+            assert_eq!(
+                array.par_iter().rev().collect::<Vec<_>>(),
+                sequential.into_iter().rev().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn split_at_odd_position() {
+        let array = [
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            None,
+            Some(6),
+            None,
+            Some(8),
+            Some(9),
+        ]
+        .into_iter()
+        .collect::<Uint32Array<true>>();
+        let (left, right) = array.par_iter().split_at(3);
+        assert_eq!(
+            Producer::into_iter(left).collect::<Vec<_>>(),
+            [Some(1), None, Some(3)]
+        );
+        let (middle, last) = right.split_at(5);
+        assert_eq!(
+            Producer::into_iter(middle).rev().collect::<Vec<_>>(),
+            [Some(8), None, Some(6), None, Some(4)]
+        );
+        assert_eq!(Producer::into_iter(last).collect::<Vec<_>>(), [Some(9)]);
+    }
+}
@@ -0,0 +1,283 @@
+//! Collection of asynchronous streams into arrays.
+//!
+//! The [`Stream`] trait is re-exported from the `futures-core` crate, so
+//! streams of the `futures` ecosystem can be collected directly.
+//!
+//! # Cancellation safety
+//!
+//! The future returned by [`collect_array`] owns the stream and the
+//! in-progress array. Dropping it before it completes drops both: no items
+//! leak, and items that were not polled from the stream remain in the stream
+//! until it is dropped.
+//!
+//! A [`ChunkStream`] keeps the in-progress chunk in the stream itself, so
+//! items that were received before a pending poll are not lost when the
+//! caller stops polling, and are emitted when polling resumes.
+
+use crate::{
+    array::{union, ArrayType, ChunkPolicy},
+    buffer::VecBuffer,
+    builder::ChunkingBuilder,
+    offset, Length, MemoryUsage,
+};
+use core::{
+    future, iter,
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+pub use futures_core::Stream;
+
+/// Returns an array with the items of the stream.
+///
+/// Items are appended to the array as they arrive, via its [`Extend`]
+/// implementation.
+pub async fn collect_array<T: ArrayType<U>, U: ?Sized, S: Stream<Item = T>>(
+    stream: S,
+) -> <T as ArrayType<U>>::Array<VecBuffer, offset::NA, union::NA>
+where
+    <T as ArrayType<U>>::Array<VecBuffer, offset::NA, union::NA>: Default + Extend<T>,
+{
+    let mut pinned = pin!(stream);
+    let mut array = <T as ArrayType<U>>::Array::<VecBuffer, offset::NA, union::NA>::default();
+    while let Some(item) = future::poll_fn(|cx| pinned.as_mut().poll_next(cx)).await {
+        array.extend(iter::once(item));
+    }
+    array
+}
+
+/// Returns a stream of arrays with `chunk_rows` items of the stream. The
+/// last array has the remaining items.
+///
+/// See [`ChunkStream`].
+///
+/// # Panics
+///
+/// Panics if `chunk_rows` is zero.
+pub fn chunk_stream<A: Default + Extend<<S as Stream>::Item>, S: Stream>(
+    stream: S,
+    chunk_rows: usize,
+) -> ChunkStream<S, A> {
+    assert_ne!(chunk_rows, 0, "chunk rows should be non-zero");
+    ChunkStream::new(stream, ChunkPolicy::max_rows(chunk_rows))
+}
+
+/// A stream of arrays with the items of a stream, cut into chunks by a
+/// [`ChunkPolicy`].
+///
+/// The stream must be [`Unpin`]; use `Box::pin` for other streams.
+#[derive(Debug)]
+pub struct ChunkStream<S, A> {
+    /// The stream of items.
+    stream: S,
+    /// The builder of the chunks.
+    builder: ChunkingBuilder<A>,
+    /// Whether the stream of items is exhausted.
+    done: bool,
+}
+
+impl<S: Stream, A: Default> ChunkStream<S, A> {
+    /// Returns a new [`ChunkStream`] that cuts the items of `stream` into
+    /// chunks using the given policy.
+    pub fn new(stream: S, policy: ChunkPolicy) -> Self {
+        Self {
+            stream,
+            builder: ChunkingBuilder::new(policy),
+            done: false,
+        }
+    }
+
+    /// Returns the in-progress chunk.
+    pub fn current(&self) -> &A {
+        self.builder.current()
+    }
+}
+
+impl<
+        S: Stream + Unpin,
+        A: Default + Extend<<S as Stream>::Item> + Length + MemoryUsage + Unpin,
+    > Stream for ChunkStream<S, A>
+{
+    type Item = A;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        while !this.done {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    this.builder.append(item);
+                    if let Some(chunk) = this.builder.pop_chunk() {
+                        return Poll::Ready(Some(chunk));
+                    }
+                }
+                Poll::Ready(None) => this.done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        let policy = this.builder.policy();
+        Poll::Ready(
+            core::mem::replace(&mut this.builder, ChunkingBuilder::new(policy))
+                .finish()
+                .pop(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{StringArray, Uint32Array};
+    use std::{
+        cell::Cell,
+        future::Future,
+        rc::Rc,
+        sync::Arc,
+        task::{Wake, Waker},
+        vec::Vec,
+    };
+
+    /// A waker that does nothing.
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// A stream that is pending before every item.
+    struct PendingStream<I> {
+        /// The items.
+        items: I,
+        /// Whether the next poll yields an item.
+        ready: bool,
+        /// Set when this stream is dropped.
+        dropped: Rc<Cell<bool>>,
+    }
+
+    impl<I> PendingStream<I> {
+        /// Returns a new [`PendingStream`] for the items.
+        fn new(items: I) -> Self {
+            Self {
+                items,
+                ready: false,
+                dropped: Rc::default(),
+            }
+        }
+    }
+
+    impl<I: Iterator + Unpin> Stream for PendingStream<I> {
+        type Item = I::Item;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+            this.ready = !this.ready;
+            if this.ready {
+                Poll::Ready(this.items.next())
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    impl<I> Drop for PendingStream<I> {
+        fn drop(&mut self) {
+            self.dropped.set(true);
+        }
+    }
+
+    /// Polls the future until it is ready.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut pinned = pin!(fut);
+        loop {
+            if let Poll::Ready(output) = pinned.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    /// Polls the stream until it is exhausted.
+    fn collect_chunks<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+        block_on(async {
+            let mut chunks = Vec::new();
+            while let Some(chunk) = future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await
+            {
+                chunks.push(chunk);
+            }
+            chunks
+        })
+    }
+
+    #[test]
+    fn collect() {
+        let array = block_on(collect_array(PendingStream::new(0..5_u32)));
+        assert_eq!(array, [0, 1, 2, 3, 4]);
+        let strings = block_on(collect_array(PendingStream::new(
+            ["a", "bc"].into_iter().map(str::to_owned),
+        )));
+        assert_eq!(strings.into_iter().collect::<Vec<_>>(), ["a", "bc"]);
+        let nullable = block_on(collect_array(PendingStream::new(
+            [Some(1_u32), None].into_iter(),
+        )));
+        assert_eq!(nullable, [Some(1), None]);
+    }
+
+    #[test]
+    fn chunks() {
+        let chunks = collect_chunks(chunk_stream::<Uint32Array, _>(
+            PendingStream::new(0..7_u32),
+            3,
+        ));
+        assert_eq!(
+            chunks.iter().map(Length::len).collect::<Vec<_>>(),
+            [3, 3, 1]
+        );
+        assert!(chunks.iter().flatten().copied().eq(0..7));
+        let exact = collect_chunks(chunk_stream::<Uint32Array, _>(
+            PendingStream::new(0..6_u32),
+            3,
+        ));
+        assert_eq!(exact.len(), 2);
+        assert!(collect_chunks(chunk_stream::<Uint32Array, _>(
+            PendingStream::new(0..0_u32),
+            3
+        ))
+        .is_empty());
+    }
+
+    #[test]
+    fn cancel_collect() {
+        let stream = PendingStream::new(["a", "b", "c"].into_iter().map(str::to_owned));
+        let dropped = Rc::clone(&stream.dropped);
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = Box::pin(collect_array(stream));
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        assert!(!dropped.get());
+        drop(fut);
+        assert!(dropped.get());
+    }
+
+    #[test]
+    fn cancel_chunk() {
+        let mut stream = chunk_stream::<StringArray, _>(
+            PendingStream::new(["a", "b", "c"].into_iter().map(str::to_owned)),
+            2,
+        );
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        // Poll until the first item is in the in-progress chunk, then stop.
+        assert!(Pin::new(&mut stream).poll_next(&mut cx).is_pending());
+        assert_eq!(stream.current().len(), 1);
+        // Resuming does not lose or duplicate items.
+        let chunks = collect_chunks(&mut stream);
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| chunk.iter().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [vec!["a", "b"], vec!["c"]]
+        );
+    }
+}
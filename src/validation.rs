@@ -0,0 +1,190 @@
+//! Validation of structural invariants.
+
+use crate::LengthMismatch;
+use core::fmt::{self, Display, Formatter};
+
+/// Checking the structural invariants of a value.
+///
+/// Values constructed via the safe APIs of this crate always satisfy their
+/// invariants. Values assembled from raw or foreign parts should be validated
+/// before use.
+pub trait Validate {
+    /// Checks all structural invariants of this value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] describing the first violated invariant.
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// The error returned when a structural invariant is violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The length of the validity bitmap does not match the length of the
+    /// data.
+    ValidityLength(LengthMismatch),
+    /// The buffer of a bitmap has fewer bytes than required for its number of
+    /// bits and offset.
+    BitmapBuffer {
+        /// The number of bytes required.
+        required: usize,
+        /// The number of bytes in the buffer.
+        actual: usize,
+    },
+    /// The offsets buffer has no values. It needs at least one value, even
+    /// when there are no slots.
+    OffsetsEmpty,
+    /// An offset value is negative or exceeds the length of the data.
+    OffsetOutOfBounds {
+        /// The index of the offset value.
+        index: usize,
+        /// The length of the data.
+        len: usize,
+    },
+    /// An offset value is smaller than the previous offset value.
+    OffsetDecreasing {
+        /// The index of the offset value.
+        index: usize,
+    },
+    /// An offset value of a string array is not on a UTF-8 character
+    /// boundary of the data.
+    Utf8Boundary {
+        /// The index of the offset value.
+        index: usize,
+    },
+    /// The data of a string array is not valid UTF-8.
+    InvalidUtf8 {
+        /// The index of the first byte of the invalid sequence in the data.
+        index: usize,
+    },
+    /// A type id of a union array is not mapped to a variant.
+    UnmappedTypeId {
+        /// The index of the type id.
+        index: usize,
+        /// The type id.
+        type_id: i8,
+    },
+    /// The number of offsets of a dense union array does not match the
+    /// number of type ids.
+    UnionOffsetsLength(LengthMismatch),
+    /// The length of a variant array of a sparse union array does not match
+    /// the number of type ids.
+    UnionVariantLength {
+        /// The index of the variant array.
+        variant: usize,
+        /// The number of type ids and the length of the variant array.
+        mismatch: LengthMismatch,
+    },
+    /// A key of a dictionary array is out of bounds of its dictionary.
+    DictionaryKeyOutOfBounds {
+        /// The index of the key.
+        index: usize,
+        /// The length of the dictionary.
+        len: usize,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::ValidityLength(LengthMismatch { expected, actual }) => write!(
+                f,
+                "validity bitmap length mismatch: expected {expected}, got {actual}"
+            ),
+            Self::BitmapBuffer { required, actual } => write!(
+                f,
+                "bitmap buffer too small: required {required} bytes, got {actual}"
+            ),
+            Self::OffsetsEmpty => write!(f, "offsets buffer is empty"),
+            Self::OffsetOutOfBounds { index, len } => write!(
+                f,
+                "offset at index {index} out of bounds for data length {len}"
+            ),
+            Self::OffsetDecreasing { index } => write!(
+                f,
+                "offset at index {index} is smaller than the previous offset"
+            ),
+            Self::Utf8Boundary { index } => write!(
+                f,
+                "offset at index {index} is not on a UTF-8 character boundary"
+            ),
+            Self::InvalidUtf8 { index } => {
+                write!(f, "invalid UTF-8 sequence at data index {index}")
+            }
+            Self::UnmappedTypeId { index, type_id } => write!(
+                f,
+                "type id {type_id} at index {index} is not mapped to a variant"
+            ),
+            Self::UnionOffsetsLength(LengthMismatch { expected, actual }) => write!(
+                f,
+                "union offsets length mismatch: expected {expected}, got {actual}"
+            ),
+            Self::UnionVariantLength {
+                variant,
+                mismatch: LengthMismatch { expected, actual },
+            } => write!(
+                f,
+                "union variant {variant} length mismatch: expected {expected}, got {actual}"
+            ),
+            Self::DictionaryKeyOutOfBounds { index, len } => write!(
+                f,
+                "dictionary key at index {index} out of bounds for dictionary length {len}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Self::ValidityLength(ref mismatch)
+            | Self::UnionOffsetsLength(ref mismatch)
+            | Self::UnionVariantLength { ref mismatch, .. } => Some(mismatch),
+            Self::BitmapBuffer { .. }
+            | Self::OffsetsEmpty
+            | Self::OffsetOutOfBounds { .. }
+            | Self::OffsetDecreasing { .. }
+            | Self::Utf8Boundary { .. }
+            | Self::InvalidUtf8 { .. }
+            | Self::UnmappedTypeId { .. }
+            | Self::DictionaryKeyOutOfBounds { .. } => None,
+        }
+    }
+}
+
+impl From<LengthMismatch> for ValidationError {
+    fn from(value: LengthMismatch) -> Self {
+        Self::ValidityLength(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ValidationError::from(LengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+            .to_string(),
+            "validity bitmap length mismatch: expected 2, got 1"
+        );
+        assert_eq!(
+            ValidationError::BitmapBuffer {
+                required: 2,
+                actual: 1
+            }
+            .to_string(),
+            "bitmap buffer too small: required 2 bytes, got 1"
+        );
+        assert_eq!(
+            ValidationError::OffsetOutOfBounds { index: 3, len: 2 }.to_string(),
+            "offset at index 3 out of bounds for data length 2"
+        );
+    }
+}
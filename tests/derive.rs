@@ -220,7 +220,7 @@ mod tests {
                 use narrow::{
                     array::{StructArray, VariableSizeListArray},
                     bitmap::{BitmapRef, ValidityBitmap},
-                    ArrayType, Length,
+                    ArrayType, Length, MemoryUsage,
                 };
 
                 #[derive(ArrayType)]
@@ -334,6 +334,9 @@ mod tests {
                     ];
                     let array = input.into_iter().collect::<StructArray<Bar, true>>();
                     assert_eq!(array.len(), 2);
+                    // 8 bytes for a, 2 bitmaps of 1 byte for b, 1 byte for the
+                    // validity of c and 1 byte for the struct validity
+                    assert_eq!(array.buffer_bytes(), 12);
                 }
 
                 #[test]
@@ -0,0 +1,60 @@
+//! Array building in a `no_std` crate.
+//!
+//! Run with `cargo test --no-default-features --test no_std` to check that the
+//! arrays, bitmaps and buffers work without the `std` feature.
+
+#![no_std]
+
+extern crate alloc;
+// The test harness requires `std`.
+extern crate std;
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec, vec::Vec};
+    use narrow::{
+        array::{BooleanArray, StringArray, Uint32Array, Uint8Array, VariableSizeListArray},
+        bitmap::{Bitmap, ValidityBitmap},
+        Index, Length,
+    };
+
+    #[test]
+    fn fixed_size_primitive() {
+        let array = [Some(1_u32), None, Some(3)]
+            .into_iter()
+            .collect::<Uint32Array<true>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.index(2), Some(Some(&3)));
+        assert_eq!(
+            array.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(3)]
+        );
+    }
+
+    #[test]
+    fn bitmap() {
+        let mut bitmap = [true, false, true].into_iter().collect::<Bitmap>();
+        bitmap.extend([false; 70]);
+        assert_eq!(bitmap.len(), 73);
+        assert_eq!(bitmap.count_ones(), 2);
+
+        let array = BooleanArray::<false>::from_bytes(vec![0b1010_1010, 0xff], 12).expect("valid");
+        assert_eq!(array.iter().filter(|&value| value).count(), 8);
+    }
+
+    #[test]
+    fn variable_size() {
+        let strings = ["a", "no_std"]
+            .into_iter()
+            .map(String::from)
+            .collect::<StringArray>();
+        assert_eq!(strings.len(), 2);
+        assert_eq!(strings.index_checked(1), "no_std");
+
+        let lists = [vec![1_u8, 2], vec![], vec![3]]
+            .into_iter()
+            .collect::<VariableSizeListArray<Uint8Array>>();
+        assert_eq!(lists.len(), 3);
+    }
+}